@@ -1,5 +1,5 @@
 import { renderJavaScriptUmiVisitor, renderJavaScriptVisitor, renderRustVisitor } from '@codoma/renderers';
-import { accountNode, arrayTypeNode, arrayValueNode, booleanTypeNode, bytesTypeNode, constantDiscriminatorNode, constantValueNode, createFromRoot, definedTypeLinkNode, definedTypeNode, instructionAccountNode, instructionArgumentNode, instructionNode, numberTypeNode, numberValueNode, optionTypeNode, prefixedCountNode, programNode, publicKeyTypeNode, publicKeyValueNode, REGISTERED_COUNT_NODE_KINDS, rootNode, sizeDiscriminatorNode, sizePrefixTypeNode, stringTypeNode, stringValueNode, structFieldTypeNode, structTypeNode, tupleTypeNode, tupleValueNode } from "codoma"
+import { accountNode, arrayTypeNode, arrayValueNode, booleanTypeNode, bytesTypeNode, constantDiscriminatorNode, constantValueNode, createFromRoot, definedTypeLinkNode, definedTypeNode, enumEmptyVariantTypeNode, enumStructVariantTypeNode, enumTypeNode, errorNode, fixedSizeTypeNode, instructionAccountNode, instructionArgumentNode, instructionNode, numberTypeNode, numberValueNode, optionTypeNode, prefixedCountNode, programNode, publicKeyTypeNode, publicKeyValueNode, REGISTERED_COUNT_NODE_KINDS, rootNode, sizeDiscriminatorNode, sizePrefixTypeNode, stringTypeNode, stringValueNode, structFieldTypeNode, structTypeNode, tupleTypeNode, tupleValueNode } from "codoma"
 import path from "path";
 import fs from "fs";
 
@@ -19,6 +19,108 @@ const root = rootNode(
         accounts: [
             accountNode({
                 name: "class",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(7)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(7), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'authority', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'isPermissioned', type: booleanTypeNode() }),
+                    structFieldTypeNode({ name: 'isFrozen', type: booleanTypeNode() }),
+                    structFieldTypeNode({ name: 'isNonTransferable', type: booleanTypeNode() }),
+                    structFieldTypeNode({ name: 'policy', type: definedTypeLinkNode('classPolicy') }),
+                    structFieldTypeNode({ name: 'recordCount', type: numberTypeNode('u64') }),
+                    structFieldTypeNode({ name: 'tokenizedCount', type: numberTypeNode('u64') }),
+                    structFieldTypeNode({ name: 'legacyRecordCount', type: numberTypeNode('u64') }),
+                    structFieldTypeNode({ name: 'maxRecords', type: numberTypeNode('u64') }),
+                    structFieldTypeNode({ name: 'creationFee', type: numberTypeNode('u64') }),
+                    structFieldTypeNode({ name: 'treasury', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'merkleRoot', type: fixedSizeTypeNode(bytesTypeNode(), 32) }),
+                    structFieldTypeNode({ name: 'groupBump', type: numberTypeNode('u8') }),
+                    structFieldTypeNode({ name: 'schemaBump', type: numberTypeNode('u8') }),
+                    structFieldTypeNode({ name: 'name', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u8")) }),
+                    structFieldTypeNode({ name: 'metadata', type: stringTypeNode("utf8") }),
+                ])
+            }),
+            accountNode({
+                name: "record",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(8)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(8), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'ownerType', type: numberTypeNode('u8'), defaultValue: numberValueNode(0), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'owner', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'isFrozen', type: booleanTypeNode() }),
+                    structFieldTypeNode({ name: 'expiry', type: numberTypeNode("i64") }),
+                    structFieldTypeNode({ name: 'isRevoked', type: booleanTypeNode() }),
+                    structFieldTypeNode({ name: 'revocationReason', type: numberTypeNode('u16') }),
+                    structFieldTypeNode({ name: 'revokedAt', type: numberTypeNode("i64") }),
+                    structFieldTypeNode({ name: 'version', type: numberTypeNode('u64') }),
+                    structFieldTypeNode({ name: 'generation', type: numberTypeNode('u32') }),
+                    structFieldTypeNode({ name: 'hash', type: fixedSizeTypeNode(bytesTypeNode(), 32) }),
+                    structFieldTypeNode({ name: 'writeState', type: numberTypeNode('u8') }),
+                    structFieldTypeNode({ name: 'stagedLen', type: numberTypeNode('u32') }),
+                    structFieldTypeNode({ name: 'stagedPayer', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'contentType', type: numberTypeNode('u8') }),
+                    structFieldTypeNode({ name: 'mintBump', type: numberTypeNode('u8') }),
+                    structFieldTypeNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
+                    structFieldTypeNode({ name: 'data', type: bytesTypeNode() }),
+                ])
+            }),
+            accountNode({
+                name: "pendingClassAuthority",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(3)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(3), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'authority', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'pendingAuthority', type: publicKeyTypeNode() }),
+                ])
+            }),
+            accountNode({
+                name: "classDelegate",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(4)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(4), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'delegate', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'permissions', type: numberTypeNode('u8') }),
+                ])
+            }),
+            accountNode({
+                name: "recordDelegate",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(5)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(5), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'owner', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'generation', type: numberTypeNode('u32') }),
+                    structFieldTypeNode({ name: 'delegate', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'permissions', type: numberTypeNode('u8') }),
+                    structFieldTypeNode({ name: 'expiry', type: numberTypeNode("i64") }),
+                ])
+            }),
+            accountNode({
+                name: "classSchema",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(6)))
+                ],
+                data: structTypeNode([
+                    structFieldTypeNode({ name: 'discriminator', type: numberTypeNode('u8'), defaultValue: numberValueNode(6), defaultValueStrategy: 'omitted' }),
+                    structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                    structFieldTypeNode({ name: 'fields', type: arrayTypeNode(definedTypeLinkNode('schemaField'), prefixedCountNode(numberTypeNode("u8"))) }),
+                ])
+            }),
+            accountNode({
+                name: "legacyClass",
                 discriminators: [
                     constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(1)))
                 ],
@@ -32,7 +134,7 @@ const root = rootNode(
                 ])
             }),
             accountNode({
-                name: "record",
+                name: "legacyRecord",
                 discriminators: [
                     constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(2)))
                 ],
@@ -63,6 +165,9 @@ const root = rootNode(
                     }),
                     instructionArgumentNode({ name: 'isPermissioned', type: booleanTypeNode() }),
                     instructionArgumentNode({ name: 'isFrozen', type: booleanTypeNode() }),
+                    instructionArgumentNode({ name: 'isNonTransferable', type: booleanTypeNode() }),
+                    instructionArgumentNode({ name: 'maxRecords', type: numberTypeNode('u64') }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
                     instructionArgumentNode({ name: 'name', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u8")) }),
                     instructionArgumentNode({ name: 'metadata', type: stringTypeNode("utf8") }),
                 ],
@@ -136,48 +241,6 @@ const root = rootNode(
                     }),
                 ]
             }),
-            instructionNode({
-                name: "updateClassAuthority",
-                discriminators: [
-                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(2)))
-                ],
-                arguments: [
-                    instructionArgumentNode({
-                        name: 'discriminator',
-                        type: numberTypeNode('u8'),
-                        defaultValue: numberValueNode(2),
-                        defaultValueStrategy: 'omitted',
-                    }),
-                    instructionArgumentNode({ name: 'newAuthority', type: publicKeyTypeNode() }),
-                ],
-                accounts: [
-                    instructionAccountNode({
-                        name: "authority",
-                        isSigner: true,
-                        isWritable: true,
-                        docs: ["Authority used to update a class"]
-                    }),
-                    instructionAccountNode({
-                        name: "payer",
-                        isSigner: true,
-                        isWritable: true,
-                        docs: ["Account that will pay of get refunded for the class update"]
-                    }),
-                    instructionAccountNode({
-                        name: "class",
-                        isSigner: false,
-                        isWritable: true,
-                        docs: ["Class account to be updated"]
-                    }),
-                    instructionAccountNode({
-                        name: "systemProgram",
-                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
-                        isSigner: false,
-                        isWritable: false,
-                        docs: ["System Program used to extend our class account"]
-                    }),
-                ]
-            }),
             instructionNode({
                 name: "freezeClass",
                 discriminators: [
@@ -222,6 +285,8 @@ const root = rootNode(
                     instructionArgumentNode({ 
                         name: 'expiration', type: numberTypeNode("i64") 
                     }),
+                    instructionArgumentNode({ name: 'contentType', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
                     instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8"))}),
                     instructionArgumentNode({ name: 'data', type: bytesTypeNode() }),
                 ],
@@ -264,6 +329,27 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["Optional authority for permissioned classes"]
                     }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
+                    instructionAccountNode({
+                        name: "treasury",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Treasury account of the class, required if the class charges a creation fee"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -281,6 +367,8 @@ const root = rootNode(
                     instructionArgumentNode({ 
                         name: 'expiration', type: numberTypeNode("i64") 
                     }),
+                    instructionArgumentNode({ name: 'contentType', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
                     instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
                     instructionArgumentNode({ name: 'metadata', type: definedTypeLinkNode('metadata')})
                 ],
@@ -323,6 +411,27 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["Optional authority for permissioned classes"]
                     }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
+                    instructionAccountNode({
+                        name: "treasury",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Treasury account of the class, required if the class charges a creation fee"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -371,6 +480,27 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to extend our record account"]
                     }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
                 ]
             }),
             instructionNode({
@@ -419,6 +549,27 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to extend our record account"]
                     }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -467,6 +618,20 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to extend our record account"]
                     }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -498,11 +663,24 @@ const root = rootNode(
                     }),
                     instructionAccountNode({
                         name: "class",
-                        isOptional: true,
                         isSigner: false,
                         isWritable: false,
                         docs: ["Class account of the record"]
                     }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -539,9 +717,8 @@ const root = rootNode(
                     }),
                     instructionAccountNode({
                         name: "class",
-                        isOptional: true,
                         isSigner: false,
-                        isWritable: false,
+                        isWritable: true,
                         docs: ["Class account of the record"]
                     }),
                     instructionAccountNode({
@@ -558,6 +735,20 @@ const root = rootNode(
                         isWritable: true,
                         docs: ["Mint account for the tokenized record"]
                     }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
                 ],
             }),
             instructionNode({
@@ -593,6 +784,13 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["Class account of the record"]
                     }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
                 ]
             }),
             instructionNode({
@@ -607,6 +805,8 @@ const root = rootNode(
                         defaultValue: numberValueNode(10),
                         defaultValueStrategy: 'omitted',
                     }),
+                    instructionArgumentNode({ name: 'mintBump', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'groupBump', type: numberTypeNode('u8') }),
                 ],
                 accounts: [
                     instructionAccountNode({
@@ -642,7 +842,7 @@ const root = rootNode(
                     instructionAccountNode({
                         name: "class",
                         isSigner: false,
-                        isWritable: false,
+                        isWritable: true,
                         docs: ["Class account of the record"]
                     }),
                     instructionAccountNode({
@@ -678,6 +878,20 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["System Program used to create our token"]
                     }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
                 ]
             }),
             instructionNode({
@@ -732,6 +946,13 @@ const root = rootNode(
                         isWritable: false,
                         docs: ["Token2022 Program used to freeze/unfreeze the tokenized record"]
                     }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
                 ]
             }),
             instructionNode({
@@ -787,10 +1008,16 @@ const root = rootNode(
                     }),
                     instructionAccountNode({
                         name: "class",
-                        isOptional: true,
                         isSigner: false,
                         isWritable: false,
                         docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
                     }),    
                 ],
             }),
@@ -848,27 +1075,1696 @@ const root = rootNode(
                     instructionAccountNode({
                         name: "class",
                         isSigner: false,
-                        isWritable: false,
-                        isOptional: true,
+                        isWritable: true,
                         docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional class delegate account of the authority"]
                     }),    
                 ],
-            })
-        ],
-        definedTypes: [
-            definedTypeNode({
-                name: "metadata",
-                docs: "Token22 Metadata Extension compatible Metadata format",
-                type: structTypeNode([
-                    structFieldTypeNode({
-                        name: 'name',
-                        type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32"))
+            }),
+            instructionNode({
+                name: "closeExpiredRecord",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(14)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(14),
+                        defaultValueStrategy: 'omitted',
                     }),
-                    structFieldTypeNode({
-                        name: 'symbol', 
-                        type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32")),
-                        defaultValue: stringValueNode("SRS"),
-                        defaultValueStrategy: 'optional'
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["The expired record account to be closed"]
+                    }),
+                    instructionAccountNode({
+                        name: "owner",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["The owner of the record that will get refunded"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account of the record"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "proposeClassAuthority",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(15)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(15),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'newAuthority', type: publicKeyTypeNode() }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Current authority of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the pending authority account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account whose authority is being transferred"]
+                    }),
+                    instructionAccountNode({
+                        name: "pendingClassAuthority",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Pending authority account of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create the pending authority account"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "acceptClassAuthority",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(16)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(16),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "newAuthority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Proposed authority of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Current authority of the class that will get refunded for the pending authority account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account whose authority is being transferred"]
+                    }),
+                    instructionAccountNode({
+                        name: "pendingClassAuthority",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Pending authority account of the class"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "cancelClassAuthorityTransfer",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(17)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(17),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Current authority of the class that will get refunded for the pending authority account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account with a pending authority transfer"]
+                    }),
+                    instructionAccountNode({
+                        name: "pendingClassAuthority",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Pending authority account of the class"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "addClassDelegate",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(18)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(18),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'delegate', type: publicKeyTypeNode() }),
+                    instructionArgumentNode({ name: 'permissions', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the class delegate account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account the delegate acts on"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class delegate account of the delegate"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create the class delegate account"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "revokeClassDelegate",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(19)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(19),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Authority of the class that will get refunded for the class delegate account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account the delegate acts on"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class delegate account to be revoked"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "approveRecordDelegate",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(20)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(20),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'delegate', type: publicKeyTypeNode() }),
+                    instructionArgumentNode({ name: 'permissions', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'expiry', type: numberTypeNode("i64") }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "owner",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Owner of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the record delegate account"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Record account the delegate acts on"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record delegate account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create the record delegate account"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "revokeRecordDelegate",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(21)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(21),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "owner",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Record owner or owner that approved the delegate, it will get refunded for the record delegate account"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Record account the delegate acts on"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record delegate account to be revoked"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "setClassSchema",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(22)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(22),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'fields', type: arrayTypeNode(definedTypeLinkNode('schemaField'), prefixedCountNode(numberTypeNode("u8"))) }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay or get refunded for the schema account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account the schema applies to"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Schema account of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create or resize the schema account"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "updateClassPolicy",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(23)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(23),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'policy', type: definedTypeLinkNode('classPolicy') }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account to be updated"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "revokeRecord",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(24)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(24),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'reason', type: numberTypeNode('u16') }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority or class delegate with the revoke permission"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be revoked"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Class delegate account of the authority"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "compareAndSwapRecordData",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(25)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(25),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'expectedVersion', type: numberTypeNode('u64') }),
+                    instructionArgumentNode({ name: 'data', type: bytesTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Record owner or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay of get refunded for the record update"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be updated"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to extend our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "compareAndSwapRecordExpiry",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(26)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(26),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'expectedVersion', type: numberTypeNode('u64') }),
+                    instructionArgumentNode({ name: 'expiry', type: numberTypeNode("i64") }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Record owner or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay of get refunded for the record update"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be updated"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to extend our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "patchRecordData",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(27)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(27),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'offset', type: numberTypeNode('u32') }),
+                    instructionArgumentNode({ name: 'truncate', type: booleanTypeNode() }),
+                    instructionArgumentNode({ name: 'patch', type: bytesTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Record owner or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay of get refunded for the record update"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be patched"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "createBufferedRecord",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(28)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(28),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'expiration', type: numberTypeNode("i64") }),
+                    instructionArgumentNode({ name: 'contentType', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
+                    instructionArgumentNode({ name: 'data', type: bytesTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "owner",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Owner of the new record"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account for the record to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
+                    instructionAccountNode({
+                        name: "treasury",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Treasury account of the class, required if the class charges a creation fee"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "beginRecordWrite",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(29)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(29),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Record owner or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay of get refunded for the record update"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account being written"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "writeRecordChunk",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(30)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(30),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'offset', type: numberTypeNode('u32') }),
+                    instructionArgumentNode({ name: 'chunk', type: bytesTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Record owner or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay of get refunded for the record update"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account being written"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "finalizeRecordWrite",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(31)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(31),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Record owner or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay of get refunded for the record update"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account being written"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "updateClassFee",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(32)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(32),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'creationFee', type: numberTypeNode('u64') }),
+                    instructionArgumentNode({ name: 'treasury', type: publicKeyTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account to be updated"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "updateClassMerkleRoot",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(33)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(33),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'merkleRoot', type: fixedSizeTypeNode(bytesTypeNode(), 32) }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account to be updated"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "createRecordWithProof",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(34)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(34),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'proof', type: arrayTypeNode(fixedSizeTypeNode(bytesTypeNode(), 32), prefixedCountNode(numberTypeNode("u8"))) }),
+                    instructionArgumentNode({ name: 'expiration', type: numberTypeNode("i64") }),
+                    instructionArgumentNode({ name: 'contentType', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
+                    instructionArgumentNode({ name: 'data', type: bytesTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "owner",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Owner of the new record, in the class allowlist"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account for the record to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Unused authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Unused class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
+                    instructionAccountNode({
+                        name: "treasury",
+                        isSigner: false,
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ["Treasury account of the class, required if the class charges a creation fee"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "createRecordFromSignature",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(35)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(35),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'expiration', type: numberTypeNode("i64") }),
+                    instructionArgumentNode({ name: 'contentType', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
+                    instructionArgumentNode({ name: 'data', type: bytesTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "owner",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Owner of the new record"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account for the record to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "instructionsSysvar",
+                        defaultValue: publicKeyValueNode('Sysvar1nstructions1111111111111111111111111', 'sysvarInstructions'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Instructions sysvar used to read the Ed25519 instruction"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
+                    instructionAccountNode({
+                        name: "treasury",
+                        isSigner: false,
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ["Treasury account of the class, required if the class charges a creation fee"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "batchCreateRecords",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(36)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(36),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'records', type: arrayTypeNode(definedTypeLinkNode('batchRecord'), prefixedCountNode(numberTypeNode("u8"))) }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the record accounts"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account for the records to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create our record accounts"]
+                    }),
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
+                    instructionAccountNode({
+                        name: "treasury",
+                        isSigner: false,
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ["Treasury account of the class, required if the class charges a creation fee"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "batchFreezeRecords",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(37)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(37),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'isFrozen', type: booleanTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority or a class delegate with the freeze permission"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Class account of the records"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "batchDeleteRecords",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(38)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(38),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Class authority or a class delegate with the delete permission"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Account that will get refunded for the record accounts"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account of the records"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "closeClass",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(39)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(39),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "destination",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Account that will get refunded for the class and group accounts"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account to be closed"]
+                    }),
+                    instructionAccountNode({
+                        name: "group",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Group mint account of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "token2022",
+                        defaultValue: publicKeyValueNode('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb', 'token2022'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Token2022 Program used to close the group mint"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "migrateRecordClass",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(40)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(40),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority of the current class or a class delegate with the delete permission"]
+                    }),
+                    instructionAccountNode({
+                        name: "newAuthority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority of the new class or a class delegate with the create permission"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the new record account and get refunded for the old one"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be migrated"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Current class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "newRecord",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account to be created in the new class"]
+                    }),
+                    instructionAccountNode({
+                        name: "newClass",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account the record is migrated to"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to create the new record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the new class, required if the new class has a schema"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "newClassDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the new authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "mint",
+                        isSigner: false,
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ["Mint account of the record token, required if the record is tokenized"]
+                    }),
+                    instructionAccountNode({
+                        name: "tokenAccount",
+                        isSigner: false,
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ["Token account holding the record token"]
+                    }),
+                    instructionAccountNode({
+                        name: "token2022",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Token2022 Program used to burn the record token"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "recreateRecord",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(41)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(41),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'expiration', type: numberTypeNode("i64") }),
+                    instructionArgumentNode({ name: 'contentType', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'bump', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
+                    instructionArgumentNode({ name: 'data', type: bytesTypeNode() }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "owner",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Owner of the new record"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account for the record to be created"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Tombstone of the deleted record to be created again"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "schema",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Schema account of the class, required if the class has a schema"]
+                    }),
+                    instructionAccountNode({
+                        name: "treasury",
+                        isSigner: false,
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ["Treasury account of the class, required if the class charges a creation fee"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "cancelRecordWrite",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(42)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(42),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Record owner or class authority for permissioned classes"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Account that will get refunded for the record account or the staged data"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Record account being written"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account of the record"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "classDelegate",
+                        isSigner: false,
+                        isWritable: false,
+                        isOptional: true,
+                        docs: ["Optional class delegate account of the authority"]
+                    }),
+                    instructionAccountNode({
+                        name: "recordDelegate",
+                        isOptional: true,
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Optional record delegate account of the authority"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "migrateClassLayout",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(43)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(43),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'groupBump', type: numberTypeNode('u8') }),
+                    instructionArgumentNode({ name: 'legacyRecordCount', type: numberTypeNode('u64') }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "authority",
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ["Authority of the class"]
+                    }),
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the larger class account"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Legacy class account to be migrated"]
+                    }),
+                    instructionAccountNode({
+                        name: "group",
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["Group mint account of the class, it may not be initialized"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our class account"]
+                    }),
+                ]
+            }),
+            instructionNode({
+                name: "migrateRecordLayout",
+                discriminators: [
+                    constantDiscriminatorNode(constantValueNode(numberTypeNode("u8"), numberValueNode(44)))
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'discriminator',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(44),
+                        defaultValueStrategy: 'omitted',
+                    }),
+                    instructionArgumentNode({ name: 'mintBump', type: numberTypeNode('u8') }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: "payer",
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ["Account that will pay for the larger record account"]
+                    }),
+                    instructionAccountNode({
+                        name: "record",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Legacy record account to be migrated"]
+                    }),
+                    instructionAccountNode({
+                        name: "class",
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Class account of the record, already migrated"]
+                    }),
+                    instructionAccountNode({
+                        name: "systemProgram",
+                        defaultValue: publicKeyValueNode('11111111111111111111111111111111', 'systemProgram'),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ["System Program used to resize our record account"]
+                    }),
+                ]
+            })
+        ],
+        definedTypes: [
+            definedTypeNode({
+                name: "metadata",
+                docs: "Token22 Metadata Extension compatible Metadata format",
+                type: structTypeNode([
+                    structFieldTypeNode({
+                        name: 'name',
+                        type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32"))
+                    }),
+                    structFieldTypeNode({
+                        name: 'symbol', 
+                        type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32")),
+                        defaultValue: stringValueNode("SRS"),
+                        defaultValueStrategy: 'optional'
                     }),
                     structFieldTypeNode({
                         name: 'uri', 
@@ -896,7 +2792,266 @@ const root = rootNode(
                         type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u32"))
                     })
                 ])
+            }),
+            definedTypeNode({
+                name: "policy",
+                docs: "Who may perform an action on the records of a class",
+                type: enumTypeNode([
+                    enumEmptyVariantTypeNode('owner'),
+                    enumEmptyVariantTypeNode('authority'),
+                    enumEmptyVariantTypeNode('either')
+                ])
+            }),
+            definedTypeNode({
+                name: "classPolicy",
+                docs: "Policy of each action on the records of a class",
+                type: structTypeNode([
+                    structFieldTypeNode({ name: 'updateData', type: definedTypeLinkNode('policy') }),
+                    structFieldTypeNode({ name: 'updateExpiry', type: definedTypeLinkNode('policy') }),
+                    structFieldTypeNode({ name: 'transfer', type: definedTypeLinkNode('policy') }),
+                    structFieldTypeNode({ name: 'delete', type: definedTypeLinkNode('policy') }),
+                    structFieldTypeNode({ name: 'tokenize', type: definedTypeLinkNode('policy') })
+                ])
+            }),
+            definedTypeNode({
+                name: "schemaFieldType",
+                docs: "Type of a class schema field",
+                type: enumTypeNode([
+                    enumEmptyVariantTypeNode('u64'),
+                    enumEmptyVariantTypeNode('i64'),
+                    enumEmptyVariantTypeNode('bool'),
+                    enumEmptyVariantTypeNode('pubkey'),
+                    enumEmptyVariantTypeNode('string'),
+                    enumEmptyVariantTypeNode('bytes')
+                ])
+            }),
+            definedTypeNode({
+                name: "schemaField",
+                docs: "Field of a class schema, string and bytes fields are limited to maxLen bytes",
+                type: structTypeNode([
+                    structFieldTypeNode({ name: 'fieldType', type: definedTypeLinkNode('schemaFieldType') }),
+                    structFieldTypeNode({ name: 'isRequired', type: booleanTypeNode() }),
+                    structFieldTypeNode({ name: 'maxLen', type: numberTypeNode('u16') }),
+                    structFieldTypeNode({ name: 'name', type: sizePrefixTypeNode(stringTypeNode("utf8"), numberTypeNode("u8")) })
+                ])
+            }),
+            definedTypeNode({
+                name: "batchRecord",
+                docs: "Record created by BatchCreateRecords, with its owner and record accounts as remaining accounts",
+                type: structTypeNode([
+                    structFieldTypeNode({ name: 'expiration', type: numberTypeNode("i64") }),
+                    structFieldTypeNode({ name: 'contentType', type: numberTypeNode('u8') }),
+                    structFieldTypeNode({ name: 'bump', type: numberTypeNode('u8') }),
+                    structFieldTypeNode({ name: 'seed', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u8")) }),
+                    structFieldTypeNode({ name: 'data', type: sizePrefixTypeNode(bytesTypeNode(), numberTypeNode("u32")) })
+                ])
+            }),
+            definedTypeNode({
+                name: "recordServiceEvent",
+                docs: "Events emitted by the program through sol_log_data",
+                type: enumTypeNode([
+                    enumStructVariantTypeNode('classCreated', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'authority', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'isPermissioned', type: booleanTypeNode() }),
+                        structFieldTypeNode({ name: 'isFrozen', type: booleanTypeNode() }),
+                        structFieldTypeNode({ name: 'isNonTransferable', type: booleanTypeNode() }),
+                        structFieldTypeNode({ name: 'maxRecords', type: numberTypeNode("u64") })
+                    ])),
+                    enumStructVariantTypeNode('classMetadataUpdated', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('classAuthorityUpdated', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'authority', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('classFrozen', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'isFrozen', type: booleanTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('recordCreated', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'owner', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'expiry', type: numberTypeNode("i64") })
+                    ])),
+                    enumStructVariantTypeNode('recordDataUpdated', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'version', type: numberTypeNode("u64") }),
+                        structFieldTypeNode({ name: 'hash', type: fixedSizeTypeNode(bytesTypeNode(), 32) })
+                    ])),
+                    enumStructVariantTypeNode('recordExpiryUpdated', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'expiry', type: numberTypeNode("i64") }),
+                        structFieldTypeNode({ name: 'version', type: numberTypeNode("u64") }),
+                        structFieldTypeNode({ name: 'hash', type: fixedSizeTypeNode(bytesTypeNode(), 32) })
+                    ])),
+                    enumStructVariantTypeNode('recordTransferred', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'newOwner', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('recordDeleted', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('recordFrozen', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'isFrozen', type: booleanTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('recordTokenized', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'mint', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'owner', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('tokenizedRecordFrozen', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'mint', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'isFrozen', type: booleanTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('tokenizedRecordTransferred', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'mint', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'newTokenAccount', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('recordBurned', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'mint', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('expiredRecordClosed', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'owner', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('classAuthorityProposed', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'pendingAuthority', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('classAuthorityTransferCancelled', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('classDelegateUpdated', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'delegate', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'permissions', type: numberTypeNode("u8") })
+                    ])),
+                    enumStructVariantTypeNode('classDelegateRevoked', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'delegate', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('recordDelegateApproved', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'delegate', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'permissions', type: numberTypeNode("u8") }),
+                        structFieldTypeNode({ name: 'expiry', type: numberTypeNode("i64") })
+                    ])),
+                    enumStructVariantTypeNode('recordDelegateRevoked', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'delegate', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('classSchemaUpdated', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('classPolicyUpdated', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'updateData', type: numberTypeNode("u8") }),
+                        structFieldTypeNode({ name: 'updateExpiry', type: numberTypeNode("u8") }),
+                        structFieldTypeNode({ name: 'transfer', type: numberTypeNode("u8") }),
+                        structFieldTypeNode({ name: 'delete', type: numberTypeNode("u8") }),
+                        structFieldTypeNode({ name: 'tokenize', type: numberTypeNode("u8") })
+                    ])),
+                    enumStructVariantTypeNode('recordRevoked', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'reason', type: numberTypeNode("u16") }),
+                        structFieldTypeNode({ name: 'revokedAt', type: numberTypeNode("i64") })
+                    ])),
+                    enumStructVariantTypeNode('classFeeUpdated', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'creationFee', type: numberTypeNode("u64") }),
+                        structFieldTypeNode({ name: 'treasury', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('classMerkleRootUpdated', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'merkleRoot', type: fixedSizeTypeNode(bytesTypeNode(), 32) })
+                    ])),
+                    enumStructVariantTypeNode('classClosed', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('recordMigrated', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'newRecord', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'newClass', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('recordRecreated', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'owner', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'expiry', type: numberTypeNode("i64") }),
+                        structFieldTypeNode({ name: 'generation', type: numberTypeNode("u32") })
+                    ])),
+                    enumStructVariantTypeNode('recordWriteStarted', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('recordChunkWritten', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'offset', type: numberTypeNode("u32") }),
+                        structFieldTypeNode({ name: 'len', type: numberTypeNode("u32") })
+                    ])),
+                    enumStructVariantTypeNode('recordWriteCancelled', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'isDeleted', type: booleanTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('classLayoutMigrated', structTypeNode([
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() })
+                    ])),
+                    enumStructVariantTypeNode('recordLayoutMigrated', structTypeNode([
+                        structFieldTypeNode({ name: 'record', type: publicKeyTypeNode() }),
+                        structFieldTypeNode({ name: 'class', type: publicKeyTypeNode() })
+                    ]))
+                ])
             })
+        ],
+        errors: [
+            errorNode({ code: 0, name: 'invalidAccountDiscriminator', message: 'The account discriminator does not match the expected account type' }),
+            errorNode({ code: 1, name: 'invalidAuthority', message: 'The authority is not the class authority' }),
+            errorNode({ code: 2, name: 'classFrozen', message: 'The class is frozen' }),
+            errorNode({ code: 3, name: 'classNotPermissioned', message: 'The class is not permissioned' }),
+            errorNode({ code: 4, name: 'recordFrozen', message: 'The record is frozen' }),
+            errorNode({ code: 5, name: 'classMismatch', message: 'The class does not match the class of the record' }),
+            errorNode({ code: 6, name: 'notOwnerOrDelegate', message: 'The authority is neither the record owner nor a valid delegate' }),
+            errorNode({ code: 7, name: 'invalidOwner', message: 'The owner account does not match the owner of the record' }),
+            errorNode({ code: 8, name: 'invalidOwnerType', message: 'The record owner type does not allow this operation' }),
+            errorNode({ code: 9, name: 'missingClass', message: 'The class account is required but was not provided' }),
+            errorNode({ code: 10, name: 'missingMint', message: 'The mint account is required but was not provided' }),
+            errorNode({ code: 11, name: 'invalidMint', message: 'The mint account is not the mint of the record' }),
+            errorNode({ code: 12, name: 'mintSupplyNotZero', message: 'The mint supply is not zero' }),
+            errorNode({ code: 13, name: 'invalidTokenAccount', message: 'The token account is not valid for the record' }),
+            errorNode({ code: 14, name: 'invalidGroup', message: 'The group account is not the group of the class' }),
+            errorNode({ code: 15, name: 'invalidPda', message: 'The program derived address could not be derived' }),
+            errorNode({ code: 16, name: 'recordExpired', message: 'The record is expired' }),
+            errorNode({ code: 17, name: 'recordNotExpired', message: 'The record is not expired' }),
+            errorNode({ code: 18, name: 'seedTooLong', message: 'The seed exceeds the maximum length' }),
+            errorNode({ code: 19, name: 'nameTooLong', message: 'The name exceeds the maximum length' }),
+            errorNode({ code: 20, name: 'metadataTooLong', message: 'The metadata exceeds the maximum length' }),
+            errorNode({ code: 21, name: 'accountTooSmall', message: 'The account is too small for the data it has to hold' }),
+            errorNode({ code: 22, name: 'accountTooLarge', message: 'The account would exceed the maximum account size' }),
+            errorNode({ code: 23, name: 'invalidPendingAuthority', message: 'The signer is not the pending authority of the class' }),
+            errorNode({ code: 24, name: 'invalidClassDelegate', message: 'The signer is not the delegate of the class delegate account' }),
+            errorNode({ code: 25, name: 'missingPermission', message: 'The class delegate does not have the required permission' }),
+            errorNode({ code: 26, name: 'invalidRecordDelegate', message: 'The record delegate account does not match the record, its owner or the signer' }),
+            errorNode({ code: 27, name: 'recordDelegateExpired', message: 'The record delegate is expired' }),
+            errorNode({ code: 28, name: 'invalidSchema', message: 'The schema account or its field definitions are invalid' }),
+            errorNode({ code: 29, name: 'schemaMismatch', message: 'The record data does not match the class schema' }),
+            errorNode({ code: 30, name: 'invalidPolicy', message: 'The class policy is invalid' }),
+            errorNode({ code: 31, name: 'nonTransferable', message: 'The records of the class are non-transferable' }),
+            errorNode({ code: 32, name: 'recordRevoked', message: 'The record is revoked' }),
+            errorNode({ code: 33, name: 'recordVersionMismatch', message: 'The record changed since the expected version' }),
+            errorNode({ code: 34, name: 'recordWriting', message: 'The record data is being written' }),
+            errorNode({ code: 35, name: 'recordNotWriting', message: 'The record data is not being written' }),
+            errorNode({ code: 36, name: 'maxRecordsReached', message: 'The class reached its maximum number of records' }),
+            errorNode({ code: 37, name: 'invalidTreasury', message: 'The treasury account does not match the class treasury' }),
+            errorNode({ code: 38, name: 'notInAllowlist', message: 'The record owner is not in the class allowlist' }),
+            errorNode({ code: 39, name: 'invalidSignature', message: 'The signature of the class authority is missing or does not match the record' }),
+            errorNode({ code: 40, name: 'classNotEmpty', message: 'The class still has live records' }),
+            errorNode({ code: 41, name: 'recordNotDeleted', message: 'The record account is not the tombstone of a deleted record' }),
+            errorNode({ code: 42, name: 'invalidPayer', message: 'The payer account is not the payer of the buffered record' })
         ]
     })
 )
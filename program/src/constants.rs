@@ -1,4 +1,11 @@
 /// Variable data length constraints
 pub const MAX_SEED_LEN: usize = 0x20;
 pub const MAX_METADATA_LEN: usize = 0xff;
-pub const CLOSED_ACCOUNT_DISCRIMINATOR: u8 = 0xff;
\ No newline at end of file
+pub const CLOSED_ACCOUNT_DISCRIMINATOR: u8 = 0xff;
+
+/// Maximum account size, the runtime limit of an account data length
+pub const MAX_ACCOUNT_SIZE: usize = 10 * 1024 * 1024;
+
+/// Domain separation of the leaves and nodes of allowlist merkle trees
+pub const MERKLE_LEAF_PREFIX: &[u8] = &[0x00];
+pub const MERKLE_NODE_PREFIX: &[u8] = &[0x01];
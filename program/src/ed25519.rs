@@ -0,0 +1,114 @@
+use crate::error::RecordServiceError;
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+    sysvars::instructions::Instructions,
+};
+
+// Ed25519SigVerify111111111111111111111111111
+pub const ED25519_PROGRAM_ID: Pubkey = [
+    0x03, 0x7d, 0x46, 0xd6, 0x7c, 0x93, 0xfb, 0xbe, 0x12, 0xf9, 0x42, 0x8f, 0x83, 0x8d, 0x40, 0xff,
+    0x05, 0x70, 0x74, 0x49, 0x27, 0xf4, 0x8a, 0x64, 0xfc, 0xca, 0x70, 0x44, 0x80, 0x00, 0x00, 0x00,
+];
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+
+// Offsets of the fields of a signature entry, relative to its start
+const PUBLIC_KEY_OFFSET_OFFSET: usize = 4;
+const PUBLIC_KEY_INSTRUCTION_INDEX_OFFSET: usize = 6;
+const MESSAGE_DATA_OFFSET_OFFSET: usize = 8;
+const MESSAGE_DATA_SIZE_OFFSET: usize = 10;
+const MESSAGE_INSTRUCTION_INDEX_OFFSET: usize = 12;
+
+/// Instruction index of the offsets pointing in the Ed25519 instruction itself
+const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+/// Check that an Ed25519 precompile instruction of the transaction verified
+/// the signature of `signer` over the concatenation of `message`
+///
+/// The precompile fails the transaction if any of its signatures is invalid,
+/// so only the public key and the message signed have to be checked. The
+/// precompile instruction can be anywhere in the transaction and hold several
+/// signatures, but the public key and the message of the matching signature
+/// must be in the data of the precompile instruction itself.
+///
+/// # Arguments
+/// * `instructions_sysvar` - The instructions sysvar account
+/// * `signer` - The expected public key of the signature
+/// * `message` - The parts of the expected message, in order
+pub fn check_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[&[u8]],
+) -> Result<(), ProgramError> {
+    let instructions = Instructions::try_from(instructions_sysvar)?;
+
+    let mut index = 0;
+    while let Ok(instruction) = instructions.load_instruction_at(index) {
+        if instruction.get_program_id().eq(&ED25519_PROGRAM_ID) {
+            let data = instruction.get_instruction_data();
+            let signatures = data.first().copied().unwrap_or_default() as usize;
+
+            for signature in 0..signatures {
+                let offsets = SIGNATURE_OFFSETS_START + signature * SIGNATURE_OFFSETS_LEN;
+
+                if is_signed(data, offsets, index as u16, signer, message).unwrap_or(false) {
+                    return Ok(());
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    Err(RecordServiceError::InvalidSignature.into())
+}
+
+/// Check if the signature entry at `offsets` of the Ed25519 instruction `data`
+/// is a signature of `signer` over `message`, returns `None` if the entry is
+/// out of bounds
+fn is_signed(
+    data: &[u8],
+    offsets: usize,
+    index: u16,
+    signer: &Pubkey,
+    message: &[&[u8]],
+) -> Option<bool> {
+    let is_in_instruction = |offset: usize| {
+        read_u16(data, offsets + offset).map(|i| i == CURRENT_INSTRUCTION_INDEX || i == index)
+    };
+
+    // The public key and the message must be in the Ed25519 instruction, so they are the ones verified
+    if !is_in_instruction(PUBLIC_KEY_INSTRUCTION_INDEX_OFFSET)?
+        || !is_in_instruction(MESSAGE_INSTRUCTION_INDEX_OFFSET)?
+    {
+        return Some(false);
+    }
+
+    let public_key_offset = read_u16(data, offsets + PUBLIC_KEY_OFFSET_OFFSET)? as usize;
+    let public_key = data.get(public_key_offset..public_key_offset + size_of::<Pubkey>())?;
+
+    if public_key.ne(signer) {
+        return Some(false);
+    }
+
+    let message_offset = read_u16(data, offsets + MESSAGE_DATA_OFFSET_OFFSET)? as usize;
+    let message_size = read_u16(data, offsets + MESSAGE_DATA_SIZE_OFFSET)? as usize;
+    let mut signed_message = data.get(message_offset..message_offset + message_size)?;
+
+    // Compare the signed message to the parts of the expected one
+    for part in message {
+        if signed_message.len() < part.len() || signed_message[..part.len()].ne(*part) {
+            return Some(false);
+        }
+        signed_message = &signed_message[part.len()..];
+    }
+
+    Some(signed_message.is_empty())
+}
+
+#[inline(always)]
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes([*data.get(offset)?, *data.get(offset + 1)?]))
+}
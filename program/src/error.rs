@@ -0,0 +1,103 @@
+use pinocchio::program_error::ProgramError;
+
+/// Errors returned by the Record Service program.
+///
+/// Each variant maps to `ProgramError::Custom(n)` where `n` is the variant
+/// index. The codes are part of the public interface and must never be
+/// reordered, new variants are only appended.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RecordServiceError {
+    /// 0 - The account discriminator does not match the expected account type
+    InvalidAccountDiscriminator,
+    /// 1 - The authority is not the class authority
+    InvalidAuthority,
+    /// 2 - The class is frozen
+    ClassFrozen,
+    /// 3 - The class is not permissioned
+    ClassNotPermissioned,
+    /// 4 - The record is frozen
+    RecordFrozen,
+    /// 5 - The class does not match the class of the record
+    ClassMismatch,
+    /// 6 - The authority is neither the record owner nor a valid delegate
+    NotOwnerOrDelegate,
+    /// 7 - The owner account does not match the owner of the record
+    InvalidOwner,
+    /// 8 - The record owner type does not allow this operation
+    InvalidOwnerType,
+    /// 9 - The class account is required but was not provided
+    MissingClass,
+    /// 10 - The mint account is required but was not provided
+    MissingMint,
+    /// 11 - The mint account is not the mint of the record
+    InvalidMint,
+    /// 12 - The mint supply is not zero
+    MintSupplyNotZero,
+    /// 13 - The token account is not valid for the record
+    InvalidTokenAccount,
+    /// 14 - The group account is not the group of the class
+    InvalidGroup,
+    /// 15 - The program derived address could not be derived
+    InvalidPda,
+    /// 16 - The record is expired
+    RecordExpired,
+    /// 17 - The record is not expired
+    RecordNotExpired,
+    /// 18 - The seed exceeds the maximum length
+    SeedTooLong,
+    /// 19 - The name exceeds the maximum length
+    NameTooLong,
+    /// 20 - The metadata exceeds the maximum length
+    MetadataTooLong,
+    /// 21 - The account is too small for the data it has to hold
+    AccountTooSmall,
+    /// 22 - The account would exceed the maximum account size
+    AccountTooLarge,
+    /// 23 - The signer is not the pending authority of the class
+    InvalidPendingAuthority,
+    /// 24 - The signer is not the delegate of the class delegate account
+    InvalidClassDelegate,
+    /// 25 - The class delegate does not have the required permission
+    MissingPermission,
+    /// 26 - The record delegate account does not match the record, its owner or the signer
+    InvalidRecordDelegate,
+    /// 27 - The record delegate is expired
+    RecordDelegateExpired,
+    /// 28 - The schema account or its field definitions are invalid
+    InvalidSchema,
+    /// 29 - The record data does not match the class schema
+    SchemaMismatch,
+    /// 30 - The class policy is invalid
+    InvalidPolicy,
+    /// 31 - The records of the class are non-transferable
+    NonTransferable,
+    /// 32 - The record is revoked
+    RecordRevoked,
+    /// 33 - The record changed since the expected version
+    RecordVersionMismatch,
+    /// 34 - The record data is being written
+    RecordWriting,
+    /// 35 - The record data is not being written
+    RecordNotWriting,
+    /// 36 - The class reached its maximum number of records
+    MaxRecordsReached,
+    /// 37 - The treasury account does not match the class treasury
+    InvalidTreasury,
+    /// 38 - The record owner is not in the class allowlist
+    NotInAllowlist,
+    /// 39 - The signature of the class authority is missing or does not match the record
+    InvalidSignature,
+    /// 40 - The class still has live records
+    ClassNotEmpty,
+    /// 41 - The record account is not the tombstone of a deleted record
+    RecordNotDeleted,
+    /// 42 - The payer account is not the payer of the buffered record
+    InvalidPayer,
+}
+
+impl From<RecordServiceError> for ProgramError {
+    fn from(e: RecordServiceError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
@@ -0,0 +1,645 @@
+use core::{mem::MaybeUninit, slice::from_raw_parts};
+use pinocchio::{log::sol_log_data, pubkey::Pubkey};
+
+use crate::utils::{write_bytes, UNINIT_BYTE};
+
+/// Maximum serialized size of an event, including the discriminator
+pub const MAX_EVENT_LEN: usize = 128;
+
+/// Stack buffer used to serialize an event before logging it
+pub struct EventWriter<'a> {
+    buffer: &'a mut [MaybeUninit<u8>],
+    len: usize,
+}
+
+impl EventWriter<'_> {
+    #[inline(always)]
+    pub fn write(&mut self, bytes: &[u8]) {
+        // Panics instead of writing past the end of the buffer
+        write_bytes(&mut self.buffer[self.len..self.len + bytes.len()], bytes);
+        self.len += bytes.len();
+    }
+}
+
+/// Events are emitted with `sol_log_data` as a single data segment.
+///
+/// The segment layout is:
+/// -  [0]: event discriminator (1 byte, u8)
+/// -  [1..]: event fields, borsh encoded
+///
+/// This matches the borsh encoding of the `RecordServiceEvent` enum in the
+/// client, so indexers can decode the segment directly.
+pub trait Event {
+    const DISCRIMINATOR: u8;
+
+    /// Serialized size of the event fields, without the discriminator
+    const LEN: usize;
+
+    fn write(&self, writer: &mut EventWriter);
+
+    #[inline(always)]
+    fn emit(&self) {
+        // The discriminator and the fields must fit in the buffer
+        const { assert!(Self::LEN < MAX_EVENT_LEN, "event is larger than MAX_EVENT_LEN") };
+
+        let mut buffer = [UNINIT_BYTE; MAX_EVENT_LEN];
+        let mut writer = EventWriter {
+            buffer: &mut buffer,
+            len: 0,
+        };
+
+        writer.write(&[Self::DISCRIMINATOR]);
+        self.write(&mut writer);
+
+        // Only the written bytes are initialized
+        let len = writer.len;
+        let data = &buffer[..len];
+        sol_log_data(&[unsafe { from_raw_parts(data.as_ptr() as _, data.len()) }]);
+    }
+}
+
+/// Emitted by CreateClass
+pub struct ClassCreated<'a> {
+    pub class: &'a Pubkey,
+    pub authority: &'a Pubkey,
+    pub is_permissioned: bool,
+    pub is_frozen: bool,
+    pub is_non_transferable: bool,
+    pub max_records: u64,
+}
+
+impl Event for ClassCreated<'_> {
+    const DISCRIMINATOR: u8 = 0;
+    const LEN: usize = 32 + 32 + 3 + 8;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+        writer.write(self.authority);
+        writer.write(&[
+            self.is_permissioned as u8,
+            self.is_frozen as u8,
+            self.is_non_transferable as u8,
+        ]);
+        writer.write(&self.max_records.to_le_bytes());
+    }
+}
+
+/// Emitted by UpdateClassMetadata
+pub struct ClassMetadataUpdated<'a> {
+    pub class: &'a Pubkey,
+}
+
+impl Event for ClassMetadataUpdated<'_> {
+    const DISCRIMINATOR: u8 = 1;
+    const LEN: usize = 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+    }
+}
+
+/// Emitted by AcceptClassAuthority
+pub struct ClassAuthorityUpdated<'a> {
+    pub class: &'a Pubkey,
+    pub authority: &'a Pubkey,
+}
+
+impl Event for ClassAuthorityUpdated<'_> {
+    const DISCRIMINATOR: u8 = 2;
+    const LEN: usize = 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+        writer.write(self.authority);
+    }
+}
+
+/// Emitted by FreezeClass
+pub struct ClassFrozen<'a> {
+    pub class: &'a Pubkey,
+    pub is_frozen: bool,
+}
+
+impl Event for ClassFrozen<'_> {
+    const DISCRIMINATOR: u8 = 3;
+    const LEN: usize = 32 + 1;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+        writer.write(&[self.is_frozen as u8]);
+    }
+}
+
+/// Emitted by CreateRecord
+pub struct RecordCreated<'a> {
+    pub record: &'a Pubkey,
+    pub class: &'a Pubkey,
+    pub owner: &'a Pubkey,
+    pub expiry: i64,
+}
+
+impl Event for RecordCreated<'_> {
+    const DISCRIMINATOR: u8 = 4;
+    const LEN: usize = 32 + 32 + 32 + 8;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.class);
+        writer.write(self.owner);
+        writer.write(&self.expiry.to_le_bytes());
+    }
+}
+
+/// Emitted by UpdateRecordData
+pub struct RecordDataUpdated<'a> {
+    pub record: &'a Pubkey,
+    pub version: u64,
+    pub hash: &'a [u8; 32],
+}
+
+impl Event for RecordDataUpdated<'_> {
+    const DISCRIMINATOR: u8 = 5;
+    const LEN: usize = 32 + 8 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(&self.version.to_le_bytes());
+        writer.write(self.hash);
+    }
+}
+
+/// Emitted by UpdateRecordExpiry
+pub struct RecordExpiryUpdated<'a> {
+    pub record: &'a Pubkey,
+    pub expiry: i64,
+    pub version: u64,
+    pub hash: &'a [u8; 32],
+}
+
+impl Event for RecordExpiryUpdated<'_> {
+    const DISCRIMINATOR: u8 = 6;
+    const LEN: usize = 32 + 8 + 8 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(&self.expiry.to_le_bytes());
+        writer.write(&self.version.to_le_bytes());
+        writer.write(self.hash);
+    }
+}
+
+/// Emitted by TransferRecord
+pub struct RecordTransferred<'a> {
+    pub record: &'a Pubkey,
+    pub new_owner: &'a Pubkey,
+}
+
+impl Event for RecordTransferred<'_> {
+    const DISCRIMINATOR: u8 = 7;
+    const LEN: usize = 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.new_owner);
+    }
+}
+
+/// Emitted by DeleteRecord
+pub struct RecordDeleted<'a> {
+    pub record: &'a Pubkey,
+}
+
+impl Event for RecordDeleted<'_> {
+    const DISCRIMINATOR: u8 = 8;
+    const LEN: usize = 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+    }
+}
+
+/// Emitted by FreezeRecord
+pub struct RecordFrozen<'a> {
+    pub record: &'a Pubkey,
+    pub is_frozen: bool,
+}
+
+impl Event for RecordFrozen<'_> {
+    const DISCRIMINATOR: u8 = 9;
+    const LEN: usize = 32 + 1;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(&[self.is_frozen as u8]);
+    }
+}
+
+/// Emitted by MintTokenizedRecord
+pub struct RecordTokenized<'a> {
+    pub record: &'a Pubkey,
+    pub mint: &'a Pubkey,
+    pub owner: &'a Pubkey,
+}
+
+impl Event for RecordTokenized<'_> {
+    const DISCRIMINATOR: u8 = 10;
+    const LEN: usize = 32 + 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.mint);
+        writer.write(self.owner);
+    }
+}
+
+/// Emitted by FreezeTokenizedRecord
+pub struct TokenizedRecordFrozen<'a> {
+    pub record: &'a Pubkey,
+    pub mint: &'a Pubkey,
+    pub is_frozen: bool,
+}
+
+impl Event for TokenizedRecordFrozen<'_> {
+    const DISCRIMINATOR: u8 = 11;
+    const LEN: usize = 32 + 32 + 1;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.mint);
+        writer.write(&[self.is_frozen as u8]);
+    }
+}
+
+/// Emitted by TransferTokenizedRecord
+pub struct TokenizedRecordTransferred<'a> {
+    pub record: &'a Pubkey,
+    pub mint: &'a Pubkey,
+    pub new_token_account: &'a Pubkey,
+}
+
+impl Event for TokenizedRecordTransferred<'_> {
+    const DISCRIMINATOR: u8 = 12;
+    const LEN: usize = 32 + 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.mint);
+        writer.write(self.new_token_account);
+    }
+}
+
+/// Emitted by BurnTokenizedRecord
+pub struct RecordBurned<'a> {
+    pub record: &'a Pubkey,
+    pub mint: &'a Pubkey,
+}
+
+impl Event for RecordBurned<'_> {
+    const DISCRIMINATOR: u8 = 13;
+    const LEN: usize = 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.mint);
+    }
+}
+
+/// Emitted by CloseExpiredRecord
+pub struct ExpiredRecordClosed<'a> {
+    pub record: &'a Pubkey,
+    pub owner: &'a Pubkey,
+}
+
+impl Event for ExpiredRecordClosed<'_> {
+    const DISCRIMINATOR: u8 = 14;
+    const LEN: usize = 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.owner);
+    }
+}
+
+/// Emitted by ProposeClassAuthority
+pub struct ClassAuthorityProposed<'a> {
+    pub class: &'a Pubkey,
+    pub pending_authority: &'a Pubkey,
+}
+
+impl Event for ClassAuthorityProposed<'_> {
+    const DISCRIMINATOR: u8 = 15;
+    const LEN: usize = 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+        writer.write(self.pending_authority);
+    }
+}
+
+/// Emitted by CancelClassAuthorityTransfer
+pub struct ClassAuthorityTransferCancelled<'a> {
+    pub class: &'a Pubkey,
+}
+
+impl Event for ClassAuthorityTransferCancelled<'_> {
+    const DISCRIMINATOR: u8 = 16;
+    const LEN: usize = 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+    }
+}
+
+/// Emitted by AddClassDelegate
+pub struct ClassDelegateUpdated<'a> {
+    pub class: &'a Pubkey,
+    pub delegate: &'a Pubkey,
+    pub permissions: u8,
+}
+
+impl Event for ClassDelegateUpdated<'_> {
+    const DISCRIMINATOR: u8 = 17;
+    const LEN: usize = 32 + 32 + 1;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+        writer.write(self.delegate);
+        writer.write(&[self.permissions]);
+    }
+}
+
+/// Emitted by RevokeClassDelegate
+pub struct ClassDelegateRevoked<'a> {
+    pub class: &'a Pubkey,
+    pub delegate: &'a Pubkey,
+}
+
+impl Event for ClassDelegateRevoked<'_> {
+    const DISCRIMINATOR: u8 = 18;
+    const LEN: usize = 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+        writer.write(self.delegate);
+    }
+}
+
+/// Emitted by ApproveRecordDelegate
+pub struct RecordDelegateApproved<'a> {
+    pub record: &'a Pubkey,
+    pub delegate: &'a Pubkey,
+    pub permissions: u8,
+    pub expiry: i64,
+}
+
+impl Event for RecordDelegateApproved<'_> {
+    const DISCRIMINATOR: u8 = 19;
+    const LEN: usize = 32 + 32 + 1 + 8;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.delegate);
+        writer.write(&[self.permissions]);
+        writer.write(&self.expiry.to_le_bytes());
+    }
+}
+
+/// Emitted by RevokeRecordDelegate
+pub struct RecordDelegateRevoked<'a> {
+    pub record: &'a Pubkey,
+    pub delegate: &'a Pubkey,
+}
+
+impl Event for RecordDelegateRevoked<'_> {
+    const DISCRIMINATOR: u8 = 20;
+    const LEN: usize = 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.delegate);
+    }
+}
+
+/// Emitted by SetClassSchema
+pub struct ClassSchemaUpdated<'a> {
+    pub class: &'a Pubkey,
+}
+
+impl Event for ClassSchemaUpdated<'_> {
+    const DISCRIMINATOR: u8 = 21;
+    const LEN: usize = 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+    }
+}
+
+/// Emitted by UpdateClassPolicy
+pub struct ClassPolicyUpdated<'a> {
+    pub class: &'a Pubkey,
+    pub update_data: u8,
+    pub update_expiry: u8,
+    pub transfer: u8,
+    pub delete: u8,
+    pub tokenize: u8,
+}
+
+impl Event for ClassPolicyUpdated<'_> {
+    const DISCRIMINATOR: u8 = 22;
+    const LEN: usize = 32 + 5;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+        writer.write(&[
+            self.update_data,
+            self.update_expiry,
+            self.transfer,
+            self.delete,
+            self.tokenize,
+        ]);
+    }
+}
+
+/// Emitted by RevokeRecord
+pub struct RecordRevoked<'a> {
+    pub record: &'a Pubkey,
+    pub reason: u16,
+    pub revoked_at: i64,
+}
+
+impl Event for RecordRevoked<'_> {
+    const DISCRIMINATOR: u8 = 23;
+    const LEN: usize = 32 + 2 + 8;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(&self.reason.to_le_bytes());
+        writer.write(&self.revoked_at.to_le_bytes());
+    }
+}
+
+/// Emitted by UpdateClassFee
+pub struct ClassFeeUpdated<'a> {
+    pub class: &'a Pubkey,
+    pub creation_fee: u64,
+    pub treasury: &'a Pubkey,
+}
+
+impl Event for ClassFeeUpdated<'_> {
+    const DISCRIMINATOR: u8 = 24;
+    const LEN: usize = 32 + 8 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+        writer.write(&self.creation_fee.to_le_bytes());
+        writer.write(self.treasury);
+    }
+}
+
+/// Emitted by UpdateClassMerkleRoot
+pub struct ClassMerkleRootUpdated<'a> {
+    pub class: &'a Pubkey,
+    pub merkle_root: &'a [u8; 32],
+}
+
+impl Event for ClassMerkleRootUpdated<'_> {
+    const DISCRIMINATOR: u8 = 25;
+    const LEN: usize = 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+        writer.write(self.merkle_root);
+    }
+}
+
+/// Emitted by CloseClass
+pub struct ClassClosed<'a> {
+    pub class: &'a Pubkey,
+}
+
+impl Event for ClassClosed<'_> {
+    const DISCRIMINATOR: u8 = 26;
+    const LEN: usize = 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+    }
+}
+
+/// Emitted by MigrateRecordClass
+pub struct RecordMigrated<'a> {
+    pub record: &'a Pubkey,
+    pub new_record: &'a Pubkey,
+    pub new_class: &'a Pubkey,
+}
+
+impl Event for RecordMigrated<'_> {
+    const DISCRIMINATOR: u8 = 27;
+    const LEN: usize = 32 + 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.new_record);
+        writer.write(self.new_class);
+    }
+}
+
+/// Emitted by RecreateRecord
+pub struct RecordRecreated<'a> {
+    pub record: &'a Pubkey,
+    pub class: &'a Pubkey,
+    pub owner: &'a Pubkey,
+    pub expiry: i64,
+    pub generation: u32,
+}
+
+impl Event for RecordRecreated<'_> {
+    const DISCRIMINATOR: u8 = 28;
+    const LEN: usize = 32 + 32 + 32 + 8 + 4;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.class);
+        writer.write(self.owner);
+        writer.write(&self.expiry.to_le_bytes());
+        writer.write(&self.generation.to_le_bytes());
+    }
+}
+
+/// Emitted by BeginRecordWrite
+pub struct RecordWriteStarted<'a> {
+    pub record: &'a Pubkey,
+}
+
+impl Event for RecordWriteStarted<'_> {
+    const DISCRIMINATOR: u8 = 29;
+    const LEN: usize = 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+    }
+}
+
+/// Emitted by WriteRecordChunk
+pub struct RecordChunkWritten<'a> {
+    pub record: &'a Pubkey,
+    pub offset: u32,
+    pub len: u32,
+}
+
+impl Event for RecordChunkWritten<'_> {
+    const DISCRIMINATOR: u8 = 30;
+    const LEN: usize = 32 + 4 + 4;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(&self.offset.to_le_bytes());
+        writer.write(&self.len.to_le_bytes());
+    }
+}
+
+/// Emitted by CancelRecordWrite, buffered records are deleted
+pub struct RecordWriteCancelled<'a> {
+    pub record: &'a Pubkey,
+    pub is_deleted: bool,
+}
+
+impl Event for RecordWriteCancelled<'_> {
+    const DISCRIMINATOR: u8 = 31;
+    const LEN: usize = 32 + 1;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(&[self.is_deleted as u8]);
+    }
+}
+
+/// Emitted by MigrateClassLayout
+pub struct ClassLayoutMigrated<'a> {
+    pub class: &'a Pubkey,
+}
+
+impl Event for ClassLayoutMigrated<'_> {
+    const DISCRIMINATOR: u8 = 32;
+    const LEN: usize = 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.class);
+    }
+}
+
+/// Emitted by MigrateRecordLayout
+pub struct RecordLayoutMigrated<'a> {
+    pub record: &'a Pubkey,
+    pub class: &'a Pubkey,
+}
+
+impl Event for RecordLayoutMigrated<'_> {
+    const DISCRIMINATOR: u8 = 33;
+    const LEN: usize = 32 + 32;
+
+    fn write(&self, writer: &mut EventWriter) {
+        writer.write(self.record);
+        writer.write(self.class);
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{
+    events::{ClassAuthorityUpdated, Event},
+    state::{Class, PendingClassAuthority},
+    utils::{close_account, Context},
+};
+
+/// AcceptClassAuthority instruction.
+///
+/// This function:
+/// 1. Validates the proposed authority against the pending authority account
+/// 2. Updates the class authority to the proposed authority
+/// 3. Closes the pending authority account and refunds the rent to the previous authority
+///
+/// # Accounts
+/// 1. `new_authority` - The proposed authority of the class (must be a signer)
+/// 2. `authority` - The current authority of the class that proposed the transfer
+/// 3. `class` - The class account whose authority is being transferred
+/// 4. `pending_class_authority` - The pending authority PDA of the class
+///
+/// # Security
+/// 1. The new authority must be a signer and match the proposed authority
+/// 2. The authority must still be the class authority, proposals made by a
+///    previous authority can't be accepted
+pub struct AcceptClassAuthorityAccounts<'info> {
+    new_authority: &'info AccountInfo,
+    authority: &'info AccountInfo,
+    class: &'info AccountInfo,
+    pending_class_authority: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for AcceptClassAuthorityAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [new_authority, authority, class, pending_class_authority] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check the class and its current authority
+        Class::check_program_id(class)?;
+
+        let class_data = class.try_borrow_data()?;
+        unsafe {
+            Class::check_discriminator_unchecked(&class_data)?;
+            Class::check_authority_key_unchecked(&class_data, authority.key())?;
+        }
+
+        // Check the pending authority account
+        PendingClassAuthority::check_class(pending_class_authority, class)?;
+
+        let data = pending_class_authority.try_borrow_data()?;
+        unsafe {
+            PendingClassAuthority::check_proposing_authority_unchecked(&data, authority)?;
+            PendingClassAuthority::check_pending_authority_unchecked(&data, new_authority)?;
+        }
+
+        Ok(Self {
+            new_authority,
+            authority,
+            class,
+            pending_class_authority,
+        })
+    }
+}
+
+pub struct AcceptClassAuthority<'info> {
+    accounts: AcceptClassAuthorityAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for AcceptClassAuthority<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = AcceptClassAuthorityAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> AcceptClassAuthority<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Accept Class Authority");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Safety: The accounts have already been validated
+        unsafe {
+            Class::update_authority_unchecked(self.accounts.class, *self.accounts.new_authority.key())
+        }?;
+
+        close_account(self.accounts.pending_class_authority, self.accounts.authority)?;
+
+        ClassAuthorityUpdated {
+            class: self.accounts.class.key(),
+            authority: self.accounts.new_authority.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{
+    error::RecordServiceError,
+    events::{ClassDelegateUpdated, Event},
+    state::{Class, ClassDelegate},
+    utils::{check_program_address, create_pda_account, ByteReader, Context},
+};
+
+/// AddClassDelegate instruction.
+///
+/// This function:
+/// 1. Validates the class authority
+/// 2. Creates the class delegate account if it does not exist yet, checking
+///    its PDA with the bump passed in
+/// 3. Stores the permissions of the delegate, replacing the previous ones
+///
+/// # Accounts
+/// 1. `authority` - The authority of the class (must be a signer)
+/// 2. `payer` - The account that will pay for the class delegate account
+/// 3. `class` - The class account the delegate acts on
+/// 4. `class_delegate` - The class delegate PDA of the delegate
+/// 5. `system_program` - Required for creating the class delegate account
+///
+/// # Security
+/// 1. The authority must be a signer and the authority of the class
+/// 2. An existing class delegate account must belong to the class and the delegate
+/// 3. A new class delegate account must be the PDA of the class and the
+///    delegate with the canonical bump passed in
+pub struct AddClassDelegateAccounts<'info> {
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    class_delegate: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for AddClassDelegateAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, class_delegate, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check the class authority
+        Class::check_authority(class, authority)?;
+
+        // If the delegate already exists, it must belong to this class
+        if !class_delegate.data_is_empty() {
+            ClassDelegate::check_class(class_delegate, class)?;
+        }
+
+        Ok(Self {
+            payer,
+            class,
+            class_delegate,
+        })
+    }
+}
+
+const DELEGATE_OFFSET: usize = 0;
+const PERMISSIONS_OFFSET: usize = DELEGATE_OFFSET + size_of::<Pubkey>();
+const BUMP_OFFSET: usize = PERMISSIONS_OFFSET + size_of::<u8>();
+
+pub struct AddClassDelegate<'info> {
+    accounts: AddClassDelegateAccounts<'info>,
+    delegate: Pubkey,
+    permissions: u8,
+    bump: u8,
+}
+
+/// Minimum length of instruction data required for AddClassDelegate
+pub const ADD_CLASS_DELEGATE_MIN_IX_LENGTH: usize = size_of::<Pubkey>() + size_of::<u8>() * 2;
+
+impl<'info> TryFrom<Context<'info>> for AddClassDelegate<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = AddClassDelegateAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < ADD_CLASS_DELEGATE_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `delegate`
+        let delegate: Pubkey = ByteReader::read_with_offset(ctx.data, DELEGATE_OFFSET)?;
+
+        // Deserialize `permissions`
+        let permissions: u8 = ByteReader::read_with_offset(ctx.data, PERMISSIONS_OFFSET)?;
+
+        // Deserialize `bump`
+        let bump: u8 = ByteReader::read_with_offset(ctx.data, BUMP_OFFSET)?;
+
+        // Check that an existing delegate account is the one of the delegate
+        if !accounts.class_delegate.data_is_empty()
+            && unsafe {
+                ClassDelegate::get_delegate_unchecked(&accounts.class_delegate.try_borrow_data()?)?
+            }
+            .ne(&delegate)
+        {
+            return Err(RecordServiceError::InvalidClassDelegate.into());
+        }
+
+        Ok(Self {
+            accounts,
+            delegate,
+            permissions,
+            bump,
+        })
+    }
+}
+
+impl<'info> AddClassDelegate<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Add Class Delegate");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        if self.accounts.class_delegate.data_is_empty() {
+            let bump: [u8; 1] = [self.bump];
+
+            // Check if the class delegate is the PDA of the class and the delegate
+            check_program_address(
+                self.accounts.class_delegate,
+                &[
+                    b"class_delegate",
+                    self.accounts.class.key(),
+                    &self.delegate,
+                    &bump,
+                ],
+                RecordServiceError::InvalidPda,
+            )?;
+
+            let seeds = [
+                Seed::from(b"class_delegate"),
+                Seed::from(self.accounts.class.key()),
+                Seed::from(&self.delegate),
+                Seed::from(&bump),
+            ];
+
+            create_pda_account(
+                self.accounts.class_delegate,
+                self.accounts.payer,
+                ClassDelegate::SIZE,
+                &[Signer::from(&seeds)],
+            )?;
+
+            let class_delegate = ClassDelegate {
+                class: *self.accounts.class.key(),
+                delegate: self.delegate,
+                permissions: self.permissions,
+            };
+
+            unsafe { class_delegate.initialize_unchecked(self.accounts.class_delegate) }?;
+        } else {
+            // Safety: The account has already been validated
+            unsafe {
+                ClassDelegate::update_permissions_unchecked(
+                    self.accounts.class_delegate,
+                    self.permissions,
+                )
+            }?;
+        }
+
+        ClassDelegateUpdated {
+            class: self.accounts.class.key(),
+            delegate: &self.delegate,
+            permissions: self.permissions,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{
+    error::RecordServiceError,
+    events::{Event, RecordDelegateApproved},
+    state::{Record, RecordDelegate},
+    utils::{check_program_address, create_pda_account, ByteReader, Context},
+};
+
+/// ApproveRecordDelegate instruction.
+///
+/// This function:
+/// 1. Validates the record owner
+/// 2. Creates the record delegate account if it does not exist yet, checking
+///    its PDA with the bump passed in
+/// 3. Stores the delegate, its permissions and expiry, replacing any previous delegate
+///
+/// # Accounts
+/// 1. `owner` - The owner of the record (must be a signer)
+/// 2. `payer` - The account that will pay for the record delegate account
+/// 3. `record` - The record the delegate acts on
+/// 4. `record_delegate` - The record delegate PDA of the record
+/// 5. `system_program` - Required for creating the record delegate account
+///
+/// # Security
+/// 1. The owner must be a signer and the owner of the record
+/// 2. Tokenized records can't have a record delegate, the token account delegate is used instead
+/// 3. The delegation is void once the record is transferred, or deleted and
+///    created again at the same address
+/// 4. The record must not be revoked
+/// 5. The record delegate account must be the PDA of the record, checked with
+///    the canonical bump passed in when it is created
+pub struct ApproveRecordDelegateAccounts<'info> {
+    owner: &'info AccountInfo,
+    payer: &'info AccountInfo,
+    record: &'info AccountInfo,
+    record_delegate: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for ApproveRecordDelegateAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [owner, payer, record, record_delegate, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if the owner is the record owner
+        Record::check_owner(record, owner)?;
+
+        // Check if the record is revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record.try_borrow_data()?)? };
+
+        // If a delegate already exists, it must belong to this record
+        if !record_delegate.data_is_empty() {
+            RecordDelegate::check_record(record_delegate, record)?;
+        }
+
+        Ok(Self {
+            owner,
+            payer,
+            record,
+            record_delegate,
+        })
+    }
+}
+
+const DELEGATE_OFFSET: usize = 0;
+const PERMISSIONS_OFFSET: usize = DELEGATE_OFFSET + size_of::<Pubkey>();
+const EXPIRY_OFFSET: usize = PERMISSIONS_OFFSET + size_of::<u8>();
+const BUMP_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+
+pub struct ApproveRecordDelegate<'info> {
+    accounts: ApproveRecordDelegateAccounts<'info>,
+    delegate: Pubkey,
+    permissions: u8,
+    expiry: i64,
+    bump: u8,
+}
+
+/// Minimum length of instruction data required for ApproveRecordDelegate
+pub const APPROVE_RECORD_DELEGATE_MIN_IX_LENGTH: usize =
+    size_of::<Pubkey>() + size_of::<u8>() + size_of::<i64>() + size_of::<u8>();
+
+impl<'info> TryFrom<Context<'info>> for ApproveRecordDelegate<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = ApproveRecordDelegateAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < APPROVE_RECORD_DELEGATE_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `delegate`
+        let delegate: Pubkey = ByteReader::read_with_offset(ctx.data, DELEGATE_OFFSET)?;
+
+        // Deserialize `permissions`
+        let permissions: u8 = ByteReader::read_with_offset(ctx.data, PERMISSIONS_OFFSET)?;
+
+        // Deserialize `expiry`
+        let expiry: i64 = ByteReader::read_with_offset(ctx.data, EXPIRY_OFFSET)?;
+
+        // Deserialize `bump`
+        let bump: u8 = ByteReader::read_with_offset(ctx.data, BUMP_OFFSET)?;
+
+        Ok(Self {
+            accounts,
+            delegate,
+            permissions,
+            expiry,
+            bump,
+        })
+    }
+}
+
+impl<'info> ApproveRecordDelegate<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Approve Record Delegate");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Safety: The account has already been validated
+        let generation =
+            unsafe { Record::get_generation_unchecked(&self.accounts.record.try_borrow_data()?)? };
+
+        let record_delegate = RecordDelegate {
+            record: *self.accounts.record.key(),
+            owner: *self.accounts.owner.key(),
+            generation,
+            delegate: self.delegate,
+            permissions: self.permissions,
+            expiry: self.expiry,
+        };
+
+        if self.accounts.record_delegate.data_is_empty() {
+            let bump: [u8; 1] = [self.bump];
+
+            // Check if the record delegate is the PDA of the record
+            check_program_address(
+                self.accounts.record_delegate,
+                &[b"record_delegate", self.accounts.record.key(), &bump],
+                RecordServiceError::InvalidPda,
+            )?;
+
+            let seeds = [
+                Seed::from(b"record_delegate"),
+                Seed::from(self.accounts.record.key()),
+                Seed::from(&bump),
+            ];
+
+            create_pda_account(
+                self.accounts.record_delegate,
+                self.accounts.payer,
+                RecordDelegate::SIZE,
+                &[Signer::from(&seeds)],
+            )?;
+
+            unsafe { record_delegate.initialize_unchecked(self.accounts.record_delegate) }?;
+        } else {
+            // Safety: The account has already been validated
+            unsafe { record_delegate.update_unchecked(self.accounts.record_delegate) }?;
+        }
+
+        RecordDelegateApproved {
+            record: self.accounts.record.key(),
+            delegate: &self.delegate,
+            permissions: self.permissions,
+            expiry: self.expiry,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
-    state::{OwnerType, Record},
+    error::RecordServiceError,
+    events::{Event, RecordBurned},
+    state::{Class, OwnerType, Permission, Record},
     token2022::{BurnChecked, CloseAccount, ThawAccount, Token},
     utils::Context,
 };
@@ -9,7 +11,6 @@ use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
     program_error::ProgramError,
-    pubkey::try_find_program_address,
     ProgramResult,
 };
 
@@ -18,7 +19,9 @@ use pinocchio::{
 /// This function:
 /// 1. Burns the mint
 /// 2. Closes the mint account
-/// 3. Sets the record owner to the owner of the token account and the owner type to pubkey
+/// 3. Sets the record owner to the owner of the token account and the owner type to pubkey,
+///    and clears the mint bump of the record
+/// 4. Decrements the tokenized count of the class
 ///
 /// # Accounts
 /// 1. `authority` - The account that has permission to burn the record token (must be a signer)
@@ -27,14 +30,17 @@ use pinocchio::{
 /// 3. `token_account` - The token account of the record token
 /// 4. `record` - The record account to be deleted
 /// 5. `token_2022_program` - Required for burning the token account
-/// 6. `class` - [remaining accounts] Required if the authority is not the record owner but the permissioned authority
+/// 6. `class` - [remaining accounts] The class of the record, its policy decides who may burn (must be writable)
+/// 7. `class_delegate` - [remaining accounts] Required if the authority is a class delegate
 ///
 /// # Security
-/// 1. The authority must be either:
-///    a. The record owner, or
-///    b. if the class is permissioned, the authority must be the permissioned authority
+/// 1. Depending on the class delete policy, the authority must be either:
+///    a. The owner of the token account, and/or
+///    b. the class authority or a class delegate with the delete permission
+/// 2. The record must not be revoked, revoked records are kept as evidence
 pub struct BurnTokenizedRecordAccounts<'info> {
     destination: &'info AccountInfo,
+    class: &'info AccountInfo,
     record: &'info AccountInfo,
     mint: &'info AccountInfo,
     token_account: &'info AccountInfo,
@@ -50,17 +56,25 @@ impl<'info> TryFrom<&'info [AccountInfo]> for BurnTokenizedRecordAccounts<'info>
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        let class = rest.first().ok_or(RecordServiceError::MissingClass)?;
+
         // Check if authority is the record owner or has a delegate
         Record::check_owner_or_delegate_tokenized(
             record,
-            rest.first(),
+            Some(class),
+            rest.get(1),
             authority,
             mint,
             token_account,
+            Permission::DeleteRecord,
         )?;
 
+        // Check if the record is revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record.try_borrow_data()?)? };
+
         Ok(Self {
             destination,
+            class,
             record,
             mint,
             token_account,
@@ -91,11 +105,9 @@ impl<'info> BurnTokenizedRecord<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
-        let bump = [
-            try_find_program_address(&[b"mint", self.accounts.record.key()], &crate::ID)
-                .ok_or(ProgramError::InvalidArgument)?
-                .1,
-        ];
+        // Mint bump stored on tokenization [this is safe, the record has already been validated]
+        let bump =
+            unsafe { Record::get_mint_bump_unchecked(&self.accounts.record.try_borrow_data()?) };
 
         let seeds = [
             Seed::from(b"mint"),
@@ -153,8 +165,21 @@ impl<'info> BurnTokenizedRecord<'info> {
                 &mut self.accounts.record.try_borrow_mut_data()?,
                 OwnerType::Pubkey,
             )?;
+            Record::update_mint_bump_unchecked(
+                &mut self.accounts.record.try_borrow_mut_data()?,
+                0,
+            )?;
         };
 
+        // Uncount the tokenized record
+        Class::update_tokenized_count(self.accounts.class, false)?;
+
+        RecordBurned {
+            record: self.accounts.record.key(),
+            mint: self.accounts.mint.key(),
+        }
+        .emit();
+
         Ok(())
     }
 }
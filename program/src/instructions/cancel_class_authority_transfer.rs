@@ -0,0 +1,84 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{
+    events::{ClassAuthorityTransferCancelled, Event},
+    state::{Class, PendingClassAuthority},
+    utils::{close_account, Context},
+};
+
+/// CancelClassAuthorityTransfer instruction.
+///
+/// This function:
+/// 1. Validates the current class authority
+/// 2. Closes the pending authority account and refunds the rent to the authority
+///
+/// # Accounts
+/// 1. `authority` - The current authority of the class (must be a signer)
+/// 2. `class` - The class account with a pending authority transfer
+/// 3. `pending_class_authority` - The pending authority PDA of the class
+///
+/// # Security
+/// 1. The authority must be a signer and the current authority of the class
+pub struct CancelClassAuthorityTransferAccounts<'info> {
+    authority: &'info AccountInfo,
+    class: &'info AccountInfo,
+    pending_class_authority: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for CancelClassAuthorityTransferAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, class, pending_class_authority] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check the pending authority account
+        PendingClassAuthority::check_class(pending_class_authority, class)?;
+
+        Ok(Self {
+            authority,
+            class,
+            pending_class_authority,
+        })
+    }
+}
+
+pub struct CancelClassAuthorityTransfer<'info> {
+    accounts: CancelClassAuthorityTransferAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for CancelClassAuthorityTransfer<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = CancelClassAuthorityTransferAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> CancelClassAuthorityTransfer<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Cancel Class Authority Transfer");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        close_account(self.accounts.pending_class_authority, self.accounts.authority)?;
+
+        ClassAuthorityTransferCancelled {
+            class: self.accounts.class.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
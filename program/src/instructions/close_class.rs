@@ -0,0 +1,129 @@
+use crate::{
+    events::{ClassClosed, Event},
+    state::Class,
+    token2022::{CloseAccount, Mint},
+    utils::Context,
+};
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    ProgramResult,
+};
+
+/// CloseClass instruction.
+///
+/// This function:
+/// 1. Validates the class authority
+/// 2. Checks that the class has no live records, nor legacy records left to migrate
+/// 3. Closes the group mint of the class, if it exists and has a close authority
+/// 4. Reallocates the class account data to 1 byte, 0xff to counter
+///    reinitialization attacks
+/// 5. Transfers the lamports from the class and its group mint to the destination
+///
+/// # Accounts
+/// 1. `authority` - The authority of the class (must be a signer)
+/// 2. `destination` - The account that will get refunded for the class account
+/// 3. `class` - The class account to be closed
+/// 4. `group` - The group mint of the class, closed if the class was ever tokenized
+/// 5. `token_2022_program` - Required for closing the group mint
+///
+/// # Security
+/// 1. The authority account must be a signer and should be the owner of the class.
+/// 2. The class must not have any live record, tokenized or not, nor any
+///    legacy record declared when it was migrated and not migrated yet.
+///    Revoked records are not live, they are kept after the class is closed.
+/// 3. Group mints created before they had a close authority are left open.
+pub struct CloseClassAccounts<'info> {
+    destination: &'info AccountInfo,
+    class: &'info AccountInfo,
+    group: &'info AccountInfo,
+    group_bump: Option<[u8; 1]>,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for CloseClassAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, destination, class, group, _token_2022_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Account Checks
+        Class::check_authority(class, authority)?;
+
+        // Check if the class is empty [this is safe, the class has already been validated]
+        unsafe { Class::check_empty_unchecked(&class.try_borrow_data()?)? };
+
+        // Check if the group is the group of the class, with the bump stored when it was created
+        let group_bump = if Mint::check_discriminator(group)? {
+            Some(Class::check_group(class, group)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            destination,
+            class,
+            group,
+            group_bump,
+        })
+    }
+}
+
+pub struct CloseClass<'info> {
+    accounts: CloseClassAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for CloseClass<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = CloseClassAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> CloseClass<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Close Class");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Close the group mint, its supply is always zero
+        if let Some(group_bump) = self.accounts.group_bump {
+            if Mint::has_close_authority(self.accounts.group)? {
+                let seeds = [
+                    Seed::from(b"group"),
+                    Seed::from(self.accounts.class.key()),
+                    Seed::from(&group_bump),
+                ];
+
+                CloseAccount {
+                    account: self.accounts.group,
+                    destination: self.accounts.destination,
+                    authority: self.accounts.group,
+                }
+                .invoke_signed(&[Signer::from(&seeds)])?;
+            }
+        }
+
+        // Safety: The account has already been validated
+        unsafe {
+            Class::close_class_unchecked(self.accounts.class, self.accounts.destination)?;
+        }
+
+        ClassClosed {
+            class: self.accounts.class.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
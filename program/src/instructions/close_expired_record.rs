@@ -0,0 +1,101 @@
+use crate::{
+    events::{Event, ExpiredRecordClosed},
+    state::{Class, Record},
+    utils::Context,
+};
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// CloseExpiredRecord instruction.
+///
+/// This function:
+/// 1. Checks that the record expiry is set and has passed
+/// 2. Reallocates the record account data to 1 byte, 0xff to counter
+///    reinitialization attacks
+/// 3. Transfers the lamports from the record back to the record owner
+/// 4. Decrements the record count of the class
+///
+/// # Accounts
+/// 1. `record` - The expired record account to be closed
+/// 2. `owner` - The owner of the record that will get refunded for the record account
+/// 3. `class` - The class of the record (must be writable)
+///
+/// # Security
+/// 1. Anyone can call this instruction, no signer is required
+/// 2. The record must be expired
+/// 3. The record must be owned by a pubkey, tokenized records must be burned instead
+/// 4. The rent is always refunded to the record owner
+/// 5. The record must not be revoked
+/// 6. The class must be the class of the record
+pub struct CloseExpiredRecordAccounts<'info> {
+    record: &'info AccountInfo,
+    owner: &'info AccountInfo,
+    class: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for CloseExpiredRecordAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [record, owner, class] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if the record is expired and the owner is the record owner
+        Record::check_expired_and_owner(record, owner)?;
+
+        // Check if the record is revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record.try_borrow_data()?)? };
+
+        // Check if the class is the class of the record [this is safe, the record has already been validated]
+        unsafe { Record::check_class_unchecked(&record.try_borrow_data()?, class)? };
+
+        Ok(Self {
+            record,
+            owner,
+            class,
+        })
+    }
+}
+
+pub struct CloseExpiredRecord<'info> {
+    accounts: CloseExpiredRecordAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for CloseExpiredRecord<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = CloseExpiredRecordAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> CloseExpiredRecord<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Close Expired Record");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Safety: The account has already been validated
+        unsafe {
+            Record::delete_record_unchecked(self.accounts.record, self.accounts.owner)?;
+        }
+
+        // Uncount the record
+        Class::remove_record(self.accounts.class, false)?;
+
+        ExpiredRecordClosed {
+            record: self.accounts.record.key(),
+            owner: self.accounts.owner.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -8,25 +8,27 @@ use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
     program_error::ProgramError,
-    pubkey::try_find_program_address,
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
 use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
 use crate::{
-    state::Class,
-    utils::{ByteReader, Context},
+    error::RecordServiceError,
+    events::{ClassCreated, Event},
+    state::{Class, ClassPolicy},
+    utils::{check_program_address, ByteReader, Context},
 };
 
 /// CreateClass instruction.
 ///
 /// This function:
 /// 1. Calculates required account space and rent
-/// 2. Derives the PDA for the class account
+/// 2. Checks the PDA of the class account with the bump passed in
 /// 3. Creates the new account
 /// 4. Transfers the minimum rent needed to make the account rent-exempt
-/// 5. Initializes the class data
+/// 5. Initializes the class data with the default policy, no records and the
+///    optional `max_records` cap, 0 meaning uncapped
 ///
 /// # Accounts
 /// 1. `authority` - The account that will own the class (must be a signer)
@@ -35,6 +37,8 @@ use crate::{
 ///
 /// # Security
 /// 1. The authority account must be a signer
+/// 2. The class account must be the PDA of the authority and the name with
+///    the bump passed in, which must be the canonical bump
 pub struct CreateClassAccounts<'info> {
     authority: &'info AccountInfo,
     payer: &'info AccountInfo,
@@ -64,18 +68,25 @@ impl<'info> TryFrom<&'info [AccountInfo]> for CreateClassAccounts<'info> {
 
 const IS_PERMISSIONED_OFFSET: usize = 0;
 const IS_FROZEN_OFFSET: usize = IS_PERMISSIONED_OFFSET + size_of::<bool>();
-const NAME_LEN_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
+const IS_NON_TRANSFERABLE_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
+const MAX_RECORDS_OFFSET: usize = IS_NON_TRANSFERABLE_OFFSET + size_of::<bool>();
+const BUMP_OFFSET: usize = MAX_RECORDS_OFFSET + size_of::<u64>();
+const NAME_LEN_OFFSET: usize = BUMP_OFFSET + size_of::<u8>();
 
 pub struct CreateClass<'info> {
     accounts: CreateClassAccounts<'info>,
     is_permissioned: bool,
     is_frozen: bool,
+    is_non_transferable: bool,
+    max_records: u64,
+    bump: u8,
     name: &'info str,
     metadata: &'info str,
 }
 
 /// Minimum length of instruction data required for CreateClass
-pub const CREATE_CLASS_MIN_IX_LENGTH: usize = size_of::<bool>() * 2 + size_of::<u8>();
+pub const CREATE_CLASS_MIN_IX_LENGTH: usize =
+    size_of::<bool>() * 3 + size_of::<u64>() + size_of::<u8>() * 2;
 
 impl<'info> TryFrom<Context<'info>> for CreateClass<'info> {
     type Error = ProgramError;
@@ -96,6 +107,16 @@ impl<'info> TryFrom<Context<'info>> for CreateClass<'info> {
         // Deserialize `is_frozen`
         let is_frozen: bool = ByteReader::read_with_offset(ctx.data, IS_FROZEN_OFFSET)?;
 
+        // Deserialize `is_non_transferable`
+        let is_non_transferable: bool =
+            ByteReader::read_with_offset(ctx.data, IS_NON_TRANSFERABLE_OFFSET)?;
+
+        // Deserialize `max_records`
+        let max_records: u64 = ByteReader::read_with_offset(ctx.data, MAX_RECORDS_OFFSET)?;
+
+        // Deserialize `bump`
+        let bump: u8 = ByteReader::read_with_offset(ctx.data, BUMP_OFFSET)?;
+
         // Read the variable length data
         let mut variable_data: ByteReader<'info> =
             ByteReader::new_with_offset(ctx.data, NAME_LEN_OFFSET);
@@ -105,7 +126,7 @@ impl<'info> TryFrom<Context<'info>> for CreateClass<'info> {
 
         #[cfg(not(feature = "perf"))]
         if name.len() > Class::MAX_CLASS_NAME_LEN {
-            return Err(ProgramError::InvalidArgument);
+            return Err(RecordServiceError::NameTooLong.into());
         }
 
         // Read the remaining data as metadata
@@ -113,13 +134,16 @@ impl<'info> TryFrom<Context<'info>> for CreateClass<'info> {
 
         #[cfg(not(feature = "perf"))]
         if metadata.len() > MAX_METADATA_LEN {
-            return Err(ProgramError::InvalidArgument);
+            return Err(RecordServiceError::MetadataTooLong.into());
         }
 
         Ok(Self {
             accounts,
             is_permissioned,
             is_frozen,
+            is_non_transferable,
+            max_records,
+            bump,
             name,
             metadata,
         })
@@ -138,15 +162,19 @@ impl<'info> CreateClass<'info> {
         let rent = Rent::get()?.minimum_balance(space);
         let lamports = rent.saturating_sub(self.accounts.class.lamports());
 
-        let seeds = [
-            b"class",
-            self.accounts.authority.key().as_ref(),
-            self.name.as_bytes(),
-        ];
+        let bump: [u8; 1] = [self.bump];
 
-        let bump: [u8; 1] = [try_find_program_address(&seeds, &crate::ID)
-            .ok_or(ProgramError::InvalidArgument)?
-            .1];
+        // Check if the class is the PDA of the authority and the name
+        check_program_address(
+            self.accounts.class,
+            &[
+                b"class",
+                self.accounts.authority.key(),
+                self.name.as_bytes(),
+                &bump,
+            ],
+            RecordServiceError::InvalidPda,
+        )?;
 
         let seeds = [
             Seed::from(b"class"),
@@ -194,10 +222,33 @@ impl<'info> CreateClass<'info> {
             authority: *self.accounts.authority.key(),
             is_permissioned: self.is_permissioned,
             is_frozen: self.is_frozen,
+            is_non_transferable: self.is_non_transferable,
+            policy: ClassPolicy::new_default(self.is_permissioned),
+            record_count: 0,
+            tokenized_count: 0,
+            legacy_record_count: 0,
+            max_records: self.max_records,
+            creation_fee: 0,
+            treasury: [0; 32],
+            merkle_root: [0; 32],
+            group_bump: 0,
+            schema_bump: 0,
             name: self.name,
             metadata: self.metadata,
         };
 
-        unsafe { class.initialize_unchecked(self.accounts.class) }
+        unsafe { class.initialize_unchecked(self.accounts.class) }?;
+
+        ClassCreated {
+            class: self.accounts.class.key(),
+            authority: self.accounts.authority.key(),
+            is_permissioned: self.is_permissioned,
+            is_frozen: self.is_frozen,
+            is_non_transferable: self.is_non_transferable,
+            max_records: self.max_records,
+        }
+        .emit();
+
+        Ok(())
     }
 }
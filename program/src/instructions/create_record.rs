@@ -5,22 +5,26 @@ use pinocchio::log::sol_log;
 
 use core::mem::size_of;
 use pinocchio::{
-    account_info::AccountInfo, instruction::{Seed, Signer}, log::sol_log_64, program_error::ProgramError, pubkey::try_find_program_address, sysvars::{rent::Rent, Sysvar}, ProgramResult
+    account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, sysvars::{rent::Rent, Sysvar}, ProgramResult
 };
 use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
 use crate::{
-    state::{Class, OwnerType, Record},
-    utils::{ByteReader, Context},
+    error::RecordServiceError,
+    events::{Event, RecordCreated, RecordRecreated},
+    state::{Class, ClassSchema, ContentType, OwnerType, Record, RecordUpdate, WriteState},
+    utils::{check_program_address, hashv, ByteReader, Context},
 };
 
 /// CreateRecord instruction.
 ///
 /// This function:
 /// 1. Calculates required account space and rent
-/// 2. Derives the PDA for the record account
+/// 2. Checks the PDA of the record account with the bump passed in
 /// 3. Creates the new account
 /// 4. Initializes the record data
+/// 5. Increments the record count of the class
+/// 6. Transfers the creation fee of the class, if any, from the payer to the class treasury
 ///
 /// # Accounts
 /// 1. `owner` - The account that will own the record
@@ -28,50 +32,79 @@ use crate::{
 /// 3. `class` - The class account that this record belongs to
 /// 4. `record` - The new record account to be created
 /// 5. `authority` - [as remaining accounts] The authority account of the class
+/// 6. `class_delegate` - [as remaining accounts] The class delegate account of the authority
+/// 7. `schema` - [as remaining accounts] The schema PDA of the class, required if the class has a schema
+/// 8. `treasury` - [as remaining accounts] The treasury of the class, required if it charges a fee
 ///
 /// # Security
 /// 1. Check if the class is permissioned, if so, the instruction must pass
-///    the class authority as signer in the remaining accounts
+///    the class authority, or a class delegate with the create permission,
+///    as signer in the remaining accounts
 /// 2. The class must not be frozen
+/// 3. The class must not have reached its maximum number of records
+/// 4. If the class has a schema, the data must match it, otherwise utf-8 records
+///    must be valid utf-8 and binary records accept any data
+/// 5. If the class charges a creation fee, the treasury must be the class treasury
+/// 6. The record account must be the PDA of the class and the seed with the
+///    bump passed in, which must be the canonical bump
 pub struct CreateRecordAccounts<'info> {
     owner: &'info AccountInfo,
     payer: &'info AccountInfo,
     class: &'info AccountInfo,
     record: &'info AccountInfo,
+    schema: Option<&'info AccountInfo>,
+    treasury: Option<&'info AccountInfo>,
 }
 
 impl<'info> TryFrom<&'info [AccountInfo]> for CreateRecordAccounts<'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        Self::try_from_with_proof(accounts, None)
+    }
+}
+
+impl<'info> CreateRecordAccounts<'info> {
+    fn try_from_with_proof(
+        accounts: &'info [AccountInfo],
+        allowlist_proof: Option<&[u8]>,
+    ) -> Result<Self, ProgramError> {
         let [owner, payer, class, record, _system_program, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        sol_log_64(0, 0, 0, 0, 0);
-
-        // Check class permission
-        Class::check_permission(class, rest.first())?;
-
-        sol_log_64(0, 0, 0, 0, 0);
+        // Check class permission, owners in the allowlist don't need the authority
+        Class::check_permission(
+            class,
+            rest.first(),
+            rest.get(1),
+            allowlist_proof.map(|proof| (owner, proof)),
+        )?;
 
         Ok(Self {
             owner,
             payer,
             class,
             record,
+            schema: rest.get(2),
+            treasury: rest.get(3),
         })
     }
 }
 
 const EXPIRY_OFFSET: usize = 0;
-const SEED_LEN_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+const CONTENT_TYPE_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+const BUMP_OFFSET: usize = CONTENT_TYPE_OFFSET + size_of::<u8>();
+const SEED_LEN_OFFSET: usize = BUMP_OFFSET + size_of::<u8>();
 
 pub struct CreateRecord<'info> {
     accounts: CreateRecordAccounts<'info>,
     expiry: i64,
+    content_type: ContentType,
+    bump: u8,
     seed: &'info [u8],
-    data: &'info str,
+    data: &'info [u8],
+    write_state: WriteState,
 }
 
 /// Minimum length of instruction data required for CreateRecord
@@ -81,40 +114,83 @@ impl<'info> TryFrom<Context<'info>> for CreateRecord<'info> {
     type Error = ProgramError;
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
-        sol_log_64(0, 0, 0, 0, 0);
+        Self::try_from_with_options(ctx, WriteState::Idle, None)
+    }
+}
 
+impl<'info> CreateRecord<'info> {
+    fn try_from_with_options(
+        ctx: Context<'info>,
+        write_state: WriteState,
+        allowlist_proof: Option<&[u8]>,
+    ) -> Result<Self, ProgramError> {
         // Deserialize our accounts array
-        let accounts = CreateRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = CreateRecordAccounts::try_from_with_proof(ctx.accounts, allowlist_proof)?;
 
+        Self::try_from_parts(accounts, ctx.data, write_state)
+    }
+
+    fn try_from_parts(
+        accounts: CreateRecordAccounts<'info>,
+        ix_data: &'info [u8],
+        write_state: WriteState,
+    ) -> Result<Self, ProgramError> {
         // Check minimum instruction data length
         #[cfg(not(feature = "perf"))]
-        if ctx.data.len() < CREATE_RECORD_MIN_IX_LENGTH {
+        if ix_data.len() < CREATE_RECORD_MIN_IX_LENGTH {
             return Err(ProgramError::InvalidArgument);
         }
 
         // Deserialize `expiry`
-        let expiry: i64 = ByteReader::read_with_offset(ctx.data, EXPIRY_OFFSET)?;
+        let expiry: i64 = ByteReader::read_with_offset(ix_data, EXPIRY_OFFSET)?;
+
+        // Deserialize `content_type`
+        let content_type =
+            ContentType::try_from(ByteReader::read_with_offset::<u8>(ix_data, CONTENT_TYPE_OFFSET)?)?;
+
+        // Deserialize `bump`
+        let bump: u8 = ByteReader::read_with_offset(ix_data, BUMP_OFFSET)?;
 
         // Deserialize variable length data
         let mut variable_data: ByteReader<'info> =
-            ByteReader::new_with_offset(ctx.data, SEED_LEN_OFFSET);
+            ByteReader::new_with_offset(ix_data, SEED_LEN_OFFSET);
 
         // Deserialize `seed`
         let seed: &[u8] = variable_data.read_bytes_with_length()?;
 
+        // Deserialize `data`
+        let data: &[u8] = variable_data.read_bytes(variable_data.remaining_bytes())?;
+
+        Self::new(accounts, expiry, content_type, bump, seed, data, write_state)
+    }
+
+    fn new(
+        accounts: CreateRecordAccounts<'info>,
+        expiry: i64,
+        content_type: ContentType,
+        bump: u8,
+        seed: &'info [u8],
+        data: &'info [u8],
+        write_state: WriteState,
+    ) -> Result<Self, ProgramError> {
         #[cfg(not(feature = "perf"))]
         if seed.len() > MAX_SEED_LEN {
-            return Err(ProgramError::InvalidArgument);
+            return Err(RecordServiceError::SeedTooLong.into());
         }
 
-        // Deserialize `data`
-        let data: &str = variable_data.read_str(variable_data.remaining_bytes())?;
+        // Check `data` against the class schema, buffered records are checked once finalized
+        if write_state == WriteState::Idle {
+            ClassSchema::check_data(accounts.schema, accounts.class, content_type, data)?;
+        }
 
         Ok(Self {
             accounts,
             expiry,
+            content_type,
+            bump,
             seed,
             data,
+            write_state,
         })
     }
 }
@@ -127,15 +203,20 @@ impl<'info> CreateRecord<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
+        // Count the record, checking the class cap
+        Class::add_record(self.accounts.class)?;
+
+        // Pay the class creation fee, checking the treasury
+        Class::pay_creation_fee(self.accounts.class, self.accounts.payer, self.accounts.treasury)?;
+
         let space = Record::MINIMUM_RECORD_SIZE + self.seed.len() + self.data.len();
         let rent = Rent::get()?.minimum_balance(space);
         let lamports = rent.saturating_sub(self.accounts.record.lamports());
 
-        let seeds = [b"record", self.accounts.class.key().as_ref(), self.seed];
+        let bump: [u8; 1] = [self.bump];
 
-        let bump: [u8; 1] = [try_find_program_address(&seeds, &crate::ID)
-            .ok_or(ProgramError::InvalidArgument)?
-            .1];
+        // Check if the record is the PDA of the class and the seed
+        self.check_address()?;
 
         let seeds = [
             Seed::from(b"record"),
@@ -179,12 +260,64 @@ impl<'info> CreateRecord<'info> {
             .invoke_signed(&signers)?;
         }    
 
+        self.initialize(0)?;
+
+        RecordCreated {
+            record: self.accounts.record.key(),
+            class: self.accounts.class.key(),
+            owner: self.accounts.owner.key(),
+            expiry: self.expiry,
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Check if the record account is the PDA of the class and the seed, with
+    /// the canonical bump passed in
+    fn check_address(&self) -> ProgramResult {
+        check_program_address(
+            self.accounts.record,
+            &[
+                b"record",
+                self.accounts.class.key(),
+                self.seed,
+                &[self.bump],
+            ],
+            RecordServiceError::InvalidPda,
+        )
+    }
+
+    /// Initialize the record data in the created account
+    fn initialize(&self, generation: u32) -> ProgramResult {
         let record = Record {
             class: *self.accounts.class.key(),
             owner_type: OwnerType::Pubkey,
             owner: *self.accounts.owner.key(),
             is_frozen: false,
             expiry: self.expiry,
+            is_revoked: false,
+            revocation_reason: 0,
+            revoked_at: 0,
+            version: 0,
+            generation,
+            // The history of buffered records starts when they are finalized
+            hash: if self.write_state == WriteState::Idle {
+                RecordUpdate::Data.chain(&[0; 32], self.data)
+            } else {
+                [0; 32]
+            },
+            write_state: self.write_state,
+            staged_len: 0,
+            // Until then the payer is kept so that it can write the data and
+            // get the rent back if the record is cancelled
+            staged_payer: if self.write_state == WriteState::Idle {
+                [0; 32]
+            } else {
+                *self.accounts.payer.key()
+            },
+            content_type: self.content_type,
+            mint_bump: 0,
             seed: self.seed,
             data: self.data,
         };
@@ -192,3 +325,385 @@ impl<'info> CreateRecord<'info> {
         unsafe { record.initialize_unchecked(self.accounts.record) }
     }
 }
+
+/// CreateBufferedRecord instruction.
+///
+/// Same as CreateRecord, but the record is created in the writing state with
+/// `data` as its first chunk, so records larger than a transaction can be
+/// uploaded with WriteRecordChunk and checked with FinalizeRecordWrite.
+///
+/// # Accounts
+/// Same as CreateRecord
+///
+/// # Security
+/// 1. Same as CreateRecord, the data is checked against the class schema once finalized
+/// 2. The owner and the payer may write, finalize or cancel the record until
+///    it is finalized, whatever the class policy
+/// 3. The record is counted in the class when it is created, CancelRecordWrite
+///    deletes it, uncounts it and refunds the rent to the payer
+pub struct CreateBufferedRecord<'info> {
+    create: CreateRecord<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for CreateBufferedRecord<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            create: CreateRecord::try_from_with_options(ctx, WriteState::Creating, None)?,
+        })
+    }
+}
+
+impl<'info> CreateBufferedRecord<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Create Buffered Record");
+        Self::try_from(ctx)?.create.execute()
+    }
+}
+
+/// RecreateRecord instruction.
+///
+/// Same as CreateRecord, at the address of a deleted record. The tombstone of
+/// the deleted record is reopened and the new record gets the next generation,
+/// so that references to the deleted record can tell that it was replaced.
+///
+/// # Accounts
+/// Same as CreateRecord, `record` is the tombstone of the deleted record
+///
+/// # Security
+/// 1. The record must be the tombstone of a deleted record of the class with the same seed
+/// 2. Same as CreateRecord for the other checks
+pub struct RecreateRecord<'info> {
+    create: CreateRecord<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for RecreateRecord<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            create: CreateRecord::try_from(ctx)?,
+        })
+    }
+}
+
+impl<'info> RecreateRecord<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Recreate Record");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let create = &self.create;
+
+        // Check if the record is the one of the class with this seed, the
+        // tombstone is reopened without the PDA signing
+        create.check_address()?;
+
+        // Check if the record was deleted
+        let generation = Record::get_deleted_generation(create.accounts.record)?
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        // Count the record, checking the class cap
+        Class::add_record(create.accounts.class)?;
+
+        // Pay the class creation fee, checking the treasury
+        Class::pay_creation_fee(
+            create.accounts.class,
+            create.accounts.payer,
+            create.accounts.treasury,
+        )?;
+
+        // Safety: The account is the tombstone of a record of the class
+        unsafe {
+            Record::reopen_unchecked(
+                create.accounts.record,
+                create.accounts.payer,
+                Record::MINIMUM_RECORD_SIZE + create.seed.len() + create.data.len(),
+            )?;
+        }
+
+        create.initialize(generation)?;
+
+        RecordRecreated {
+            record: create.accounts.record.key(),
+            class: create.accounts.class.key(),
+            owner: create.accounts.owner.key(),
+            expiry: create.expiry,
+            generation,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+/// CreateRecordWithProof instruction.
+///
+/// Same as CreateRecord, with a merkle proof prepended to the instruction data
+/// as its number of hashes (u8) followed by the 32 bytes sibling hashes. The
+/// owner, if it is in the class allowlist, creates the record without the
+/// class authority, even in permissioned classes.
+///
+/// # Accounts
+/// Same as CreateRecord, the `authority` and `class_delegate` are not checked
+///
+/// # Security
+/// 1. The owner must be a signer
+/// 2. The proof must link the owner to the merkle root of the class
+/// 3. Same as CreateRecord for the other checks
+pub struct CreateRecordWithProof<'info> {
+    create: CreateRecord<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for CreateRecordWithProof<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize `proof`
+        let mut proof_data: ByteReader<'info> = ByteReader::new(ctx.data);
+        let proof_len: u8 = proof_data.read()?;
+        let proof = proof_data.read_bytes(proof_len as usize * size_of::<[u8; 32]>())?;
+
+        let data = &ctx.data[size_of::<u8>() + proof.len()..];
+
+        Ok(Self {
+            create: CreateRecord::try_from_with_options(
+                Context {
+                    accounts: ctx.accounts,
+                    data,
+                },
+                WriteState::Idle,
+                Some(proof),
+            )?,
+        })
+    }
+}
+
+impl<'info> CreateRecordWithProof<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Create Record With Proof");
+        Self::try_from(ctx)?.create.execute()
+    }
+}
+
+/// CreateRecordFromSignature instruction.
+///
+/// Same as CreateRecord, but the class authority attests the record with an
+/// Ed25519 signature instead of signing the transaction, so a relayer can
+/// issue records for an authority that can only sign messages.
+///
+/// An Ed25519 precompile instruction of the transaction, before or after
+/// this one, must verify the authority signature over:
+/// class (32) | seed length (u8) | seed | bump (u8) | owner (32) | expiry (i64) |
+/// content type (u8) | sha256 of the data (32)
+///
+/// # Accounts
+/// 1. `owner` - The account that will own the record
+/// 2. `payer` - The account that will pay for the record account
+/// 3. `class` - The class account that this record belongs to
+/// 4. `record` - The new record account to be created
+/// 5. `system_program` - The system program
+/// 6. `instructions_sysvar` - The instructions sysvar, to read the Ed25519 instruction
+/// 7. `schema` - [optional] The schema PDA of the class, required if the class has a schema
+/// 8. `treasury` - [optional] The treasury of the class, required if it charges a fee
+///
+/// # Security
+/// 1. One of the signatures of an Ed25519 instruction must be of the class
+///    authority, with the public key and the message in the Ed25519 instruction data
+/// 2. The signed message must match the record to be created
+/// 3. A signature can't be replayed, the record PDA can only be created once
+///    and the bump is signed so that it attests a single address
+/// 4. Same as CreateRecord for the other checks
+pub struct CreateRecordFromSignature<'info> {
+    create: CreateRecord<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for CreateRecordFromSignature<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        let [owner, payer, class, record, _system_program, instructions_sysvar, rest @ ..] =
+            ctx.accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let accounts = CreateRecordAccounts {
+            owner,
+            payer,
+            class,
+            record,
+            schema: rest.first(),
+            treasury: rest.get(1),
+        };
+
+        let create = CreateRecord::try_from_parts(accounts, ctx.data, WriteState::Idle)?;
+
+        // Check the authority signature over the record
+        Class::check_attested_permission(
+            class,
+            instructions_sysvar,
+            &[
+                class.key(),
+                &[create.seed.len() as u8],
+                create.seed,
+                &[create.bump],
+                owner.key(),
+                &create.expiry.to_le_bytes(),
+                &[create.content_type as u8],
+                &hashv(&[create.data]),
+            ],
+        )?;
+
+        Ok(Self { create })
+    }
+}
+
+impl<'info> CreateRecordFromSignature<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Create Record From Signature");
+        Self::try_from(ctx)?.create.execute()
+    }
+}
+
+/// BatchCreateRecords instruction.
+///
+/// Same as CreateRecord for many records at once, the class permission is
+/// checked once and each record is then created from its own owner and
+/// record accounts.
+///
+/// The instruction data is the number of records (u8) followed by each
+/// record as: expiry (i64) | content type (u8) | bump (u8) | seed length (u8) |
+/// seed | data length (u32) | data
+///
+/// # Accounts
+/// 1. `payer` - The account that will pay for the record accounts
+/// 2. `class` - The class account that the records belong to
+/// 3. `system_program` - The system program
+/// 4. `authority` - [optional] The authority account of the class
+/// 5. `class_delegate` - [optional] The class delegate account of the authority
+/// 6. `schema` - [optional] The schema PDA of the class, required if the class has a schema
+/// 7. `treasury` - [optional] The treasury of the class, required if it charges a fee
+/// 8. `owner`, `record` - [remaining accounts] The owner and the new record account,
+///    for each record
+///
+/// # Security
+/// 1. Same as CreateRecord, the class permission applies to every record
+pub struct BatchCreateRecordsAccounts<'info> {
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    schema: &'info AccountInfo,
+    treasury: &'info AccountInfo,
+    records: &'info [AccountInfo],
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for BatchCreateRecordsAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            payer,
+            class,
+            _system_program,
+            authority,
+            class_delegate,
+            schema,
+            treasury,
+            records @ ..,
+        ] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check class permission once for every record
+        Class::check_permission(class, Some(authority), Some(class_delegate), None)?;
+
+        Ok(Self {
+            payer,
+            class,
+            schema,
+            treasury,
+            records,
+        })
+    }
+}
+
+pub struct BatchCreateRecords<'info> {
+    accounts: BatchCreateRecordsAccounts<'info>,
+    records: &'info [u8],
+}
+
+impl<'info> TryFrom<Context<'info>> for BatchCreateRecords<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = BatchCreateRecordsAccounts::try_from(ctx.accounts)?;
+
+        // Deserialize `count`
+        let count: u8 = ByteReader::read_with_offset(ctx.data, 0)?;
+
+        // Check that each record has its owner and record accounts
+        if accounts.records.len() != count as usize * 2 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        Ok(Self {
+            accounts,
+            records: &ctx.data[size_of::<u8>()..],
+        })
+    }
+}
+
+impl<'info> BatchCreateRecords<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Batch Create Records");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let mut records: ByteReader<'info> = ByteReader::new(self.records);
+
+        for accounts in self.accounts.records.chunks_exact(2) {
+            let [owner, record] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            // Deserialize the record
+            let expiry: i64 = records.read()?;
+            let content_type = ContentType::try_from(records.read::<u8>()?)?;
+            let bump: u8 = records.read()?;
+            let seed: &[u8] = records.read_bytes_with_length()?;
+            let data_len: u32 = records.read()?;
+            let data: &[u8] = records.read_bytes(data_len as usize)?;
+
+            CreateRecord::new(
+                CreateRecordAccounts {
+                    owner,
+                    payer: self.accounts.payer,
+                    class: self.accounts.class,
+                    record,
+                    schema: Some(self.accounts.schema),
+                    treasury: Some(self.accounts.treasury),
+                },
+                expiry,
+                content_type,
+                bump,
+                seed,
+                data,
+                WriteState::Idle,
+            )?
+            .execute()?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{state::Record, utils::Context};
+use crate::{
+    events::{Event, RecordDeleted},
+    error::RecordServiceError,
+    state::{Class, Permission, Record},
+    utils::Context,
+};
 #[cfg(not(feature = "perf"))]
 use pinocchio::{log::sol_log, sysvars::{Sysvar, rent::Rent}};
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
@@ -7,26 +12,37 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramR
 ///
 /// This function:
 /// 1. Reallocates the record account data to 1 byte, 0xff to counter
-///    reinitialization attacks
+///    reinitialization attacks, followed by the record generation if the
+///    record was recreated, see RecreateRecord
 /// 2. Transfers the lamports from the record to the authority
-/// 3. If the record has an authority delegate, it will close the delegate account
-///    as well
+/// 3. Decrements the record count of the class, and its tokenized count if the
+///    record token was burned outside of BurnTokenizedRecord
 ///
 /// # Accounts
 /// 1. `authority` - The account that has permission to delete the record (must be a signer)
 /// 2. `payer` - The account that will get refunded for the record account
 /// 3. `record` - The record account to be deleted
-/// 4. `class` - [optional] The class of the record to be deleted
+/// 4. `class` - The class of the record to be deleted, its policy decides who may delete (must be writable)
 /// 5. `token2022_program` - [optional] The token2022 program to be used to close the mint account
 /// 6. `mint` - [optional] The mint of the record to be deleted
+/// 7. `class_delegate` - [optional] The class delegate account of the authority
+/// 8. `record_delegate` - [optional] The record delegate account of the authority
 ///
 /// # Security
-/// 1. The authority must be either:
-///    a. The record owner, or
-///    b. if the class is permissioned, the authority can be the permissioned authority
+/// 1. Depending on the class delete policy, the authority must be either:
+///    a. The record owner, or a record delegate approved by the owner with
+///    the delete permission, and/or
+///    b. the class authority or a class delegate with the delete permission
+/// 2. The record must not be revoked, revoked records are kept as evidence
+/// 3. The class must be the class of the record
+/// 4. The record delegate account is not closed, it is void for a record
+///    created again at the same address and the owner that approved it gets
+///    its rent back with RevokeRecordDelegate
 pub struct DeleteRecordAccounts<'info> {
     payer: &'info AccountInfo,
     record: &'info AccountInfo,
+    class: &'info AccountInfo,
+    is_tokenized: bool,
 }
 
 impl<'info> TryFrom<&'info [AccountInfo]> for DeleteRecordAccounts<'info> {
@@ -37,12 +53,32 @@ impl<'info> TryFrom<&'info [AccountInfo]> for DeleteRecordAccounts<'info> {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        let class = rest.first().ok_or(RecordServiceError::MissingClass)?;
+
         // Check if authority is the record owner or has a delegate
-        Record::check_owner_or_delegate_or_deleted(record, rest.first(), authority, rest.last())?;
+        Record::check_owner_or_delegate_or_deleted(
+            record,
+            Some(class),
+            rest.get(3),
+            rest.get(4),
+            authority,
+            rest.get(2),
+        )?;
+
+        // Check if the record is revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record.try_borrow_data()?)? };
+
+        // Check if the class is the class of the record [this is safe, the record has already been validated]
+        unsafe { Record::check_class_unchecked(&record.try_borrow_data()?, class)? };
+
+        // Check if the record token was burned [this is safe, the record has already been validated]
+        let is_tokenized = unsafe { Record::is_tokenized_unchecked(&record.try_borrow_data()?) };
 
         Ok(Self {
             payer,
             record,
+            class,
+            is_tokenized,
         })
     }
 }
@@ -75,6 +111,115 @@ impl<'info> DeleteRecord<'info> {
             Record::delete_record_unchecked(self.accounts.record, self.accounts.payer)?;
         }
 
+        // Uncount the record
+        Class::remove_record(self.accounts.class, self.accounts.is_tokenized)?;
+
+        RecordDeleted {
+            record: self.accounts.record.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+/// BatchDeleteRecords instruction.
+///
+/// Same as DeleteRecord, by the class authority, for many records at once.
+/// The class authority and the delete policy are checked once and each record
+/// of the remaining accounts is then deleted.
+///
+/// # Accounts
+/// 1. `authority` - The class authority or a class delegate allowed to delete (must be a signer)
+/// 2. `payer` - The account that will get refunded for the record accounts
+/// 3. `class` - The class of the records to be deleted (must be writable)
+/// 4. `class_delegate` - [optional] The class delegate account of the authority
+/// 5. `records` - [remaining accounts] The record accounts to be deleted
+///
+/// # Security
+/// 1. The class delete policy must let the authority delete records
+/// 2. Every record must be of the class and must not be revoked
+/// 3. Tokenized records can't be batch deleted, their mint must be closed with DeleteRecord
+pub struct BatchDeleteRecordsAccounts<'info> {
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    records: &'info [AccountInfo],
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for BatchDeleteRecordsAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, class_delegate, records @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority may delete the records of the class, once for every record
+        Record::validate_delegate(
+            class,
+            Some(class_delegate),
+            authority,
+            Permission::DeleteRecord,
+        )?;
+
+        Ok(Self {
+            payer,
+            class,
+            records,
+        })
+    }
+}
+
+pub struct BatchDeleteRecords<'info> {
+    accounts: BatchDeleteRecordsAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for BatchDeleteRecords<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = BatchDeleteRecordsAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> BatchDeleteRecords<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Batch Delete Records");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        for record in self.accounts.records {
+            // Check if the Record is correct
+            Record::check_program_id_and_discriminator(record)?;
+
+            // Check the record [this is safe, the record has already been validated]
+            unsafe {
+                let data = record.try_borrow_data()?;
+
+                Record::check_class_unchecked(&data, self.accounts.class)?;
+                Record::check_not_revoked_unchecked(&data)?;
+
+                if Record::is_tokenized_unchecked(&data) {
+                    return Err(RecordServiceError::MissingMint.into());
+                }
+            }
+
+            DeleteRecord {
+                accounts: DeleteRecordAccounts {
+                    payer: self.accounts.payer,
+                    record,
+                    class: self.accounts.class,
+                    is_tokenized: false,
+                },
+            }
+            .execute()?;
+        }
+
         Ok(())
     }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    events::{ClassFrozen, Event},
     state::Class,
     utils::{ByteReader, Context},
 };
@@ -84,6 +85,14 @@ impl<'info> FreezeClass<'info> {
                 self.accounts.class,
                 self.is_frozen,
             )
+        }?;
+
+        ClassFrozen {
+            class: self.accounts.class.key(),
+            is_frozen: self.is_frozen,
         }
+        .emit();
+
+        Ok(())
     }
 }
@@ -1,8 +1,12 @@
 use crate::{
-    state::{Class, Record, CLASS_OFFSET},
+    error::RecordServiceError,
+    events::{Event, RecordFrozen},
+    state::{Class, Permission, Record, CLASS_OFFSET},
     utils::{ByteReader, Context},
 };
 use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult};
 
 /// FreezeRecord instruction.
@@ -16,9 +20,11 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::
 /// 1. `authority` - The account that has permission to freeze/unfreeze the record (must be a signer)
 /// 2. `record` - The record account to be frozen/unfrozen
 /// 3. `class` - The class of the record to be frozen/unfrozen
+/// 4. `class_delegate` - [optional] The class delegate account of the authority
 ///
 /// # Security
-/// The authority must be the class authority
+/// 1. The authority must be the class authority or a class delegate with the freeze permission
+/// 2. The record must not be revoked
 pub struct FreezeRecordAccounts<'info> {
     record: &'info AccountInfo,
 }
@@ -26,21 +32,24 @@ pub struct FreezeRecordAccounts<'info> {
 impl<'info> TryFrom<&'info [AccountInfo]> for FreezeRecordAccounts<'info> {
     type Error = ProgramError;
     fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, record, class] = accounts else {
+        let [authority, record, class, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        // Check if authority is the class authority
-        Class::check_authority(class, authority)?;
+        // Check if authority is the class authority or a class delegate
+        Class::check_authority_or_delegate(class, authority, rest.first(), Permission::FreezeRecord)?;
 
         // Check if the Record is correct
         Record::check_program_id_and_discriminator(record)?;
 
         // Check if the class is the correct class
         if class.key().ne(&record.try_borrow_data()?[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::ClassMismatch.into());
         }
 
+        // Check if the record is revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record.try_borrow_data()?)? };
+
         Ok(Self { record })
     }
 }
@@ -92,6 +101,111 @@ impl<'info> FreezeRecord<'info> {
                 &mut self.accounts.record.try_borrow_mut_data()?,
                 self.is_frozen,
             )
+        }?;
+
+        RecordFrozen {
+            record: self.accounts.record.key(),
+            is_frozen: self.is_frozen,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+/// BatchFreezeRecords instruction.
+///
+/// Same as FreezeRecord for many records at once, the class authority is
+/// checked once and each record of the remaining accounts is then frozen or
+/// unfrozen.
+///
+/// # Accounts
+/// 1. `authority` - The account allowed to freeze/unfreeze the records (must be a signer)
+/// 2. `class` - The class of the records to be frozen/unfrozen
+/// 3. `class_delegate` - [optional] The class delegate account of the authority
+/// 4. `records` - [remaining accounts] The record accounts to be frozen/unfrozen
+///
+/// # Security
+/// 1. The authority must be the class authority or a class delegate with the freeze permission
+/// 2. Every record must be of the class and must not be revoked
+pub struct BatchFreezeRecordsAccounts<'info> {
+    class: &'info AccountInfo,
+    records: &'info [AccountInfo],
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for BatchFreezeRecordsAccounts<'info> {
+    type Error = ProgramError;
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, class, class_delegate, records @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority or a class delegate, once for every record
+        Class::check_authority_or_delegate(
+            class,
+            authority,
+            Some(class_delegate),
+            Permission::FreezeRecord,
+        )?;
+
+        Ok(Self { class, records })
+    }
+}
+
+pub struct BatchFreezeRecords<'info> {
+    accounts: BatchFreezeRecordsAccounts<'info>,
+    is_frozen: bool,
+}
+
+impl<'info> TryFrom<Context<'info>> for BatchFreezeRecords<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = BatchFreezeRecordsAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < FREEZE_RECORD_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
         }
+
+        // Deserialize `is_frozen`
+        let is_frozen: bool = ByteReader::read_with_offset(ctx.data, IS_FROZEN_OFFSET)?;
+
+        Ok(Self {
+            accounts,
+            is_frozen,
+        })
+    }
+}
+
+impl<'info> BatchFreezeRecords<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Batch Freeze Records");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        for record in self.accounts.records {
+            // Check if the Record is correct
+            Record::check_program_id_and_discriminator(record)?;
+
+            // Check if the class is the class of the record and if the record is revoked
+            // [this is safe, the record has already been validated]
+            unsafe {
+                Record::check_class_unchecked(&record.try_borrow_data()?, self.accounts.class)?;
+                Record::check_not_revoked_unchecked(&record.try_borrow_data()?)?;
+            }
+
+            FreezeRecord {
+                accounts: FreezeRecordAccounts { record },
+                is_frozen: self.is_frozen,
+            }
+            .execute()?;
+        }
+
+        Ok(())
     }
 }
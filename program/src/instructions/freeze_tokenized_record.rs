@@ -1,11 +1,15 @@
 use crate::{
-    state::{Class, Record, CLASS_OFFSET, OWNER_OFFSET},
+    error::RecordServiceError,
+    events::{Event, TokenizedRecordFrozen},
+    state::{Class, Permission, Record, CLASS_OFFSET, OWNER_OFFSET},
     token2022::{FreezeAccount, ThawAccount, Token},
     utils::{ByteReader, Context},
 };
 use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
 use pinocchio::{
-    account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::{try_find_program_address, Pubkey}, ProgramResult
+    account_info::AccountInfo, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::Pubkey, ProgramResult
 };
 
 /// FreezeRecord instruction.
@@ -22,9 +26,11 @@ use pinocchio::{
 /// 4. `record` - The record account to be frozen/unfrozen
 /// 5. `class` - The class of the record to be frozen/unfrozen
 /// 6. `token_2022_program` - Required for freezing/unfreezing the token account
+/// 7. `class_delegate` - [optional] The class delegate account of the authority
 ///
 /// # Security
-/// The authority must be: the class authority
+/// 1. The authority must be: the class authority or a class delegate with the freeze permission
+/// 2. The record must not be revoked
 pub struct FreezeTokenizedRecordAccounts<'info> {
     mint: &'info AccountInfo,
     token_account: &'info AccountInfo,
@@ -34,12 +40,12 @@ pub struct FreezeTokenizedRecordAccounts<'info> {
 impl<'info> TryFrom<&'info [AccountInfo]> for FreezeTokenizedRecordAccounts<'info> {
     type Error = ProgramError;
     fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, mint, token_account, record, class, _token_2022_program] = accounts else {
+        let [authority, mint, token_account, record, class, _token_2022_program, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        // Check if authority is the class authority
-        Class::check_authority(class, authority)?;
+        // Check if authority is the class authority or a class delegate
+        Class::check_authority_or_delegate(class, authority, rest.first(), Permission::FreezeRecord)?;
 
         // Check if the Record is correct
         Record::check_program_id_and_discriminator(record)?;
@@ -47,14 +53,17 @@ impl<'info> TryFrom<&'info [AccountInfo]> for FreezeTokenizedRecordAccounts<'inf
         let record_data = record.try_borrow_data()?;
         // Check if the class is the correct class
         if class.key().ne(&record_data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::ClassMismatch.into());
         }
 
         // Check if the token is linked to the record
         if mint.key().ne(&record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::InvalidMint.into());
         }
 
+        // Check if the record is revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record_data)? };
+
         Ok(Self {
             mint,
             token_account,
@@ -112,11 +121,9 @@ impl<'info> FreezeTokenizedRecord<'info> {
             return Ok(());
         }
 
-        let bump = [
-            try_find_program_address(&[b"mint", self.accounts.record.key()], &crate::ID)
-                .ok_or(ProgramError::InvalidArgument)?
-                .1,
-        ];
+        // Mint bump stored on tokenization [this is safe, the record has already been validated]
+        let bump =
+            unsafe { Record::get_mint_bump_unchecked(&self.accounts.record.try_borrow_data()?) };
 
         let seeds = [
             Seed::from(b"mint"),
@@ -142,6 +149,13 @@ impl<'info> FreezeTokenizedRecord<'info> {
             .invoke_signed(&signers)?;
         }
 
+        TokenizedRecordFrozen {
+            record: self.accounts.record.key(),
+            mint: self.accounts.mint.key(),
+            is_frozen: self.is_frozen,
+        }
+        .emit();
+
         Ok(())
     }
 }
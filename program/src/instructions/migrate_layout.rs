@@ -0,0 +1,273 @@
+use crate::{
+    error::RecordServiceError,
+    events::{ClassLayoutMigrated, Event, RecordLayoutMigrated},
+    state::{Class, Record, OWNER_OFFSET},
+    token2022::Mint,
+    utils::{check_canonical_bump, check_program_address, ByteReader, Context},
+};
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{create_program_address, Pubkey},
+    ProgramResult,
+};
+
+/// MigrateClassLayout instruction.
+///
+/// This function:
+/// 1. Checks that the class has the legacy layout and validates its authority
+/// 2. Checks the group mint PDA of the class with the bump passed in
+/// 3. Moves the name and the metadata after the fields added since, topping
+///    up the rent from the payer
+/// 4. Initializes the new fields like CreateClass does, stores the group
+///    bump if the class was already tokenized and the number of legacy
+///    records of the class
+///
+/// # Accounts
+/// 1. `authority` - The authority of the class (must be a signer)
+/// 2. `payer` - The account that will pay for the larger class account
+/// 3. `class` - The legacy class account to be migrated
+/// 4. `group` - The group mint PDA of the class, created or not
+/// 5. `system_program` - Required for topping up the rent
+///
+/// # Security
+/// 1. The authority account must be a signer and the authority of the class,
+///    every legacy field is kept as is
+/// 2. The group must be the PDA of the class with the canonical bump passed
+///    in, so that the stored bump signs for the group mint
+/// 3. Records are counted in the class as they are migrated with
+///    MigrateRecordLayout, and uncounted from the legacy records declared by
+///    the authority. The class can't be closed until none is left.
+pub struct MigrateClassLayoutAccounts<'info> {
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    group: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for MigrateClassLayoutAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, group, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if the class has the legacy layout
+        Class::check_legacy(class)?;
+
+        // Check if the authority is the class authority, the legacy layout
+        // starts like the current one [this is safe, the class has already been validated]
+        unsafe { Class::check_authority_unchecked(&class.try_borrow_data()?, authority)? };
+
+        Ok(Self {
+            payer,
+            class,
+            group,
+        })
+    }
+}
+
+pub struct MigrateClassLayout<'info> {
+    accounts: MigrateClassLayoutAccounts<'info>,
+    group_bump: u8,
+    legacy_record_count: u64,
+}
+
+impl<'info> TryFrom<Context<'info>> for MigrateClassLayout<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = MigrateClassLayoutAccounts::try_from(ctx.accounts)?;
+
+        // Deserialize `group_bump`
+        let group_bump: u8 = ByteReader::read_with_offset(ctx.data, 0)?;
+
+        // Deserialize `legacy_record_count`
+        let legacy_record_count: u64 = ByteReader::read_with_offset(ctx.data, size_of::<u8>())?;
+
+        // Check if the group is the PDA of the class
+        check_program_address(
+            accounts.group,
+            &[b"group", accounts.class.key(), &[group_bump]],
+            RecordServiceError::InvalidGroup,
+        )?;
+
+        Ok(Self {
+            accounts,
+            group_bump,
+            legacy_record_count,
+        })
+    }
+}
+
+impl<'info> MigrateClassLayout<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Migrate Class Layout");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Only store the bump of a group that exists, MintTokenizedRecord
+        // stores it when it creates the group
+        let group_bump = if Mint::check_discriminator(self.accounts.group)? {
+            self.group_bump
+        } else {
+            0
+        };
+
+        // Safety: The account has already been validated
+        unsafe {
+            Class::migrate_legacy_unchecked(
+                self.accounts.class,
+                self.accounts.payer,
+                group_bump,
+                self.legacy_record_count,
+            )?;
+        }
+
+        ClassLayoutMigrated {
+            class: self.accounts.class.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+/// MigrateRecordLayout instruction.
+///
+/// This function:
+/// 1. Checks that the record has the legacy layout and its class the current one
+/// 2. Checks the mint PDA of tokenized records with the bump passed in
+/// 3. Moves the seed and the data after the fields added since, topping up
+///    the rent from the payer
+/// 4. Initializes the new fields, the record is a utf-8 record whose history
+///    starts with its current data
+/// 5. Counts the record, and its token, in the class, and uncounts it from the
+///    legacy records of the class
+///
+/// # Accounts
+/// 1. `payer` - The account that will pay for the larger record account
+/// 2. `record` - The legacy record account to be migrated
+/// 3. `class` - The class of the record, already migrated (must be writable)
+/// 4. `system_program` - Required for topping up the rent
+///
+/// # Security
+/// 1. Anyone may migrate a record, every legacy field is kept as is
+/// 2. The class must be the class of the record
+/// 3. The owner of a tokenized record must be the mint PDA of the record with
+///    the canonical bump passed in, the bump is ignored for other records
+/// 4. The record is counted even if the class has reached its maximum number
+///    of records, it already exists
+pub struct MigrateRecordLayoutAccounts<'info> {
+    payer: &'info AccountInfo,
+    record: &'info AccountInfo,
+    class: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for MigrateRecordLayoutAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [payer, record, class, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if the record has the legacy layout
+        Record::check_legacy(record)?;
+
+        // Check if the class is the class of the record, the legacy layout
+        // starts like the current one [this is safe, the record has already been validated]
+        unsafe { Record::check_class_unchecked(&record.try_borrow_data()?, class)? };
+
+        Ok(Self {
+            payer,
+            record,
+            class,
+        })
+    }
+}
+
+pub struct MigrateRecordLayout<'info> {
+    accounts: MigrateRecordLayoutAccounts<'info>,
+    mint_bump: u8,
+    is_tokenized: bool,
+}
+
+impl<'info> TryFrom<Context<'info>> for MigrateRecordLayout<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = MigrateRecordLayoutAccounts::try_from(ctx.accounts)?;
+
+        // Deserialize `mint_bump`
+        let mint_bump: u8 = ByteReader::read_with_offset(ctx.data, 0)?;
+
+        let data = accounts.record.try_borrow_data()?;
+
+        // Check if the record is tokenized [this is safe, the record has already been validated]
+        let is_tokenized = unsafe { Record::is_tokenized_unchecked(&data) };
+
+        // Check if the owner of a tokenized record is its mint
+        let mint_bump = if is_tokenized {
+            let seeds: [&[u8]; 3] = [b"mint", accounts.record.key(), &[mint_bump]];
+
+            let mint = create_program_address(&seeds, &crate::ID)
+                .map_err(|_| RecordServiceError::InvalidMint)?;
+
+            if mint.ne(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()]) {
+                return Err(RecordServiceError::InvalidMint.into());
+            }
+
+            check_canonical_bump(&seeds, RecordServiceError::InvalidMint)?;
+
+            mint_bump
+        } else {
+            0
+        };
+
+        drop(data);
+
+        Ok(Self {
+            accounts,
+            mint_bump,
+            is_tokenized,
+        })
+    }
+}
+
+impl<'info> MigrateRecordLayout<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Migrate Record Layout");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Count the record in the class, checking the class layout
+        Class::add_migrated_record(self.accounts.class, self.is_tokenized)?;
+
+        // Safety: The account has already been validated
+        unsafe {
+            Record::migrate_legacy_unchecked(
+                self.accounts.record,
+                self.accounts.payer,
+                self.mint_bump,
+            )?;
+        }
+
+        RecordLayoutMigrated {
+            record: self.accounts.record.key(),
+            class: self.accounts.class.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
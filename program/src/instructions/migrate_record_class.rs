@@ -0,0 +1,326 @@
+use crate::{
+    error::RecordServiceError,
+    events::{Event, RecordMigrated},
+    state::{Class, ClassSchema, OwnerType, Permission, Record, OWNER_OFFSET},
+    token2022::{BurnChecked, CloseAccount, Mint, ThawAccount, Token},
+    utils::{check_program_address, create_pda_account, ByteReader, Context},
+};
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+/// MigrateRecordClass instruction.
+///
+/// This function:
+/// 1. Validates the authorities of both classes
+/// 2. Burns the record token and closes its mint if the record is tokenized,
+///    the token owner becomes the record owner
+/// 3. Creates the record at the PDA of the new class with the same seed, copying
+///    its owner, expiry, frozen flag, data and history
+/// 4. Reallocates the old record account data to 1 byte, 0xff to counter
+///    reinitialization attacks, and refunds it to the payer
+/// 5. Moves the record count from the old class to the new class
+///
+/// # Accounts
+/// 1. `authority` - The authority of the current class, or a class delegate with the delete permission (must be a signer)
+/// 2. `new_authority` - The authority of the new class, or a class delegate with the create permission (must be a signer)
+/// 3. `payer` - The account that will pay for the new record account and get refunded for the old one
+/// 4. `record` - The record account to be migrated
+/// 5. `class` - The current class of the record (must be writable)
+/// 6. `new_record` - The record account to be created in the new class
+/// 7. `new_class` - The class the record is migrated to (must be writable)
+/// 8. `system_program` - Required for creating the new record account
+/// 9. `schema` - [optional] The schema PDA of the new class, required if the new class has a schema
+/// 10. `class_delegate` - [optional] The class delegate account of the authority
+/// 11. `new_class_delegate` - [optional] The class delegate account of the new authority
+/// 12. `mint` - [optional] The mint of the record token, required if the record is tokenized
+/// 13. `token_account` - [optional] The token account holding the record token
+/// 14. `token_2022_program` - [optional] Required for burning the record token
+///
+/// # Security
+/// 1. Both class authorities, or their delegates, must sign
+/// 2. The new class must not be frozen or have reached its maximum number of records
+/// 3. The record data must match the schema of the new class
+/// 4. The record must not be revoked nor being written in chunks
+/// 5. Tokenized records leave the group of the old class, they can be
+///    tokenized again in the new class with MintTokenizedRecord
+/// 6. Record delegates are not carried over to the new record
+/// 7. The new record account must be the PDA of the new class and the seed
+///    with the bump passed in, which must be the canonical bump
+pub struct MigrateRecordClassAccounts<'info> {
+    payer: &'info AccountInfo,
+    record: &'info AccountInfo,
+    class: &'info AccountInfo,
+    new_record: &'info AccountInfo,
+    new_class: &'info AccountInfo,
+    schema: &'info AccountInfo,
+    tokenized: Option<(&'info AccountInfo, &'info AccountInfo)>,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for MigrateRecordClassAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            authority,
+            new_authority,
+            payer,
+            record,
+            class,
+            new_record,
+            new_class,
+            _system_program,
+            schema,
+            rest @ ..,
+        ] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if the record is moved to another class
+        if class.key().eq(new_class.key()) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Check if authority may remove records from the current class
+        Class::check_authority_or_delegate(
+            class,
+            authority,
+            rest.first(),
+            Permission::DeleteRecord,
+        )?;
+
+        // Check if new authority may add records to the new class
+        Class::check_authority_or_delegate(
+            new_class,
+            new_authority,
+            rest.get(1),
+            Permission::CreateRecord,
+        )?;
+        Class::check_not_frozen(new_class)?;
+
+        // Check if the Record is correct
+        Record::check_program_id_and_discriminator(record)?;
+
+        // Check the record [this is safe, the record has already been validated]
+        let is_tokenized = unsafe {
+            let data = record.try_borrow_data()?;
+
+            Record::check_class_unchecked(&data, class)?;
+            Record::check_not_revoked_unchecked(&data)?;
+            Record::check_not_writing_unchecked(&data)?;
+
+            Record::is_tokenized_unchecked(&data)
+        };
+
+        let tokenized = if is_tokenized {
+            let mint = rest.get(2).ok_or(RecordServiceError::MissingMint)?;
+            let token_account = rest.get(3).ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            // Check if the mint is the record token
+            Mint::check_program_id(mint)?;
+            unsafe { Mint::check_discriminator_unchecked(&mint.try_borrow_data()?)? };
+
+            if mint
+                .key()
+                .ne(&record.try_borrow_data()?[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
+            {
+                return Err(RecordServiceError::InvalidMint.into());
+            }
+
+            // Check the token account, the burn checks that it holds the record token
+            Token::check_program_id(token_account)?;
+            unsafe { Token::check_discriminator_unchecked(&token_account.try_borrow_data()?)? };
+
+            Some((mint, token_account))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            payer,
+            record,
+            class,
+            new_record,
+            new_class,
+            schema,
+            tokenized,
+        })
+    }
+}
+
+pub struct MigrateRecordClass<'info> {
+    accounts: MigrateRecordClassAccounts<'info>,
+    bump: [u8; 1],
+}
+
+impl<'info> TryFrom<Context<'info>> for MigrateRecordClass<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = MigrateRecordClassAccounts::try_from(ctx.accounts)?;
+
+        // Check the record data against the new class schema
+        {
+            let data = accounts.record.try_borrow_data()?;
+            let record = unsafe { Record::from_bytes_unchecked(&data)? };
+
+            ClassSchema::check_data(
+                Some(accounts.schema),
+                accounts.new_class,
+                record.content_type,
+                record.data,
+            )?;
+        }
+
+        // Deserialize `bump`
+        let bump: u8 = ByteReader::read_with_offset(ctx.data, 0)?;
+
+        Ok(Self {
+            accounts,
+            bump: [bump],
+        })
+    }
+}
+
+impl<'info> MigrateRecordClass<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Migrate Record Class");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Give the record back to the token owner, it leaves the group of the class
+        let token_owner = match self.accounts.tokenized {
+            Some((mint, token_account)) => Some(self.burn_record_token(mint, token_account)?),
+            None => None,
+        };
+
+        // Move the record count to the new class, checking its cap
+        Class::remove_record(self.accounts.class, token_owner.is_some())?;
+        Class::add_record(self.accounts.new_class)?;
+
+        {
+            let data = self.accounts.record.try_borrow_data()?;
+
+            // Safety: The account has already been validated
+            let mut record = unsafe { Record::from_bytes_unchecked(&data)? };
+            record.class = *self.accounts.new_class.key();
+
+            if let Some((owner, is_frozen)) = token_owner {
+                record.owner_type = OwnerType::Pubkey;
+                record.owner = owner;
+                record.is_frozen = is_frozen;
+                record.mint_bump = 0;
+            }
+
+            // Check if the new record is the PDA of the new class and the seed
+            check_program_address(
+                self.accounts.new_record,
+                &[
+                    b"record",
+                    self.accounts.new_class.key(),
+                    record.seed,
+                    &self.bump,
+                ],
+                RecordServiceError::InvalidPda,
+            )?;
+
+            let seeds = [
+                Seed::from(b"record"),
+                Seed::from(self.accounts.new_class.key()),
+                Seed::from(record.seed),
+                Seed::from(&self.bump),
+            ];
+
+            create_pda_account(
+                self.accounts.new_record,
+                self.accounts.payer,
+                Record::MINIMUM_RECORD_SIZE + record.seed.len() + record.data.len(),
+                &[Signer::from(&seeds)],
+            )?;
+
+            unsafe { record.initialize_unchecked(self.accounts.new_record) }?;
+        }
+
+        // Safety: The account has already been validated
+        unsafe {
+            Record::delete_record_unchecked(self.accounts.record, self.accounts.payer)?;
+        }
+
+        RecordMigrated {
+            record: self.accounts.record.key(),
+            new_record: self.accounts.new_record.key(),
+            new_class: self.accounts.new_class.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+
+    /// Burn the record token and close its mint, returning the token owner and
+    /// whether the token was frozen
+    fn burn_record_token(
+        &self,
+        mint: &AccountInfo,
+        token_account: &AccountInfo,
+    ) -> Result<(Pubkey, bool), ProgramError> {
+        // Mint bump stored on tokenization [this is safe, the record has already been validated]
+        let bump =
+            unsafe { Record::get_mint_bump_unchecked(&self.accounts.record.try_borrow_data()?) };
+
+        let seeds = [
+            Seed::from(b"mint"),
+            Seed::from(self.accounts.record.key()),
+            Seed::from(&bump),
+        ];
+
+        let signers = [Signer::from(&seeds)];
+
+        let (owner, is_frozen) = unsafe {
+            let data = token_account.try_borrow_data()?;
+            (
+                Token::get_owner_unchecked(&data)?,
+                Token::get_is_frozen_unchecked(&data)?,
+            )
+        };
+
+        if is_frozen {
+            ThawAccount {
+                mint,
+                account: token_account,
+                freeze_authority: mint,
+            }
+            .invoke_signed(&signers)?;
+        }
+
+        // Burn the record token, the mint is its permanent delegate
+        BurnChecked {
+            mint,
+            account: token_account,
+            authority: mint,
+            amount: 1,
+            decimals: 0,
+        }
+        .invoke_signed(&signers)?;
+
+        // Close the mint account
+        CloseAccount {
+            account: mint,
+            destination: self.accounts.payer,
+            authority: mint,
+        }
+        .invoke_signed(&signers)?;
+
+        Ok((owner, is_frozen))
+    }
+}
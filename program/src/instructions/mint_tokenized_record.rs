@@ -5,19 +5,21 @@ use pinocchio::log::sol_log;
 use pinocchio_associated_token_account::instructions::Create;
 
 use crate::{
-    state::{OwnerType, Record, CLASS_OFFSET, IS_FROZEN_OFFSET, OWNER_OFFSET},
+    error::RecordServiceError,
+    events::{Event, RecordTokenized},
+    state::{Class, OwnerType, Permission, Record, CLASS_OFFSET, IS_FROZEN_OFFSET, OWNER_OFFSET},
     token2022::{
         constants::{
-            TOKEN_2022_CLOSE_MINT_AUTHORITY_LEN, TOKEN_2022_GROUP_LEN, TOKEN_2022_GROUP_POINTER_LEN, TOKEN_2022_MEMBER_LEN, TOKEN_2022_MEMBER_POINTER_LEN, TOKEN_2022_METADATA_LEN, TOKEN_2022_METADATA_POINTER_LEN, TOKEN_2022_MINT_BASE_LEN, TOKEN_2022_MINT_LEN, TOKEN_2022_PERMANENT_DELEGATE_LEN, TOKEN_2022_PROGRAM_ID
-        }, FreezeAccount, InitializeGroup, InitializeGroupMemberPointer, InitializeGroupPointer, InitializeMember, InitializeMetadata, InitializeMetadataPointer, InitializeMint2, InitializeMintCloseAuthority, InitializePermanentDelegate, Mint, MintToChecked, Token, UpdateMetadata
+            TOKEN_2022_CLOSE_MINT_AUTHORITY_LEN, TOKEN_2022_GROUP_LEN, TOKEN_2022_GROUP_POINTER_LEN, TOKEN_2022_MEMBER_LEN, TOKEN_2022_MEMBER_POINTER_LEN, TOKEN_2022_METADATA_LEN, TOKEN_2022_METADATA_POINTER_LEN, TOKEN_2022_MINT_BASE_LEN, TOKEN_2022_MINT_LEN, TOKEN_2022_NON_TRANSFERABLE_LEN, TOKEN_2022_PERMANENT_DELEGATE_LEN, TOKEN_2022_PROGRAM_ID
+        }, FreezeAccount, InitializeGroup, InitializeGroupMemberPointer, InitializeGroupPointer, InitializeMember, InitializeMetadata, InitializeMetadataPointer, InitializeMint2, InitializeMintCloseAuthority, InitializeNonTransferableMint, InitializePermanentDelegate, Mint, MintToChecked, Token, UpdateMetadata
     },
-    utils::Context, ID,
+    utils::{check_program_address, ByteReader, Context},
 };
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
     program_error::ProgramError,
-    pubkey::{find_program_address, try_find_program_address, Pubkey},
+    pubkey::{find_program_address, Pubkey},
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
@@ -31,6 +33,17 @@ use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 /// 3. Creates a Token2022 token mint
 /// 4. Creates a Token2022 token account
 /// 5. Mints a token to the token account
+/// 6. Increments the tokenized count of the class
+///
+/// The mint of a record in a non-transferable class is created with the
+/// Token2022 NonTransferable extension.
+///
+/// The group mint of the class is created on the first tokenization, with a
+/// close authority so that it can be closed along with the class. Its bump is
+/// stored in the class and the bump of the record mint in the record, so that
+/// later instructions sign for them without deriving the addresses again. Both
+/// bumps are passed in the instruction data, the group bump is only used when
+/// the group is created.
 ///
 /// # Accounts
 /// 1. `owner` - The owner of the record
@@ -38,16 +51,25 @@ use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 /// 3. `authority` - The authority of minting this record, could be the owner or a delegate
 /// 4. `record` - The record for which the token will be minted
 /// 5. `mint` - The mint account of the record token
-/// 6. `class` - The class of the record
+/// 6. `class` - The class of the record (must be writable)
 /// 7. `group` - The group of the record
 /// 8. `token_account` - The associated token account where we mint the record token to
 /// 9. `token_2022_program` - The Token2022 program
 /// 10. `system_program` - Required for initializing our accounts
+/// 11. `class_delegate` - [optional] The class delegate account of the authority
+/// 12. `record_delegate` - [optional] The record delegate account of the authority
 ///
 /// # Security
-/// 1. The authority must be:
-///    a. The record's owner, or
-///    b. if the class is permissioned, the authority can be the permissioned authority
+/// 1. Depending on the class tokenize policy, the authority must be either:
+///    a. The record's owner, or a record delegate approved by the owner with
+///    the mint permission, and/or
+///    b. the class authority or a class delegate with the mint permission
+/// 2. The record must not be expired
+/// 3. The record must not be revoked
+/// 4. The record data must not be being written in chunks
+/// 5. The mint and the group must be the PDAs of the record and the class with
+///    the bumps passed in, which must be the canonical bumps. Once created,
+///    the group is checked with the bump stored in the class
 pub struct MintTokenizedRecordAccounts<'info> {
     owner: &'info AccountInfo,
     payer: &'info AccountInfo,
@@ -64,25 +86,41 @@ impl<'info> TryFrom<&'info [AccountInfo]> for MintTokenizedRecordAccounts<'info>
     type Error = ProgramError;
 
     fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [owner, payer, authority, record, mint, class, group, token_account, _associated_token_program, token_2022_program, system_program] =
+        let [owner, payer, authority, record, mint, class, group, token_account, _associated_token_program, token_2022_program, system_program, rest @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
         // Check if authority is the record owner
-        Record::check_owner_or_delegate(record, Some(class), authority)?;
+        Record::check_owner_or_delegate(
+            record,
+            Some(class),
+            rest.first(),
+            rest.get(1),
+            authority,
+            Permission::MintTokenizedRecord,
+        )?;
 
         let record_data = record.try_borrow_data()?;
 
+        // Check if the record is expired [this is safe, the record has already been validated]
+        unsafe { Record::check_not_expired_unchecked(&record_data)? };
+
+        // Check if the record is revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record_data)? };
+
+        // Check if the record is being written [this is safe, the record has already been validated]
+        unsafe { Record::check_not_writing_unchecked(&record_data)? };
+
         // Check if the owner of the record is the same as the owner of the token account
         if record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()].ne(owner.key()) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::InvalidOwner.into());
         }
 
         // Check that the class of the record is the same as the class passed in 
         if record_data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()].ne(class.key()) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::ClassMismatch.into());
         }
 
         let seeds = [owner.key(), TOKEN_2022_PROGRAM_ID.as_ref(), mint.key()];
@@ -90,12 +128,7 @@ impl<'info> TryFrom<&'info [AccountInfo]> for MintTokenizedRecordAccounts<'info>
             find_program_address(&seeds, &pinocchio_associated_token_account::ID);
 
         if token_account_address.ne(token_account.key()) {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        let group_key = find_program_address(&[b"group", class.key()], &ID).0;
-        if group_key.ne(group.key()) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::InvalidTokenAccount.into());
         }
 
         Ok(Self {
@@ -112,8 +145,14 @@ impl<'info> TryFrom<&'info [AccountInfo]> for MintTokenizedRecordAccounts<'info>
     }
 }
 
+const MINT_BUMP_OFFSET: usize = 0;
+const GROUP_BUMP_OFFSET: usize = MINT_BUMP_OFFSET + size_of::<u8>();
+
 pub struct MintTokenizedRecord<'info> {
     accounts: MintTokenizedRecordAccounts<'info>,
+    is_non_transferable: bool,
+    mint_bump: [u8; 1],
+    group_bump: [u8; 1],
 }
 
 impl<'info> TryFrom<Context<'info>> for MintTokenizedRecord<'info> {
@@ -123,7 +162,22 @@ impl<'info> TryFrom<Context<'info>> for MintTokenizedRecord<'info> {
         // Deserialize our accounts array
         let accounts = MintTokenizedRecordAccounts::try_from(ctx.accounts)?;
 
-        Ok(Self { accounts })
+        // Check if the record token must be non-transferable [this is safe, the class has already been validated]
+        let is_non_transferable =
+            unsafe { Class::is_non_transferable_unchecked(&accounts.class.try_borrow_data()?) };
+
+        // Deserialize `mint_bump`
+        let mint_bump: u8 = ByteReader::read_with_offset(ctx.data, MINT_BUMP_OFFSET)?;
+
+        // Deserialize `group_bump`
+        let group_bump: u8 = ByteReader::read_with_offset(ctx.data, GROUP_BUMP_OFFSET)?;
+
+        Ok(Self {
+            accounts,
+            is_non_transferable,
+            mint_bump: [mint_bump],
+            group_bump: [group_bump],
+        })
     }
 }
 
@@ -135,21 +189,41 @@ impl<'info> MintTokenizedRecord<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
-        // Get Mint length
-        let mint_bump = self.derive_mint_address_bump()?;
-        let group_bump = self.derive_group_address_bump()?;
+        // Check if the mint is the PDA of the record
+        check_program_address(
+            self.accounts.mint,
+            &[b"mint", self.accounts.record.key(), &self.mint_bump],
+            RecordServiceError::InvalidMint,
+        )?;
+        let mint_bump = self.mint_bump;
+
+        // Check if the group already exists, its bump is stored in the class once created
+        let group_bump = if Mint::check_discriminator(self.accounts.group)? {
+            Class::check_group(self.accounts.class, self.accounts.group)?
+        } else {
+            // Check if the group is the PDA of the class
+            check_program_address(
+                self.accounts.group,
+                &[b"group", self.accounts.class.key(), &self.group_bump],
+                RecordServiceError::InvalidGroup,
+            )?;
+            let group_bump = self.group_bump;
 
-        // Check if the group already exists
-        if !Mint::check_discriminator(self.accounts.group)? {
             // Create the group mint account if needed
             self.create_group_mint_account(&group_bump)?;
+            // Initialize group mint close authority extension, to close it with the class
+            self.initialize_group_mint_close_authority()?;
             // Initialize the group pointer extension
             self.initialize_group_pointer()?;
             // Initialize the group mint account
             self.initialize_group_mint_account()?;
             // Initialize the group
             self.initialize_group(&group_bump)?;
-        }
+            // Store the group bump [this is safe, the class has already been validated]
+            unsafe { Class::update_group_bump_unchecked(self.accounts.class, group_bump[0])? };
+
+            group_bump
+        };
 
         // Create mint account
         self.create_mint_account(&mint_bump)?;
@@ -161,6 +235,10 @@ impl<'info> MintTokenizedRecord<'info> {
         self.initialize_metadata_pointer()?;
         // Initialize the group member pointer extension
         self.initialize_group_member_pointer()?;
+        // Initialize the non-transferable extension for non-transferable classes
+        if self.is_non_transferable {
+            self.initialize_non_transferable_mint()?;
+        }
         // Initialize mint
         self.initialize_mint()?;
         // Initialize metadata
@@ -195,28 +273,30 @@ impl<'info> MintTokenizedRecord<'info> {
             .clone_from_slice(self.accounts.mint.key());
 
         // 3. Update the record_type to be tokenized
-        unsafe { Record::update_owner_type_unchecked(&mut record_data, OwnerType::Token) }
-    }
+        unsafe { Record::update_owner_type_unchecked(&mut record_data, OwnerType::Token) }?;
 
-    fn derive_mint_address_bump(&self) -> Result<[u8; 1], ProgramError> {
-        let seeds = [b"mint", self.accounts.record.key().as_ref()];
+        // 4. Store the mint bump, to sign for the mint without deriving it again
+        unsafe { Record::update_mint_bump_unchecked(&mut record_data, mint_bump[0]) }?;
 
-        Ok([try_find_program_address(&seeds, &crate::ID)
-            .ok_or(ProgramError::InvalidArgument)?
-            .1])
-    }
+        // 5. Count the tokenized record
+        Class::update_tokenized_count(self.accounts.class, true)?;
 
-    fn derive_group_address_bump(&self) -> Result<[u8; 1], ProgramError> {
-        let seeds = [b"group", self.accounts.class.key().as_ref()];
+        RecordTokenized {
+            record: self.accounts.record.key(),
+            mint: self.accounts.mint.key(),
+            owner: self.accounts.owner.key(),
+        }
+        .emit();
 
-        Ok([try_find_program_address(&seeds, &crate::ID)
-            .ok_or(ProgramError::InvalidArgument)?
-            .1])
+        Ok(())
     }
 
     fn create_group_mint_account(&self, bump: &[u8; 1]) -> Result<(), ProgramError> {
         // Space of all our static extensions
-        let space = TOKEN_2022_MINT_LEN + TOKEN_2022_MINT_BASE_LEN + TOKEN_2022_GROUP_POINTER_LEN;
+        let space = TOKEN_2022_MINT_LEN
+            + TOKEN_2022_MINT_BASE_LEN
+            + TOKEN_2022_CLOSE_MINT_AUTHORITY_LEN
+            + TOKEN_2022_GROUP_POINTER_LEN;
 
         let lamports = Rent::get()?.minimum_balance(space + TOKEN_2022_GROUP_LEN);
 
@@ -263,6 +343,14 @@ impl<'info> MintTokenizedRecord<'info> {
         Ok(())
     }
 
+    fn initialize_group_mint_close_authority(&self) -> Result<(), ProgramError> {
+        InitializeMintCloseAuthority {
+            mint: self.accounts.group,
+            close_authority: self.accounts.group.key(),
+        }
+        .invoke()
+    }
+
     fn initialize_group_pointer(&self) -> Result<(), ProgramError> {
         InitializeGroupPointer {
             mint: self.accounts.group,
@@ -303,13 +391,17 @@ impl<'info> MintTokenizedRecord<'info> {
 
     fn create_mint_account(&self, bump: &[u8; 1]) -> Result<(), ProgramError> {
         // Space of all our static extensions
-        let space = TOKEN_2022_MINT_LEN
+        let mut space = TOKEN_2022_MINT_LEN
             + TOKEN_2022_MINT_BASE_LEN
             + TOKEN_2022_PERMANENT_DELEGATE_LEN
             + TOKEN_2022_CLOSE_MINT_AUTHORITY_LEN
             + TOKEN_2022_METADATA_POINTER_LEN
             + TOKEN_2022_MEMBER_POINTER_LEN;
 
+        if self.is_non_transferable {
+            space += TOKEN_2022_NON_TRANSFERABLE_LEN;
+        }
+
 
         // To avoid resizing the mint, we calculate the correct lamports for our token AOT with:
         // 1. `space` - The sum of the above static extension lengths
@@ -374,6 +466,13 @@ impl<'info> MintTokenizedRecord<'info> {
         .invoke()
     }
 
+    fn initialize_non_transferable_mint(&self) -> Result<(), ProgramError> {
+        InitializeNonTransferableMint {
+            mint: self.accounts.mint,
+        }
+        .invoke()
+    }
+
     fn initialize_mint_close_authority(&self) -> Result<(), ProgramError> {
         InitializeMintCloseAuthority {
             mint: self.accounts.mint,
@@ -433,38 +532,22 @@ impl<'info> MintTokenizedRecord<'info> {
         .invoke_signed(&signers)?;
 
         if let Some(additional_metadata_data) = additional_metadata_data {
-            let additional_metadata_num = u32::from_le_bytes(
-                additional_metadata_data[0..size_of::<u32>()]
-                    .try_into()
-                    .unwrap(),
-            );
-
-            let mut offset = size_of::<u32>();
+            let mut additional_metadata = ByteReader::new(additional_metadata_data);
+            let additional_metadata_num: u32 = additional_metadata.read()?;
 
-            // Process each additional metadata entry
+            // Process each additional metadata entry, borrowing its key and value from the record
             for _ in 0..additional_metadata_num {
-                let starting_value_offset = offset;
-
-                let field_len = u32::from_le_bytes(
-                    additional_metadata_data[offset..offset + size_of::<u32>()]
-                        .try_into()
-                        .unwrap(),
-                ) as usize;
-                offset += size_of::<u32>() + field_len;
-                let value_len = u32::from_le_bytes(
-                    additional_metadata_data[offset..offset + size_of::<u32>()]
-                        .try_into()
-                        .unwrap(),
-                ) as usize;
-                offset += size_of::<u32>() + value_len;
-
-                let entry_data = additional_metadata_data[starting_value_offset..offset].to_vec();
+                let key_len: u32 = additional_metadata.read()?;
+                let key = additional_metadata.read_bytes(key_len as usize)?;
+                let value_len: u32 = additional_metadata.read()?;
+                let value = additional_metadata.read_bytes(value_len as usize)?;
 
                 // Call UpdateMetadata for this entry
                 UpdateMetadata {
                     metadata: self.accounts.mint,
                     update_authority: self.accounts.mint,
-                    additional_metadata: &entry_data,
+                    key,
+                    value,
                 }
                 .invoke_signed(&signers)?;
             }
@@ -515,7 +598,7 @@ impl<'info> MintTokenizedRecord<'info> {
 
             // Check Ownership
             if unsafe { Token::get_owner_unchecked(&data)? }.ne(self.accounts.owner.key()) {
-                return Err(ProgramError::InvalidAccountData);
+                return Err(RecordServiceError::InvalidTokenAccount.into());
             }
 
             return Ok(());
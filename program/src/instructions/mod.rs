@@ -3,26 +3,39 @@ pub use create_class::CreateClass;
 
 pub mod update_class;
 pub use update_class::UpdateClassMetadata;
-pub use update_class::UpdateClassAuthority;
 
 pub mod freeze_class;
 pub use freeze_class::FreezeClass;
 
 pub mod create_record;
 pub use create_record::CreateRecord;
+pub use create_record::CreateBufferedRecord;
+pub use create_record::RecreateRecord;
+pub use create_record::CreateRecordWithProof;
+pub use create_record::CreateRecordFromSignature;
+pub use create_record::BatchCreateRecords;
 
 pub mod update_record;
 pub use update_record::UpdateRecordData;
 pub use update_record::UpdateRecordExpiry;
+pub use update_record::CompareAndSwapRecordData;
+pub use update_record::CompareAndSwapRecordExpiry;
+pub use update_record::PatchRecordData;
+pub use update_record::BeginRecordWrite;
+pub use update_record::WriteRecordChunk;
+pub use update_record::FinalizeRecordWrite;
+pub use update_record::CancelRecordWrite;
 
 pub mod transfer_record;
 pub use transfer_record::TransferRecord;
 
 pub mod freeze_record;
 pub use freeze_record::FreezeRecord;
+pub use freeze_record::BatchFreezeRecords;
 
 pub mod delete_record;
 pub use delete_record::DeleteRecord;
+pub use delete_record::BatchDeleteRecords;
 
 pub mod mint_tokenized_record;
 pub use mint_tokenized_record::*;
@@ -35,3 +48,51 @@ pub use freeze_tokenized_record::*;
 
 pub mod burn_tokenized_record;
 pub use burn_tokenized_record::*;
+
+pub mod close_expired_record;
+pub use close_expired_record::*;
+
+pub mod propose_class_authority;
+pub use propose_class_authority::*;
+
+pub mod accept_class_authority;
+pub use accept_class_authority::*;
+
+pub mod cancel_class_authority_transfer;
+pub use cancel_class_authority_transfer::*;
+
+pub mod add_class_delegate;
+pub use add_class_delegate::*;
+
+pub mod revoke_class_delegate;
+pub use revoke_class_delegate::*;
+
+pub mod approve_record_delegate;
+pub use approve_record_delegate::*;
+
+pub mod revoke_record_delegate;
+pub use revoke_record_delegate::*;
+
+pub mod set_class_schema;
+pub use set_class_schema::*;
+
+pub mod update_class_policy;
+pub use update_class_policy::*;
+
+pub mod revoke_record;
+pub use revoke_record::*;
+
+pub mod update_class_fee;
+pub use update_class_fee::*;
+
+pub mod update_class_merkle_root;
+pub use update_class_merkle_root::*;
+
+pub mod close_class;
+pub use close_class::*;
+
+pub mod migrate_record_class;
+pub use migrate_record_class::*;
+
+pub mod migrate_layout;
+pub use migrate_layout::*;
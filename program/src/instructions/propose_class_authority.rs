@@ -0,0 +1,163 @@
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{
+    error::RecordServiceError,
+    events::{ClassAuthorityProposed, Event},
+    state::{Class, PendingClassAuthority},
+    utils::{check_program_address, create_pda_account, ByteReader, Context},
+};
+
+/// ProposeClassAuthority instruction.
+///
+/// This function:
+/// 1. Validates the current class authority
+/// 2. Creates the pending authority account of the class if it does not exist
+///    yet, checking its PDA with the bump passed in
+/// 3. Stores the proposed authority, replacing any previous proposal
+///
+/// # Accounts
+/// 1. `authority` - The current authority of the class (must be a signer)
+/// 2. `payer` - The account that will pay for the pending authority account
+/// 3. `class` - The class account whose authority is being transferred
+/// 4. `pending_class_authority` - The pending authority PDA of the class
+/// 5. `system_program` - Required for creating the pending authority account
+///
+/// # Security
+/// 1. The authority must be a signer and the current authority of the class
+/// 2. The class authority only changes once the proposed authority accepts
+/// 3. A new pending authority account must be the PDA of the class with the
+///    canonical bump passed in
+pub struct ProposeClassAuthorityAccounts<'info> {
+    authority: &'info AccountInfo,
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    pending_class_authority: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for ProposeClassAuthorityAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, pending_class_authority, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check the class authority
+        Class::check_authority(class, authority)?;
+
+        // If a proposal already exists, it must belong to this class
+        if !pending_class_authority.data_is_empty() {
+            PendingClassAuthority::check_class(pending_class_authority, class)?;
+        }
+
+        Ok(Self {
+            authority,
+            payer,
+            class,
+            pending_class_authority,
+        })
+    }
+}
+
+pub struct ProposeClassAuthority<'info> {
+    accounts: ProposeClassAuthorityAccounts<'info>,
+    new_authority: Pubkey,
+    bump: u8,
+}
+
+impl<'info> TryFrom<Context<'info>> for ProposeClassAuthority<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = ProposeClassAuthorityAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < size_of::<Pubkey>() + size_of::<u8>() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `new_authority`
+        let new_authority: Pubkey = ctx.data[0..size_of::<Pubkey>()]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+        // Deserialize `bump`
+        let bump: u8 = ByteReader::read_with_offset(ctx.data, size_of::<Pubkey>())?;
+
+        Ok(Self {
+            accounts,
+            new_authority,
+            bump,
+        })
+    }
+}
+
+impl<'info> ProposeClassAuthority<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Propose Class Authority");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        if self.accounts.pending_class_authority.data_is_empty() {
+            let bump: [u8; 1] = [self.bump];
+
+            // Check if the pending authority is the PDA of the class
+            check_program_address(
+                self.accounts.pending_class_authority,
+                &[b"pending_authority", self.accounts.class.key(), &bump],
+                RecordServiceError::InvalidPda,
+            )?;
+
+            let seeds = [
+                Seed::from(b"pending_authority"),
+                Seed::from(self.accounts.class.key()),
+                Seed::from(&bump),
+            ];
+
+            create_pda_account(
+                self.accounts.pending_class_authority,
+                self.accounts.payer,
+                PendingClassAuthority::SIZE,
+                &[Signer::from(&seeds)],
+            )?;
+
+            let pending_class_authority = PendingClassAuthority {
+                class: *self.accounts.class.key(),
+                authority: *self.accounts.authority.key(),
+                pending_authority: self.new_authority,
+            };
+
+            unsafe { pending_class_authority.initialize_unchecked(self.accounts.pending_class_authority) }?;
+        } else {
+            // Safety: The account has already been validated
+            unsafe {
+                PendingClassAuthority::update_pending_authority_unchecked(
+                    self.accounts.pending_class_authority,
+                    self.accounts.authority.key(),
+                    &self.new_authority,
+                )
+            }?;
+        }
+
+        ClassAuthorityProposed {
+            class: self.accounts.class.key(),
+            pending_authority: &self.new_authority,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+
+use crate::{
+    events::{ClassDelegateRevoked, Event},
+    state::{Class, ClassDelegate},
+    utils::{close_account, Context},
+};
+
+/// RevokeClassDelegate instruction.
+///
+/// This function:
+/// 1. Validates the class authority
+/// 2. Closes the class delegate account and refunds the rent to the authority
+///
+/// # Accounts
+/// 1. `authority` - The authority of the class (must be a signer)
+/// 2. `class` - The class account the delegate acts on
+/// 3. `class_delegate` - The class delegate account to be revoked
+///
+/// # Security
+/// 1. The authority must be a signer and the authority of the class
+/// 2. The class delegate account must belong to the class
+pub struct RevokeClassDelegateAccounts<'info> {
+    authority: &'info AccountInfo,
+    class: &'info AccountInfo,
+    class_delegate: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for RevokeClassDelegateAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, class, class_delegate] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check the class delegate account
+        ClassDelegate::check_class(class_delegate, class)?;
+
+        Ok(Self {
+            authority,
+            class,
+            class_delegate,
+        })
+    }
+}
+
+pub struct RevokeClassDelegate<'info> {
+    accounts: RevokeClassDelegateAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for RevokeClassDelegate<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = RevokeClassDelegateAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> RevokeClassDelegate<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Revoke Class Delegate");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Safety: The account has already been validated
+        let delegate: Pubkey = unsafe {
+            ClassDelegate::get_delegate_unchecked(&self.accounts.class_delegate.try_borrow_data()?)
+        }?;
+
+        close_account(self.accounts.class_delegate, self.accounts.authority)?;
+
+        ClassDelegateRevoked {
+            class: self.accounts.class.key(),
+            delegate: &delegate,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    error::RecordServiceError,
+    events::{Event, RecordRevoked},
+    state::{Class, Permission, Record, CLASS_OFFSET},
+    utils::{ByteReader, Context},
+};
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+/// RevokeRecord instruction.
+///
+/// This function:
+/// 1. Loads the current record state
+/// 2. Marks the record as revoked with the reason code and the current timestamp
+/// 3. Saves the updated state
+/// 4. Uncounts the record, and its token, from the class
+///
+/// Unlike DeleteRecord, the record account is kept so that verifiers can see
+/// that the record existed and when and why it was revoked. It is no longer a
+/// live record of the class: it frees its place under the maximum number of
+/// records and doesn't keep the class from being closed, and it stays after
+/// the class is closed.
+///
+/// # Accounts
+/// 1. `authority` - The account that has permission to revoke the record (must be a signer)
+/// 2. `record` - The record account to be revoked
+/// 3. `class` - The class of the record to be revoked (must be writable)
+/// 4. `class_delegate` - [optional] The class delegate account of the authority
+///
+/// # Security
+/// 1. The authority must be the class authority or a class delegate with the revoke permission
+/// 2. The record must not be revoked already, revoked records can't be modified or deleted
+pub struct RevokeRecordAccounts<'info> {
+    record: &'info AccountInfo,
+    class: &'info AccountInfo,
+    is_tokenized: bool,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for RevokeRecordAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, record, class, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check if authority is the class authority or a class delegate
+        Class::check_authority_or_delegate(class, authority, rest.first(), Permission::RevokeRecord)?;
+
+        // Check if the Record is correct
+        Record::check_program_id_and_discriminator(record)?;
+
+        let record_data = record.try_borrow_data()?;
+
+        // Check if the class is the correct class
+        if class.key().ne(&record_data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
+            return Err(RecordServiceError::ClassMismatch.into());
+        }
+
+        // Check if the record is already revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record_data)? };
+
+        // Check if the record is tokenized [this is safe, the record has already been validated]
+        let is_tokenized = unsafe { Record::is_tokenized_unchecked(&record_data) };
+
+        drop(record_data);
+
+        Ok(Self {
+            record,
+            class,
+            is_tokenized,
+        })
+    }
+}
+
+const REASON_OFFSET: usize = 0;
+
+pub struct RevokeRecord<'info> {
+    accounts: RevokeRecordAccounts<'info>,
+    reason: u16,
+}
+
+/// Minimum length of instruction data required for RevokeRecord
+pub const REVOKE_RECORD_MIN_IX_LENGTH: usize = size_of::<u16>();
+
+impl<'info> TryFrom<Context<'info>> for RevokeRecord<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = RevokeRecordAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < REVOKE_RECORD_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `reason`
+        let reason: u16 = ByteReader::read_with_offset(ctx.data, REASON_OFFSET)?;
+
+        Ok(Self { accounts, reason })
+    }
+}
+
+impl<'info> RevokeRecord<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Revoke Record");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        let revoked_at = Clock::get()?.unix_timestamp;
+
+        // Revoke the record [this is safe, check safety docs]
+        unsafe {
+            Record::revoke_unchecked(
+                &mut self.accounts.record.try_borrow_mut_data()?,
+                self.reason,
+                revoked_at,
+            )
+        }?;
+
+        // Uncount the record from the class, it is no longer live
+        Class::remove_record(self.accounts.class, self.accounts.is_tokenized)?;
+
+        RecordRevoked {
+            record: self.accounts.record.key(),
+            reason: self.reason,
+            revoked_at,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+
+use crate::{
+    events::{Event, RecordDelegateRevoked},
+    state::{Record, RecordDelegate},
+    utils::{close_account, Context},
+};
+
+/// RevokeRecordDelegate instruction.
+///
+/// This function:
+/// 1. Validates the record owner
+/// 2. Closes the record delegate account and refunds the rent to the owner
+///
+/// # Accounts
+/// 1. `owner` - The current record owner or the owner that approved the delegate (must be a signer)
+/// 2. `record` - The record the delegate acts on
+/// 3. `record_delegate` - The record delegate account to be revoked
+///
+/// # Security
+/// 1. The owner must be a signer and either the current owner of the record or
+///    the owner that approved the delegate
+/// 2. The record delegate account must belong to the record
+pub struct RevokeRecordDelegateAccounts<'info> {
+    owner: &'info AccountInfo,
+    record: &'info AccountInfo,
+    record_delegate: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for RevokeRecordDelegateAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [owner, record, record_delegate] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check the record delegate account
+        RecordDelegate::check_record(record_delegate, record)?;
+
+        if !owner.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check if the owner approved the delegate, otherwise it must be the current record owner
+        let is_approver = unsafe {
+            RecordDelegate::check_owner_unchecked(&record_delegate.try_borrow_data()?, owner)
+        }
+        .is_ok();
+
+        if !is_approver {
+            Record::check_owner(record, owner)?;
+        }
+
+        Ok(Self {
+            owner,
+            record,
+            record_delegate,
+        })
+    }
+}
+
+pub struct RevokeRecordDelegate<'info> {
+    accounts: RevokeRecordDelegateAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for RevokeRecordDelegate<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = RevokeRecordDelegateAccounts::try_from(ctx.accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> RevokeRecordDelegate<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Revoke Record Delegate");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Safety: The account has already been validated
+        let delegate: Pubkey = unsafe {
+            RecordDelegate::get_delegate_unchecked(&self.accounts.record_delegate.try_borrow_data()?)
+        }?;
+
+        close_account(self.accounts.record_delegate, self.accounts.owner)?;
+
+        RecordDelegateRevoked {
+            record: self.accounts.record.key(),
+            delegate: &delegate,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
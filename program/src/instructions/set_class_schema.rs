@@ -0,0 +1,163 @@
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    ProgramResult,
+};
+
+use crate::{
+    error::RecordServiceError,
+    events::{ClassSchemaUpdated, Event},
+    state::{Class, ClassSchema},
+    utils::{check_program_address, create_pda_account, ByteReader, Context},
+};
+
+/// SetClassSchema instruction.
+///
+/// This function:
+/// 1. Validates the class authority and the field definitions
+/// 2. Creates the schema account of the class if it does not exist yet,
+///    checking its PDA with the bump passed in, and stores its bump in the class
+/// 3. Stores the field definitions, resizing the account if needed
+///
+/// # Accounts
+/// 1. `authority` - The authority of the class (must be a signer)
+/// 2. `payer` - The account that will pay or get refunded for the schema account
+/// 3. `class` - The class account the schema applies to
+/// 4. `schema` - The schema PDA of the class
+/// 5. `system_program` - Required for creating and resizing the schema account
+///
+/// # Security
+/// 1. The authority must be a signer and the authority of the class
+/// 2. The schema account must be the schema PDA of the class, checked with the
+///    canonical bump passed in when it is created and with the bump stored in
+///    the class once the schema exists
+/// 3. Existing records are not checked against the new schema, only their
+///    next data update is
+pub struct SetClassSchemaAccounts<'info> {
+    payer: &'info AccountInfo,
+    class: &'info AccountInfo,
+    schema: &'info AccountInfo,
+    schema_bump: u8,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for SetClassSchemaAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, class, schema, _system_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Check the class authority
+        Class::check_authority(class, authority)?;
+
+        // Check the schema account, it is derived when it is created
+        let schema_bump = Class::get_schema_bump(class)?;
+
+        if schema_bump != 0 {
+            ClassSchema::check_address(schema, class, schema_bump)?;
+            ClassSchema::check_program_id_and_discriminator(schema)?;
+        }
+
+        Ok(Self {
+            payer,
+            class,
+            schema,
+            schema_bump,
+        })
+    }
+}
+
+pub struct SetClassSchema<'info> {
+    accounts: SetClassSchemaAccounts<'info>,
+    bump: u8,
+    fields: &'info [u8],
+}
+
+impl<'info> TryFrom<Context<'info>> for SetClassSchema<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = SetClassSchemaAccounts::try_from(ctx.accounts)?;
+
+        // Deserialize `bump`
+        let bump: u8 = ByteReader::read_with_offset(ctx.data, 0)?;
+
+        // Deserialize `fields`
+        let fields = &ctx.data[size_of::<u8>()..];
+
+        // Check the field definitions
+        ClassSchema::validate_fields(fields)?;
+
+        Ok(Self {
+            accounts,
+            bump,
+            fields,
+        })
+    }
+}
+
+impl<'info> SetClassSchema<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Set Class Schema");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        if self.accounts.schema_bump == 0 {
+            let bump: [u8; 1] = [self.bump];
+
+            // Check if the schema is the PDA of the class
+            check_program_address(
+                self.accounts.schema,
+                &[b"schema", self.accounts.class.key(), &bump],
+                RecordServiceError::InvalidSchema,
+            )?;
+
+            let seeds = [
+                Seed::from(b"schema"),
+                Seed::from(self.accounts.class.key()),
+                Seed::from(&bump),
+            ];
+
+            create_pda_account(
+                self.accounts.schema,
+                self.accounts.payer,
+                ClassSchema::MINIMUM_SCHEMA_SIZE + self.fields.len(),
+                &[Signer::from(&seeds)],
+            )?;
+
+            let schema = ClassSchema {
+                class: *self.accounts.class.key(),
+                fields: self.fields,
+            };
+
+            unsafe { schema.initialize_unchecked(self.accounts.schema) }?;
+
+            // Safety: The class has already been validated
+            unsafe { Class::update_schema_bump_unchecked(self.accounts.class, bump[0]) }?;
+        } else {
+            // Safety: The account has already been validated
+            unsafe {
+                ClassSchema::update_fields_unchecked(
+                    self.accounts.schema,
+                    self.accounts.payer,
+                    self.fields,
+                )
+            }?;
+        }
+
+        ClassSchemaUpdated {
+            class: self.accounts.class.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use crate::{
-    state::Record,
+    error::RecordServiceError,
+    events::{Event, RecordTransferred},
+    state::{Class, Permission, Record},
     utils::{ByteReader, Context},
 };
 use core::mem::size_of;
@@ -19,13 +21,19 @@ use pinocchio::{
 /// # Accounts
 /// 1. `authority` - The account that has permission to transfer the record (must be a signer)
 /// 2. `record` - The record account to be transferred
-/// 3. `class` - [optional] The class of the record to be transferred
+/// 3. `class` - The class of the record to be transferred, its policy decides who may transfer
+/// 4. `class_delegate` - [optional] The class delegate account of the authority
+/// 5. `record_delegate` - [optional] The record delegate account of the authority
 ///
 /// # Security
-/// 1. The authority must be either:
-///    a. The record owner, or
-///    b. if the class is permissioned, the authority can be the permissioned authority
+/// 1. Depending on the class transfer policy, the authority must be either:
+///    a. The record owner, or a record delegate approved by the owner with the
+///    transfer permission, and/or
+///    b. the class authority or a class delegate with the transfer permission
 /// 2. The record must not be frozen
+/// 3. The record must not be expired
+/// 4. The class must not be non-transferable
+/// 5. The record must not be revoked
 pub struct TransferRecordAccounts<'info> {
     record: &'info AccountInfo,
 }
@@ -38,7 +46,25 @@ impl<'info> TryFrom<&'info [AccountInfo]> for TransferRecordAccounts<'info> {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        Record::check_owner_or_delegate(record, rest.first(), authority)?;
+        let class = rest.first().ok_or(RecordServiceError::MissingClass)?;
+
+        Record::check_owner_or_delegate(
+            record,
+            Some(class),
+            rest.get(1),
+            rest.get(2),
+            authority,
+            Permission::TransferRecord,
+        )?;
+
+        // Check if the records of the class can change hands
+        Class::check_transferable(class)?;
+
+        // Check if the record is expired [this is safe, the record has already been validated]
+        unsafe { Record::check_not_expired_unchecked(&record.try_borrow_data()?)? };
+
+        // Check if the record is revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record.try_borrow_data()?)? };
 
         Ok(Self { record })
     }
@@ -91,6 +117,14 @@ impl<'info> TransferRecord<'info> {
                 &mut self.accounts.record.try_borrow_mut_data()?,
                 &self.new_owner,
             )
+        }?;
+
+        RecordTransferred {
+            record: self.accounts.record.key(),
+            new_owner: &self.new_owner,
         }
+        .emit();
+
+        Ok(())
     }
 }
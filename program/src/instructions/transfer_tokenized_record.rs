@@ -1,11 +1,16 @@
-use crate::{state::Record, token2022::TransferChecked, utils::Context};
+use crate::{
+    error::RecordServiceError,
+    events::{Event, TokenizedRecordTransferred},
+    state::{Class, Permission, Record},
+    token2022::TransferChecked,
+    utils::Context,
+};
 #[cfg(not(feature = "perf"))]
 use pinocchio::log::sol_log;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
     program_error::ProgramError,
-    pubkey::try_find_program_address,
     ProgramResult,
 };
 
@@ -23,13 +28,17 @@ use pinocchio::{
 /// 4. `new_token_account` - The new owner of the token account
 /// 5. `record` - The record account to be updated
 /// 6. `system_program` - Required for account resizing operations
-/// 7. `class` - [optional] The class of the token account
+/// 7. `class` - The class of the record, its policy decides who may transfer
+/// 8. `class_delegate` - [optional] The class delegate account of the authority
 ///
 /// # Security
-/// 1. The authority must be:
-///    a. The mint's owner, or
-///    b. if the class is permissioned, the authority must be the permissioned authority
+/// 1. Depending on the class transfer policy, the authority must be either:
+///    a. The owner of the token account, and/or
+///    b. the class authority or a class delegate with the transfer permission
 /// 2. The record must not be frozen
+/// 3. The record must not be expired
+/// 4. The class must not be non-transferable
+/// 5. The record must not be revoked
 pub struct TransferTokenizedRecordAccounts<'info> {
     mint: &'info AccountInfo,
     token_account: &'info AccountInfo,
@@ -51,15 +60,28 @@ impl<'info> TryFrom<&'info [AccountInfo]> for TransferTokenizedRecordAccounts<'i
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        let class = rest.first().ok_or(RecordServiceError::MissingClass)?;
+
         // Check if authority is the record owner or has a delegate
         Record::check_owner_or_delegate_tokenized(
             record,
-            rest.first(),
+            Some(class),
+            rest.get(1),
             authority,
             mint,
             token_account,
+            Permission::TransferRecord,
         )?;
 
+        // Check if the records of the class can change hands
+        Class::check_transferable(class)?;
+
+        // Check if the record is expired [this is safe, the record has already been validated]
+        unsafe { Record::check_not_expired_unchecked(&record.try_borrow_data()?)? };
+
+        // Check if the record is revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record.try_borrow_data()?)? };
+
         Ok(Self {
             mint,
             token_account,
@@ -92,11 +114,9 @@ impl<'info> TransferTokenizedRecord<'info> {
     }
 
     pub fn execute(&self) -> ProgramResult {
-        let bump = [
-            try_find_program_address(&[b"mint", self.accounts.record.key()], &crate::ID)
-                .ok_or(ProgramError::InvalidArgument)?
-                .1,
-        ];
+        // Mint bump stored on tokenization [this is safe, the record has already been validated]
+        let bump =
+            unsafe { Record::get_mint_bump_unchecked(&self.accounts.record.try_borrow_data()?) };
 
         let seeds = [
             Seed::from(b"mint"),
@@ -116,6 +136,13 @@ impl<'info> TransferTokenizedRecord<'info> {
         }
         .invoke_signed(&signers)?;
 
+        TokenizedRecordTransferred {
+            record: self.accounts.record.key(),
+            mint: self.accounts.mint.key(),
+            new_token_account: self.accounts.new_token_account.key(),
+        }
+        .emit();
+
         Ok(())
     }
 }
@@ -0,0 +1,111 @@
+use crate::{
+    error::RecordServiceError,
+    events::{ClassFeeUpdated, Event},
+    state::Class,
+    utils::{ByteReader, Context},
+};
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+/// UpdateClassFee instruction.
+///
+/// This function:
+/// 1. Validates the class authority
+/// 2. Stores the new creation fee and treasury in the class
+///
+/// # Accounts
+/// 1. `authority` - The authority of the class (must be a signer)
+/// 2. `class` - The class account to be updated
+///
+/// # Security
+/// 1. The authority account must be a signer and should be the owner of the class.
+/// 2. A class charging a fee must have a treasury, the default pubkey is rejected.
+pub struct UpdateClassFeeAccounts<'info> {
+    class: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for UpdateClassFeeAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, class] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Account Checks
+        Class::check_authority(class, authority)?;
+
+        Ok(Self { class })
+    }
+}
+
+const CREATION_FEE_OFFSET: usize = 0;
+const TREASURY_OFFSET: usize = CREATION_FEE_OFFSET + size_of::<u64>();
+
+pub struct UpdateClassFee<'info> {
+    accounts: UpdateClassFeeAccounts<'info>,
+    creation_fee: u64,
+    treasury: Pubkey,
+}
+
+/// Minimum length of instruction data required for UpdateClassFee
+pub const UPDATE_CLASS_FEE_MIN_IX_LENGTH: usize = size_of::<u64>() + size_of::<Pubkey>();
+
+impl<'info> TryFrom<Context<'info>> for UpdateClassFee<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateClassFeeAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < UPDATE_CLASS_FEE_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `creation_fee`
+        let creation_fee: u64 = ByteReader::read_with_offset(ctx.data, CREATION_FEE_OFFSET)?;
+
+        // Deserialize `treasury`
+        let treasury: Pubkey = ByteReader::read_with_offset(ctx.data, TREASURY_OFFSET)?;
+
+        // The fee must be paid to a treasury
+        if creation_fee > 0 && treasury == Pubkey::default() {
+            return Err(RecordServiceError::InvalidTreasury.into());
+        }
+
+        Ok(Self {
+            accounts,
+            creation_fee,
+            treasury,
+        })
+    }
+}
+
+impl<'info> UpdateClassFee<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Update Class Fee");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        unsafe {
+            Class::update_fee_unchecked(self.accounts.class, self.creation_fee, &self.treasury)
+        }?;
+
+        ClassFeeUpdated {
+            class: self.accounts.class.key(),
+            creation_fee: self.creation_fee,
+            treasury: &self.treasury,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    events::{ClassMerkleRootUpdated, Event},
+    state::Class,
+    utils::{ByteReader, Context},
+};
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// UpdateClassMerkleRoot instruction.
+///
+/// This function:
+/// 1. Validates the class authority
+/// 2. Stores the new merkle root of the class allowlist
+///
+/// # Accounts
+/// 1. `authority` - The authority of the class (must be a signer)
+/// 2. `class` - The class account to be updated
+///
+/// # Security
+/// 1. The authority account must be a signer and should be the owner of the class.
+/// 2. A zeroed merkle root disables the allowlist.
+pub struct UpdateClassMerkleRootAccounts<'info> {
+    class: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for UpdateClassMerkleRootAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, class] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Account Checks
+        Class::check_authority(class, authority)?;
+
+        Ok(Self { class })
+    }
+}
+
+pub struct UpdateClassMerkleRoot<'info> {
+    accounts: UpdateClassMerkleRootAccounts<'info>,
+    merkle_root: [u8; 32],
+}
+
+/// Minimum length of instruction data required for UpdateClassMerkleRoot
+pub const UPDATE_CLASS_MERKLE_ROOT_MIN_IX_LENGTH: usize = size_of::<[u8; 32]>();
+
+impl<'info> TryFrom<Context<'info>> for UpdateClassMerkleRoot<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateClassMerkleRootAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < UPDATE_CLASS_MERKLE_ROOT_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `merkle_root`
+        let merkle_root: [u8; 32] = ByteReader::read_with_offset(ctx.data, 0)?;
+
+        Ok(Self {
+            accounts,
+            merkle_root,
+        })
+    }
+}
+
+impl<'info> UpdateClassMerkleRoot<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Update Class Merkle Root");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        unsafe { Class::update_merkle_root_unchecked(self.accounts.class, &self.merkle_root) }?;
+
+        ClassMerkleRootUpdated {
+            class: self.accounts.class.key(),
+            merkle_root: &self.merkle_root,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    events::{ClassPolicyUpdated, Event},
+    state::{Class, ClassPolicy},
+    utils::Context,
+};
+use core::mem::size_of;
+#[cfg(not(feature = "perf"))]
+use pinocchio::log::sol_log;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// UpdateClassPolicy instruction.
+///
+/// This function:
+/// 1. Validates the class authority
+/// 2. Validates the policy of each action
+/// 3. Stores the new policy in the class
+///
+/// # Accounts
+/// 1. `authority` - The authority of the class (must be a signer)
+/// 2. `class` - The class account to be updated
+///
+/// # Security
+/// 1. The authority account must be a signer and should be the owner of the class.
+/// 2. The policy applies to every record of the class, including existing ones.
+pub struct UpdateClassPolicyAccounts<'info> {
+    class: &'info AccountInfo,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for UpdateClassPolicyAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, class] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Account Checks
+        Class::check_authority(class, authority)?;
+
+        Ok(Self { class })
+    }
+}
+
+pub struct UpdateClassPolicy<'info> {
+    accounts: UpdateClassPolicyAccounts<'info>,
+    policy: ClassPolicy,
+}
+
+/// Minimum length of instruction data required for UpdateClassPolicy
+pub const UPDATE_CLASS_POLICY_MIN_IX_LENGTH: usize = size_of::<ClassPolicy>();
+
+impl<'info> TryFrom<Context<'info>> for UpdateClassPolicy<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateClassPolicyAccounts::try_from(ctx.accounts)?;
+
+        // Check minimum instruction data length
+        #[cfg(not(feature = "perf"))]
+        if ctx.data.len() < UPDATE_CLASS_POLICY_MIN_IX_LENGTH {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Deserialize `policy`
+        let policy = ClassPolicy::try_from_bytes(&ctx.data[..UPDATE_CLASS_POLICY_MIN_IX_LENGTH])?;
+
+        Ok(Self { accounts, policy })
+    }
+}
+
+impl<'info> UpdateClassPolicy<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Update Class Policy");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        unsafe { Class::update_policy_unchecked(self.accounts.class, self.policy) }?;
+
+        ClassPolicyUpdated {
+            class: self.accounts.class.key(),
+            update_data: self.policy.update_data as u8,
+            update_expiry: self.policy.update_expiry as u8,
+            transfer: self.policy.transfer as u8,
+            delete: self.policy.delete as u8,
+            tokenize: self.policy.tokenize as u8,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
@@ -1,11 +1,16 @@
 use core::mem::size_of;
 use crate::{
-    state::{Class, Record, CLASS_OFFSET},
+    error::RecordServiceError,
+    events::{
+        Event, RecordChunkWritten, RecordDataUpdated, RecordExpiryUpdated, RecordWriteCancelled,
+        RecordWriteStarted,
+    },
+    state::{Class, ClassSchema, Permission, Record, RecordUpdate, WriteState},
     utils::{ByteReader, Context},
 };
 #[cfg(not(feature = "perf"))]
 use pinocchio::log::sol_log;
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
 
 /// UpdateRecord instruction.
 ///
@@ -13,26 +18,57 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::
 /// 1. Validates the authority and record
 /// 2. Updates the record's data content
 /// 3. Resizes the account if needed
+/// 4. Increments the record version and chains the change into the record hash
 ///
 /// # Accounts
-/// 1. `authority` - The account that has permission to update the record (must be a signer)
+/// 1. `authority` - The record owner or the class authority, depending on the class policy (must be a signer)
 /// 2. `payer` - The account that will pay for the record account
 /// 3. `record` - The record account to be updated
 /// 4. `class` - The class account of the record
 /// 5. `system_program` - Required for account resizing operations
+/// 6. `class_delegate` - [optional] The class delegate account of the authority
+/// 7. `record_delegate` - [optional] The record delegate account of the authority
+/// 8. `schema` - [optional] The schema PDA of the class when updating the data, required if the class has a schema
 /// 
 /// # Security
-/// 1. The authority must be the class authority
+/// 1. The class policy of the action decides if the authority can be:
+///    a. The record owner, or a record delegate approved by the owner with the
+///    update data or update expiry permission, or
+///    b. the class authority or a class delegate with the update data or
+///    update expiry permission
+/// 2. The record must not be expired when updating its data
+/// 3. The record must not be revoked
+/// 4. If the class has a schema, the data must match it, otherwise utf-8 records
+///    must be valid utf-8 and binary records accept any data
+/// 5. The record data must not be being written in chunks when updating it
 pub struct UpdateRecordAccounts<'info> {
     payer: &'info AccountInfo,
     record: &'info AccountInfo,
+    class: &'info AccountInfo,
+    rest: &'info [AccountInfo],
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for UpdateRecordAccounts<'info> {
-    type Error = ProgramError;
+impl<'info> UpdateRecordAccounts<'info> {
+    fn try_from_with_permission(
+        accounts: &'info [AccountInfo],
+        permission: Permission,
+    ) -> Result<Self, ProgramError> {
+        Self::try_from_with_options(accounts, permission, false)
+    }
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, payer, record, class, _system_program] = accounts else {
+    /// Same as `try_from_with_permission` with the update data permission,
+    /// the owner and the payer of a record created with CreateBufferedRecord
+    /// are also allowed until it is finalized
+    fn try_from_for_write(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        Self::try_from_with_options(accounts, Permission::UpdateRecordData, true)
+    }
+
+    fn try_from_with_options(
+        accounts: &'info [AccountInfo],
+        permission: Permission,
+        allow_creator: bool,
+    ) -> Result<Self, ProgramError> {
+        let [authority, payer, record, class, _system_program, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -40,24 +76,42 @@ impl<'info> TryFrom<&'info [AccountInfo]> for UpdateRecordAccounts<'info> {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Check if authority is the record owner or has a delegate
-        Class::check_authority(class, authority)?;
-
         // Check if the Record is correct
         Record::check_program_id_and_discriminator(record)?;
 
-        // Check if the class is the correct class
-        if class.key().ne(&record.try_borrow_data()?[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+        // Check if the record is revoked [this is safe, the record has already been validated]
+        unsafe { Record::check_not_revoked_unchecked(&record.try_borrow_data()?)? };
+
+        // Check if the authority created the record and it is not finalized yet [this is safe, the record has already been validated]
+        let is_creator = allow_creator && unsafe {
+            let data = record.try_borrow_data()?;
+            Record::check_class_unchecked(&data, class)?;
+            Record::is_creator_unchecked(&data, authority)
+        };
+
+        // Check if authority is the record owner, a record delegate, the class authority or a class delegate
+        if !is_creator {
+            let data = record.try_borrow_data()?;
+
+            if !Record::is_allowed_owner(&data, class, authority, permission)?
+                && !Record::is_record_delegate(record, &data, class, rest.get(1), authority, permission)?
+            {
+                Record::validate_delegate(class, rest.first(), authority, permission)?;
+            }
         }
 
-        Ok(Self { payer, record })
+        Ok(Self {
+            payer,
+            record,
+            class,
+            rest,
+        })
     }
 }
 
 pub struct UpdateRecordData<'info> {
     accounts: UpdateRecordAccounts<'info>,
-    data: &'info str,
+    data: &'info [u8],
 }
 
 impl<'info> TryFrom<Context<'info>> for UpdateRecordData<'info> {
@@ -65,13 +119,26 @@ impl<'info> TryFrom<Context<'info>> for UpdateRecordData<'info> {
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
         // Deserialize our accounts array
-        let accounts = UpdateRecordAccounts::try_from(ctx.accounts)?;
+        let accounts =
+            UpdateRecordAccounts::try_from_with_permission(ctx.accounts, Permission::UpdateRecordData)?;
+
+        // Check if the record is expired, renewing it through UpdateRecordExpiry is still allowed
+        unsafe { Record::check_not_expired_unchecked(&accounts.record.try_borrow_data()?)? };
+
+        // Check if the record is being written [this is safe, the record has already been validated]
+        unsafe { Record::check_not_writing_unchecked(&accounts.record.try_borrow_data()?)? };
 
         // Check ix data has minimum length and create a byte reader
         let mut instruction_data = ByteReader::new(ctx.data);
 
         // Deserialize `data`
-        let data: &str = instruction_data.read_str(instruction_data.remaining_bytes())?;
+        let data: &[u8] = instruction_data.read_bytes(instruction_data.remaining_bytes())?;
+
+        // Check `data` against the class schema and the record content type [this is safe, the record has already been validated]
+        let schema = accounts.rest.get(2);
+        let content_type =
+            unsafe { Record::get_content_type_unchecked(&accounts.record.try_borrow_data()?)? };
+        ClassSchema::check_data(schema, accounts.class, content_type, data)?;
 
         Ok(Self { accounts, data })
     }
@@ -88,7 +155,25 @@ impl<'info> UpdateRecordData<'info> {
         // Update the record data [this is safe, check safety docs]
         unsafe {
             Record::update_data_unchecked(self.accounts.record, self.accounts.payer, self.data)
+        }?;
+
+        // Chain the new data into the record history [this is safe, check safety docs]
+        let (version, hash) = unsafe {
+            Record::update_history_unchecked(
+                &mut self.accounts.record.try_borrow_mut_data()?,
+                RecordUpdate::Data,
+                self.data,
+            )
+        }?;
+
+        RecordDataUpdated {
+            record: self.accounts.record.key(),
+            version,
+            hash: &hash,
         }
+        .emit();
+
+        Ok(())
     }
 }
 
@@ -102,7 +187,10 @@ impl<'info> TryFrom<Context<'info>> for UpdateRecordExpiry<'info> {
 
     fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
         // Deserialize our accounts array
-        let accounts = UpdateRecordAccounts::try_from(ctx.accounts)?;
+        let accounts = UpdateRecordAccounts::try_from_with_permission(
+            ctx.accounts,
+            Permission::UpdateRecordExpiry,
+        )?;
 
         // Check minimum instruction data length
         #[cfg(not(feature = "perf"))]
@@ -126,8 +214,592 @@ impl<'info> UpdateRecordExpiry<'info> {
 
     pub fn execute(&self) -> ProgramResult {
         // Update the record data [this is safe, check safety docs]
+        let mut record_data = self.accounts.record.try_borrow_mut_data()?;
+
         unsafe {
-            Record::update_expiry_unchecked(&mut self.accounts.record.try_borrow_mut_data()?, self.expiry)
+            Record::update_expiry_unchecked(&mut record_data, self.expiry)
+        }?;
+
+        // Chain the new expiry into the record history [this is safe, check safety docs]
+        let (version, hash) = unsafe {
+            Record::update_history_unchecked(
+                &mut record_data,
+                RecordUpdate::Expiry,
+                &self.expiry.to_le_bytes(),
+            )
+        }?;
+
+        RecordExpiryUpdated {
+            record: self.accounts.record.key(),
+            expiry: self.expiry,
+            version,
+            hash: &hash,
         }
+        .emit();
+
+        Ok(())
+    }
+}
+
+/// Split the `expected_version` prepended to the instruction data
+fn split_expected_version(data: &[u8]) -> Result<(u64, &[u8]), ProgramError> {
+    // Check minimum instruction data length
+    if data.len() < size_of::<u64>() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (expected_version, data) = data.split_at(size_of::<u64>());
+
+    Ok((
+        u64::from_le_bytes(
+            expected_version
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        ),
+        data,
+    ))
+}
+
+/// CompareAndSwapRecord instructions.
+///
+/// Same as UpdateRecordData and UpdateRecordExpiry, with an `expected_version`
+/// (u64) prepended to the instruction data. The update fails with
+/// `RecordVersionMismatch` if the record version is not the expected one, so
+/// concurrent writers can safely read, modify and write a record.
+///
+/// # Accounts
+/// Same as UpdateRecordData and UpdateRecordExpiry respectively
+///
+/// # Security
+/// 1. Same as UpdateRecordData and UpdateRecordExpiry respectively
+/// 2. The record version must be equal to `expected_version`
+pub struct CompareAndSwapRecordData<'info> {
+    update: UpdateRecordData<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for CompareAndSwapRecordData<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize `expected_version`
+        let (expected_version, data) = split_expected_version(ctx.data)?;
+
+        let update = UpdateRecordData::try_from(Context {
+            accounts: ctx.accounts,
+            data,
+        })?;
+
+        // Check if the record changed since the expected version [this is safe, the record has already been validated]
+        unsafe {
+            Record::check_version_unchecked(
+                &update.accounts.record.try_borrow_data()?,
+                expected_version,
+            )?
+        };
+
+        Ok(Self { update })
+    }
+}
+
+impl<'info> CompareAndSwapRecordData<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Compare And Swap Record Data");
+        Self::try_from(ctx)?.update.execute()
+    }
+}
+
+pub struct CompareAndSwapRecordExpiry<'info> {
+    update: UpdateRecordExpiry<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for CompareAndSwapRecordExpiry<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize `expected_version`
+        let (expected_version, data) = split_expected_version(ctx.data)?;
+
+        let update = UpdateRecordExpiry::try_from(Context {
+            accounts: ctx.accounts,
+            data,
+        })?;
+
+        // Check if the record changed since the expected version [this is safe, the record has already been validated]
+        unsafe {
+            Record::check_version_unchecked(
+                &update.accounts.record.try_borrow_data()?,
+                expected_version,
+            )?
+        };
+
+        Ok(Self { update })
+    }
+}
+
+impl<'info> CompareAndSwapRecordExpiry<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Compare And Swap Record Expiry");
+        Self::try_from(ctx)?.update.execute()
+    }
+}
+
+/// PatchRecordData instruction.
+///
+/// This instruction:
+/// 1. Validates the authority and record
+/// 2. Writes `patch` at `offset` of the record data
+/// 3. Extends the account if the patch ends after the current data, or
+///    resizes it to end with the patch if `truncate` is set
+/// 4. Increments the record version and chains the new data into the record hash
+///
+/// # Accounts
+/// Same as UpdateRecordData
+///
+/// # Security
+/// 1. Same as UpdateRecordData, the whole patched data is checked against the class schema
+/// 2. The patch must start inside the current data or right after it
+pub struct PatchRecordData<'info> {
+    accounts: UpdateRecordAccounts<'info>,
+    schema: Option<&'info AccountInfo>,
+    offset: usize,
+    truncate: bool,
+    patch: &'info [u8],
+}
+
+impl<'info> TryFrom<Context<'info>> for PatchRecordData<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts =
+            UpdateRecordAccounts::try_from_with_permission(ctx.accounts, Permission::UpdateRecordData)?;
+
+        // Check if the record is expired
+        unsafe { Record::check_not_expired_unchecked(&accounts.record.try_borrow_data()?)? };
+
+        // Check if the record is being written [this is safe, the record has already been validated]
+        unsafe { Record::check_not_writing_unchecked(&accounts.record.try_borrow_data()?)? };
+
+        let schema = accounts.rest.get(2);
+
+        // Check ix data has minimum length and create a byte reader
+        let mut instruction_data = ByteReader::new(ctx.data);
+
+        // Deserialize `offset`
+        let offset = instruction_data.read::<u32>()? as usize;
+
+        // Deserialize `truncate`
+        let truncate: bool = instruction_data.read()?;
+
+        // Deserialize `patch`
+        let patch: &[u8] = instruction_data.read_bytes(instruction_data.remaining_bytes())?;
+
+        Ok(Self {
+            accounts,
+            schema,
+            offset,
+            truncate,
+            patch,
+        })
+    }
+}
+
+impl<'info> PatchRecordData<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Patch Record Data");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Patch the record data [this is safe, check safety docs]
+        unsafe {
+            Record::patch_data_unchecked(
+                self.accounts.record,
+                self.accounts.payer,
+                self.offset,
+                self.patch,
+                self.truncate,
+            )
+        }?;
+
+        // Check the patched data against the class schema [this is safe, the record has already been validated]
+        {
+            let record_data = self.accounts.record.try_borrow_data()?;
+            ClassSchema::check_data(
+                self.schema,
+                self.accounts.class,
+                unsafe { Record::get_content_type_unchecked(&record_data)? },
+                unsafe { Record::get_data_unchecked(&record_data) },
+            )?;
+        }
+
+        // Chain the patched data into the record history [this is safe, check safety docs]
+        let (version, hash) = unsafe {
+            Record::update_data_history_unchecked(&mut self.accounts.record.try_borrow_mut_data()?)
+        }?;
+
+        RecordDataUpdated {
+            record: self.accounts.record.key(),
+            version,
+            hash: &hash,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+/// BeginRecordWrite instruction.
+///
+/// This instruction:
+/// 1. Validates the authority and record
+/// 2. Puts the record in the writing state, the new data is then staged after
+///    the current one with WriteRecordChunk and replaces it when
+///    FinalizeRecordWrite checks it
+///
+/// # Accounts
+/// Same as UpdateRecordExpiry
+///
+/// # Security
+/// 1. Same as UpdateRecordData
+/// 2. The record must not be already being written
+/// 3. The current data is kept until the write is finalized, CancelRecordWrite
+///    drops the staged data
+pub struct BeginRecordWrite<'info> {
+    accounts: UpdateRecordAccounts<'info>,
+}
+
+impl<'info> TryFrom<Context<'info>> for BeginRecordWrite<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts =
+            UpdateRecordAccounts::try_from_with_permission(ctx.accounts, Permission::UpdateRecordData)?;
+
+        // Check if the record is expired
+        unsafe { Record::check_not_expired_unchecked(&accounts.record.try_borrow_data()?)? };
+
+        // Check if the record is being written [this is safe, the record has already been validated]
+        unsafe { Record::check_not_writing_unchecked(&accounts.record.try_borrow_data()?)? };
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> BeginRecordWrite<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Begin Record Write");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Start the write [this is safe, check safety docs]
+        unsafe {
+            Record::update_write_state_unchecked(
+                &mut self.accounts.record.try_borrow_mut_data()?,
+                WriteState::Updating,
+            )
+        }?;
+
+        RecordWriteStarted {
+            record: self.accounts.record.key(),
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+/// WriteRecordChunk instruction.
+///
+/// This instruction:
+/// 1. Validates the authority and record
+/// 2. Writes `chunk` at `offset` of the record data for buffered records, or
+///    of the data staged after the record data for records being updated
+/// 3. Extends the account if the chunk ends after the current data
+///
+/// # Accounts
+/// Same as UpdateRecordExpiry
+///
+/// # Security
+/// 1. Same as UpdateRecordData, the data is checked once the write is finalized
+/// 2. The owner and the payer of a buffered record may also write it
+/// 3. The record must be being written
+/// 4. The chunk must start inside the written data or right after it
+pub struct WriteRecordChunk<'info> {
+    accounts: UpdateRecordAccounts<'info>,
+    write_state: WriteState,
+    offset: usize,
+    chunk: &'info [u8],
+}
+
+impl<'info> TryFrom<Context<'info>> for WriteRecordChunk<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateRecordAccounts::try_from_for_write(ctx.accounts)?;
+
+        // Check if the record is being written [this is safe, the record has already been validated]
+        let write_state =
+            unsafe { Record::get_write_state_unchecked(&accounts.record.try_borrow_data()?)? };
+        if write_state == WriteState::Idle {
+            return Err(RecordServiceError::RecordNotWriting.into());
+        }
+
+        // Check ix data has minimum length and create a byte reader
+        let mut instruction_data = ByteReader::new(ctx.data);
+
+        // Deserialize `offset`
+        let offset = instruction_data.read::<u32>()? as usize;
+
+        // Deserialize `chunk`
+        let chunk: &[u8] = instruction_data.read_bytes(instruction_data.remaining_bytes())?;
+
+        Ok(Self {
+            accounts,
+            write_state,
+            offset,
+            chunk,
+        })
+    }
+}
+
+impl<'info> WriteRecordChunk<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Write Record Chunk");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Write the chunk [this is safe, check safety docs]
+        unsafe {
+            if self.write_state == WriteState::Creating {
+                Record::patch_data_unchecked(
+                    self.accounts.record,
+                    self.accounts.payer,
+                    self.offset,
+                    self.chunk,
+                    false,
+                )
+            } else {
+                Record::stage_data_unchecked(
+                    self.accounts.record,
+                    self.accounts.payer,
+                    self.offset,
+                    self.chunk,
+                )
+            }
+        }?;
+
+        RecordChunkWritten {
+            record: self.accounts.record.key(),
+            offset: self.offset as u32,
+            len: self.chunk.len() as u32,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+/// FinalizeRecordWrite instruction.
+///
+/// This instruction:
+/// 1. Validates the authority and record
+/// 2. Replaces the record data with the staged data for records being updated
+/// 3. Checks the written data against the class schema
+/// 4. Starts the record history for buffered records, or increments the
+///    record version and chains the new data into the record hash
+/// 5. Puts the record back in the idle state
+///
+/// # Accounts
+/// Same as UpdateRecordData
+///
+/// # Security
+/// 1. Same as UpdateRecordData
+/// 2. The owner and the payer of a buffered record may also finalize it
+/// 3. The record must be being written
+pub struct FinalizeRecordWrite<'info> {
+    accounts: UpdateRecordAccounts<'info>,
+    schema: Option<&'info AccountInfo>,
+    write_state: WriteState,
+}
+
+impl<'info> TryFrom<Context<'info>> for FinalizeRecordWrite<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateRecordAccounts::try_from_for_write(ctx.accounts)?;
+
+        // Check if the record is being written [this is safe, the record has already been validated]
+        let write_state =
+            unsafe { Record::get_write_state_unchecked(&accounts.record.try_borrow_data()?)? };
+        if write_state == WriteState::Idle {
+            return Err(RecordServiceError::RecordNotWriting.into());
+        }
+
+        let schema = accounts.rest.get(2);
+
+        Ok(Self {
+            accounts,
+            schema,
+            write_state,
+        })
+    }
+}
+
+impl<'info> FinalizeRecordWrite<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Finalize Record Write");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        // Swap in the staged data [this is safe, check safety docs]
+        if self.write_state == WriteState::Updating {
+            unsafe {
+                Record::commit_staged_data_unchecked(self.accounts.record, self.accounts.payer)
+            }?;
+        }
+
+        // Check the written data against the class schema [this is safe, the record has already been validated]
+        {
+            let record_data = self.accounts.record.try_borrow_data()?;
+            ClassSchema::check_data(
+                self.schema,
+                self.accounts.class,
+                unsafe { Record::get_content_type_unchecked(&record_data)? },
+                unsafe { Record::get_data_unchecked(&record_data) },
+            )?;
+        }
+
+        let mut record_data = self.accounts.record.try_borrow_mut_data()?;
+
+        // Start or chain the record history [this is safe, check safety docs]
+        let (version, hash) = if self.write_state == WriteState::Creating {
+            (0, unsafe { Record::initialize_history_unchecked(&mut record_data) }?)
+        } else {
+            unsafe { Record::update_data_history_unchecked(&mut record_data) }?
+        };
+
+        // End the write [this is safe, check safety docs]
+        unsafe { Record::update_write_state_unchecked(&mut record_data, WriteState::Idle) }?;
+
+        RecordDataUpdated {
+            record: self.accounts.record.key(),
+            version,
+            hash: &hash,
+        }
+        .emit();
+
+        Ok(())
+    }
+}
+
+/// CancelRecordWrite instruction.
+///
+/// This instruction:
+/// 1. Validates the authority and record
+/// 2. Deletes buffered records and decrements the record count of the class,
+///    or drops the data staged for records being updated and puts them back
+///    in the idle state
+///
+/// # Accounts
+/// 1. `authority` - The record owner or the class authority, depending on the class policy (must be a signer)
+/// 2. `payer` - The account that will get refunded for the record account or the staged data,
+///    the payer of the record for buffered records
+/// 3. `record` - The record account being written
+/// 4. `class` - The class account of the record (must be writable)
+/// 5. `system_program` - Required for account resizing operations
+/// 6. `class_delegate` - [optional] The class delegate account of the authority
+/// 7. `record_delegate` - [optional] The record delegate account of the authority
+///
+/// # Security
+/// 1. Same as UpdateRecordData
+/// 2. The owner and the payer of a buffered record may also cancel it
+/// 3. The record must be being written
+/// 4. The rent of a buffered record is refunded to the account that paid it,
+///    the creation fee is not refunded
+pub struct CancelRecordWrite<'info> {
+    accounts: UpdateRecordAccounts<'info>,
+    write_state: WriteState,
+}
+
+impl<'info> TryFrom<Context<'info>> for CancelRecordWrite<'info> {
+    type Error = ProgramError;
+
+    fn try_from(ctx: Context<'info>) -> Result<Self, Self::Error> {
+        // Deserialize our accounts array
+        let accounts = UpdateRecordAccounts::try_from_for_write(ctx.accounts)?;
+
+        // Check if the record is being written [this is safe, the record has already been validated]
+        let write_state =
+            unsafe { Record::get_write_state_unchecked(&accounts.record.try_borrow_data()?)? };
+        if write_state == WriteState::Idle {
+            return Err(RecordServiceError::RecordNotWriting.into());
+        }
+
+        // Check if the refund of a buffered record goes to its payer [this is safe, the record has already been validated]
+        if write_state == WriteState::Creating {
+            unsafe {
+                Record::check_staged_payer_unchecked(
+                    &accounts.record.try_borrow_data()?,
+                    accounts.payer,
+                )?
+            };
+        }
+
+        Ok(Self {
+            accounts,
+            write_state,
+        })
+    }
+}
+
+impl<'info> CancelRecordWrite<'info> {
+    pub fn process(ctx: Context<'info>) -> ProgramResult {
+        #[cfg(not(feature = "perf"))]
+        sol_log("Cancel Record Write");
+        Self::try_from(ctx)?.execute()
+    }
+
+    pub fn execute(&self) -> ProgramResult {
+        if self.write_state == WriteState::Creating {
+            // Delete the unfinished record [this is safe, check safety docs]
+            unsafe { Record::delete_record_unchecked(self.accounts.record, self.accounts.payer) }?;
+
+            // Uncount the record, buffered records are never tokenized
+            Class::remove_record(self.accounts.class, false)?;
+        } else {
+            // Drop the staged data [this is safe, check safety docs]
+            unsafe {
+                Record::discard_staged_data_unchecked(self.accounts.record, self.accounts.payer)
+            }?;
+
+            // End the write [this is safe, check safety docs]
+            unsafe {
+                Record::update_write_state_unchecked(
+                    &mut self.accounts.record.try_borrow_mut_data()?,
+                    WriteState::Idle,
+                )
+            }?;
+        }
+
+        RecordWriteCancelled {
+            record: self.accounts.record.key(),
+            is_deleted: self.write_state == WriteState::Creating,
+        }
+        .emit();
+
+        Ok(())
     }
 }
@@ -1,7 +1,7 @@
 #![cfg_attr(not(test), no_std)]
 use instructions::*;
 use pinocchio::{
-    account_info::AccountInfo, default_allocator, program_entrypoint, program_error::ProgramError,
+    account_info::AccountInfo, no_allocator, program_entrypoint, program_error::ProgramError,
     pubkey::Pubkey, ProgramResult,
 };
 use utils::Context;
@@ -10,15 +10,19 @@ use utils::Context;
 use pinocchio::nostd_panic_handler;
 
 pub mod constants;
+pub mod ed25519;
+pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 #[cfg(test)]
+#[allow(clippy::needless_borrow, clippy::doc_overindented_list_items)]
 pub mod tests;
 pub mod token2022;
 pub mod utils;
 
 program_entrypoint!(process_instruction);
-default_allocator!();
+no_allocator!();
 
 #[cfg(not(test))]
 nostd_panic_handler!();
@@ -43,7 +47,7 @@ fn process_instruction(
     match discriminator {
         0 => CreateClass::process(Context { accounts, data }),
         1 => UpdateClassMetadata::process(Context { accounts, data }),
-        2 => UpdateClassAuthority::process(Context { accounts, data }),
+        // 2 was UpdateClassAuthority, authority transfers go through ProposeClassAuthority
         3 => FreezeClass::process(Context { accounts, data }),
         4 => CreateRecord::process(Context { accounts, data }),
         5 => UpdateRecordData::process(Context { accounts, data }),
@@ -55,6 +59,37 @@ fn process_instruction(
         11 => FreezeTokenizedRecord::process(Context { accounts, data }),
         12 => TransferTokenizedRecord::process(Context { accounts, data }),
         13 => BurnTokenizedRecord::process(Context { accounts, data }),
+        14 => CloseExpiredRecord::process(Context { accounts, data }),
+        15 => ProposeClassAuthority::process(Context { accounts, data }),
+        16 => AcceptClassAuthority::process(Context { accounts, data }),
+        17 => CancelClassAuthorityTransfer::process(Context { accounts, data }),
+        18 => AddClassDelegate::process(Context { accounts, data }),
+        19 => RevokeClassDelegate::process(Context { accounts, data }),
+        20 => ApproveRecordDelegate::process(Context { accounts, data }),
+        21 => RevokeRecordDelegate::process(Context { accounts, data }),
+        22 => SetClassSchema::process(Context { accounts, data }),
+        23 => UpdateClassPolicy::process(Context { accounts, data }),
+        24 => RevokeRecord::process(Context { accounts, data }),
+        25 => CompareAndSwapRecordData::process(Context { accounts, data }),
+        26 => CompareAndSwapRecordExpiry::process(Context { accounts, data }),
+        27 => PatchRecordData::process(Context { accounts, data }),
+        28 => CreateBufferedRecord::process(Context { accounts, data }),
+        29 => BeginRecordWrite::process(Context { accounts, data }),
+        30 => WriteRecordChunk::process(Context { accounts, data }),
+        31 => FinalizeRecordWrite::process(Context { accounts, data }),
+        32 => UpdateClassFee::process(Context { accounts, data }),
+        33 => UpdateClassMerkleRoot::process(Context { accounts, data }),
+        34 => CreateRecordWithProof::process(Context { accounts, data }),
+        35 => CreateRecordFromSignature::process(Context { accounts, data }),
+        36 => BatchCreateRecords::process(Context { accounts, data }),
+        37 => BatchFreezeRecords::process(Context { accounts, data }),
+        38 => BatchDeleteRecords::process(Context { accounts, data }),
+        39 => CloseClass::process(Context { accounts, data }),
+        40 => MigrateRecordClass::process(Context { accounts, data }),
+        41 => RecreateRecord::process(Context { accounts, data }),
+        42 => CancelRecordWrite::process(Context { accounts, data }),
+        43 => MigrateClassLayout::process(Context { accounts, data }),
+        44 => MigrateRecordLayout::process(Context { accounts, data }),
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
@@ -1,12 +1,122 @@
-use crate::utils::{resize_account, ByteWriter};
+use crate::{
+    constants::CLOSED_ACCOUNT_DISCRIMINATOR,
+    ed25519::check_signature,
+    error::RecordServiceError,
+    utils::{resize_account, verify_merkle_proof, ByteWriter},
+};
+
+use super::{ClassDelegate, Permission};
 use core::{mem::size_of, str};
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{create_program_address, Pubkey},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
 
 const DISCRIMINATOR_OFFSET: usize = 0;
 const AUTHORITY_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
 pub const IS_PERMISSIONED_OFFSET: usize = AUTHORITY_OFFSET + size_of::<Pubkey>();
 const IS_FROZEN_OFFSET: usize = IS_PERMISSIONED_OFFSET + size_of::<bool>();
-const NAME_LEN_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
+const IS_NON_TRANSFERABLE_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
+const POLICY_OFFSET: usize = IS_NON_TRANSFERABLE_OFFSET + size_of::<bool>();
+const RECORD_COUNT_OFFSET: usize = POLICY_OFFSET + size_of::<ClassPolicy>();
+const TOKENIZED_COUNT_OFFSET: usize = RECORD_COUNT_OFFSET + size_of::<u64>();
+const LEGACY_RECORD_COUNT_OFFSET: usize = TOKENIZED_COUNT_OFFSET + size_of::<u64>();
+const MAX_RECORDS_OFFSET: usize = LEGACY_RECORD_COUNT_OFFSET + size_of::<u64>();
+const CREATION_FEE_OFFSET: usize = MAX_RECORDS_OFFSET + size_of::<u64>();
+const TREASURY_OFFSET: usize = CREATION_FEE_OFFSET + size_of::<u64>();
+const MERKLE_ROOT_OFFSET: usize = TREASURY_OFFSET + size_of::<Pubkey>();
+const GROUP_BUMP_OFFSET: usize = MERKLE_ROOT_OFFSET + size_of::<[u8; 32]>();
+const SCHEMA_BUMP_OFFSET: usize = GROUP_BUMP_OFFSET + size_of::<u8>();
+const NAME_LEN_OFFSET: usize = SCHEMA_BUMP_OFFSET + size_of::<u8>();
+/// Offset of the name length in the layout of legacy classes, which ends
+/// with the frozen flag before the name and the metadata
+const LEGACY_NAME_LEN_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
+
+/// Who may perform an action on the records of a class
+#[repr(u8)]
+#[derive(Copy, Clone)]
+pub enum Policy {
+    /// The record owner, or a record delegate approved by the owner
+    Owner,
+    /// The class authority, or a class delegate with the matching permission
+    Authority,
+    /// Both the owner and the authority
+    Either,
+}
+
+impl Policy {
+    #[inline(always)]
+    pub fn allows_owner(self) -> bool {
+        matches!(self, Policy::Owner | Policy::Either)
+    }
+
+    #[inline(always)]
+    pub fn allows_authority(self) -> bool {
+        matches!(self, Policy::Authority | Policy::Either)
+    }
+}
+
+impl TryFrom<u8> for Policy {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Policy::Owner),
+            1 => Ok(Policy::Authority),
+            2 => Ok(Policy::Either),
+            _ => Err(RecordServiceError::InvalidPolicy.into()),
+        }
+    }
+}
+
+/// Policy of each action on the records of a class
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ClassPolicy {
+    pub update_data: Policy,
+    pub update_expiry: Policy,
+    pub transfer: Policy,
+    pub delete: Policy,
+    pub tokenize: Policy,
+}
+
+impl ClassPolicy {
+    /// Policy matching the rules of classes without an explicit policy: the
+    /// authority manages the data and the expiry, while owners transfer, delete
+    /// and tokenize their records, together with the authority in permissioned classes
+    pub fn new_default(is_permissioned: bool) -> Self {
+        let owner_actions = if is_permissioned {
+            Policy::Either
+        } else {
+            Policy::Owner
+        };
+
+        Self {
+            update_data: Policy::Authority,
+            update_expiry: Policy::Authority,
+            transfer: owner_actions,
+            delete: owner_actions,
+            tokenize: owner_actions,
+        }
+    }
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != size_of::<Self>() {
+            return Err(RecordServiceError::InvalidPolicy.into());
+        }
+
+        Ok(Self {
+            update_data: Policy::try_from(data[0])?,
+            update_expiry: Policy::try_from(data[1])?,
+            transfer: Policy::try_from(data[2])?,
+            delete: Policy::try_from(data[3])?,
+            tokenize: Policy::try_from(data[4])?,
+        })
+    }
+}
 
 #[repr(C)]
 pub struct Class<'info> {
@@ -16,6 +126,29 @@ pub struct Class<'info> {
     pub is_permissioned: bool,
     /// Whether the class is frozen or not
     pub is_frozen: bool,
+    /// Whether the records of the class can never change hands
+    pub is_non_transferable: bool,
+    /// Who may update, transfer, delete and tokenize the records of the class
+    pub policy: ClassPolicy,
+    /// Number of live records of the class, revoked records are not counted
+    pub record_count: u64,
+    /// Number of tokenized records of the class
+    pub tokenized_count: u64,
+    /// Number of records left in the legacy layout, declared by the authority
+    /// when the class was migrated and uncounted as the records are migrated
+    pub legacy_record_count: u64,
+    /// Maximum number of records of the class, if not capped, it is 0
+    pub max_records: u64,
+    /// Lamports paid to the treasury for every record created, if free, it is 0
+    pub creation_fee: u64,
+    /// Account receiving the creation fees
+    pub treasury: Pubkey,
+    /// Merkle root of the owners allowed to create records, if there is no allowlist, it is 0
+    pub merkle_root: [u8; 32],
+    /// Bump of the group mint PDA, set when the group is created by the first tokenization
+    pub group_bump: u8,
+    /// Bump of the schema PDA, set when the schema is created, if the class has no schema, it is 0
+    pub schema_bump: u8,
     /// Human-readable name for the class
     pub name: &'info str,
     /// Optional metadata about the class
@@ -23,10 +156,20 @@ pub struct Class<'info> {
 }
 
 impl<'info> Class<'info> {
-    pub const DISCRIMINATOR: u8 = 1;
+    pub const DISCRIMINATOR: u8 = 7;
+    /// Discriminator of the classes created before the policy, counts, fee,
+    /// allowlist and bumps were added, see MigrateClassLayout
+    pub const LEGACY_DISCRIMINATOR: u8 = 1;
     pub const MAX_CLASS_NAME_LEN: usize = 0xff;
-    pub const MINIMUM_CLASS_SIZE: usize =
-        size_of::<u8>() + size_of::<Pubkey>() + size_of::<bool>() * 2 + size_of::<u8>();
+    pub const MINIMUM_CLASS_SIZE: usize = size_of::<u8>()
+        + size_of::<Pubkey>()
+        + size_of::<bool>() * 3
+        + size_of::<ClassPolicy>()
+        + size_of::<u64>() * 5
+        + size_of::<Pubkey>()
+        + size_of::<[u8; 32]>()
+        + size_of::<u8>() * 2
+        + size_of::<u8>();
 
     /// Check if the program id and discriminator are valid
     #[inline(always)]
@@ -45,7 +188,7 @@ impl<'info> Class<'info> {
     /// This function does not perform owner checks
     pub unsafe fn check_discriminator_unchecked(data: &[u8]) -> Result<(), ProgramError> {
         if data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::InvalidAccountDiscriminator.into());
         }
 
         Ok(())
@@ -63,11 +206,19 @@ impl<'info> Class<'info> {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        if authority
-            .key()
-            .ne(&data[AUTHORITY_OFFSET..AUTHORITY_OFFSET + size_of::<Pubkey>()])
-        {
-            return Err(ProgramError::InvalidAccountData);
+        Self::check_authority_key_unchecked(data, authority.key())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_authority_key_unchecked(
+        data: &[u8],
+        authority: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        if authority.ne(&data[AUTHORITY_OFFSET..AUTHORITY_OFFSET + size_of::<Pubkey>()]) {
+            return Err(RecordServiceError::InvalidAuthority.into());
         }
 
         Ok(())
@@ -87,9 +238,111 @@ impl<'info> Class<'info> {
         }
     }
 
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_authority_or_delegate_unchecked(
+        class: &AccountInfo,
+        data: &[u8],
+        authority: &AccountInfo,
+        class_delegate: Option<&AccountInfo>,
+        permission: Permission,
+    ) -> Result<(), ProgramError> {
+        // Optional accounts that are not provided are replaced by the program id
+        match class_delegate.filter(|class_delegate| class_delegate.key().ne(&crate::ID)) {
+            Some(class_delegate) => {
+                ClassDelegate::check_permission(class_delegate, class, authority, permission)
+            }
+            None => Self::check_authority_unchecked(data, authority),
+        }
+    }
+
+    pub fn check_authority_or_delegate(
+        class: &AccountInfo,
+        authority: &AccountInfo,
+        class_delegate: Option<&AccountInfo>,
+        permission: Permission,
+    ) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let data = class.try_borrow_data()?;
+
+        unsafe {
+            Self::check_discriminator_unchecked(&data)?;
+            Self::check_authority_or_delegate_unchecked(
+                class,
+                &data,
+                authority,
+                class_delegate,
+                permission,
+            )
+        }
+    }
+
+    /// Get the policy of the action that requires the given permission
+    pub fn get_policy(class: &AccountInfo, permission: Permission) -> Result<Policy, ProgramError> {
+        Self::check_program_id(class)?;
+
+        let data = class.try_borrow_data()?;
+
+        unsafe {
+            Self::check_discriminator_unchecked(&data)?;
+            Self::get_policy_unchecked(&data, permission)
+        }
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_policy_unchecked(
+        data: &[u8],
+        permission: Permission,
+    ) -> Result<Policy, ProgramError> {
+        let offset = match permission {
+            Permission::UpdateRecordData => POLICY_OFFSET,
+            Permission::UpdateRecordExpiry => POLICY_OFFSET + 1,
+            Permission::TransferRecord => POLICY_OFFSET + 2,
+            Permission::DeleteRecord => POLICY_OFFSET + 3,
+            Permission::MintTokenizedRecord => POLICY_OFFSET + 4,
+            // Creating, freezing and revoking records is always up to the authority
+            Permission::CreateRecord | Permission::FreezeRecord | Permission::RevokeRecord => {
+                return Ok(Policy::Authority)
+            }
+        };
+
+        Policy::try_from(data[offset])
+    }
+
+    /// Check that the records of the class can be transferred
+    pub fn check_transferable(class: &AccountInfo) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let data = class.try_borrow_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        if data[IS_NON_TRANSFERABLE_OFFSET] == 1 {
+            return Err(RecordServiceError::NonTransferable.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn is_non_transferable_unchecked(data: &[u8]) -> bool {
+        data[IS_NON_TRANSFERABLE_OFFSET] == 1
+    }
+
     pub fn check_permission(
         class: &AccountInfo,
         authority: Option<&AccountInfo>,
+        class_delegate: Option<&AccountInfo>,
+        allowlist_proof: Option<(&AccountInfo, &[u8])>,
     ) -> Result<(), ProgramError> {
         Self::check_program_id(class)?;
 
@@ -97,18 +350,229 @@ impl<'info> Class<'info> {
 
         unsafe { Self::check_discriminator_unchecked(&data)? }
 
-        if data[IS_PERMISSIONED_OFFSET] == 1 {
-            let authority = authority.ok_or(ProgramError::InvalidAccountData)?;
-            unsafe { Self::check_authority_unchecked(&data, authority) }?;
+        if let Some((owner, proof)) = allowlist_proof {
+            unsafe { Self::check_allowlist_unchecked(&data, owner, proof) }?;
+        } else if data[IS_PERMISSIONED_OFFSET] == 1 {
+            let authority = authority.ok_or(RecordServiceError::InvalidAuthority)?;
+            unsafe {
+                Self::check_authority_or_delegate_unchecked(
+                    class,
+                    &data,
+                    authority,
+                    class_delegate,
+                    Permission::CreateRecord,
+                )
+            }?;
+        }
+
+        if data[IS_FROZEN_OFFSET] == 1 {
+            return Err(RecordServiceError::ClassFrozen.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check that `group` is the group mint of the class, with the bump stored
+    /// when the group was created, and return the bump
+    pub fn check_group(class: &AccountInfo, group: &AccountInfo) -> Result<[u8; 1], ProgramError> {
+        Self::check_program_id(class)?;
+
+        let data = class.try_borrow_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        let bump = [data[GROUP_BUMP_OFFSET]];
+
+        let address = create_program_address(&[b"group", class.key(), &bump], &crate::ID)
+            .map_err(|_| RecordServiceError::InvalidGroup)?;
+        if address.ne(group.key()) {
+            return Err(RecordServiceError::InvalidGroup.into());
         }
 
+        Ok(bump)
+    }
+
+    /// Get the bump of the schema PDA of the class, 0 if the class has no schema
+    pub fn get_schema_bump(class: &AccountInfo) -> Result<u8, ProgramError> {
+        Self::check_program_id(class)?;
+
+        let data = class.try_borrow_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        Ok(data[SCHEMA_BUMP_OFFSET])
+    }
+
+    /// Check that the class is not frozen, records can't be added to frozen classes
+    pub fn check_not_frozen(class: &AccountInfo) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let data = class.try_borrow_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
         if data[IS_FROZEN_OFFSET] == 1 {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::ClassFrozen.into());
+        }
+
+        Ok(())
+    }
+
+    /// Count a new record of the class, failing if the class is capped and
+    /// already has its maximum number of records
+    pub fn add_record(class: &AccountInfo) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let mut data = class.try_borrow_mut_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        let record_count = Self::read_count(&data, RECORD_COUNT_OFFSET)?;
+        let max_records = Self::read_count(&data, MAX_RECORDS_OFFSET)?;
+
+        if max_records != 0 && record_count >= max_records {
+            return Err(RecordServiceError::MaxRecordsReached.into());
+        }
+
+        let record_count = record_count
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        ByteWriter::write_with_offset(&mut data, RECORD_COUNT_OFFSET, record_count)
+    }
+
+    /// Count a record migrated from the legacy layout, and uncount it from the
+    /// legacy records left, it already exists so the maximum number of records
+    /// of the class doesn't apply
+    pub fn add_migrated_record(class: &AccountInfo, is_tokenized: bool) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let mut data = class.try_borrow_mut_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        let record_count = Self::read_count(&data, RECORD_COUNT_OFFSET)?
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        ByteWriter::write_with_offset(&mut data, RECORD_COUNT_OFFSET, record_count)?;
+
+        let legacy_record_count =
+            Self::read_count(&data, LEGACY_RECORD_COUNT_OFFSET)?.saturating_sub(1);
+        ByteWriter::write_with_offset(&mut data, LEGACY_RECORD_COUNT_OFFSET, legacy_record_count)?;
+
+        if is_tokenized {
+            let tokenized_count = Self::read_count(&data, TOKENIZED_COUNT_OFFSET)?
+                .checked_add(1)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            ByteWriter::write_with_offset(&mut data, TOKENIZED_COUNT_OFFSET, tokenized_count)?;
         }
 
         Ok(())
     }
 
+    /// Uncount a deleted record of the class
+    pub fn remove_record(class: &AccountInfo, is_tokenized: bool) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let mut data = class.try_borrow_mut_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        let record_count = Self::read_count(&data, RECORD_COUNT_OFFSET)?.saturating_sub(1);
+        ByteWriter::write_with_offset(&mut data, RECORD_COUNT_OFFSET, record_count)?;
+
+        if is_tokenized {
+            let tokenized_count =
+                Self::read_count(&data, TOKENIZED_COUNT_OFFSET)?.saturating_sub(1);
+            ByteWriter::write_with_offset(&mut data, TOKENIZED_COUNT_OFFSET, tokenized_count)?;
+        }
+
+        Ok(())
+    }
+
+    /// Count a record of the class being tokenized, or uncount it when its token is burned
+    pub fn update_tokenized_count(
+        class: &AccountInfo,
+        is_tokenized: bool,
+    ) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let mut data = class.try_borrow_mut_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        let tokenized_count = Self::read_count(&data, TOKENIZED_COUNT_OFFSET)?;
+        let tokenized_count = if is_tokenized {
+            tokenized_count
+                .checked_add(1)
+                .ok_or(ProgramError::ArithmeticOverflow)?
+        } else {
+            tokenized_count.saturating_sub(1)
+        };
+
+        ByteWriter::write_with_offset(&mut data, TOKENIZED_COUNT_OFFSET, tokenized_count)
+    }
+
+    /// Check that the class has no live records left, nor legacy records
+    /// left to migrate
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_empty_unchecked(data: &[u8]) -> Result<(), ProgramError> {
+        if Self::read_count(data, RECORD_COUNT_OFFSET)? != 0
+            || Self::read_count(data, LEGACY_RECORD_COUNT_OFFSET)? != 0
+        {
+            return Err(RecordServiceError::ClassNotEmpty.into());
+        }
+
+        Ok(())
+    }
+
+    /// Transfer the creation fee of the class, if any, from the payer to the class treasury
+    pub fn pay_creation_fee(
+        class: &AccountInfo,
+        payer: &AccountInfo,
+        treasury: Option<&AccountInfo>,
+    ) -> ProgramResult {
+        Self::check_program_id(class)?;
+
+        let (fee, treasury) = {
+            let data = class.try_borrow_data()?;
+
+            unsafe { Self::check_discriminator_unchecked(&data)? }
+
+            let fee = Self::read_count(&data, CREATION_FEE_OFFSET)?;
+            if fee == 0 {
+                return Ok(());
+            }
+
+            // Check if the treasury is the treasury of the class
+            let treasury = treasury.ok_or(RecordServiceError::InvalidTreasury)?;
+            if treasury.key().ne(&data[TREASURY_OFFSET..TREASURY_OFFSET + size_of::<Pubkey>()]) {
+                return Err(RecordServiceError::InvalidTreasury.into());
+            }
+
+            (fee, treasury)
+        };
+
+        Transfer {
+            from: payer,
+            to: treasury,
+            lamports: fee,
+        }
+        .invoke()
+    }
+
+    #[inline(always)]
+    fn read_count(data: &[u8], offset: usize) -> Result<u64, ProgramError> {
+        Ok(u64::from_le_bytes(
+            data[offset..offset + size_of::<u64>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        ))
+    }
+
     /// # Safety
     ///
     /// This function does not perform owner checks
@@ -127,6 +591,140 @@ impl<'info> Class<'info> {
         Ok(())
     }
 
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_policy_unchecked(
+        class: &'info AccountInfo,
+        policy: ClassPolicy,
+    ) -> Result<(), ProgramError> {
+        let mut data = class.try_borrow_mut_data()?;
+
+        ByteWriter::write_with_offset(&mut data, POLICY_OFFSET, policy)
+    }
+
+    /// Check that the class authority signed the `message` attesting a record
+    /// in an Ed25519 instruction, in place of signing the transaction
+    pub fn check_attested_permission(
+        class: &AccountInfo,
+        instructions_sysvar: &AccountInfo,
+        message: &[&[u8]],
+    ) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        let data = class.try_borrow_data()?;
+
+        unsafe { Self::check_discriminator_unchecked(&data)? }
+
+        let authority: &Pubkey = data[AUTHORITY_OFFSET..AUTHORITY_OFFSET + size_of::<Pubkey>()]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        check_signature(instructions_sysvar, authority, message)?;
+
+        if data[IS_FROZEN_OFFSET] == 1 {
+            return Err(RecordServiceError::ClassFrozen.into());
+        }
+
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_allowlist_unchecked(
+        data: &[u8],
+        owner: &AccountInfo,
+        proof: &[u8],
+    ) -> Result<(), ProgramError> {
+        if !owner.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let root = &data[MERKLE_ROOT_OFFSET..MERKLE_ROOT_OFFSET + size_of::<[u8; 32]>()];
+
+        // A zeroed root is an empty allowlist
+        if root == [0; 32] || !verify_merkle_proof(root, owner.key(), proof) {
+            return Err(RecordServiceError::NotInAllowlist.into());
+        }
+
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_merkle_root_unchecked(
+        class: &'info AccountInfo,
+        merkle_root: &[u8; 32],
+    ) -> Result<(), ProgramError> {
+        let mut data = class.try_borrow_mut_data()?;
+
+        data[MERKLE_ROOT_OFFSET..MERKLE_ROOT_OFFSET + size_of::<[u8; 32]>()]
+            .clone_from_slice(merkle_root);
+
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_fee_unchecked(
+        class: &'info AccountInfo,
+        creation_fee: u64,
+        treasury: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        let mut data = class.try_borrow_mut_data()?;
+
+        ByteWriter::write_with_offset(&mut data, CREATION_FEE_OFFSET, creation_fee)?;
+        data[TREASURY_OFFSET..TREASURY_OFFSET + size_of::<Pubkey>()].clone_from_slice(treasury);
+
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_group_bump_unchecked(
+        class: &'info AccountInfo,
+        group_bump: u8,
+    ) -> Result<(), ProgramError> {
+        let mut data = class.try_borrow_mut_data()?;
+
+        data[GROUP_BUMP_OFFSET] = group_bump;
+
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_schema_bump_unchecked(
+        class: &'info AccountInfo,
+        schema_bump: u8,
+    ) -> Result<(), ProgramError> {
+        let mut data = class.try_borrow_mut_data()?;
+
+        data[SCHEMA_BUMP_OFFSET] = schema_bump;
+
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn close_class_unchecked(
+        class: &'info AccountInfo,
+        destination: &'info AccountInfo,
+    ) -> Result<(), ProgramError> {
+        resize_account(class, destination, 1, true)?;
+        {
+            let mut data_ref = class.try_borrow_mut_data()?;
+            data_ref[DISCRIMINATOR_OFFSET] = CLOSED_ACCOUNT_DISCRIMINATOR;
+        }
+        Ok(())
+    }
+
     /// # Safety
     ///
     /// This function does not perform owner checks
@@ -175,6 +773,67 @@ impl<'info> Class<'info> {
         Ok(())
     }
 
+    /// Check if the class has the legacy layout
+    #[inline(always)]
+    pub fn check_legacy(class: &AccountInfo) -> Result<(), ProgramError> {
+        Self::check_program_id(class)?;
+
+        if class.try_borrow_data()?[DISCRIMINATOR_OFFSET].ne(&Self::LEGACY_DISCRIMINATOR) {
+            return Err(RecordServiceError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(())
+    }
+
+    /// Migrate a legacy class to the current layout in place
+    ///
+    /// The name and the metadata are moved after the new fields, which get the
+    /// values of a class created with CreateClass: the default policy, no
+    /// records counted, no cap, no fee, no allowlist and no schema. Records
+    /// are counted as they are migrated, and uncounted from the legacy records
+    /// left.
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn migrate_legacy_unchecked(
+        class: &'info AccountInfo,
+        payer: &'info AccountInfo,
+        group_bump: u8,
+        legacy_record_count: u64,
+    ) -> Result<(), ProgramError> {
+        let legacy_len = class.data_len();
+        let len = legacy_len + NAME_LEN_OFFSET - LEGACY_NAME_LEN_OFFSET;
+
+        resize_account(class, payer, len, false)?;
+
+        let mut data = class.try_borrow_mut_data()?;
+
+        // Move the name and the metadata after the new fields
+        data.copy_within(LEGACY_NAME_LEN_OFFSET..legacy_len, NAME_LEN_OFFSET);
+
+        let is_permissioned = data[IS_PERMISSIONED_OFFSET] == 1;
+
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+        ByteWriter::write_with_offset(&mut data, IS_NON_TRANSFERABLE_OFFSET, false)?;
+        ByteWriter::write_with_offset(
+            &mut data,
+            POLICY_OFFSET,
+            ClassPolicy::new_default(is_permissioned),
+        )?;
+        ByteWriter::write_with_offset(&mut data, RECORD_COUNT_OFFSET, 0u64)?;
+        ByteWriter::write_with_offset(&mut data, TOKENIZED_COUNT_OFFSET, 0u64)?;
+        ByteWriter::write_with_offset(&mut data, LEGACY_RECORD_COUNT_OFFSET, legacy_record_count)?;
+        ByteWriter::write_with_offset(&mut data, MAX_RECORDS_OFFSET, 0u64)?;
+        ByteWriter::write_with_offset(&mut data, CREATION_FEE_OFFSET, 0u64)?;
+        ByteWriter::write_with_offset(&mut data, TREASURY_OFFSET, [0u8; 32])?;
+        ByteWriter::write_with_offset(&mut data, MERKLE_ROOT_OFFSET, [0u8; 32])?;
+        ByteWriter::write_with_offset(&mut data, GROUP_BUMP_OFFSET, group_bump)?;
+        ByteWriter::write_with_offset(&mut data, SCHEMA_BUMP_OFFSET, 0u8)?;
+
+        Ok(())
+    }
+
     /// # Safety
     ///
     /// This function does not perform owner checks
@@ -185,7 +844,7 @@ impl<'info> Class<'info> {
         let required_space = Self::MINIMUM_CLASS_SIZE + self.name.len() + self.metadata.len();
 
         if required_space > account_info.data_len() {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::AccountTooSmall.into());
         }
 
         let mut data = account_info.try_borrow_mut_data()?;
@@ -198,6 +857,17 @@ impl<'info> Class<'info> {
         ByteWriter::write_with_offset(&mut data, AUTHORITY_OFFSET, self.authority)?;
         ByteWriter::write_with_offset(&mut data, IS_PERMISSIONED_OFFSET, self.is_permissioned)?;
         ByteWriter::write_with_offset(&mut data, IS_FROZEN_OFFSET, self.is_frozen)?;
+        ByteWriter::write_with_offset(&mut data, IS_NON_TRANSFERABLE_OFFSET, self.is_non_transferable)?;
+        ByteWriter::write_with_offset(&mut data, POLICY_OFFSET, self.policy)?;
+        ByteWriter::write_with_offset(&mut data, RECORD_COUNT_OFFSET, self.record_count)?;
+        ByteWriter::write_with_offset(&mut data, TOKENIZED_COUNT_OFFSET, self.tokenized_count)?;
+        ByteWriter::write_with_offset(&mut data, LEGACY_RECORD_COUNT_OFFSET, self.legacy_record_count)?;
+        ByteWriter::write_with_offset(&mut data, MAX_RECORDS_OFFSET, self.max_records)?;
+        ByteWriter::write_with_offset(&mut data, CREATION_FEE_OFFSET, self.creation_fee)?;
+        ByteWriter::write_with_offset(&mut data, TREASURY_OFFSET, self.treasury)?;
+        ByteWriter::write_with_offset(&mut data, MERKLE_ROOT_OFFSET, self.merkle_root)?;
+        ByteWriter::write_with_offset(&mut data, GROUP_BUMP_OFFSET, self.group_bump)?;
+        ByteWriter::write_with_offset(&mut data, SCHEMA_BUMP_OFFSET, self.schema_bump)?;
 
         let mut variable_data = ByteWriter::new_with_offset(&mut data, NAME_LEN_OFFSET);
         variable_data.write_str_with_length(self.name)?;
@@ -0,0 +1,163 @@
+use crate::{error::RecordServiceError, utils::ByteWriter};
+use core::mem::size_of;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+/// Offsets
+const DISCRIMINATOR_OFFSET: usize = 0;
+const CLASS_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const DELEGATE_OFFSET: usize = CLASS_OFFSET + size_of::<Pubkey>();
+const PERMISSIONS_OFFSET: usize = DELEGATE_OFFSET + size_of::<Pubkey>();
+
+/// Actions a class delegate can be allowed to perform, stored as a bitmask
+#[repr(u8)]
+#[derive(Copy, Clone)]
+pub enum Permission {
+    /// Create records in a permissioned class
+    CreateRecord = 1 << 0,
+    /// Update the data of a record
+    UpdateRecordData = 1 << 1,
+    /// Update the expiry of a record
+    UpdateRecordExpiry = 1 << 2,
+    /// Freeze and thaw records
+    FreezeRecord = 1 << 3,
+    /// Transfer records of a permissioned class
+    TransferRecord = 1 << 4,
+    /// Delete records of a permissioned class
+    DeleteRecord = 1 << 5,
+    /// Tokenize records of a permissioned class
+    MintTokenizedRecord = 1 << 6,
+    /// Revoke records
+    RevokeRecord = 1 << 7,
+}
+
+#[repr(C)]
+pub struct ClassDelegate {
+    /// The class the delegate acts on
+    pub class: Pubkey,
+    /// The delegated key, it signs in place of the class authority
+    pub delegate: Pubkey,
+    /// Bitmask of the granted permissions
+    pub permissions: u8,
+}
+
+impl ClassDelegate {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 4;
+
+    /// Size of a class delegate account
+    pub const SIZE: usize = size_of::<u8>() + size_of::<Pubkey>() * 2 + size_of::<u8>();
+
+    /// Check if the program id and discriminator are valid
+    #[inline(always)]
+    pub fn check_program_id_and_discriminator(
+        account_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        // Check Program ID
+        if unsafe { account_info.owner().ne(&crate::ID) } {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // Check discriminator
+        let data = account_info.try_borrow_data()?;
+        if data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
+            return Err(RecordServiceError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check if the account is a delegate account of the class
+    #[inline(always)]
+    pub fn check_class(
+        account_info: &AccountInfo,
+        class: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        Self::check_program_id_and_discriminator(account_info)?;
+
+        let data = account_info.try_borrow_data()?;
+        if class
+            .key()
+            .ne(&data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::ClassMismatch.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check if the authority is a delegate of the class with the given permission
+    #[inline(always)]
+    pub fn check_permission(
+        account_info: &AccountInfo,
+        class: &AccountInfo,
+        authority: &AccountInfo,
+        permission: Permission,
+    ) -> Result<(), ProgramError> {
+        Self::check_class(account_info, class)?;
+
+        if !authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let data = account_info.try_borrow_data()?;
+        if authority
+            .key()
+            .ne(&data[DELEGATE_OFFSET..DELEGATE_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::InvalidClassDelegate.into());
+        }
+
+        if data[PERMISSIONS_OFFSET] & permission as u8 == 0 {
+            return Err(RecordServiceError::MissingPermission.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_delegate_unchecked(data: &[u8]) -> Result<Pubkey, ProgramError> {
+        data[DELEGATE_OFFSET..DELEGATE_OFFSET + size_of::<Pubkey>()]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_permissions_unchecked(
+        account_info: &AccountInfo,
+        permissions: u8,
+    ) -> Result<(), ProgramError> {
+        let mut data = account_info.try_borrow_mut_data()?;
+
+        data[PERMISSIONS_OFFSET] = permissions;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn initialize_unchecked(&self, account_info: &AccountInfo) -> Result<(), ProgramError> {
+        if account_info.data_len() < Self::SIZE {
+            return Err(RecordServiceError::AccountTooSmall.into());
+        }
+
+        let mut data = account_info.try_borrow_mut_data()?;
+        if data[DISCRIMINATOR_OFFSET] != 0x00 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+        ByteWriter::write_with_offset(&mut data, CLASS_OFFSET, self.class)?;
+        ByteWriter::write_with_offset(&mut data, DELEGATE_OFFSET, self.delegate)?;
+        ByteWriter::write_with_offset(&mut data, PERMISSIONS_OFFSET, self.permissions)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,286 @@
+use crate::{
+    error::RecordServiceError,
+    utils::{resize_account, ByteReader, ByteWriter},
+};
+use core::{mem::size_of, str};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{create_program_address, Pubkey},
+};
+
+use super::{Class, ContentType};
+
+/// Offsets
+const DISCRIMINATOR_OFFSET: usize = 0;
+const CLASS_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const FIELDS_OFFSET: usize = CLASS_OFFSET + size_of::<Pubkey>();
+
+/// Type of a schema field and its borsh encoding in the record data
+#[repr(u8)]
+#[derive(Copy, Clone)]
+pub enum FieldType {
+    /// 8 bytes, little endian
+    U64,
+    /// 8 bytes, little endian
+    I64,
+    /// 1 byte, 0 or 1
+    Bool,
+    /// 32 bytes
+    Pubkey,
+    /// u32 length prefix followed by at most `max_len` utf-8 bytes
+    String,
+    /// u32 length prefix followed by at most `max_len` bytes
+    Bytes,
+}
+
+impl TryFrom<u8> for FieldType {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(FieldType::U64),
+            1 => Ok(FieldType::I64),
+            2 => Ok(FieldType::Bool),
+            3 => Ok(FieldType::Pubkey),
+            4 => Ok(FieldType::String),
+            5 => Ok(FieldType::Bytes),
+            _ => Err(RecordServiceError::InvalidSchema.into()),
+        }
+    }
+}
+
+/// Schema that the data of every record of a class must follow.
+///
+/// The fields are stored as:
+/// -  field_count (u8)
+/// -  for each field: field_type (u8) | is_required (bool) | max_len (u16) | name_len (u8) | name
+///
+/// The record data is the borsh encoding of the fields in order, fields that
+/// are not required are encoded as a borsh `Option`.
+#[repr(C)]
+pub struct ClassSchema<'info> {
+    /// The class the schema applies to
+    pub class: Pubkey,
+    /// The encoded field definitions
+    pub fields: &'info [u8],
+}
+
+impl<'info> ClassSchema<'info> {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 6;
+
+    /// Maximum number of fields in a schema
+    pub const MAX_FIELDS: usize = 32;
+
+    /// Maximum length of a field name
+    pub const MAX_FIELD_NAME_LEN: usize = 32;
+
+    /// Minimum size required for a valid schema account
+    pub const MINIMUM_SCHEMA_SIZE: usize = size_of::<u8>() + size_of::<Pubkey>();
+
+    /// Check if the program id and discriminator are valid
+    #[inline(always)]
+    pub fn check_program_id_and_discriminator(
+        account_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        // Check Program ID
+        if unsafe { account_info.owner().ne(&crate::ID) } {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // Check discriminator
+        let data = account_info.try_borrow_data()?;
+        if data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
+            return Err(RecordServiceError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check if the account is the schema PDA of the class, with the bump
+    /// stored in the class when the schema was created
+    #[inline(always)]
+    pub fn check_address(
+        schema: &AccountInfo,
+        class: &AccountInfo,
+        bump: u8,
+    ) -> Result<(), ProgramError> {
+        let address = create_program_address(&[b"schema", class.key(), &[bump]], &crate::ID)
+            .map_err(|_| RecordServiceError::InvalidSchema)?;
+
+        if schema.key().ne(&address) {
+            return Err(RecordServiceError::InvalidSchema.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check the record data against the schema of the class.
+    ///
+    /// Classes without a schema accept any utf-8 data, or any data if the
+    /// record content type is binary, and don't need the schema account.
+    pub fn check_data(
+        schema: Option<&AccountInfo>,
+        class: &AccountInfo,
+        content_type: ContentType,
+        data: &[u8],
+    ) -> Result<(), ProgramError> {
+        let bump = Class::get_schema_bump(class)?;
+
+        if bump == 0 {
+            if content_type == ContentType::Utf8 {
+                str::from_utf8(data).map_err(|_| ProgramError::InvalidInstructionData)?;
+            }
+            return Ok(());
+        }
+
+        // Optional accounts that are not provided are replaced by the program id
+        let schema = schema
+            .filter(|schema| schema.key().ne(&crate::ID))
+            .ok_or(RecordServiceError::InvalidSchema)?;
+
+        Self::check_address(schema, class, bump)?;
+        Self::check_program_id_and_discriminator(schema)?;
+
+        let schema_data = schema.try_borrow_data()?;
+
+        Self::validate_data(&schema_data[FIELDS_OFFSET..], data)
+            .map_err(|_| RecordServiceError::SchemaMismatch.into())
+    }
+
+    /// Check that the encoded field definitions are well formed
+    pub fn validate_fields(fields: &[u8]) -> Result<(), ProgramError> {
+        let mut reader = ByteReader::new(fields);
+
+        let field_count: u8 = reader.read()?;
+        if field_count as usize > Self::MAX_FIELDS {
+            return Err(RecordServiceError::InvalidSchema.into());
+        }
+
+        for _ in 0..field_count {
+            let field_type = FieldType::try_from(reader.read::<u8>()?)?;
+
+            let is_required: u8 = reader.read()?;
+            if is_required > 1 {
+                return Err(RecordServiceError::InvalidSchema.into());
+            }
+
+            let max_len: u16 = reader.read()?;
+            if matches!(field_type, FieldType::String | FieldType::Bytes) && max_len == 0 {
+                return Err(RecordServiceError::InvalidSchema.into());
+            }
+
+            let name = reader.read_str_with_length()?;
+            if name.is_empty() || name.len() > Self::MAX_FIELD_NAME_LEN {
+                return Err(RecordServiceError::InvalidSchema.into());
+            }
+        }
+
+        if reader.remaining_bytes() != 0 {
+            return Err(RecordServiceError::InvalidSchema.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check that the data is the borsh encoding of the fields
+    fn validate_data(fields: &[u8], data: &[u8]) -> Result<(), ProgramError> {
+        let mut fields = ByteReader::new(fields);
+        let mut data = ByteReader::new(data);
+
+        let field_count: u8 = fields.read()?;
+
+        for _ in 0..field_count {
+            let field_type = FieldType::try_from(fields.read::<u8>()?)?;
+            let is_required: u8 = fields.read()?;
+            let max_len: u16 = fields.read()?;
+            fields.read_bytes_with_length()?;
+
+            // Optional fields are prefixed by the borsh option tag
+            if is_required == 0 {
+                match data.read::<u8>()? {
+                    0 => continue,
+                    1 => {}
+                    _ => return Err(RecordServiceError::SchemaMismatch.into()),
+                }
+            }
+
+            match field_type {
+                FieldType::U64 | FieldType::I64 => {
+                    data.read_bytes(size_of::<u64>())?;
+                }
+                FieldType::Bool => {
+                    if data.read::<u8>()? > 1 {
+                        return Err(RecordServiceError::SchemaMismatch.into());
+                    }
+                }
+                FieldType::Pubkey => {
+                    data.read_bytes(size_of::<Pubkey>())?;
+                }
+                FieldType::String | FieldType::Bytes => {
+                    let len = data.read::<u32>()? as usize;
+
+                    if len > max_len as usize {
+                        return Err(RecordServiceError::SchemaMismatch.into());
+                    }
+
+                    let value = data.read_bytes(len)?;
+
+                    if matches!(field_type, FieldType::String) {
+                        str::from_utf8(value).map_err(|_| RecordServiceError::SchemaMismatch)?;
+                    }
+                }
+            }
+        }
+
+        if data.remaining_bytes() != 0 {
+            return Err(RecordServiceError::SchemaMismatch.into());
+        }
+
+        Ok(())
+    }
+
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_fields_unchecked(
+        schema: &AccountInfo,
+        payer: &AccountInfo,
+        fields: &[u8],
+    ) -> Result<(), ProgramError> {
+        let new_len = FIELDS_OFFSET + fields.len();
+
+        if new_len != schema.data_len() {
+            resize_account(schema, payer, new_len, new_len < schema.data_len())?;
+        }
+
+        let mut data = schema.try_borrow_mut_data()?;
+        data[FIELDS_OFFSET..].clone_from_slice(fields);
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn initialize_unchecked(&self, account_info: &AccountInfo) -> Result<(), ProgramError> {
+        if account_info.data_len() < Self::MINIMUM_SCHEMA_SIZE + self.fields.len() {
+            return Err(RecordServiceError::AccountTooSmall.into());
+        }
+
+        let mut data = account_info.try_borrow_mut_data()?;
+        if data[DISCRIMINATOR_OFFSET] != 0x00 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+        ByteWriter::write_with_offset(&mut data, CLASS_OFFSET, self.class)?;
+
+        let mut variable_data = ByteWriter::new_with_offset(&mut data, FIELDS_OFFSET);
+        variable_data.write_bytes(self.fields)?;
+
+        Ok(())
+    }
+}
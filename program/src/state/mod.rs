@@ -3,3 +3,15 @@ pub use class::*;
 
 pub mod record;
 pub use record::*;
+
+pub mod pending_class_authority;
+pub use pending_class_authority::*;
+
+pub mod class_delegate;
+pub use class_delegate::*;
+
+pub mod record_delegate;
+pub use record_delegate::*;
+
+pub mod class_schema;
+pub use class_schema::*;
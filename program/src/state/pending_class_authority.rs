@@ -0,0 +1,145 @@
+use crate::{error::RecordServiceError, utils::ByteWriter};
+use core::mem::size_of;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+/// Offsets
+const DISCRIMINATOR_OFFSET: usize = 0;
+const CLASS_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const AUTHORITY_OFFSET: usize = CLASS_OFFSET + size_of::<Pubkey>();
+const PENDING_AUTHORITY_OFFSET: usize = AUTHORITY_OFFSET + size_of::<Pubkey>();
+
+#[repr(C)]
+pub struct PendingClassAuthority {
+    /// The class whose authority is being transferred
+    pub class: Pubkey,
+    /// The class authority that proposed the transfer
+    pub authority: Pubkey,
+    /// The proposed authority, it must sign to accept the transfer
+    pub pending_authority: Pubkey,
+}
+
+impl PendingClassAuthority {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 3;
+
+    /// Size of a pending class authority account
+    pub const SIZE: usize = size_of::<u8>() + size_of::<Pubkey>() * 3;
+
+    /// Check if the program id and discriminator are valid
+    #[inline(always)]
+    pub fn check_program_id_and_discriminator(
+        account_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        // Check Program ID
+        if unsafe { account_info.owner().ne(&crate::ID) } {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // Check discriminator
+        let data = account_info.try_borrow_data()?;
+        if data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
+            return Err(RecordServiceError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check if the account is the pending authority account of the class
+    #[inline(always)]
+    pub fn check_class(
+        account_info: &AccountInfo,
+        class: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        Self::check_program_id_and_discriminator(account_info)?;
+
+        let data = account_info.try_borrow_data()?;
+        if class
+            .key()
+            .ne(&data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::ClassMismatch.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_proposing_authority_unchecked(
+        data: &[u8],
+        authority: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if authority
+            .key()
+            .ne(&data[AUTHORITY_OFFSET..AUTHORITY_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::InvalidAuthority.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_pending_authority_unchecked(
+        data: &[u8],
+        authority: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if !authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if authority
+            .key()
+            .ne(&data[PENDING_AUTHORITY_OFFSET..PENDING_AUTHORITY_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::InvalidPendingAuthority.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_pending_authority_unchecked(
+        account_info: &AccountInfo,
+        authority: &Pubkey,
+        pending_authority: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        let mut data = account_info.try_borrow_mut_data()?;
+
+        data[AUTHORITY_OFFSET..AUTHORITY_OFFSET + size_of::<Pubkey>()].clone_from_slice(authority);
+        data[PENDING_AUTHORITY_OFFSET..PENDING_AUTHORITY_OFFSET + size_of::<Pubkey>()]
+            .clone_from_slice(pending_authority);
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn initialize_unchecked(&self, account_info: &AccountInfo) -> Result<(), ProgramError> {
+        if account_info.data_len() < Self::SIZE {
+            return Err(RecordServiceError::AccountTooSmall.into());
+        }
+
+        let mut data = account_info.try_borrow_mut_data()?;
+        if data[DISCRIMINATOR_OFFSET] != 0x00 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+        ByteWriter::write_with_offset(&mut data, CLASS_OFFSET, self.class)?;
+        ByteWriter::write_with_offset(&mut data, AUTHORITY_OFFSET, self.authority)?;
+        ByteWriter::write_with_offset(&mut data, PENDING_AUTHORITY_OFFSET, self.pending_authority)?;
+
+        Ok(())
+    }
+}
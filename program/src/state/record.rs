@@ -1,12 +1,12 @@
 use crate::{
-    constants::CLOSED_ACCOUNT_DISCRIMINATOR, token2022::{CloseAccount, Mint, Token}, utils::{resize_account, ByteWriter}
+    constants::CLOSED_ACCOUNT_DISCRIMINATOR, error::RecordServiceError, token2022::{CloseAccount, Mint, Token}, utils::{hashv, resize_account, ByteWriter}
 };
-use core::{mem::size_of, str};
+use core::mem::size_of;
 use pinocchio::{
-    account_info::{AccountInfo, Ref, RefMut}, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::{try_find_program_address, Pubkey}
+    account_info::{AccountInfo, Ref, RefMut}, instruction::{Seed, Signer}, program_error::ProgramError, pubkey::Pubkey, sysvars::{clock::Clock, Sysvar}
 };
 
-use super::{Class, IS_PERMISSIONED_OFFSET};
+use super::{Class, Permission, RecordDelegate};
 
 /// Offsets
 const DISCRIMINATOR_OFFSET: usize = 0;
@@ -15,8 +15,24 @@ const OWNER_TYPE_OFFSET: usize = CLASS_OFFSET + size_of::<Pubkey>();
 pub const OWNER_OFFSET: usize = OWNER_TYPE_OFFSET + size_of::<u8>();
 pub const IS_FROZEN_OFFSET: usize = OWNER_OFFSET + size_of::<Pubkey>();
 const EXPIRY_OFFSET: usize = IS_FROZEN_OFFSET + size_of::<bool>();
-const SEED_LEN_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+const IS_REVOKED_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+const REVOCATION_REASON_OFFSET: usize = IS_REVOKED_OFFSET + size_of::<bool>();
+const REVOKED_AT_OFFSET: usize = REVOCATION_REASON_OFFSET + size_of::<u16>();
+const VERSION_OFFSET: usize = REVOKED_AT_OFFSET + size_of::<i64>();
+const GENERATION_OFFSET: usize = VERSION_OFFSET + size_of::<u64>();
+const HASH_OFFSET: usize = GENERATION_OFFSET + size_of::<u32>();
+const WRITE_STATE_OFFSET: usize = HASH_OFFSET + size_of::<[u8; 32]>();
+const STAGED_LEN_OFFSET: usize = WRITE_STATE_OFFSET + size_of::<u8>();
+const STAGED_PAYER_OFFSET: usize = STAGED_LEN_OFFSET + size_of::<u32>();
+const CONTENT_TYPE_OFFSET: usize = STAGED_PAYER_OFFSET + size_of::<Pubkey>();
+const MINT_BUMP_OFFSET: usize = CONTENT_TYPE_OFFSET + size_of::<u8>();
+const SEED_LEN_OFFSET: usize = MINT_BUMP_OFFSET + size_of::<u8>();
 pub const SEED_OFFSET: usize = SEED_LEN_OFFSET + size_of::<u8>();
+/// Offset of the seed length in the layout of legacy records, which ends with
+/// the expiry before the seed and the data
+const LEGACY_SEED_LEN_OFFSET: usize = EXPIRY_OFFSET + size_of::<i64>();
+/// Offset of the generation in the tombstone of a deleted record
+const GENERATION_TOMBSTONE_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
 
 #[repr(C)]
 pub struct Record<'info> {
@@ -30,10 +46,121 @@ pub struct Record<'info> {
     pub is_frozen: bool,
     /// Optional expiration timestamp, if not set, the expiry is [0; 8]
     pub expiry: i64,
+    /// Whether the record has been revoked by the class
+    pub is_revoked: bool,
+    /// Reason code of the revocation, set by the class
+    pub revocation_reason: u16,
+    /// Timestamp of the revocation, if not revoked, it is [0; 8]
+    pub revoked_at: i64,
+    /// Number of updates of the data and the expiry since the record was created
+    pub version: u64,
+    /// Number of records deleted at the address of this record before it was created
+    pub generation: u32,
+    /// Rolling hash of the record history, see [`RecordUpdate`], it is
+    /// [0; 32] until a record created with CreateBufferedRecord is finalized
+    pub hash: [u8; 32],
+    /// Whether the record data is being written in chunks
+    pub write_state: WriteState,
+    /// Length of the new data staged after the record data while it is being
+    /// replaced, if no data is staged, it is 0
+    pub staged_len: u32,
+    /// Payer of a record created with CreateBufferedRecord, which may write it
+    /// and gets the rent back if it is cancelled, [0; 32] once it is finalized
+    pub staged_payer: Pubkey,
+    /// Encoding of the record data, see [`ContentType`]
+    pub content_type: ContentType,
+    /// Bump of the record mint PDA, set when the record is tokenized
+    pub mint_bump: u8,
     /// The record name/key
     pub seed: &'info [u8],
-    /// The record's data content
-    pub data: &'info str,
+    /// The record's data content, encoded with the class schema or the content type
+    pub data: &'info [u8],
+}
+
+/// Kind of change recorded in the hash chain of a record.
+///
+/// Every change sets the record hash to
+/// `sha256(previous_hash | kind (u8) | payload)`, where the payload is the new
+/// data or the little endian new expiry. The chain starts from `[0; 32]` with
+/// the data of the record at creation.
+#[repr(u8)]
+#[derive(Copy, Clone)]
+pub enum RecordUpdate {
+    /// The data of the record changed
+    Data,
+    /// The expiry of the record changed
+    Expiry,
+}
+
+impl RecordUpdate {
+    /// Hash of the history after applying this change to `previous_hash`
+    #[inline(always)]
+    pub fn chain(self, previous_hash: &[u8], payload: &[u8]) -> [u8; 32] {
+        hashv(&[previous_hash, &[self as u8], payload])
+    }
+}
+
+/// State of a chunked write of the record data.
+///
+/// While a write is in progress, the data can only be changed with
+/// WriteRecordChunk until FinalizeRecordWrite checks it, or CancelRecordWrite
+/// drops it.
+#[repr(u8)]
+#[derive(Copy, Clone, PartialEq)]
+pub enum WriteState {
+    /// No write in progress
+    Idle,
+    /// The record was created with CreateBufferedRecord, its data is incomplete
+    Creating,
+    /// The new data is staged after the current one, see BeginRecordWrite
+    Updating,
+}
+
+impl TryFrom<u8> for WriteState {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(WriteState::Idle),
+            1 => Ok(WriteState::Creating),
+            2 => Ok(WriteState::Updating),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Encoding of the record data.
+///
+/// Only utf-8 records are checked when the class has no schema, any other
+/// content type is stored as opaque bytes.
+#[repr(u8)]
+#[derive(Copy, Clone, PartialEq)]
+pub enum ContentType {
+    /// utf-8 text
+    Utf8,
+    /// Arbitrary bytes
+    Binary,
+    /// CBOR encoded data
+    Cbor,
+    /// Borsh encoded data
+    Borsh,
+    /// Protobuf encoded data
+    Protobuf,
+}
+
+impl TryFrom<u8> for ContentType {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ContentType::Utf8),
+            1 => Ok(ContentType::Binary),
+            2 => Ok(ContentType::Cbor),
+            3 => Ok(ContentType::Borsh),
+            4 => Ok(ContentType::Protobuf),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
 }
 
 #[repr(C)]
@@ -47,7 +174,10 @@ pub enum OwnerType {
 
 impl<'info> Record<'info> {
     /// The discriminator byte used to identify this account type
-    pub const DISCRIMINATOR: u8 = 2;
+    pub const DISCRIMINATOR: u8 = 8;
+    /// Discriminator of the records created before the revocation, history,
+    /// chunked writes, content type and bumps were added, see MigrateRecordLayout
+    pub const LEGACY_DISCRIMINATOR: u8 = 2;
 
     /// Minimum size required for a valid record account
     pub const MINIMUM_RECORD_SIZE: usize = size_of::<u8>()
@@ -56,6 +186,17 @@ impl<'info> Record<'info> {
         + size_of::<Pubkey>()
         + size_of::<bool>()
         + size_of::<i64>()
+        + size_of::<bool>()
+        + size_of::<u16>()
+        + size_of::<i64>()
+        + size_of::<u64>()
+        + size_of::<u32>()
+        + size_of::<[u8; 32]>()
+        + size_of::<u8>()
+        + size_of::<u32>()
+        + size_of::<Pubkey>()
+        + size_of::<u8>()
+        + size_of::<u8>()
         + size_of::<u8>();
 
     /// Check if the program id and discriminator are valid
@@ -71,7 +212,7 @@ impl<'info> Record<'info> {
         // Check discriminator
         let data = account_info.try_borrow_data()?;
         if data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::InvalidAccountDiscriminator.into());
         }
 
         Ok(())
@@ -80,26 +221,58 @@ impl<'info> Record<'info> {
     #[inline(always)]
     pub fn validate_delegate(
         class: &AccountInfo,
+        class_delegate: Option<&AccountInfo>,
         authority: &AccountInfo,
+        permission: Permission,
     ) -> Result<(), ProgramError> {
         Class::check_program_id(class)?;
 
         let class_data = class.try_borrow_data()?;
 
-        if class_data[IS_PERMISSIONED_OFFSET].ne(&1u8) {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
         unsafe {
             Class::check_discriminator_unchecked(&class_data)?;
-            Class::check_authority_unchecked(&class_data, authority)
+
+            // Check if the class policy lets the authority perform the action
+            if !Class::get_policy_unchecked(&class_data, permission)?.allows_authority() {
+                return Err(RecordServiceError::NotOwnerOrDelegate.into());
+            }
+
+            Class::check_authority_or_delegate_unchecked(
+                class,
+                &class_data,
+                authority,
+                class_delegate,
+                permission,
+            )
+        }
+    }
+
+    /// Check if the authority is the record owner and the class policy lets
+    /// the owner perform the action
+    #[inline(always)]
+    pub fn is_allowed_owner(
+        data: &[u8],
+        class: &AccountInfo,
+        authority: &AccountInfo,
+        permission: Permission,
+    ) -> Result<bool, ProgramError> {
+        // Check if the class is the class of the record
+        if class.key().ne(&data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
+            return Err(RecordServiceError::ClassMismatch.into());
         }
+
+        Ok(authority
+            .key()
+            .eq(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
+            && Class::get_policy(class, permission)?.allows_owner())
     }
 
     #[inline(always)]
     pub fn check_owner_or_delegate_or_deleted(
         record: &AccountInfo,
         class: Option<&AccountInfo>,
+        class_delegate: Option<&AccountInfo>,
+        record_delegate: Option<&AccountInfo>,
         authority: &AccountInfo,
         mint: Option<&AccountInfo>
     ) -> Result<(), ProgramError> {
@@ -110,22 +283,18 @@ impl<'info> Record<'info> {
 
         // Check if the Mint has been burned without passing through the BurnTokenizedRecord instruction
         if data[OWNER_TYPE_OFFSET].eq(&(OwnerType::Token as u8)) {
-            let mint = mint.ok_or(ProgramError::InvalidAccountData)?;
+            let mint = mint.ok_or(RecordServiceError::MissingMint)?;
 
             if mint.key().ne(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()]) {
-                return Err(ProgramError::InvalidAccountData);
+                return Err(RecordServiceError::InvalidMint.into());
             }
 
             if Mint::get_supply(mint)? != 0 {
-                return Err(ProgramError::InvalidAccountData);
+                return Err(RecordServiceError::MintSupplyNotZero.into());
             }
 
             // Close the Mint and get back the rent
-            let bump = [
-            try_find_program_address(&[b"mint", record.key()], &crate::ID)
-                .ok_or(ProgramError::InvalidArgument)?
-                .1,
-            ];
+            let bump = [data[MINT_BUMP_OFFSET]];
 
             let seeds = [
                 Seed::from(b"mint"),
@@ -151,28 +320,36 @@ impl<'info> Record<'info> {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Check if the authority is the owner
-        if authority
-            .key()
-            .eq(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
-        {
+        // Check if the authority is the owner and the class policy allows it
+        let class = class.ok_or(RecordServiceError::MissingClass)?;
+        if Self::is_allowed_owner(&data, class, authority, Permission::DeleteRecord)? {
             return Ok(());
         }
 
-        // Validate the delegate
-        let class = class.ok_or(ProgramError::InvalidAccountData)?;
-        if class.key().ne(&data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+        // Check if the authority is a record delegate of the owner
+        if Self::is_record_delegate(
+            record,
+            &data,
+            class,
+            record_delegate,
+            authority,
+            Permission::DeleteRecord,
+        )? {
+            return Ok(());
         }
 
-        Self::validate_delegate(class, authority)
+        // Validate the delegate
+        Self::validate_delegate(class, class_delegate, authority, Permission::DeleteRecord)
     }
 
     #[inline(always)]
     pub fn check_owner_or_delegate(
         record: &AccountInfo,
         class: Option<&AccountInfo>,
+        class_delegate: Option<&AccountInfo>,
+        record_delegate: Option<&AccountInfo>,
         authority: &AccountInfo,
+        permission: Permission,
     ) -> Result<(), ProgramError> {
         // Check the program id and the discriminator
         Self::check_program_id_and_discriminator(record)?;
@@ -184,35 +361,79 @@ impl<'info> Record<'info> {
 
         let data = record.try_borrow_data()?;
 
-        // Check if the authority is the owner
-        if authority
-            .key()
-            .eq(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
-        {
+        // Check if the authority is the owner and the class policy allows it
+        let class = class.ok_or(RecordServiceError::MissingClass)?;
+        if Self::is_allowed_owner(&data, class, authority, permission)? {
             return Ok(());
         }
 
         // Check if the owner type is pubkey
         if data[OWNER_TYPE_OFFSET].ne(&(OwnerType::Pubkey as u8)) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::InvalidOwnerType.into());
+        }
+
+        // Check if the authority is a record delegate of the owner
+        if Self::is_record_delegate(record, &data, class, record_delegate, authority, permission)? {
+            return Ok(());
         }
 
         // Validate the delegate
-        let class = class.ok_or(ProgramError::MissingRequiredSignature)?;
-        if class.key().ne(&data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+        Self::validate_delegate(class, class_delegate, authority, permission)
+    }
+
+    /// Check if the authority is a record delegate approved by the owner with
+    /// the permission, the record delegate being optional
+    ///
+    /// Returns `false` if no record delegate is provided, and an error if the
+    /// provided record delegate does not let the authority act.
+    #[inline(always)]
+    pub fn is_record_delegate(
+        record: &AccountInfo,
+        data: &[u8],
+        class: &AccountInfo,
+        record_delegate: Option<&AccountInfo>,
+        authority: &AccountInfo,
+        permission: Permission,
+    ) -> Result<bool, ProgramError> {
+        // Optional accounts that are not provided are replaced by the program id
+        let Some(record_delegate) =
+            record_delegate.filter(|record_delegate| record_delegate.key().ne(&crate::ID))
+        else {
+            return Ok(false);
+        };
+
+        // Tokenized records are delegated with their token account
+        if data[OWNER_TYPE_OFFSET].ne(&(OwnerType::Pubkey as u8)) {
+            return Err(RecordServiceError::InvalidOwnerType.into());
+        }
+
+        // Record delegates act on behalf of the owner
+        if !Class::get_policy(class, permission)?.allows_owner() {
+            return Err(RecordServiceError::NotOwnerOrDelegate.into());
         }
 
-        Self::validate_delegate(class, authority)
+        RecordDelegate::check_permission(
+            record_delegate,
+            record,
+            &data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()],
+            // [this is safe, the record has already been validated]
+            unsafe { Self::get_generation_unchecked(data)? },
+            authority,
+            permission,
+        )?;
+
+        Ok(true)
     }
 
     #[inline(always)]
     pub fn check_owner_or_delegate_tokenized(
         record: &AccountInfo,
         class: Option<&AccountInfo>,
+        class_delegate: Option<&AccountInfo>,
         authority: &AccountInfo,
         mint: &AccountInfo,
         token_account: &AccountInfo,
+        permission: Permission,
     ) -> Result<(), ProgramError> {
         // Check the program id and the discriminator
         Self::check_program_id_and_discriminator(record)?;
@@ -239,7 +460,7 @@ impl<'info> Record<'info> {
             .key()
             .ne(&record_data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
         {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::InvalidMint.into());
         }
 
         // Check if the token account is owned by the token program
@@ -252,21 +473,438 @@ impl<'info> Record<'info> {
             Token::check_discriminator_unchecked(&token_data)?;
         }
 
-        // Check if the authority is the owner
+        // Check if the class is the class of the record
+        let class = class.ok_or(RecordServiceError::MissingClass)?;
+        if class.key().ne(&record_data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
+            return Err(RecordServiceError::ClassMismatch.into());
+        }
+
+        // Check if the authority is the token owner and the class policy allows it
         if authority
             .key()
             .eq(unsafe { &Token::get_owner_unchecked(&token_data)? })
+            && Class::get_policy(class, permission)?.allows_owner()
         {
             return Ok(());
         }
 
         // Validate the delegate
-        let class = class.ok_or(ProgramError::InvalidAccountData)?;
-        if class.key().ne(&record_data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
-            return Err(ProgramError::InvalidAccountData);
+        Self::validate_delegate(class, class_delegate, authority, permission)
+    }
+
+    #[inline(always)]
+    pub fn check_owner(record: &AccountInfo, owner: &AccountInfo) -> Result<(), ProgramError> {
+        // Check the program id and the discriminator
+        Self::check_program_id_and_discriminator(record)?;
+
+        // Check if the owner is signer
+        if !owner.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let data = record.try_borrow_data()?;
+
+        // Check if the owner type is pubkey
+        if data[OWNER_TYPE_OFFSET].ne(&(OwnerType::Pubkey as u8)) {
+            return Err(RecordServiceError::InvalidOwnerType.into());
+        }
+
+        // Check if the owner is the record owner
+        if owner
+            .key()
+            .ne(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::InvalidOwner.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn check_expired_and_owner(
+        record: &AccountInfo,
+        owner: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        // Check the program id and the discriminator
+        Self::check_program_id_and_discriminator(record)?;
+
+        let data = record.try_borrow_data()?;
+
+        // Check if the owner type is pubkey, tokenized records must be burned instead
+        if data[OWNER_TYPE_OFFSET].ne(&(OwnerType::Pubkey as u8)) {
+            return Err(RecordServiceError::InvalidOwnerType.into());
+        }
+
+        // Check if the owner is the one receiving the rent
+        if owner
+            .key()
+            .ne(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::InvalidOwner.into());
+        }
+
+        // Check if the record is expired
+        if !unsafe { Self::is_expired_unchecked(&data)? } {
+            return Err(RecordServiceError::RecordNotExpired.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn is_expired_unchecked(data: &[u8]) -> Result<bool, ProgramError> {
+        let expiry = i64::from_le_bytes(
+            data[EXPIRY_OFFSET..EXPIRY_OFFSET + size_of::<i64>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        // An expiry of 0 means the record never expires
+        if expiry == 0 {
+            return Ok(false);
+        }
+
+        Ok(expiry <= Clock::get()?.unix_timestamp)
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_not_expired_unchecked(data: &[u8]) -> Result<(), ProgramError> {
+        if Self::is_expired_unchecked(data)? {
+            return Err(RecordServiceError::RecordExpired.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_not_revoked_unchecked(data: &[u8]) -> Result<(), ProgramError> {
+        if data[IS_REVOKED_OFFSET].eq(&1u8) {
+            return Err(RecordServiceError::RecordRevoked.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn revoke_unchecked(
+        data: &mut RefMut<'info, [u8]>,
+        reason: u16,
+        revoked_at: i64,
+    ) -> Result<(), ProgramError> {
+        data[IS_REVOKED_OFFSET] = 1;
+        data[REVOCATION_REASON_OFFSET..REVOCATION_REASON_OFFSET + size_of::<u16>()]
+            .clone_from_slice(&reason.to_le_bytes());
+        data[REVOKED_AT_OFFSET..REVOKED_AT_OFFSET + size_of::<i64>()]
+            .clone_from_slice(&revoked_at.to_le_bytes());
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_class_unchecked(data: &[u8], class: &AccountInfo) -> Result<(), ProgramError> {
+        if class.key().ne(&data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]) {
+            return Err(RecordServiceError::ClassMismatch.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn is_tokenized_unchecked(data: &[u8]) -> bool {
+        data[OWNER_TYPE_OFFSET].eq(&(OwnerType::Token as u8))
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_not_writing_unchecked(data: &[u8]) -> Result<(), ProgramError> {
+        if data[WRITE_STATE_OFFSET].ne(&(WriteState::Idle as u8)) {
+            return Err(RecordServiceError::RecordWriting.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_write_state_unchecked(data: &[u8]) -> Result<WriteState, ProgramError> {
+        WriteState::try_from(data[WRITE_STATE_OFFSET])
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_content_type_unchecked(data: &[u8]) -> Result<ContentType, ProgramError> {
+        ContentType::try_from(data[CONTENT_TYPE_OFFSET]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_write_state_unchecked(
+        data: &mut RefMut<'info, [u8]>,
+        write_state: WriteState,
+    ) -> Result<(), ProgramError> {
+        data[WRITE_STATE_OFFSET] = write_state as u8;
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// Check if the authority created the record with CreateBufferedRecord,
+    /// as its owner or its payer, and the record is not finalized yet
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn is_creator_unchecked(data: &[u8], authority: &AccountInfo) -> bool {
+        data[WRITE_STATE_OFFSET].eq(&(WriteState::Creating as u8))
+            && (authority
+                .key()
+                .eq(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
+                || authority
+                    .key()
+                    .eq(&data[STAGED_PAYER_OFFSET..STAGED_PAYER_OFFSET + size_of::<Pubkey>()]))
+    }
+
+    #[inline(always)]
+    /// Check if `payer` is the payer of a record created with
+    /// CreateBufferedRecord that is not finalized yet
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_staged_payer_unchecked(
+        data: &[u8],
+        payer: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if payer
+            .key()
+            .ne(&data[STAGED_PAYER_OFFSET..STAGED_PAYER_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::InvalidPayer.into());
         }
 
-        Self::validate_delegate(class, authority)
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_staged_len_unchecked(data: &[u8]) -> usize {
+        u32::from_le_bytes([
+            data[STAGED_LEN_OFFSET],
+            data[STAGED_LEN_OFFSET + 1],
+            data[STAGED_LEN_OFFSET + 2],
+            data[STAGED_LEN_OFFSET + 3],
+        ]) as usize
+    }
+
+    #[inline(always)]
+    /// Write `chunk` at `offset` of the data staged after the record data,
+    /// extending the staged data if needed
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn stage_data_unchecked(
+        record: &'info AccountInfo,
+        payer: &'info AccountInfo,
+        offset: usize,
+        chunk: &[u8],
+    ) -> Result<(), ProgramError> {
+        let staged_len = Self::get_staged_len_unchecked(&record.try_borrow_data()?);
+
+        let current_len = record.data_len();
+        let staged_offset = current_len - staged_len;
+
+        // The chunk must start inside the staged data or right after it
+        if offset > staged_len {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let chunk_end = staged_offset + offset + chunk.len();
+        let new_len = chunk_end.max(current_len);
+
+        if new_len != current_len {
+            resize_account(record, payer, new_len, false)?;
+        }
+
+        {
+            let mut data_ref = record.try_borrow_mut_data()?;
+            if data_ref[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
+                return Err(RecordServiceError::InvalidAccountDiscriminator.into());
+            }
+            data_ref[staged_offset + offset..chunk_end].clone_from_slice(chunk);
+
+            let staged_len =
+                u32::try_from(new_len - staged_offset).map_err(|_| ProgramError::InvalidArgument)?;
+            ByteWriter::write_with_offset(&mut data_ref, STAGED_LEN_OFFSET, staged_len)?;
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// Replace the record data with the staged data
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn commit_staged_data_unchecked(
+        record: &'info AccountInfo,
+        payer: &'info AccountInfo,
+    ) -> Result<(), ProgramError> {
+        let new_len = {
+            let mut data_ref = record.try_borrow_mut_data()?;
+
+            let data_offset = SEED_OFFSET + data_ref[SEED_LEN_OFFSET] as usize;
+            let staged_len = Self::get_staged_len_unchecked(&data_ref);
+            let staged_offset = data_ref.len() - staged_len;
+
+            data_ref.copy_within(staged_offset.., data_offset);
+            ByteWriter::write_with_offset(&mut data_ref, STAGED_LEN_OFFSET, 0u32)?;
+
+            data_offset + staged_len
+        };
+
+        resize_account(record, payer, new_len, true)
+    }
+
+    #[inline(always)]
+    /// Drop the data staged after the record data
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn discard_staged_data_unchecked(
+        record: &'info AccountInfo,
+        payer: &'info AccountInfo,
+    ) -> Result<(), ProgramError> {
+        let new_len = {
+            let mut data_ref = record.try_borrow_mut_data()?;
+
+            let staged_len = Self::get_staged_len_unchecked(&data_ref);
+            ByteWriter::write_with_offset(&mut data_ref, STAGED_LEN_OFFSET, 0u32)?;
+
+            data_ref.len() - staged_len
+        };
+
+        resize_account(record, payer, new_len, true)
+    }
+
+    #[inline(always)]
+    /// Start the hash chain of a record created with CreateBufferedRecord,
+    /// with the data currently stored in the record, and forget its payer
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn initialize_history_unchecked(
+        data: &mut RefMut<'info, [u8]>,
+    ) -> Result<[u8; 32], ProgramError> {
+        let hash = RecordUpdate::Data.chain(&[0; 32], Self::get_data_unchecked(data));
+
+        data[HASH_OFFSET..HASH_OFFSET + size_of::<[u8; 32]>()].clone_from_slice(&hash);
+        data[STAGED_PAYER_OFFSET..STAGED_PAYER_OFFSET + size_of::<Pubkey>()].fill(0);
+
+        Ok(hash)
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_version_unchecked(data: &[u8], expected_version: u64) -> Result<(), ProgramError> {
+        let version = u64::from_le_bytes(
+            data[VERSION_OFFSET..VERSION_OFFSET + size_of::<u64>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        if version.ne(&expected_version) {
+            return Err(RecordServiceError::RecordVersionMismatch.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_history_unchecked(
+        data: &mut RefMut<'info, [u8]>,
+        update: RecordUpdate,
+        payload: &[u8],
+    ) -> Result<(u64, [u8; 32]), ProgramError> {
+        let version = Self::next_version_unchecked(data)?;
+
+        let hash = update.chain(&data[HASH_OFFSET..HASH_OFFSET + size_of::<[u8; 32]>()], payload);
+
+        data[VERSION_OFFSET..VERSION_OFFSET + size_of::<u64>()]
+            .clone_from_slice(&version.to_le_bytes());
+        data[HASH_OFFSET..HASH_OFFSET + size_of::<[u8; 32]>()].clone_from_slice(&hash);
+
+        Ok((version, hash))
+    }
+
+    #[inline(always)]
+    /// Same as `update_history_unchecked` with the data currently stored in
+    /// the record as payload
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_data_history_unchecked(
+        data: &mut RefMut<'info, [u8]>,
+    ) -> Result<(u64, [u8; 32]), ProgramError> {
+        let version = Self::next_version_unchecked(data)?;
+
+        let hash = RecordUpdate::Data.chain(
+            &data[HASH_OFFSET..HASH_OFFSET + size_of::<[u8; 32]>()],
+            Self::get_data_unchecked(data),
+        );
+
+        data[VERSION_OFFSET..VERSION_OFFSET + size_of::<u64>()]
+            .clone_from_slice(&version.to_le_bytes());
+        data[HASH_OFFSET..HASH_OFFSET + size_of::<[u8; 32]>()].clone_from_slice(&hash);
+
+        Ok((version, hash))
+    }
+
+    #[inline(always)]
+    unsafe fn next_version_unchecked(data: &[u8]) -> Result<u64, ProgramError> {
+        u64::from_le_bytes(
+            data[VERSION_OFFSET..VERSION_OFFSET + size_of::<u64>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        )
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)
     }
 
     #[inline(always)]
@@ -317,7 +955,7 @@ impl<'info> Record<'info> {
     ) -> Result<(), ProgramError> {
         // Check if the record is frozen
         if data[IS_FROZEN_OFFSET].eq(&1u8) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::RecordFrozen.into());
         }
 
         // Check if the new_owner is the same
@@ -341,7 +979,7 @@ impl<'info> Record<'info> {
     ) -> Result<(), ProgramError> {
         // Check if the record is frozen
         if data[IS_FROZEN_OFFSET].eq(&1u8) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::RecordFrozen.into());
         }
 
         // Update the expiry
@@ -357,7 +995,7 @@ impl<'info> Record<'info> {
     pub unsafe fn update_data_unchecked(
         record: &'info AccountInfo,
         payer: &'info AccountInfo,
-        data: &'info str,
+        data: &'info [u8],
     ) -> Result<(), ProgramError> {
         let seed_len = {
             let data_ref = record.try_borrow_data()?;
@@ -375,12 +1013,70 @@ impl<'info> Record<'info> {
         {
             let mut data_ref = record.try_borrow_mut_data()?;
             if data_ref[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
-                return Err(ProgramError::InvalidAccountData);
+                return Err(RecordServiceError::InvalidAccountDiscriminator.into());
             }
             let data_buffer = unsafe {
                 core::slice::from_raw_parts_mut(data_ref.as_mut_ptr().add(offset), data.len())
             };
-            data_buffer.clone_from_slice(data.as_bytes());
+            data_buffer.clone_from_slice(data);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_data_unchecked(data: &[u8]) -> &[u8] {
+        &data[SEED_LEN_OFFSET + size_of::<u8>() + data[SEED_LEN_OFFSET] as usize
+            ..data.len() - Self::get_staged_len_unchecked(data)]
+    }
+
+    #[inline(always)]
+    /// Write `patch` at `offset` of the record data, extending the record if
+    /// needed, or resizing it to end with the patch if `truncate` is set
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn patch_data_unchecked(
+        record: &'info AccountInfo,
+        payer: &'info AccountInfo,
+        offset: usize,
+        patch: &[u8],
+        truncate: bool,
+    ) -> Result<(), ProgramError> {
+        let seed_len = {
+            let data_ref = record.try_borrow_data()?;
+            data_ref[SEED_LEN_OFFSET] as usize
+        };
+
+        let data_offset = seed_len + SEED_LEN_OFFSET + size_of::<u8>();
+        let current_len = record.data_len();
+
+        // The patch must start inside the current data or right after it
+        if offset > current_len - data_offset {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let patch_end = data_offset + offset + patch.len();
+        let new_len = if truncate {
+            patch_end
+        } else {
+            patch_end.max(current_len)
+        };
+
+        if new_len != current_len {
+            resize_account(record, payer, new_len, new_len < current_len)?;
+        }
+
+        {
+            let mut data_ref = record.try_borrow_mut_data()?;
+            if data_ref[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
+                return Err(RecordServiceError::InvalidAccountDiscriminator.into());
+            }
+            data_ref[data_offset + offset..patch_end].clone_from_slice(patch);
         }
 
         Ok(())
@@ -394,14 +1090,108 @@ impl<'info> Record<'info> {
         record: &'info AccountInfo,
         payer: &'info AccountInfo,
     ) -> Result<(), ProgramError> {
-        resize_account(record, payer, 1, true)?;
+        let generation = Self::get_generation_unchecked(&record.try_borrow_data()?)?;
+
+        // The tombstone keeps the generation so that a record recreated at this
+        // address is told apart, first generation tombstones are a single byte
+        let tombstone_len = if generation == 0 {
+            size_of::<u8>()
+        } else {
+            size_of::<u8>() + size_of::<u32>()
+        };
+
+        resize_account(record, payer, tombstone_len, true)?;
         {
             let mut data_ref = record.try_borrow_mut_data()?;
             data_ref[DISCRIMINATOR_OFFSET] = CLOSED_ACCOUNT_DISCRIMINATOR;
+            if generation != 0 {
+                ByteWriter::write_with_offset(
+                    &mut data_ref,
+                    GENERATION_TOMBSTONE_OFFSET,
+                    generation,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the generation of the record that was deleted at this address,
+    /// failing if the account is not the tombstone of a record
+    pub fn get_deleted_generation(record: &AccountInfo) -> Result<u32, ProgramError> {
+        // Check Program ID
+        if unsafe { record.owner().ne(&crate::ID) } {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let data = record.try_borrow_data()?;
+        if data.is_empty() || data[DISCRIMINATOR_OFFSET].ne(&CLOSED_ACCOUNT_DISCRIMINATOR) {
+            return Err(RecordServiceError::RecordNotDeleted.into());
         }
+
+        // Single byte tombstones are left by records of the first generation
+        if data.len() == size_of::<u8>() {
+            return Ok(0);
+        }
+
+        Ok(u32::from_le_bytes(
+            data[GENERATION_TOMBSTONE_OFFSET..GENERATION_TOMBSTONE_OFFSET + size_of::<u32>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        ))
+    }
+
+    #[inline(always)]
+    /// Reopen the tombstone of a record so that it can be initialized again
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn reopen_unchecked(
+        record: &'info AccountInfo,
+        payer: &'info AccountInfo,
+        space: usize,
+    ) -> Result<(), ProgramError> {
+        resize_account(record, payer, space, true)?;
+
+        let mut data_ref = record.try_borrow_mut_data()?;
+        data_ref.fill(0);
+
         Ok(())
     }
 
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_mint_bump_unchecked(data: &[u8]) -> [u8; 1] {
+        [data[MINT_BUMP_OFFSET]]
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_mint_bump_unchecked(
+        data: &mut RefMut<'info, [u8]>,
+        mint_bump: u8,
+    ) -> Result<(), ProgramError> {
+        data[MINT_BUMP_OFFSET] = mint_bump;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_generation_unchecked(data: &[u8]) -> Result<u32, ProgramError> {
+        Ok(u32::from_le_bytes(
+            data[GENERATION_OFFSET..GENERATION_OFFSET + size_of::<u32>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        ))
+    }
+
     #[inline(always)]
     /// # Safety
     ///
@@ -482,7 +1272,7 @@ impl<'info> Record<'info> {
         let additional_metadata_data =
             if u32::from_le_bytes(data[offset..offset + size_of::<u32>()].try_into().unwrap()) != 0
             {
-                Some(&data[offset..])
+                Some(&data[offset..data.len() - Self::get_staged_len_unchecked(data)])
             } else {
                 None
             };
@@ -490,6 +1280,123 @@ impl<'info> Record<'info> {
         Ok((metadata_data, additional_metadata_data))
     }
 
+    #[inline(always)]
+    /// Read a whole record, the seed and the data borrow from `data`
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn from_bytes_unchecked(data: &'info [u8]) -> Result<Self, ProgramError> {
+        let read_i64 = |offset: usize| -> Result<i64, ProgramError> {
+            Ok(i64::from_le_bytes(
+                data[offset..offset + size_of::<i64>()]
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)?,
+            ))
+        };
+
+        let seed_len = data[SEED_LEN_OFFSET] as usize;
+
+        Ok(Self {
+            class: data[CLASS_OFFSET..CLASS_OFFSET + size_of::<Pubkey>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+            owner_type: if data[OWNER_TYPE_OFFSET].eq(&(OwnerType::Token as u8)) {
+                OwnerType::Token
+            } else {
+                OwnerType::Pubkey
+            },
+            owner: data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+            is_frozen: data[IS_FROZEN_OFFSET] == 1,
+            expiry: read_i64(EXPIRY_OFFSET)?,
+            is_revoked: data[IS_REVOKED_OFFSET] == 1,
+            revocation_reason: u16::from_le_bytes(
+                data[REVOCATION_REASON_OFFSET..REVOCATION_REASON_OFFSET + size_of::<u16>()]
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)?,
+            ),
+            revoked_at: read_i64(REVOKED_AT_OFFSET)?,
+            version: u64::from_le_bytes(
+                data[VERSION_OFFSET..VERSION_OFFSET + size_of::<u64>()]
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidAccountData)?,
+            ),
+            generation: Self::get_generation_unchecked(data)?,
+            hash: data[HASH_OFFSET..HASH_OFFSET + size_of::<[u8; 32]>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+            write_state: Self::get_write_state_unchecked(data)?,
+            staged_len: Self::get_staged_len_unchecked(data) as u32,
+            staged_payer: data[STAGED_PAYER_OFFSET..STAGED_PAYER_OFFSET + size_of::<Pubkey>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+            content_type: Self::get_content_type_unchecked(data)?,
+            mint_bump: data[MINT_BUMP_OFFSET],
+            seed: &data[SEED_OFFSET..SEED_OFFSET + seed_len],
+            data: Self::get_data_unchecked(data),
+        })
+    }
+
+    /// Check if the record has the legacy layout
+    #[inline(always)]
+    pub fn check_legacy(record: &AccountInfo) -> Result<(), ProgramError> {
+        // Check Program ID
+        if unsafe { record.owner().ne(&crate::ID) } {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        if record.try_borrow_data()?[DISCRIMINATOR_OFFSET].ne(&Self::LEGACY_DISCRIMINATOR) {
+            return Err(RecordServiceError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(())
+    }
+
+    /// Migrate a legacy record to the current layout in place
+    ///
+    /// The seed and the data are moved after the new fields. The record is not
+    /// revoked, its history starts with its current data, legacy records only
+    /// hold utf-8 text, and tokenized records keep the bump of their mint.
+    ///
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn migrate_legacy_unchecked(
+        record: &'info AccountInfo,
+        payer: &'info AccountInfo,
+        mint_bump: u8,
+    ) -> Result<(), ProgramError> {
+        let legacy_len = record.data_len();
+        let len = legacy_len + SEED_LEN_OFFSET - LEGACY_SEED_LEN_OFFSET;
+
+        resize_account(record, payer, len, false)?;
+
+        let mut data = record.try_borrow_mut_data()?;
+
+        // Move the seed and the data after the new fields
+        data.copy_within(LEGACY_SEED_LEN_OFFSET..legacy_len, SEED_LEN_OFFSET);
+
+        let data_offset = SEED_OFFSET + data[SEED_LEN_OFFSET] as usize;
+        let hash = RecordUpdate::Data.chain(&[0; 32], &data[data_offset..]);
+
+        ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+        ByteWriter::write_with_offset(&mut data, IS_REVOKED_OFFSET, false)?;
+        ByteWriter::write_with_offset(&mut data, REVOCATION_REASON_OFFSET, 0u16)?;
+        ByteWriter::write_with_offset(&mut data, REVOKED_AT_OFFSET, 0i64)?;
+        ByteWriter::write_with_offset(&mut data, VERSION_OFFSET, 0u64)?;
+        ByteWriter::write_with_offset(&mut data, GENERATION_OFFSET, 0u32)?;
+        ByteWriter::write_with_offset(&mut data, HASH_OFFSET, hash)?;
+        ByteWriter::write_with_offset(&mut data, WRITE_STATE_OFFSET, WriteState::Idle as u8)?;
+        ByteWriter::write_with_offset(&mut data, STAGED_LEN_OFFSET, 0u32)?;
+        ByteWriter::write_with_offset(&mut data, STAGED_PAYER_OFFSET, [0u8; 32])?;
+        ByteWriter::write_with_offset(&mut data, CONTENT_TYPE_OFFSET, ContentType::Utf8 as u8)?;
+        ByteWriter::write_with_offset(&mut data, MINT_BUMP_OFFSET, mint_bump)?;
+
+        Ok(())
+    }
+
     #[inline(always)]
     /// # Safety
     ///
@@ -500,7 +1407,7 @@ impl<'info> Record<'info> {
     ) -> Result<(), ProgramError> {
         let required_space = Self::MINIMUM_RECORD_SIZE + self.seed.len() + self.data.len();
         if account_info.data_len() < required_space {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::AccountTooSmall.into());
         }
 
         let mut data = account_info.try_borrow_mut_data()?;
@@ -514,10 +1421,21 @@ impl<'info> Record<'info> {
         ByteWriter::write_with_offset(&mut data, OWNER_OFFSET, self.owner)?;
         ByteWriter::write_with_offset(&mut data, IS_FROZEN_OFFSET, self.is_frozen)?;
         ByteWriter::write_with_offset(&mut data, EXPIRY_OFFSET, self.expiry)?;
+        ByteWriter::write_with_offset(&mut data, IS_REVOKED_OFFSET, self.is_revoked)?;
+        ByteWriter::write_with_offset(&mut data, REVOCATION_REASON_OFFSET, self.revocation_reason)?;
+        ByteWriter::write_with_offset(&mut data, REVOKED_AT_OFFSET, self.revoked_at)?;
+        ByteWriter::write_with_offset(&mut data, VERSION_OFFSET, self.version)?;
+        ByteWriter::write_with_offset(&mut data, GENERATION_OFFSET, self.generation)?;
+        ByteWriter::write_with_offset(&mut data, HASH_OFFSET, self.hash)?;
+        ByteWriter::write_with_offset(&mut data, WRITE_STATE_OFFSET, self.write_state as u8)?;
+        ByteWriter::write_with_offset(&mut data, STAGED_LEN_OFFSET, self.staged_len)?;
+        ByteWriter::write_with_offset(&mut data, STAGED_PAYER_OFFSET, self.staged_payer)?;
+        ByteWriter::write_with_offset(&mut data, CONTENT_TYPE_OFFSET, self.content_type as u8)?;
+        ByteWriter::write_with_offset(&mut data, MINT_BUMP_OFFSET, self.mint_bump)?;
 
         let mut variable_data = ByteWriter::new_with_offset(&mut data, SEED_LEN_OFFSET);
         variable_data.write_bytes_with_length(self.seed)?;
-        variable_data.write_str(self.data)?;
+        variable_data.write_bytes(self.data)?;
 
         Ok(())
     }
@@ -0,0 +1,205 @@
+use crate::{error::RecordServiceError, utils::ByteWriter};
+use core::mem::size_of;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+};
+
+use super::Permission;
+
+/// Offsets
+const DISCRIMINATOR_OFFSET: usize = 0;
+const RECORD_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<u8>();
+const OWNER_OFFSET: usize = RECORD_OFFSET + size_of::<Pubkey>();
+const GENERATION_OFFSET: usize = OWNER_OFFSET + size_of::<Pubkey>();
+const DELEGATE_OFFSET: usize = GENERATION_OFFSET + size_of::<u32>();
+const PERMISSIONS_OFFSET: usize = DELEGATE_OFFSET + size_of::<Pubkey>();
+const EXPIRY_OFFSET: usize = PERMISSIONS_OFFSET + size_of::<u8>();
+
+#[repr(C)]
+pub struct RecordDelegate {
+    /// The record the delegate acts on
+    pub record: Pubkey,
+    /// The record owner that approved the delegate, the delegate is void once the record changes hands
+    pub owner: Pubkey,
+    /// The generation of the record when the delegate was approved, the
+    /// delegate is void for the records recreated at the same address
+    pub generation: u32,
+    /// The delegated key
+    pub delegate: Pubkey,
+    /// Bitmask of the granted permissions
+    pub permissions: u8,
+    /// Optional expiration timestamp of the delegation, if not set, the expiry is [0; 8]
+    pub expiry: i64,
+}
+
+impl RecordDelegate {
+    /// The discriminator byte used to identify this account type
+    pub const DISCRIMINATOR: u8 = 5;
+
+    /// Size of a record delegate account
+    pub const SIZE: usize = size_of::<u8>()
+        + size_of::<Pubkey>() * 3
+        + size_of::<u32>()
+        + size_of::<u8>()
+        + size_of::<i64>();
+
+    /// Check if the program id and discriminator are valid
+    #[inline(always)]
+    pub fn check_program_id_and_discriminator(
+        account_info: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        // Check Program ID
+        if unsafe { account_info.owner().ne(&crate::ID) } {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // Check discriminator
+        let data = account_info.try_borrow_data()?;
+        if data[DISCRIMINATOR_OFFSET].ne(&Self::DISCRIMINATOR) {
+            return Err(RecordServiceError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check if the account is the delegate account of the record
+    #[inline(always)]
+    pub fn check_record(
+        account_info: &AccountInfo,
+        record: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        Self::check_program_id_and_discriminator(account_info)?;
+
+        let data = account_info.try_borrow_data()?;
+        if record
+            .key()
+            .ne(&data[RECORD_OFFSET..RECORD_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::InvalidRecordDelegate.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check if the authority is an active delegate of the record with the given permission
+    #[inline(always)]
+    pub fn check_permission(
+        account_info: &AccountInfo,
+        record: &AccountInfo,
+        owner: &[u8],
+        generation: u32,
+        authority: &AccountInfo,
+        permission: Permission,
+    ) -> Result<(), ProgramError> {
+        Self::check_record(account_info, record)?;
+
+        if !authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let data = account_info.try_borrow_data()?;
+
+        // The delegate was approved by a previous owner of the record
+        if owner.ne(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()]) {
+            return Err(RecordServiceError::InvalidRecordDelegate.into());
+        }
+
+        // The delegate was approved for a deleted record at the same address
+        if generation.to_le_bytes().ne(&data[GENERATION_OFFSET..GENERATION_OFFSET + size_of::<u32>()]) {
+            return Err(RecordServiceError::InvalidRecordDelegate.into());
+        }
+
+        if authority
+            .key()
+            .ne(&data[DELEGATE_OFFSET..DELEGATE_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::InvalidRecordDelegate.into());
+        }
+
+        if data[PERMISSIONS_OFFSET] & permission as u8 == 0 {
+            return Err(RecordServiceError::MissingPermission.into());
+        }
+
+        let expiry = i64::from_le_bytes(
+            data[EXPIRY_OFFSET..EXPIRY_OFFSET + size_of::<i64>()]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        );
+
+        // An expiry of 0 means the delegation never expires
+        if expiry != 0 && expiry <= Clock::get()?.unix_timestamp {
+            return Err(RecordServiceError::RecordDelegateExpired.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn check_owner_unchecked(
+        data: &[u8],
+        owner: &AccountInfo,
+    ) -> Result<(), ProgramError> {
+        if owner
+            .key()
+            .ne(&data[OWNER_OFFSET..OWNER_OFFSET + size_of::<Pubkey>()])
+        {
+            return Err(RecordServiceError::InvalidOwner.into());
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn get_delegate_unchecked(data: &[u8]) -> Result<Pubkey, ProgramError> {
+        data[DELEGATE_OFFSET..DELEGATE_OFFSET + size_of::<Pubkey>()]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn update_unchecked(&self, account_info: &AccountInfo) -> Result<(), ProgramError> {
+        let mut data = account_info.try_borrow_mut_data()?;
+
+        ByteWriter::write_with_offset(&mut data, OWNER_OFFSET, self.owner)?;
+        ByteWriter::write_with_offset(&mut data, GENERATION_OFFSET, self.generation)?;
+        ByteWriter::write_with_offset(&mut data, DELEGATE_OFFSET, self.delegate)?;
+        ByteWriter::write_with_offset(&mut data, PERMISSIONS_OFFSET, self.permissions)?;
+        ByteWriter::write_with_offset(&mut data, EXPIRY_OFFSET, self.expiry)?;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    /// # Safety
+    ///
+    /// This function does not perform owner checks
+    pub unsafe fn initialize_unchecked(&self, account_info: &AccountInfo) -> Result<(), ProgramError> {
+        if account_info.data_len() < Self::SIZE {
+            return Err(RecordServiceError::AccountTooSmall.into());
+        }
+
+        {
+            let mut data = account_info.try_borrow_mut_data()?;
+            if data[DISCRIMINATOR_OFFSET] != 0x00 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+
+            ByteWriter::write_with_offset(&mut data, DISCRIMINATOR_OFFSET, Self::DISCRIMINATOR)?;
+            ByteWriter::write_with_offset(&mut data, RECORD_OFFSET, self.record)?;
+        }
+
+        self.update_unchecked(account_info)
+    }
+}
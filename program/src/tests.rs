@@ -1,8 +1,17 @@
 use borsh::de::BorshDeserialize;
 use borsh::ser::BorshSerialize;
 use core::str::FromStr;
+use std::{process::Command, sync::Once};
+use ed25519_dalek::{Keypair, SecretKey, Signer};
 use trezoa_account::{Account, WritableAccount};
-use trezoa_program::program_error::ProgramError;
+use trezoa_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    sysvar::{
+        self,
+        instructions::{construct_instructions_data, BorrowedAccountMeta, BorrowedInstruction},
+    },
+};
 
 use kaigan::types::{RemainderStr, RemainderVec, U8PrefixString, U8PrefixVec};
 use mollusk_svm::{program::keyed_account_for_system_program, result::Check, Mollusk};
@@ -10,9 +19,15 @@ use trezoa_pubkey::Pubkey;
 
 use trezoa_record_service_client::{
     accounts::*,
+    attestation::record_attestation,
+    errors::TrezoaRecordServiceError,
     instructions::*,
+    merkle::AllowlistTree,
     programs::TREZOA_RECORD_SERVICE_ID,
-    types::{Metadata, AdditionalMetadata},
+    types::{
+        AdditionalMetadata, BatchRecord, ClassPolicy, Metadata, Policy, SchemaField,
+        SchemaFieldType,
+    },
 };
 
 pub const AUTHORITY: Pubkey = Pubkey::new_from_array([0xaa; 32]);
@@ -94,6 +109,43 @@ fn make_remainder_str(s: &str) -> RemainderStr {
     RemainderStr::from_str(s).expect("Invalid metadata")
 }
 
+fn make_schema_fields(fields: &[SchemaField]) -> U8PrefixVec<SchemaField> {
+    // Kaigan deserializes each item with the size of its type, which doesn't
+    // fit variable length items, so the fields are added to an empty vec
+    let mut schema_fields = U8PrefixVec::try_from_slice(&[0]).expect("Invalid fields");
+    schema_fields.extend_from_slice(fields);
+    schema_fields
+}
+
+fn make_merkle_proof(proof: &[[u8; 32]]) -> U8PrefixVec<[u8; 32]> {
+    let mut data = vec![proof.len() as u8];
+    for hash in proof {
+        data.extend_from_slice(hash);
+    }
+    U8PrefixVec::try_from_slice(&data).expect("Invalid proof")
+}
+
+fn make_batch_records(records: &[BatchRecord]) -> U8PrefixVec<BatchRecord> {
+    // Same as the schema fields, the records have a variable length
+    let mut batch_records = U8PrefixVec::try_from_slice(&[0]).expect("Invalid records");
+    batch_records.extend_from_slice(records);
+    batch_records
+}
+
+fn make_schema_field(
+    field_type: SchemaFieldType,
+    is_required: bool,
+    max_len: u16,
+    name: &str,
+) -> SchemaField {
+    SchemaField {
+        field_type,
+        is_required,
+        max_len,
+        name: make_u8prefix_string(name),
+    }
+}
+
 fn keyed_account_for_authority() -> (Pubkey, Account) {
     (
         AUTHORITY,
@@ -124,6 +176,40 @@ fn keyed_account_for_class(
     is_frozen: bool,
     name: &str,
     metadata: &str,
+) -> (Pubkey, Account) {
+    keyed_account_for_class_with_policy(
+        authority,
+        is_permissioned,
+        is_frozen,
+        name,
+        metadata,
+        make_default_class_policy(is_permissioned),
+    )
+}
+
+fn make_default_class_policy(is_permissioned: bool) -> ClassPolicy {
+    let policy = if is_permissioned {
+        Policy::Either
+    } else {
+        Policy::Owner
+    };
+
+    ClassPolicy {
+        update_data: Policy::Authority,
+        update_expiry: Policy::Authority,
+        transfer: policy,
+        delete: policy,
+        tokenize: policy,
+    }
+}
+
+fn keyed_account_for_class_with_policy(
+    authority: Pubkey,
+    is_permissioned: bool,
+    is_frozen: bool,
+    name: &str,
+    metadata: &str,
+    policy: ClassPolicy,
 ) -> (Pubkey, Account) {
     let (address, _bump) = Pubkey::find_program_address(
         &[b"class", &authority.as_ref(), name.as_ref()],
@@ -131,10 +217,21 @@ fn keyed_account_for_class(
     );
 
     let class_account_data = Class {
-        discriminator: 1,
+        discriminator: 7,
         authority,
         is_permissioned,
         is_frozen,
+        is_non_transferable: false,
+        policy,
+        record_count: 0,
+        tokenized_count: 0,
+        legacy_record_count: 0,
+        max_records: 0,
+        creation_fee: 0,
+        treasury: Pubkey::default(),
+        merkle_root: [0; 32],
+        group_bump: 0,
+        schema_bump: 0,
         name: make_u8prefix_string(name),
         metadata: make_remainder_str(metadata),
     }
@@ -152,170 +249,444 @@ fn keyed_account_for_class(
     (address, class_account)
 }
 
-fn keyed_account_for_record(
+fn keyed_account_for_non_transferable_class() -> (Pubkey, Account) {
+    let (address, mut class_account) = keyed_account_for_class_default();
+
+    let mut class = Class::from_bytes(&class_account.data).expect("Invalid class");
+    class.is_non_transferable = true;
+
+    class_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class.try_to_vec().expect("Invalid class"));
+    (address, class_account)
+}
+
+fn keyed_account_for_class_with_counts(
+    record_count: u64,
+    tokenized_count: u64,
+    max_records: u64,
+) -> (Pubkey, Account) {
+    let (address, mut class_account) = keyed_account_for_class_default();
+
+    let mut class = Class::from_bytes(&class_account.data).expect("Invalid class");
+    class.record_count = record_count;
+    class.tokenized_count = tokenized_count;
+    class.max_records = max_records;
+
+    class_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class.try_to_vec().expect("Invalid class"));
+    (address, class_account)
+}
+
+fn keyed_account_for_class_with_legacy_records(legacy_record_count: u64) -> (Pubkey, Account) {
+    let (address, mut class_account) = keyed_account_for_class_default();
+
+    let mut class = Class::from_bytes(&class_account.data).expect("Invalid class");
+    class.legacy_record_count = legacy_record_count;
+
+    class_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class.try_to_vec().expect("Invalid class"));
+    (address, class_account)
+}
+
+fn keyed_account_for_class_with_fee(creation_fee: u64, treasury: Pubkey) -> (Pubkey, Account) {
+    let (address, mut class_account) = keyed_account_for_class_default();
+
+    let mut class = Class::from_bytes(&class_account.data).expect("Invalid class");
+    class.creation_fee = creation_fee;
+    class.treasury = treasury;
+
+    class_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class.try_to_vec().expect("Invalid class"));
+    (address, class_account)
+}
+
+fn keyed_account_for_class_with_group(is_permissioned: bool) -> (Pubkey, Account) {
+    let (address, mut class_account) =
+        keyed_account_for_class(AUTHORITY, is_permissioned, false, "test", "test");
+
+    let (_, group_bump) =
+        Pubkey::find_program_address(&[b"group", address.as_ref()], &TREZOA_RECORD_SERVICE_ID);
+
+    let mut class = Class::from_bytes(&class_account.data).expect("Invalid class");
+    class.group_bump = group_bump;
+
+    class_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class.try_to_vec().expect("Invalid class"));
+    (address, class_account)
+}
+
+fn keyed_account_for_class_with_schema() -> (Pubkey, Account) {
+    let (address, mut class_account) = keyed_account_for_class_default();
+
+    let (_, schema_bump) =
+        Pubkey::find_program_address(&[b"schema", address.as_ref()], &TREZOA_RECORD_SERVICE_ID);
+
+    let mut class = Class::from_bytes(&class_account.data).expect("Invalid class");
+    class.schema_bump = schema_bump;
+
+    class_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class.try_to_vec().expect("Invalid class"));
+    (address, class_account)
+}
+
+fn keyed_account_for_class_with_merkle_root(
+    is_permissioned: bool,
+    merkle_root: [u8; 32],
+) -> (Pubkey, Account) {
+    let (address, mut class_account) =
+        keyed_account_for_class(AUTHORITY, is_permissioned, false, "test", "test");
+
+    let mut class = Class::from_bytes(&class_account.data).expect("Invalid class");
+    class.merkle_root = merkle_root;
+
+    class_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class.try_to_vec().expect("Invalid class"));
+    (address, class_account)
+}
+
+fn keyed_account_for_pending_class_authority(
     class: Pubkey,
-    owner_type: u8,
-    owner: Pubkey,
-    is_frozen: bool,
-    expiry: i64,
-    seed: &[u8],
-    data: &[u8],
+    authority: Pubkey,
+    pending_authority: Pubkey,
 ) -> (Pubkey, Account) {
     let (address, _bump) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), seed.as_ref()],
+        &[b"pending_authority", class.as_ref()],
         &TREZOA_RECORD_SERVICE_ID,
     );
-    let record_account_data = Record {
-        discriminator: 2,
+
+    let pending_class_authority_account_data = PendingClassAuthority {
+        discriminator: 3,
         class,
-        owner_type,
-        owner,
-        is_frozen,
-        expiry,
-        seed: make_u8prefix_vec_u8(seed),
-        data: RemainderVec::<u8>::try_from_slice(data).unwrap(),
+        authority,
+        pending_authority,
     }
     .try_to_vec()
-    .expect("Invalid record");
+    .expect("Invalid pending class authority");
 
-    let mut record_account = Account::new(
+    let mut pending_class_authority_account = Account::new(
         100_000_000u64,
-        record_account_data.len(),
+        pending_class_authority_account_data.len(),
         &Pubkey::from(crate::ID),
     );
-    record_account
+    pending_class_authority_account
         .data_as_mut_slice()
-        .clone_from_slice(&record_account_data);
-
-    (address, record_account)
+        .clone_from_slice(&pending_class_authority_account_data);
+    (address, pending_class_authority_account)
 }
 
-/// Fake Metadata that has
-/// - name: "test"
-/// - symbol: "SRS"
-/// - uri: "test"
-/// - additional_metadata: []
-const METADATA: &[u8; 27] = &[
-    4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 0, 0,
-    0, 0,
-];
-
-fn keyed_account_for_record_with_metadata(
+fn keyed_account_for_class_delegate(
     class: Pubkey,
-    owner_type: u8,
-    owner: Pubkey,
-    is_frozen: bool,
-    expiry: i64,
-    name: &str,
-    metadata: Option<&[u8]>,
+    delegate: Pubkey,
+    permissions: u8,
 ) -> (Pubkey, Account) {
     let (address, _bump) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), name.as_ref()],
+        &[b"class_delegate", class.as_ref(), delegate.as_ref()],
         &TREZOA_RECORD_SERVICE_ID,
     );
-    let record_account_data = Record {
-        discriminator: 2,
+
+    let class_delegate_account_data = ClassDelegate {
+        discriminator: 4,
         class,
-        owner_type,
-        owner,
-        is_frozen,
-        expiry,
-        seed: make_u8prefix_vec_u8(name.as_bytes()),
-        data: RemainderVec::<u8>::try_from_slice(metadata.unwrap_or(METADATA)).unwrap(),
+        delegate,
+        permissions,
     }
     .try_to_vec()
-    .expect("Invalid record");
+    .expect("Invalid class delegate");
 
-    let mut record_account = Account::new(
+    let mut class_delegate_account = Account::new(
         100_000_000u64,
-        record_account_data.len(),
+        class_delegate_account_data.len(),
         &Pubkey::from(crate::ID),
     );
-    record_account
+    class_delegate_account
         .data_as_mut_slice()
-        .clone_from_slice(&record_account_data);
-
-    (address, record_account)
+        .clone_from_slice(&class_delegate_account_data);
+    (address, class_delegate_account)
 }
 
-/// Fake Metadata that has
-/// - name: "test"
-/// - symbol: "SRS"
-/// - uri: "test"
-/// - additional_metadata: [
-///     { label: "test", value: "test" }
-/// ]
-const METADATA_WITH_ADDITIONAL_METADATA: &[u8] = &[
-    4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 1, 0,
-    0, 0, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 116, 101, 115, 116,
-];
-
-fn keyed_account_for_record_with_metadata_and_additional_metadata(
-    class: Pubkey,
-    owner_type: u8,
+fn keyed_account_for_record_delegate(
+    record: Pubkey,
     owner: Pubkey,
-    is_frozen: bool,
+    delegate: Pubkey,
+    permissions: u8,
     expiry: i64,
-    name: &str,
 ) -> (Pubkey, Account) {
     let (address, _bump) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), name.as_ref()],
+        &[b"record_delegate", record.as_ref()],
         &TREZOA_RECORD_SERVICE_ID,
     );
-    let record_account_data = Record {
-        discriminator: 2,
-        class,
-        owner_type,
+
+    let record_delegate_account_data = RecordDelegate {
+        discriminator: 5,
+        record,
         owner,
-        is_frozen,
+        generation: 0,
+        delegate,
+        permissions,
         expiry,
-        seed: make_u8prefix_vec_u8(name.as_bytes()),
-        data: RemainderVec::<u8>::try_from_slice(METADATA_WITH_ADDITIONAL_METADATA).unwrap(),
     }
     .try_to_vec()
-    .expect("Invalid record");
+    .expect("Invalid record delegate");
 
-    let mut record_account = Account::new(
+    let mut record_delegate_account = Account::new(
         100_000_000u64,
-        record_account_data.len(),
+        record_delegate_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    record_delegate_account
+        .data_as_mut_slice()
+        .clone_from_slice(&record_delegate_account_data);
+    (address, record_delegate_account)
+}
+
+fn keyed_account_for_class_schema(class: Pubkey, fields: &[SchemaField]) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"schema", class.as_ref()],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+
+    let class_schema_account_data = ClassSchema {
+        discriminator: 6,
+        class,
+        fields: make_schema_fields(fields),
+    }
+    .try_to_vec()
+    .expect("Invalid class schema");
+
+    let mut class_schema_account = Account::new(
+        100_000_000u64,
+        class_schema_account_data.len(),
         &Pubkey::from(crate::ID),
     );
+    class_schema_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class_schema_account_data);
+    (address, class_schema_account)
+}
+
+fn make_record_hash(previous_hash: &[u8], update: u8, payload: &[u8]) -> [u8; 32] {
+    trezoa_program::hash::hashv(&[previous_hash, &[update], payload]).to_bytes()
+}
+
+/// Set the version and the hash of `record_account` to the ones of `previous`
+/// after one more update of the given kind
+fn chain_record_update(
+    record_account: &mut Account,
+    previous: &Account,
+    update: u8,
+    payload: &[u8],
+) {
+    let previous = Record::from_bytes(&previous.data).expect("Invalid record");
+
+    let mut record = Record::from_bytes(&record_account.data).expect("Invalid record");
+    record.version = previous.version + 1;
+    record.hash = make_record_hash(&previous.hash, update, payload);
+
     record_account
         .data_as_mut_slice()
-        .clone_from_slice(&record_account_data);
+        .clone_from_slice(&record.try_to_vec().expect("Invalid record"));
+}
 
-    (address, record_account)
+/// Ed25519 key of a class authority that attests records with signatures
+fn make_signing_authority(secret: u8) -> Keypair {
+    let secret = SecretKey::from_bytes(&[secret; 32]).expect("Invalid secret key");
+    let public = (&secret).into();
+    Keypair { secret, public }
 }
 
-const METADATA_WITH_MULTIPLE_ADDITIONAL_METADATA: &[u8] = &[
-    4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 3, 0,
-    0, 0, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 115, 101,
-    115, 116, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 114, 101, 115, 116, 4, 0, 0, 0, 116, 101,
-    115, 116,
-];
+// The Ed25519 precompile is not run by mollusk, the instruction is verified
+// with the precompile before it is passed to the program. The precompiles and
+// the feature set are deprecated in favor of the validator crates
+#[allow(deprecated)]
+fn make_ed25519_instruction(signatures: &[(&Keypair, &[u8])]) -> Instruction {
+    const OFFSETS_LEN: usize = 14;
+
+    let mut offsets = vec![signatures.len() as u8, 0];
+    let mut payloads = Vec::new();
+
+    for (signer, message) in signatures {
+        let public_key_offset = (2 + OFFSETS_LEN * signatures.len() + payloads.len()) as u16;
+        let signature_offset = public_key_offset + 32;
+        let message_offset = signature_offset + 64;
+
+        for offset in [
+            signature_offset,
+            u16::MAX,
+            public_key_offset,
+            u16::MAX,
+            message_offset,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            offsets.extend_from_slice(&offset.to_le_bytes());
+        }
 
-fn keyed_account_for_record_with_metadata_and_multiple_additional_metadata(
+        payloads.extend_from_slice(signer.public.as_bytes());
+        payloads.extend_from_slice(&signer.sign(message).to_bytes());
+        payloads.extend_from_slice(message);
+    }
+
+    let instruction = Instruction {
+        program_id: Pubkey::from_str("Ed25519SigVerify111111111111111111111111111").unwrap(),
+        accounts: vec![],
+        data: [offsets, payloads].concat(),
+    };
+
+    trezoa_precompiles::get_precompile(&instruction.program_id, |_| true)
+        .expect("Ed25519 precompile not found")
+        .verify(
+            &instruction.data,
+            &[&instruction.data],
+            &trezoa_feature_set::FeatureSet::all_enabled(),
+        )
+        .expect("Invalid Ed25519 instruction");
+
+    instruction
+}
+
+fn keyed_account_for_instructions_sysvar(
+    instructions: &[&Instruction],
+    current_index: u16,
+) -> (Pubkey, Account) {
+    let instructions = instructions
+        .iter()
+        .map(|instruction| BorrowedInstruction {
+            program_id: &instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| BorrowedAccountMeta {
+                    pubkey: &meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: &instruction.data,
+        })
+        .collect::<Vec<_>>();
+
+    let mut data = construct_instructions_data(&instructions);
+    let len = data.len();
+    data[len - 2..].copy_from_slice(&current_index.to_le_bytes());
+
+    let mut sysvar_account = Account::new(1_000_000, data.len(), &sysvar::ID);
+    sysvar_account.data_as_mut_slice().copy_from_slice(&data);
+
+    (sysvar::instructions::ID, sysvar_account)
+}
+
+fn make_class_bump(authority: &Pubkey, name: &str) -> u8 {
+    Pubkey::find_program_address(
+        &[b"class", authority.as_ref(), name.as_bytes()],
+        &TREZOA_RECORD_SERVICE_ID,
+    )
+    .1
+}
+
+fn make_record_bump(class: &Pubkey, seed: &[u8]) -> u8 {
+    Pubkey::find_program_address(&[b"record", class.as_ref(), seed], &TREZOA_RECORD_SERVICE_ID).1
+}
+
+fn make_group_bump(class: &Pubkey) -> u8 {
+    Pubkey::find_program_address(&[b"group", class.as_ref()], &TREZOA_RECORD_SERVICE_ID).1
+}
+
+fn make_mint_bump(record: &Pubkey) -> u8 {
+    Pubkey::find_program_address(&[b"mint", record.as_ref()], &TREZOA_RECORD_SERVICE_ID).1
+}
+
+fn make_class_delegate_bump(class: &Pubkey, delegate: &Pubkey) -> u8 {
+    Pubkey::find_program_address(
+        &[b"class_delegate", class.as_ref(), delegate.as_ref()],
+        &TREZOA_RECORD_SERVICE_ID,
+    )
+    .1
+}
+
+fn make_record_delegate_bump(record: &Pubkey) -> u8 {
+    Pubkey::find_program_address(&[b"record_delegate", record.as_ref()], &TREZOA_RECORD_SERVICE_ID)
+        .1
+}
+
+fn make_pending_class_authority_bump(class: &Pubkey) -> u8 {
+    Pubkey::find_program_address(
+        &[b"pending_authority", class.as_ref()],
+        &TREZOA_RECORD_SERVICE_ID,
+    )
+    .1
+}
+
+fn make_schema_bump(class: &Pubkey) -> u8 {
+    Pubkey::find_program_address(&[b"schema", class.as_ref()], &TREZOA_RECORD_SERVICE_ID).1
+}
+
+/// Find a valid bump below the canonical one, and the address it gives
+fn make_non_canonical_program_address(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    let (_, canonical_bump) = Pubkey::find_program_address(seeds, &TREZOA_RECORD_SERVICE_ID);
+
+    (0..canonical_bump)
+        .rev()
+        .find_map(|bump| {
+            let bump_seed = [bump];
+            let mut seeds = seeds.to_vec();
+            seeds.push(&bump_seed);
+
+            Pubkey::create_program_address(&seeds, &TREZOA_RECORD_SERVICE_ID)
+                .ok()
+                .map(|address| (address, bump))
+        })
+        .expect("No other valid bump")
+}
+
+fn make_record_mint_bump(record: &Pubkey, owner_type: u8) -> u8 {
+    // Only tokenized records store the bump of their mint
+    if owner_type == 0 {
+        return 0;
+    }
+
+    make_mint_bump(record)
+}
+
+fn keyed_account_for_record(
     class: Pubkey,
     owner_type: u8,
     owner: Pubkey,
     is_frozen: bool,
     expiry: i64,
-    name: &str,
+    seed: &[u8],
+    data: &[u8],
 ) -> (Pubkey, Account) {
     let (address, _bump) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), name.as_ref()],
+        &[b"record", class.as_ref(), seed],
         &TREZOA_RECORD_SERVICE_ID,
     );
     let record_account_data = Record {
-        discriminator: 2,
+        discriminator: 8,
         class,
         owner_type,
         owner,
         is_frozen,
         expiry,
-        seed: make_u8prefix_vec_u8(name.as_bytes()),
-        data: RemainderVec::<u8>::try_from_slice(METADATA_WITH_MULTIPLE_ADDITIONAL_METADATA)
-            .unwrap(),
+        is_revoked: false,
+        revocation_reason: 0,
+        revoked_at: 0,
+        version: 0,
+        generation: 0,
+        hash: make_record_hash(&[0; 32], 0, data),
+        write_state: 0,
+        staged_len: 0,
+        staged_payer: Pubkey::default(),
+        content_type: 0,
+        mint_bump: make_record_mint_bump(&address, owner_type),
+        seed: make_u8prefix_vec_u8(seed),
+        data: RemainderVec::<u8>::try_from_slice(data).unwrap(),
     }
     .try_to_vec()
     .expect("Invalid record");
@@ -332,204 +703,433 @@ fn keyed_account_for_record_with_metadata_and_multiple_additional_metadata(
     (address, record_account)
 }
 
-const MINT_DATA_WITH_EXTENSIONS: &[u8] = &[
-    1, 0, 0, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
-    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
-    0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101,
-    42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 1,
-];
-const MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY: &[u8] = &[
-    1, 0, 0, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
-    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
-    0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101,
-    42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 1,
-];
-const MINT_CLOSE_AUTHORITY_EXTENSION: &[u8] = &[
-    3, 0, 32, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
-    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127,
-];
-const MINT_PERMANENT_DELEGATE_EXTENSION: &[u8] = &[
-    12, 0, 32, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
-    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127,
-];
-const MINT_METADATA_POINTER_EXTENSION: &[u8] = &[
-    18, 0, 64, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
-    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31,
-    190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127,
-];
-const MINT_GROUP_MEMBER_POINTER_EXTENSION: &[u8] = &[
-    22, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87,
-    188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 44, 183, 51, 50, 60, 76,
-    5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4,
-    96, 81, 27, 127,
-];
-const MINT_METADATA_EXTENSION: &[u8] = &[
-    19, 0, 91, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
-    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31,
-    190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127,
+/// Fake Metadata that has
+/// - name: "test"
+/// - symbol: "SRS"
+/// - uri: "test"
+/// - additional_metadata: []
+const METADATA: &[u8; 27] = &[
     4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 0, 0,
     0, 0,
 ];
-const MINT_GROUP_MEMBER_EXTENSION: &[u8] = &[
-    23, 0, 72, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
-    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 52, 137, 177, 136, 59, 205, 145, 103,
-    193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152,
-    136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0,
-];
 
-fn keyed_account_for_mint(record: Pubkey) -> (Pubkey, Account) {
-    let (address, _bump) =
-        Pubkey::find_program_address(&[b"mint", &record.as_ref()], &TREZOA_RECORD_SERVICE_ID);
+fn keyed_account_for_legacy_class(
+    authority: Pubkey,
+    is_permissioned: bool,
+    is_frozen: bool,
+    name: &str,
+    metadata: &str,
+) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"class", authority.as_ref(), name.as_ref()],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
 
-    // Base data (82) + 84 (padding + account_type) + Extensions (36 + 36 + 68) + Metadata (83 + name.len() + uri.len())
-    let total_size = MINT_DATA_WITH_EXTENSIONS.len()
-        + MINT_CLOSE_AUTHORITY_EXTENSION.len()
-        + MINT_PERMANENT_DELEGATE_EXTENSION.len()
-        + MINT_METADATA_POINTER_EXTENSION.len()
-        + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()
-        + MINT_METADATA_EXTENSION.len()
-        + MINT_GROUP_MEMBER_EXTENSION.len();
+    let class_account_data = LegacyClass {
+        discriminator: 1,
+        authority,
+        is_permissioned,
+        is_frozen,
+        name: make_u8prefix_string(name),
+        metadata: make_remainder_str(metadata),
+    }
+    .try_to_vec()
+    .expect("Invalid class");
 
-    let mut mint_account_data = vec![0u8; total_size];
+    let mut class_account = Account::new(
+        100_000_000u64,
+        class_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    class_account
+        .data_as_mut_slice()
+        .clone_from_slice(&class_account_data);
+    (address, class_account)
+}
 
-    // Mint Data
-    mint_account_data[0..MINT_DATA_WITH_EXTENSIONS.len()]
-        .copy_from_slice(MINT_DATA_WITH_EXTENSIONS);
-    let mut offset = MINT_DATA_WITH_EXTENSIONS.len();
-    // Close Authority Extension
-    mint_account_data[offset..offset + MINT_CLOSE_AUTHORITY_EXTENSION.len()]
-        .copy_from_slice(MINT_CLOSE_AUTHORITY_EXTENSION);
-    offset += MINT_CLOSE_AUTHORITY_EXTENSION.len();
-    // Permanent Delegate Extension
-    mint_account_data[offset..offset + MINT_PERMANENT_DELEGATE_EXTENSION.len()]
-        .copy_from_slice(MINT_PERMANENT_DELEGATE_EXTENSION);
-    offset += MINT_PERMANENT_DELEGATE_EXTENSION.len();
-    // Metadata Pointer Extension
-    mint_account_data[offset..offset + MINT_METADATA_POINTER_EXTENSION.len()]
-        .copy_from_slice(MINT_METADATA_POINTER_EXTENSION);
-    offset += MINT_METADATA_POINTER_EXTENSION.len();
-    // Group Pointer Extension
-    mint_account_data[offset..offset + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()]
-        .copy_from_slice(MINT_GROUP_MEMBER_POINTER_EXTENSION);
-    offset += MINT_GROUP_MEMBER_POINTER_EXTENSION.len();
-    // Metadata Extension
-    mint_account_data[offset..offset + MINT_METADATA_EXTENSION.len()]
-        .copy_from_slice(MINT_METADATA_EXTENSION);
-    offset += MINT_METADATA_EXTENSION.len();
-    // Group Extension
-    mint_account_data[offset..offset + MINT_GROUP_MEMBER_EXTENSION.len()]
-        .copy_from_slice(MINT_GROUP_MEMBER_EXTENSION);
+fn keyed_account_for_legacy_record(
+    class: Pubkey,
+    owner_type: u8,
+    owner: Pubkey,
+    is_frozen: bool,
+    expiry: i64,
+    seed: &[u8],
+    data: &[u8],
+) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), seed],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let record_account_data = LegacyRecord {
+        discriminator: 2,
+        class,
+        owner_type,
+        owner,
+        is_frozen,
+        expiry,
+        seed: make_u8prefix_vec_u8(seed),
+        data: make_remainder_vec(data),
+    }
+    .try_to_vec()
+    .expect("Invalid record");
 
-    // Create the mint account
-    let mut record_mint_account = Account::new(
+    let mut record_account = Account::new(
         100_000_000u64,
-        mint_account_data.len(),
-        &TOKEN_2022_PROGRAM_ID,
+        record_account_data.len(),
+        &Pubkey::from(crate::ID),
     );
-    record_mint_account
+    record_account
         .data_as_mut_slice()
-        .copy_from_slice(&mint_account_data);
+        .clone_from_slice(&record_account_data);
 
-    (address, record_mint_account)
+    (address, record_account)
 }
 
-const MINT_METADATA_EXTENSION_UPDATED: &[u8] = &[
-    19, 0, 92, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
-    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31,
-    190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127,
-    5, 0, 0, 0, 116, 101, 115, 116, 50, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 0,
-    0, 0, 0,
-];
-const MINT_GROUP_MEMBER_EXTENSION_UPDATED: &[u8] = &[
-    23, 0, 72, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
-    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 52, 137, 177, 136, 59, 205, 145, 103,
-    193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152,
-    136, 141, 87, 92, 2, 0, 0, 0, 0, 0, 0, 0,
-];
+fn keyed_account_for_revoked_record(
+    class: Pubkey,
+    owner: Pubkey,
+    revocation_reason: u16,
+    revoked_at: i64,
+) -> (Pubkey, Account) {
+    let (address, mut record_account) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
 
-fn keyed_account_for_updated_mint(record: Pubkey) -> (Pubkey, Account) {
-    let (address, _bump) =
-        Pubkey::find_program_address(&[b"mint", &record.as_ref()], &TREZOA_RECORD_SERVICE_ID);
+    let mut record = Record::from_bytes(&record_account.data).expect("Invalid record");
+    record.is_revoked = true;
+    record.revocation_reason = revocation_reason;
+    record.revoked_at = revoked_at;
 
-    // Base data (82) + 84 (padding + account_type) + Extensions (36 + 36 + 68) + Metadata (83 + name.len() + uri.len())
-    let total_size = MINT_DATA_WITH_EXTENSIONS.len()
-        + MINT_CLOSE_AUTHORITY_EXTENSION.len()
-        + MINT_PERMANENT_DELEGATE_EXTENSION.len()
-        + MINT_METADATA_POINTER_EXTENSION.len()
-        + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()
-        + MINT_METADATA_EXTENSION_UPDATED.len()
-        + MINT_GROUP_MEMBER_EXTENSION_UPDATED.len();
+    record_account
+        .data_as_mut_slice()
+        .clone_from_slice(&record.try_to_vec().expect("Invalid record"));
+    (address, record_account)
+}
 
-    let mut mint_account_data = vec![0u8; total_size];
+fn keyed_account_for_binary_record(
+    class: Pubkey,
+    owner: Pubkey,
+    data: &[u8],
+) -> (Pubkey, Account) {
+    let (address, mut record_account) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", data);
 
-    // Mint Data
-    mint_account_data[0..MINT_DATA_WITH_EXTENSIONS.len()]
-        .copy_from_slice(MINT_DATA_WITH_EXTENSIONS);
-    let mut offset = MINT_DATA_WITH_EXTENSIONS.len();
-    // Close Authority Extension
-    mint_account_data[offset..offset + MINT_CLOSE_AUTHORITY_EXTENSION.len()]
-        .copy_from_slice(MINT_CLOSE_AUTHORITY_EXTENSION);
-    offset += MINT_CLOSE_AUTHORITY_EXTENSION.len();
-    // Permanent Delegate Extension
-    mint_account_data[offset..offset + MINT_PERMANENT_DELEGATE_EXTENSION.len()]
-        .copy_from_slice(MINT_PERMANENT_DELEGATE_EXTENSION);
-    offset += MINT_PERMANENT_DELEGATE_EXTENSION.len();
-    // Metadata Pointer Extension
-    mint_account_data[offset..offset + MINT_METADATA_POINTER_EXTENSION.len()]
-        .copy_from_slice(MINT_METADATA_POINTER_EXTENSION);
-    offset += MINT_METADATA_POINTER_EXTENSION.len();
-    // Group Pointer Extension
-    mint_account_data[offset..offset + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()]
-        .copy_from_slice(MINT_GROUP_MEMBER_POINTER_EXTENSION);
-    offset += MINT_GROUP_MEMBER_POINTER_EXTENSION.len();
-    // Metadata Extension
-    mint_account_data[offset..offset + MINT_METADATA_EXTENSION_UPDATED.len()]
-        .copy_from_slice(MINT_METADATA_EXTENSION_UPDATED);
-    offset += MINT_METADATA_EXTENSION_UPDATED.len();
-    // Group Extension
-    mint_account_data[offset..offset + MINT_GROUP_MEMBER_EXTENSION_UPDATED.len()]
-        .copy_from_slice(MINT_GROUP_MEMBER_EXTENSION_UPDATED);
+    let mut record = Record::from_bytes(&record_account.data).expect("Invalid record");
+    record.content_type = 1;
 
-    // Create the mint account
-    let mut record_mint_account = Account::new(
-        100_000_000u64,
-        mint_account_data.len(),
-        &TOKEN_2022_PROGRAM_ID,
-    );
-    record_mint_account
+    record_account
         .data_as_mut_slice()
-        .copy_from_slice(&mint_account_data);
-
-    (address, record_mint_account)
+        .clone_from_slice(&record.try_to_vec().expect("Invalid record"));
+    (address, record_account)
 }
 
-const MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA: &[u8; 111] = &[
-    19, 0, 107, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19,
-    33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101,
-    31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27,
-    127, 4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 1,
-    0, 0, 0, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 116, 101, 115, 116,
-];
+fn keyed_account_for_writing_record(
+    class: Pubkey,
+    owner: Pubkey,
+    write_state: u8,
+    data: &[u8],
+    staged_payer: Pubkey,
+) -> (Pubkey, Account) {
+    let (address, mut record_account) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", data);
 
-fn keyed_account_for_mint_with_additional_metadata(record: Pubkey) -> (Pubkey, Account) {
-    let (address, _bump) =
-        Pubkey::find_program_address(&[b"mint", &record.as_ref()], &TREZOA_RECORD_SERVICE_ID);
+    let mut record = Record::from_bytes(&record_account.data).expect("Invalid record");
+    record.write_state = write_state;
+    record.hash = [0; 32];
+    record.staged_payer = staged_payer;
 
-    let total_size = MINT_DATA_WITH_EXTENSIONS.len()
-        + MINT_CLOSE_AUTHORITY_EXTENSION.len()
-        + MINT_PERMANENT_DELEGATE_EXTENSION.len()
-        + MINT_METADATA_POINTER_EXTENSION.len()
-        + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()
-        + MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA.len()
-        + MINT_GROUP_MEMBER_EXTENSION.len();
+    record_account
+        .data_as_mut_slice()
+        .clone_from_slice(&record.try_to_vec().expect("Invalid record"));
+    (address, record_account)
+}
 
-    let mut mint_account_data = vec![0u8; total_size];
+fn keyed_account_for_staging_record(
+    class: Pubkey,
+    owner: Pubkey,
+    data: &[u8],
+    staged: &[u8],
+) -> (Pubkey, Account) {
+    let (address, mut record_account) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", &[data, staged].concat());
+
+    let mut record = Record::from_bytes(&record_account.data).expect("Invalid record");
+    record.write_state = 2;
+    record.staged_len = staged.len() as u32;
+    record.hash = make_record_hash(&[0; 32], 0, data);
+
+    record_account
+        .data_as_mut_slice()
+        .clone_from_slice(&record.try_to_vec().expect("Invalid record"));
+    (address, record_account)
+}
+
+fn keyed_account_for_record_with_generation(
+    class: Pubkey,
+    owner: Pubkey,
+    generation: u32,
+) -> (Pubkey, Account) {
+    let (address, mut record_account) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+
+    let mut record = Record::from_bytes(&record_account.data).expect("Invalid record");
+    record.generation = generation;
+
+    record_account
+        .data_as_mut_slice()
+        .clone_from_slice(&record.try_to_vec().expect("Invalid record"));
+    (address, record_account)
+}
+
+fn keyed_account_for_deleted_record(class: Pubkey, generation: u32) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+
+    // First generation tombstones are a single byte
+    let mut tombstone = vec![0xff];
+    if generation != 0 {
+        tombstone.extend_from_slice(&generation.to_le_bytes());
+    }
+
+    let mut record_account =
+        Account::new(100_000_000u64, tombstone.len(), &Pubkey::from(crate::ID));
+    record_account
+        .data_as_mut_slice()
+        .clone_from_slice(&tombstone);
+    (address, record_account)
+}
+
+fn keyed_account_for_record_with_metadata(
+    class: Pubkey,
+    owner_type: u8,
+    owner: Pubkey,
+    is_frozen: bool,
+    expiry: i64,
+    name: &str,
+    metadata: Option<&[u8]>,
+) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), name.as_ref()],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let record_account_data = Record {
+        discriminator: 8,
+        class,
+        owner_type,
+        owner,
+        is_frozen,
+        expiry,
+        is_revoked: false,
+        revocation_reason: 0,
+        revoked_at: 0,
+        version: 0,
+        generation: 0,
+        hash: make_record_hash(&[0; 32], 0, metadata.unwrap_or(METADATA)),
+        write_state: 0,
+        staged_len: 0,
+        staged_payer: Pubkey::default(),
+        content_type: 0,
+        mint_bump: make_record_mint_bump(&address, owner_type),
+        seed: make_u8prefix_vec_u8(name.as_bytes()),
+        data: RemainderVec::<u8>::try_from_slice(metadata.unwrap_or(METADATA)).unwrap(),
+    }
+    .try_to_vec()
+    .expect("Invalid record");
+
+    let mut record_account = Account::new(
+        100_000_000u64,
+        record_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    record_account
+        .data_as_mut_slice()
+        .clone_from_slice(&record_account_data);
+
+    (address, record_account)
+}
+
+/// Fake Metadata that has
+/// - name: "test"
+/// - symbol: "SRS"
+/// - uri: "test"
+/// - additional_metadata: [
+///     { label: "test", value: "test" }
+/// ]
+const METADATA_WITH_ADDITIONAL_METADATA: &[u8] = &[
+    4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 1, 0,
+    0, 0, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 116, 101, 115, 116,
+];
+
+fn keyed_account_for_record_with_metadata_and_additional_metadata(
+    class: Pubkey,
+    owner_type: u8,
+    owner: Pubkey,
+    is_frozen: bool,
+    expiry: i64,
+    name: &str,
+) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), name.as_ref()],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let record_account_data = Record {
+        discriminator: 8,
+        class,
+        owner_type,
+        owner,
+        is_frozen,
+        expiry,
+        is_revoked: false,
+        revocation_reason: 0,
+        revoked_at: 0,
+        version: 0,
+        generation: 0,
+        hash: make_record_hash(&[0; 32], 0, METADATA_WITH_ADDITIONAL_METADATA),
+        write_state: 0,
+        staged_len: 0,
+        staged_payer: Pubkey::default(),
+        content_type: 0,
+        mint_bump: make_record_mint_bump(&address, owner_type),
+        seed: make_u8prefix_vec_u8(name.as_bytes()),
+        data: RemainderVec::<u8>::try_from_slice(METADATA_WITH_ADDITIONAL_METADATA).unwrap(),
+    }
+    .try_to_vec()
+    .expect("Invalid record");
+
+    let mut record_account = Account::new(
+        100_000_000u64,
+        record_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    record_account
+        .data_as_mut_slice()
+        .clone_from_slice(&record_account_data);
+
+    (address, record_account)
+}
+
+const METADATA_WITH_MULTIPLE_ADDITIONAL_METADATA: &[u8] = &[
+    4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 3, 0,
+    0, 0, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 115, 101,
+    115, 116, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 114, 101, 115, 116, 4, 0, 0, 0, 116, 101,
+    115, 116,
+];
+
+fn keyed_account_for_record_with_metadata_and_multiple_additional_metadata(
+    class: Pubkey,
+    owner_type: u8,
+    owner: Pubkey,
+    is_frozen: bool,
+    expiry: i64,
+    name: &str,
+) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), name.as_ref()],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let record_account_data = Record {
+        discriminator: 8,
+        class,
+        owner_type,
+        owner,
+        is_frozen,
+        expiry,
+        is_revoked: false,
+        revocation_reason: 0,
+        revoked_at: 0,
+        version: 0,
+        generation: 0,
+        hash: make_record_hash(&[0; 32], 0, METADATA_WITH_MULTIPLE_ADDITIONAL_METADATA),
+        write_state: 0,
+        staged_len: 0,
+        staged_payer: Pubkey::default(),
+        content_type: 0,
+        mint_bump: make_record_mint_bump(&address, owner_type),
+        seed: make_u8prefix_vec_u8(name.as_bytes()),
+        data: RemainderVec::<u8>::try_from_slice(METADATA_WITH_MULTIPLE_ADDITIONAL_METADATA)
+            .unwrap(),
+    }
+    .try_to_vec()
+    .expect("Invalid record");
+
+    let mut record_account = Account::new(
+        100_000_000u64,
+        record_account_data.len(),
+        &Pubkey::from(crate::ID),
+    );
+    record_account
+        .data_as_mut_slice()
+        .clone_from_slice(&record_account_data);
+
+    (address, record_account)
+}
+
+const MINT_DATA_WITH_EXTENSIONS: &[u8] = &[
+    1, 0, 0, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
+    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+    0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101,
+    42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 1,
+];
+const MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY: &[u8] = &[
+    1, 0, 0, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
+    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0,
+    0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101,
+    42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 1,
+];
+const MINT_CLOSE_AUTHORITY_EXTENSION: &[u8] = &[
+    3, 0, 32, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
+    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127,
+];
+const MINT_PERMANENT_DELEGATE_EXTENSION: &[u8] = &[
+    12, 0, 32, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
+    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127,
+];
+const MINT_METADATA_POINTER_EXTENSION: &[u8] = &[
+    18, 0, 64, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
+    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31,
+    190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127,
+];
+const MINT_GROUP_MEMBER_POINTER_EXTENSION: &[u8] = &[
+    22, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87,
+    188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 44, 183, 51, 50, 60, 76,
+    5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4,
+    96, 81, 27, 127,
+];
+const MINT_METADATA_EXTENSION: &[u8] = &[
+    19, 0, 91, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
+    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31,
+    190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127,
+    4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 0, 0,
+    0, 0,
+];
+const MINT_GROUP_MEMBER_EXTENSION: &[u8] = &[
+    23, 0, 72, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
+    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 52, 137, 177, 136, 59, 205, 145, 103,
+    193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152,
+    136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0,
+];
+
+fn keyed_account_for_mint(record: Pubkey) -> (Pubkey, Account) {
+    let (address, _bump) =
+        Pubkey::find_program_address(&[b"mint", &record.as_ref()], &TREZOA_RECORD_SERVICE_ID);
+
+    // Base data (82) + 84 (padding + account_type) + Extensions (36 + 36 + 68) + Metadata (83 + name.len() + uri.len())
+    let total_size = MINT_DATA_WITH_EXTENSIONS.len()
+        + MINT_CLOSE_AUTHORITY_EXTENSION.len()
+        + MINT_PERMANENT_DELEGATE_EXTENSION.len()
+        + MINT_METADATA_POINTER_EXTENSION.len()
+        + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()
+        + MINT_METADATA_EXTENSION.len()
+        + MINT_GROUP_MEMBER_EXTENSION.len();
+
+    let mut mint_account_data = vec![0u8; total_size];
 
     // Mint Data
     mint_account_data[0..MINT_DATA_WITH_EXTENSIONS.len()]
@@ -552,9 +1152,9 @@ fn keyed_account_for_mint_with_additional_metadata(record: Pubkey) -> (Pubkey, A
         .copy_from_slice(MINT_GROUP_MEMBER_POINTER_EXTENSION);
     offset += MINT_GROUP_MEMBER_POINTER_EXTENSION.len();
     // Metadata Extension
-    mint_account_data[offset..offset + MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA.len()]
-        .copy_from_slice(MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA);
-    offset += MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA.len();
+    mint_account_data[offset..offset + MINT_METADATA_EXTENSION.len()]
+        .copy_from_slice(MINT_METADATA_EXTENSION);
+    offset += MINT_METADATA_EXTENSION.len();
     // Group Extension
     mint_account_data[offset..offset + MINT_GROUP_MEMBER_EXTENSION.len()]
         .copy_from_slice(MINT_GROUP_MEMBER_EXTENSION);
@@ -572,27 +1172,32 @@ fn keyed_account_for_mint_with_additional_metadata(record: Pubkey) -> (Pubkey, A
     (address, record_mint_account)
 }
 
-const MINT_METADATA_EXTENSIONE_WITH_MULTIPLE_ADDITIONAL_METADATA: &[u8; 143] = &[
-    19, 0, 139, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19,
-    33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101,
-    31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27,
-    127, 4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 3,
-    0, 0, 0, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 115, 101,
-    115, 116, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 114, 101, 115, 116, 4, 0, 0, 0, 116, 101,
-    115, 116,
+const MINT_METADATA_EXTENSION_UPDATED: &[u8] = &[
+    19, 0, 92, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
+    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31,
+    190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127,
+    5, 0, 0, 0, 116, 101, 115, 116, 50, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 0,
+    0, 0, 0,
+];
+const MINT_GROUP_MEMBER_EXTENSION_UPDATED: &[u8] = &[
+    23, 0, 72, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19, 33,
+    142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 52, 137, 177, 136, 59, 205, 145, 103,
+    193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152,
+    136, 141, 87, 92, 2, 0, 0, 0, 0, 0, 0, 0,
 ];
 
-fn keyed_account_for_mint_with_multiple_additional_metadata(record: Pubkey) -> (Pubkey, Account) {
+fn keyed_account_for_updated_mint(record: Pubkey) -> (Pubkey, Account) {
     let (address, _bump) =
         Pubkey::find_program_address(&[b"mint", &record.as_ref()], &TREZOA_RECORD_SERVICE_ID);
 
+    // Base data (82) + 84 (padding + account_type) + Extensions (36 + 36 + 68) + Metadata (83 + name.len() + uri.len())
     let total_size = MINT_DATA_WITH_EXTENSIONS.len()
         + MINT_CLOSE_AUTHORITY_EXTENSION.len()
         + MINT_PERMANENT_DELEGATE_EXTENSION.len()
         + MINT_METADATA_POINTER_EXTENSION.len()
         + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()
-        + MINT_METADATA_EXTENSIONE_WITH_MULTIPLE_ADDITIONAL_METADATA.len()
-        + MINT_GROUP_MEMBER_EXTENSION.len();
+        + MINT_METADATA_EXTENSION_UPDATED.len()
+        + MINT_GROUP_MEMBER_EXTENSION_UPDATED.len();
 
     let mut mint_account_data = vec![0u8; total_size];
 
@@ -617,8 +1222,136 @@ fn keyed_account_for_mint_with_multiple_additional_metadata(record: Pubkey) -> (
         .copy_from_slice(MINT_GROUP_MEMBER_POINTER_EXTENSION);
     offset += MINT_GROUP_MEMBER_POINTER_EXTENSION.len();
     // Metadata Extension
-    mint_account_data
-        [offset..offset + MINT_METADATA_EXTENSIONE_WITH_MULTIPLE_ADDITIONAL_METADATA.len()]
+    mint_account_data[offset..offset + MINT_METADATA_EXTENSION_UPDATED.len()]
+        .copy_from_slice(MINT_METADATA_EXTENSION_UPDATED);
+    offset += MINT_METADATA_EXTENSION_UPDATED.len();
+    // Group Extension
+    mint_account_data[offset..offset + MINT_GROUP_MEMBER_EXTENSION_UPDATED.len()]
+        .copy_from_slice(MINT_GROUP_MEMBER_EXTENSION_UPDATED);
+
+    // Create the mint account
+    let mut record_mint_account = Account::new(
+        100_000_000u64,
+        mint_account_data.len(),
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    record_mint_account
+        .data_as_mut_slice()
+        .copy_from_slice(&mint_account_data);
+
+    (address, record_mint_account)
+}
+
+const MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA: &[u8; 111] = &[
+    19, 0, 107, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19,
+    33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101,
+    31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27,
+    127, 4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 1,
+    0, 0, 0, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 116, 101, 115, 116,
+];
+
+fn keyed_account_for_mint_with_additional_metadata(record: Pubkey) -> (Pubkey, Account) {
+    let (address, _bump) =
+        Pubkey::find_program_address(&[b"mint", &record.as_ref()], &TREZOA_RECORD_SERVICE_ID);
+
+    let total_size = MINT_DATA_WITH_EXTENSIONS.len()
+        + MINT_CLOSE_AUTHORITY_EXTENSION.len()
+        + MINT_PERMANENT_DELEGATE_EXTENSION.len()
+        + MINT_METADATA_POINTER_EXTENSION.len()
+        + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()
+        + MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA.len()
+        + MINT_GROUP_MEMBER_EXTENSION.len();
+
+    let mut mint_account_data = vec![0u8; total_size];
+
+    // Mint Data
+    mint_account_data[0..MINT_DATA_WITH_EXTENSIONS.len()]
+        .copy_from_slice(MINT_DATA_WITH_EXTENSIONS);
+    let mut offset = MINT_DATA_WITH_EXTENSIONS.len();
+    // Close Authority Extension
+    mint_account_data[offset..offset + MINT_CLOSE_AUTHORITY_EXTENSION.len()]
+        .copy_from_slice(MINT_CLOSE_AUTHORITY_EXTENSION);
+    offset += MINT_CLOSE_AUTHORITY_EXTENSION.len();
+    // Permanent Delegate Extension
+    mint_account_data[offset..offset + MINT_PERMANENT_DELEGATE_EXTENSION.len()]
+        .copy_from_slice(MINT_PERMANENT_DELEGATE_EXTENSION);
+    offset += MINT_PERMANENT_DELEGATE_EXTENSION.len();
+    // Metadata Pointer Extension
+    mint_account_data[offset..offset + MINT_METADATA_POINTER_EXTENSION.len()]
+        .copy_from_slice(MINT_METADATA_POINTER_EXTENSION);
+    offset += MINT_METADATA_POINTER_EXTENSION.len();
+    // Group Pointer Extension
+    mint_account_data[offset..offset + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()]
+        .copy_from_slice(MINT_GROUP_MEMBER_POINTER_EXTENSION);
+    offset += MINT_GROUP_MEMBER_POINTER_EXTENSION.len();
+    // Metadata Extension
+    mint_account_data[offset..offset + MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA.len()]
+        .copy_from_slice(MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA);
+    offset += MINT_METADATA_EXTENSION_WITH_ADDITIONAL_METADATA.len();
+    // Group Extension
+    mint_account_data[offset..offset + MINT_GROUP_MEMBER_EXTENSION.len()]
+        .copy_from_slice(MINT_GROUP_MEMBER_EXTENSION);
+
+    // Create the mint account
+    let mut record_mint_account = Account::new(
+        100_000_000u64,
+        mint_account_data.len(),
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    record_mint_account
+        .data_as_mut_slice()
+        .copy_from_slice(&mint_account_data);
+
+    (address, record_mint_account)
+}
+
+const MINT_METADATA_EXTENSIONE_WITH_MULTIPLE_ADDITIONAL_METADATA: &[u8; 143] = &[
+    19, 0, 139, 0, 44, 183, 51, 50, 60, 76, 5, 80, 101, 31, 190, 147, 58, 233, 60, 212, 133, 19,
+    33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27, 127, 44, 183, 51, 50, 60, 76, 5, 80, 101,
+    31, 190, 147, 58, 233, 60, 212, 133, 19, 33, 142, 101, 42, 77, 206, 214, 6, 73, 4, 96, 81, 27,
+    127, 4, 0, 0, 0, 116, 101, 115, 116, 3, 0, 0, 0, 83, 82, 83, 4, 0, 0, 0, 116, 101, 115, 116, 3,
+    0, 0, 0, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 115, 101,
+    115, 116, 4, 0, 0, 0, 116, 101, 115, 116, 4, 0, 0, 0, 114, 101, 115, 116, 4, 0, 0, 0, 116, 101,
+    115, 116,
+];
+
+fn keyed_account_for_mint_with_multiple_additional_metadata(record: Pubkey) -> (Pubkey, Account) {
+    let (address, _bump) =
+        Pubkey::find_program_address(&[b"mint", &record.as_ref()], &TREZOA_RECORD_SERVICE_ID);
+
+    let total_size = MINT_DATA_WITH_EXTENSIONS.len()
+        + MINT_CLOSE_AUTHORITY_EXTENSION.len()
+        + MINT_PERMANENT_DELEGATE_EXTENSION.len()
+        + MINT_METADATA_POINTER_EXTENSION.len()
+        + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()
+        + MINT_METADATA_EXTENSIONE_WITH_MULTIPLE_ADDITIONAL_METADATA.len()
+        + MINT_GROUP_MEMBER_EXTENSION.len();
+
+    let mut mint_account_data = vec![0u8; total_size];
+
+    // Mint Data
+    mint_account_data[0..MINT_DATA_WITH_EXTENSIONS.len()]
+        .copy_from_slice(MINT_DATA_WITH_EXTENSIONS);
+    let mut offset = MINT_DATA_WITH_EXTENSIONS.len();
+    // Close Authority Extension
+    mint_account_data[offset..offset + MINT_CLOSE_AUTHORITY_EXTENSION.len()]
+        .copy_from_slice(MINT_CLOSE_AUTHORITY_EXTENSION);
+    offset += MINT_CLOSE_AUTHORITY_EXTENSION.len();
+    // Permanent Delegate Extension
+    mint_account_data[offset..offset + MINT_PERMANENT_DELEGATE_EXTENSION.len()]
+        .copy_from_slice(MINT_PERMANENT_DELEGATE_EXTENSION);
+    offset += MINT_PERMANENT_DELEGATE_EXTENSION.len();
+    // Metadata Pointer Extension
+    mint_account_data[offset..offset + MINT_METADATA_POINTER_EXTENSION.len()]
+        .copy_from_slice(MINT_METADATA_POINTER_EXTENSION);
+    offset += MINT_METADATA_POINTER_EXTENSION.len();
+    // Group Pointer Extension
+    mint_account_data[offset..offset + MINT_GROUP_MEMBER_POINTER_EXTENSION.len()]
+        .copy_from_slice(MINT_GROUP_MEMBER_POINTER_EXTENSION);
+    offset += MINT_GROUP_MEMBER_POINTER_EXTENSION.len();
+    // Metadata Extension
+    mint_account_data
+        [offset..offset + MINT_METADATA_EXTENSIONE_WITH_MULTIPLE_ADDITIONAL_METADATA.len()]
         .copy_from_slice(MINT_METADATA_EXTENSIONE_WITH_MULTIPLE_ADDITIONAL_METADATA);
     offset += MINT_METADATA_EXTENSIONE_WITH_MULTIPLE_ADDITIONAL_METADATA.len();
     // Group Extension
@@ -647,6 +1380,10 @@ const GROUP_MINT_DATA_WITH_EXTENSIONS: &[u8] = &[
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
 ];
+const GROUP_MINT_CLOSE_AUTHORITY_EXTENSION: &[u8] = &[
+    3, 0, 32, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87,
+    188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92,
+];
 const MINT_GROUP_POINTER_EXTENSION: &[u8] = &[
     20, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87,
     188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59,
@@ -665,6 +1402,7 @@ fn keyed_account_for_group(class: Pubkey) -> (Pubkey, Account) {
         Pubkey::find_program_address(&[b"group", &class.as_ref()], &TREZOA_RECORD_SERVICE_ID);
 
     let total_size = GROUP_MINT_DATA_WITH_EXTENSIONS.len()
+        + GROUP_MINT_CLOSE_AUTHORITY_EXTENSION.len()
         + MINT_GROUP_POINTER_EXTENSION.len()
         + MINT_GROUP_EXTENSION.len();
 
@@ -674,6 +1412,10 @@ fn keyed_account_for_group(class: Pubkey) -> (Pubkey, Account) {
     group_account_data[0..GROUP_MINT_DATA_WITH_EXTENSIONS.len()]
         .copy_from_slice(GROUP_MINT_DATA_WITH_EXTENSIONS);
     let mut offset = GROUP_MINT_DATA_WITH_EXTENSIONS.len();
+    // Close Authority Extension
+    group_account_data[offset..offset + GROUP_MINT_CLOSE_AUTHORITY_EXTENSION.len()]
+        .copy_from_slice(GROUP_MINT_CLOSE_AUTHORITY_EXTENSION);
+    offset += GROUP_MINT_CLOSE_AUTHORITY_EXTENSION.len();
     // Group Pointer Extension
     group_account_data[offset..offset + MINT_GROUP_POINTER_EXTENSION.len()]
         .copy_from_slice(MINT_GROUP_POINTER_EXTENSION);
@@ -682,84 +1424,5441 @@ fn keyed_account_for_group(class: Pubkey) -> (Pubkey, Account) {
     group_account_data[offset..offset + MINT_GROUP_EXTENSION.len()]
         .copy_from_slice(MINT_GROUP_EXTENSION);
 
-    let mut group_account = Account::new(
-        100_000_000u64,
-        group_account_data.len(),
-        &TOKEN_2022_PROGRAM_ID,
+    let mut group_account = Account::new(
+        100_000_000u64,
+        group_account_data.len(),
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    group_account
+        .data_as_mut_slice()
+        .copy_from_slice(&group_account_data);
+
+    (address, group_account)
+}
+
+fn keyed_account_for_token(owner: Pubkey, mint: Pubkey, is_frozen: bool) -> (Pubkey, Account) {
+    let (address, _bump) = Pubkey::find_program_address(
+        &[
+            owner.as_ref(),
+            mollusk_svm_programs_token::token2022::ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &mollusk_svm_programs_token::associated_token::ID,
+    );
+
+    let mut token_account_data = [0u8; 170];
+    // Mint
+    token_account_data[0..32].copy_from_slice(&mint.to_bytes());
+    // Owner
+    token_account_data[32..64].copy_from_slice(&owner.to_bytes());
+    // Amount
+    token_account_data[64..72].copy_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]);
+    // Delegate
+    token_account_data[72..108].copy_from_slice(&[0; 36]);
+    // State
+    token_account_data[108] = if is_frozen { 2 } else { 1 };
+    // IsNative
+    token_account_data[109..121].copy_from_slice(&[0; 12]);
+    token_account_data[121..129].copy_from_slice(&[0; 8]);
+    token_account_data[129..165].copy_from_slice(&[0; 36]);
+    // Account type
+    token_account_data[165] = 2;
+    // Extension
+    token_account_data[166..170].copy_from_slice(&[7, 0, 0, 0]);
+
+    // Create the mint account
+    let mut token_account = Account::new(
+        100_000_000u64,
+        token_account_data.len(),
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    token_account
+        .data_as_mut_slice()
+        .copy_from_slice(&token_account_data);
+
+    (address, token_account)
+}
+
+/* Tests */
+
+#[test]
+fn create_class() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateClass {
+        authority,
+        payer: authority,
+        class,
+        system_program,
+    }
+    .instruction(CreateClassInstructionArgs {
+        is_permissioned: false,
+        is_frozen: false,
+        is_non_transferable: false,
+        max_records: 0,
+        bump: make_class_bump(&authority, "test"),
+        name: make_u8prefix_string("test"),
+        metadata: make_remainder_str("test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class).data(&class_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn update_class_metadata() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+
+    // Class Updated
+    let (_, class_data_updated) = keyed_account_for_class(authority, false, false, "test", "test2");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateClassMetadata {
+        authority,
+        payer,
+        class,
+        system_program,
+    }
+    .instruction(UpdateClassMetadataInstructionArgs {
+        metadata: RemainderStr::from_str("test2").unwrap(),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class)
+                .data(&class_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the class_authority != authority of the instruction
+fn update_class_metadata_incorrect_authority() {
+    // Authority
+    let (random_authority, random_authority_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateClassMetadata {
+        authority: random_authority,
+        payer: random_authority,
+        class,
+        system_program,
+    }
+    .instruction(UpdateClassMetadataInstructionArgs {
+        metadata: RemainderStr::from_str("test2").unwrap(),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (random_authority, random_authority_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidAuthority as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_update_class_authority() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // New Authority
+    let (new_authority, _) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    // The one step authority update has been removed in favor of ProposeClassAuthority
+    let mut data = vec![2];
+    data.extend_from_slice(new_authority.as_ref());
+
+    let instruction = Instruction {
+        program_id: TREZOA_RECORD_SERVICE_ID,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(class, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+        data,
+    };
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::InvalidInstructionData)],
+    );
+}
+
+#[test]
+fn update_class_frozen() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class frozen
+    let (_, class_data_frozen) = keyed_account_for_class(authority, false, true, "test", "test");
+
+    let instruction = FreezeClass { authority, class }
+        .instruction(FreezeClassInstructionArgs { is_frozen: true });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(authority, authority_data), (class, class_data)],
+        &[
+            Check::success(),
+            Check::account(&class).data(&class_data_frozen.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn update_class_frozen_already_frozen() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, false, true, "test", "test");
+    // Class frozen
+    let (_, class_data_frozen) = keyed_account_for_class(authority, false, true, "test", "test");
+
+    let instruction = FreezeClass { authority, class }
+        .instruction(FreezeClassInstructionArgs { is_frozen: true });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(authority, authority_data), (class, class_data)],
+        &[
+            Check::success(),
+            Check::account(&class).data(&class_data_frozen.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_buffered_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_writing_record(class, owner, 1, b"te", owner);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateBufferedRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateBufferedRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"te"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_binary_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_binary_record(class, owner, &[0xff, 0xfe]);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 1,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(&[0xff, 0xfe]),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_create_record_invalid_utf8() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, _) = keyed_account_for_binary_record(class, owner, &[0xff, 0xfe]);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(&[0xff, 0xfe]),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::err(ProgramError::InvalidInstructionData),
+        ],
+    );
+}
+
+#[test]
+fn fail_create_record_invalid_bump() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test").wrapping_sub(1),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidPda as u32,
+        ))],
+    );
+}
+
+#[test]
+/// Fails because the bump is valid but not the canonical one
+fn fail_create_record_non_canonical_bump() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record at the address of another valid bump
+    let (record, bump) = make_non_canonical_program_address(&[b"record", class.as_ref(), b"test"]);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump,
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidPda as u32,
+        ))],
+    );
+}
+
+#[test]
+fn create_record_with_max_records() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_with_counts(1, 0, 2);
+    // Class with the new record counted
+    let (_, class_data_updated) = keyed_account_for_class_with_counts(2, 0, 2);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+            Check::account(&class).data(&class_data_updated.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_create_record_max_records_reached() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_with_counts(2, 0, 2);
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::err(ProgramError::Custom(
+                TrezoaRecordServiceError::MaxRecordsReached as u32,
+            )),
+        ],
+    );
+}
+
+#[test]
+fn recreate_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Tombstone of the deleted record
+    let (record, record_data) = keyed_account_for_deleted_record(class, 0);
+    // Record of the next generation
+    let (_, record_data_recreated) = keyed_account_for_record_with_generation(class, owner, 1);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = RecreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(RecreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, record_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_recreated.data)
+                .build(),
+            Check::account(&record).rent_exempt().build(),
+        ],
+    );
+}
+
+#[test]
+fn recreate_record_next_generation() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Tombstone of a recreated record
+    let (record, record_data) = keyed_account_for_deleted_record(class, 1);
+    // Record of the next generation
+    let (_, record_data_recreated) = keyed_account_for_record_with_generation(class, owner, 2);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = RecreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(RecreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, record_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_recreated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_recreate_record_not_deleted() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Live record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = RecreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(RecreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, record_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::err(ProgramError::Custom(
+                TrezoaRecordServiceError::RecordNotDeleted as u32,
+            )),
+        ],
+    );
+}
+
+#[test]
+fn create_record_with_fee() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_with_fee(1_000_000, RANDOM_PUBKEY);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: Some(RANDOM_PUBKEY),
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+            (RANDOM_PUBKEY, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+            Check::account(&RANDOM_PUBKEY).lamports(1_000_000).build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_create_record_wrong_treasury() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_with_fee(1_000_000, RANDOM_PUBKEY);
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: Some(NEW_OWNER),
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+            (NEW_OWNER, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidTreasury as u32,
+        ))],
+    );
+}
+
+#[test]
+fn create_record_with_proof() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Allowlist
+    let tree = AllowlistTree::new(&[NEW_OWNER, owner, RANDOM_PUBKEY]);
+    // Class
+    let (class, class_data) = keyed_account_for_class_with_merkle_root(true, tree.root());
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordWithProof {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordWithProofInstructionArgs {
+        proof: make_merkle_proof(&tree.proof(&owner).expect("Owner not in allowlist")),
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_create_record_with_proof_not_in_allowlist() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Allowlist, without the owner
+    let tree = AllowlistTree::new(&[NEW_OWNER, RANDOM_PUBKEY]);
+    // Class
+    let (class, class_data) = keyed_account_for_class_with_merkle_root(true, tree.root());
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordWithProof {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordWithProofInstructionArgs {
+        // Proof of another owner
+        proof: make_merkle_proof(&tree.proof(&NEW_OWNER).expect("Owner not in allowlist")),
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::NotInAllowlist as u32,
+        ))],
+    );
+}
+
+#[test]
+fn verify_merkle_proof_off_chain() {
+    // The off-chain hash matches sha256, so proofs don't pass against zeroed roots
+    assert_eq!(
+        crate::utils::hashv(&[b"test", b"data"]),
+        trezoa_program::hash::hashv(&[b"test", b"data"]).to_bytes()
+    );
+
+    let tree = AllowlistTree::new(&[NEW_OWNER, OWNER, RANDOM_PUBKEY]);
+    let proof = tree.proof(&OWNER).expect("Owner not in allowlist").concat();
+
+    assert!(crate::utils::verify_merkle_proof(&tree.root(), OWNER.as_ref(), &proof));
+    assert!(!crate::utils::verify_merkle_proof(&tree.root(), AUTHORITY.as_ref(), &proof));
+    assert!(!crate::utils::verify_merkle_proof(&[0; 32], OWNER.as_ref(), &proof));
+}
+
+#[test]
+fn create_record_from_signature() {
+    // Authority, attesting records with its Ed25519 key
+    let signer = make_signing_authority(1);
+    let authority = Pubkey::new_from_array(signer.public.to_bytes());
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    // Ed25519 instruction, with the authority signature over the record
+    let ed25519_instruction = make_ed25519_instruction(&[(
+        &signer,
+        &record_attestation(&class, b"test", make_record_bump(&class, b"test"), &owner, 0, 0, b"test"),
+    )]);
+
+    let instruction = CreateRecordFromSignature {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        instructions_sysvar: sysvar::instructions::ID,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordFromSignatureInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    // Instructions Sysvar
+    let (instructions_sysvar, instructions_sysvar_data) =
+        keyed_account_for_instructions_sysvar(&[&ed25519_instruction, &instruction], 1);
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+            (instructions_sysvar, instructions_sysvar_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_record_from_signature_later_in_transaction() {
+    // Authority, attesting records with its Ed25519 key
+    let signer = make_signing_authority(1);
+    let authority = Pubkey::new_from_array(signer.public.to_bytes());
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    // Ed25519 instruction with two signatures, the authority one being the second
+    let ed25519_instruction = make_ed25519_instruction(&[
+        (&make_signing_authority(2), b"another message"),
+        (
+            &signer,
+            &record_attestation(&class, b"test", make_record_bump(&class, b"test"), &owner, 0, 0, b"test"),
+        ),
+    ]);
+
+    let instruction = CreateRecordFromSignature {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        instructions_sysvar: sysvar::instructions::ID,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordFromSignatureInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    // Instructions Sysvar, with the Ed25519 instruction after the current one
+    let (instructions_sysvar, instructions_sysvar_data) =
+        keyed_account_for_instructions_sysvar(&[&instruction, &ed25519_instruction], 0);
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+            (instructions_sysvar, instructions_sysvar_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_create_record_from_signature_not_authority() {
+    // Authority, attesting records with its Ed25519 key
+    let signer = make_signing_authority(1);
+    let authority = Pubkey::new_from_array(signer.public.to_bytes());
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    // Ed25519 instruction, signed by another key
+    let ed25519_instruction = make_ed25519_instruction(&[(
+        &make_signing_authority(2),
+        &record_attestation(&class, b"test", make_record_bump(&class, b"test"), &owner, 0, 0, b"test"),
+    )]);
+
+    let instruction = CreateRecordFromSignature {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        instructions_sysvar: sysvar::instructions::ID,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordFromSignatureInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    // Instructions Sysvar
+    let (instructions_sysvar, instructions_sysvar_data) =
+        keyed_account_for_instructions_sysvar(&[&ed25519_instruction, &instruction], 1);
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+            (instructions_sysvar, instructions_sysvar_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidSignature as u32,
+        ))],
+    );
+}
+
+#[test]
+/// Fails because the authority attested the record as utf-8 and the relayer
+/// creates it as binary
+fn fail_create_record_from_signature_other_content_type() {
+    // Authority, attesting records with its Ed25519 key
+    let signer = make_signing_authority(1);
+    let authority = Pubkey::new_from_array(signer.public.to_bytes());
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    // Ed25519 instruction, with the authority signature over a utf-8 record
+    let ed25519_instruction = make_ed25519_instruction(&[(
+        &signer,
+        &record_attestation(&class, b"test", make_record_bump(&class, b"test"), &owner, 0, 0, b"test"),
+    )]);
+
+    let instruction = CreateRecordFromSignature {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        instructions_sysvar: sysvar::instructions::ID,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordFromSignatureInstructionArgs {
+        expiration: 0,
+        content_type: 1,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    // Instructions Sysvar
+    let (instructions_sysvar, instructions_sysvar_data) =
+        keyed_account_for_instructions_sysvar(&[&ed25519_instruction, &instruction], 1);
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+            (instructions_sysvar, instructions_sysvar_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidSignature as u32,
+        ))],
+    );
+}
+
+#[test]
+fn batch_create_records() {
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class with the new records counted
+    let (_, class_data_updated) = keyed_account_for_class_with_counts(2, 0, 0);
+    // Records
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let (record_2, record_2_data) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test2", b"test2");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = BatchCreateRecords {
+        payer,
+        class,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction_with_remaining_accounts(
+        BatchCreateRecordsInstructionArgs {
+            records: make_batch_records(&[
+                BatchRecord {
+                    expiration: 0,
+                    content_type: 0,
+                    bump: make_record_bump(&class, b"test"),
+                    seed: make_u8prefix_vec_u8(b"test"),
+                    data: b"test".to_vec(),
+                },
+                BatchRecord {
+                    expiration: 0,
+                    content_type: 0,
+                    bump: make_record_bump(&class, b"test2"),
+                    seed: make_u8prefix_vec_u8(b"test2"),
+                    data: b"test2".to_vec(),
+                },
+            ]),
+        },
+        &[
+            AccountMeta::new_readonly(OWNER, false),
+            AccountMeta::new(record, false),
+            AccountMeta::new_readonly(NEW_OWNER, false),
+            AccountMeta::new(record_2, false),
+        ],
+    );
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (payer, payer_data),
+            (class, class_data),
+            (system_program, system_program_data),
+            (OWNER, Account::default()),
+            (record, Account::default()),
+            (NEW_OWNER, Account::default()),
+            (record_2, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+            Check::account(&record_2).data(&record_2_data.data).build(),
+            Check::account(&class).data(&class_data_updated.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_record_with_metadata() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordTokenizable {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordTokenizableInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        metadata: Metadata {
+            name: make_u32prefix_string("test"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![],
+        },
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_record_with_metadata_and_additional_metadata() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_record_with_metadata_and_additional_metadata(
+        class, 0, owner, false, 0, "test",
+    );
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecordTokenizable {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordTokenizableInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        metadata: Metadata {
+            name: make_u32prefix_string("test"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![AdditionalMetadata {
+                label: make_u32prefix_string("test"),
+                value: make_u32prefix_string("test"),
+            }],
+        },
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn create_permissioned_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: authority,
+        class,
+        record,
+        system_program,
+        authority: Some(authority),
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
+        seed: make_u8prefix_vec_u8(b"test"),
+        data: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+            (authority, authority_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&record_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn update_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record updated
+    let (_, mut record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
+    chain_record_update(&mut record_data_updated, &record_data, 0, b"test2");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn compare_and_swap_record_data() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record updated
+    let (_, mut record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
+    chain_record_update(&mut record_data_updated, &record_data, 0, b"test2");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CompareAndSwapRecordData {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction(CompareAndSwapRecordDataInstructionArgs {
+        expected_version: 0,
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_compare_and_swap_record_data_version_mismatch() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CompareAndSwapRecordData {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction(CompareAndSwapRecordDataInstructionArgs {
+        expected_version: 1,
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::err(ProgramError::Custom(
+                TrezoaRecordServiceError::RecordVersionMismatch as u32,
+            )),
+        ],
+    );
+}
+
+#[test]
+fn compare_and_swap_record_expiry() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record, already updated once
+    let (record, original_record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let (_, mut record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
+    chain_record_update(&mut record_data, &original_record_data, 0, b"test2");
+    // Record updated
+    let (_, mut record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 1000, b"test", b"test2");
+    chain_record_update(&mut record_data_updated, &record_data, 1, &1000i64.to_le_bytes());
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CompareAndSwapRecordExpiry {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(CompareAndSwapRecordExpiryInstructionArgs {
+        expected_version: 1,
+        expiry: 1000,
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn patch_record_data() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record patched
+    let (_, mut record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"testing");
+    chain_record_update(&mut record_data_updated, &record_data, 0, b"testing");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = PatchRecordData {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction(PatchRecordDataInstructionArgs {
+        offset: 2,
+        truncate: false,
+        patch: make_remainder_vec(b"sting"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn patch_record_data_truncate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record patched
+    let (_, mut record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"to");
+    chain_record_update(&mut record_data_updated, &record_data, 0, b"to");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = PatchRecordData {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction(PatchRecordDataInstructionArgs {
+        offset: 1,
+        truncate: true,
+        patch: make_remainder_vec(b"o"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_patch_record_data_offset_out_of_bounds() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = PatchRecordData {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction(PatchRecordDataInstructionArgs {
+        offset: 5,
+        truncate: false,
+        patch: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::err(ProgramError::InvalidArgument),
+        ],
+    );
+}
+
+#[test]
+fn begin_record_write() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record being written, its data is kept until the write is finalized
+    let (_, record_data_updated) = keyed_account_for_staging_record(class, OWNER, b"test", b"");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = BeginRecordWrite {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn write_record_chunk() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_writing_record(class, OWNER, 1, b"te", Pubkey::default());
+    // Record with the chunk
+    let (_, record_data_updated) =
+        keyed_account_for_writing_record(class, OWNER, 1, b"test", Pubkey::default());
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = WriteRecordChunk {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(WriteRecordChunkInstructionArgs {
+        offset: 2,
+        chunk: make_remainder_vec(b"st"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_write_record_chunk_not_writing() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = WriteRecordChunk {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(WriteRecordChunkInstructionArgs {
+        offset: 4,
+        chunk: make_remainder_vec(b"test"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::err(ProgramError::Custom(
+                TrezoaRecordServiceError::RecordNotWriting as u32,
+            )),
+        ],
+    );
+}
+
+#[test]
+fn write_staged_record_chunk() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_staging_record(class, OWNER, b"test", b"te");
+    // Record with the chunk staged after its data
+    let (_, record_data_updated) =
+        keyed_account_for_staging_record(class, OWNER, b"test", b"test2");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = WriteRecordChunk {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(WriteRecordChunkInstructionArgs {
+        offset: 2,
+        chunk: make_remainder_vec(b"st2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn write_buffered_record_chunk_by_payer() {
+    // Payer of the buffered record, not allowed to update data by the class policy
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_writing_record(class, OWNER, 1, b"te", payer);
+    // Record with the chunk
+    let (_, record_data_updated) =
+        keyed_account_for_writing_record(class, OWNER, 1, b"test", payer);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = WriteRecordChunk {
+        authority: payer,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(WriteRecordChunkInstructionArgs {
+        offset: 2,
+        chunk: make_remainder_vec(b"st"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn finalize_buffered_record_write() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_writing_record(class, OWNER, 1, b"test", Pubkey::default());
+    // Record finalized
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = FinalizeRecordWrite {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn finalize_record_write() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (_, original_record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let (record, record_data) = keyed_account_for_staging_record(class, OWNER, b"test", b"test2");
+    // Record finalized
+    let (_, mut record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
+    chain_record_update(&mut record_data_updated, &original_record_data, 0, b"test2");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = FinalizeRecordWrite {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn cancel_buffered_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_with_counts(1, 0, 0);
+    // Class without the record
+    let (_, class_data_updated) = keyed_account_for_class_with_counts(0, 0, 0);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_writing_record(class, owner, 1, b"te", payer);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CancelRecordWrite {
+        authority: owner,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&[0xff]).build(),
+            Check::account(&class)
+                .data(&class_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the owner cancels the buffered record with the rent going to
+/// itself instead of the payer of the record
+fn fail_cancel_buffered_record_other_payer() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, _) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_with_counts(1, 0, 0);
+    // Record
+    let (record, record_data) = keyed_account_for_writing_record(class, owner, 1, b"te", payer);
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CancelRecordWrite {
+        authority: owner,
+        payer: owner,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidPayer as u32,
+        ))],
+    );
+}
+
+#[test]
+fn cancel_record_write() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_staging_record(class, OWNER, b"test", b"test2");
+    // Record back to its data
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CancelRecordWrite {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_update_record_writing() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_writing_record(class, OWNER, 1, b"te", Pubkey::default());
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::RecordWriting as u32,
+        ))],
+    );
+}
+
+#[test]
+fn update_record_with_metadata() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    // New metadata
+    let new_metadata = Metadata {
+        name: make_u32prefix_string("test2"),
+        symbol: make_u32prefix_string("SRS"),
+        uri: make_u32prefix_string("test"),
+        additional_metadata: vec![],
+    };
+    // Record updated
+    let (_, mut record_data_updated) = keyed_account_for_record_with_metadata(
+        class,
+        0,
+        OWNER,
+        false,
+        0,
+        "test",
+        Some(&new_metadata.try_to_vec().unwrap()),
+    );
+    chain_record_update(
+        &mut record_data_updated,
+        &record_data,
+        0,
+        &new_metadata.try_to_vec().unwrap(),
+    );
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecordTokenizable {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction(UpdateRecordTokenizableInstructionArgs {
+        metadata: Metadata {
+            name: make_u32prefix_string("test2"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![],
+        },
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because class authority != from authority of the instruction
+fn update_record_with_delegate_incorrect_authority() {
+    // Authority
+    let (random_authority, random_authority_data) = keyed_account_for_random_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecord {
+        authority: random_authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (random_authority, random_authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (system_program, system_program_data),
+            (class, class_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidAuthority as u32,
+        ))],
+    );
+}
+
+#[test]
+fn update_class_expiry() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = UpdateRecordExpiry {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(UpdateRecordExpiryInstructionArgs {
+        expiry: 1000,
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    // Record updated
+    let (_, mut record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 1000, b"test", b"test");
+    chain_record_update(&mut record_data_updated, &record_data, 1, &1000i64.to_le_bytes());
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (system_program, system_program_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn transfer_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+
+    let instruction = TransferRecord {
+        authority: owner,
+        record,
+        class,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: Pubkey::new_from_array([0xcc; 32]),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(owner, owner_data), (record, record_data), (class, class_data)],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn transfer_record_with_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+
+    let instruction = TransferRecord {
+        authority,
+        record,
+        class,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: Pubkey::new_from_array([0xcc; 32]),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the record is frozen
+fn fail_transfer_record_frozen() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+
+    let instruction = TransferRecord {
+        authority: owner,
+        record,
+        class,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: Pubkey::new_from_array([0xcc; 32]),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(owner, owner_data), (record, record_data), (class, class_data)],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::RecordFrozen as u32,
+        ))],
+    );
+}
+
+#[test]
+/// Fails because the record is expired
+fn fail_transfer_record_expired() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 100, b"test", b"test");
+
+    let instruction = TransferRecord {
+        authority: owner,
+        record,
+        class,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: Pubkey::new_from_array([0xcc; 32]),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+    mollusk.sysvars.clock.unix_timestamp = 200;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(owner, owner_data), (record, record_data), (class, class_data)],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::RecordExpired as u32,
+        ))],
+    );
+}
+
+#[test]
+fn delete_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    let instruction = DeleteRecord {
+        authority: owner,
+        payer,
+        record,
+        class,
+        token2022_program: None,
+        mint: None,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&[0xff]).build(),
+        ],
+    );
+}
+
+#[test]
+fn delete_recreated_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record of the second generation
+    let (record, record_data) = keyed_account_for_record_with_generation(class, OWNER, 1);
+    // Tombstone keeping the generation
+    let (_, tombstone_data) = keyed_account_for_deleted_record(class, 1);
+
+    let instruction = DeleteRecord {
+        authority: owner,
+        payer,
+        record,
+        class,
+        token2022_program: None,
+        mint: None,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&tombstone_data.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn delete_record_decrements_record_count() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_with_counts(1, 0, 0);
+    // Class with the record uncounted
+    let (_, class_data_updated) = keyed_account_for_class_with_counts(0, 0, 0);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    let instruction = DeleteRecord {
+        authority: owner,
+        payer,
+        record,
+        class,
+        token2022_program: None,
+        mint: None,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&[0xff]).build(),
+            Check::account(&class).data(&class_data_updated.data).build(),
+        ],
+    );
+}
+
+#[test]
+fn delete_record_with_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    let instruction = DeleteRecord {
+        authority,
+        payer,
+        record,
+        class,
+        token2022_program: None,
+        mint: None,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&[0xff]).build(),
+        ],
+    );
+}
+
+#[test]
+fn delete_tokenized_record_with_no_supply() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record, _bump) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mut mint_data) = keyed_account_for_mint(record);
+    mint_data.data_as_mut_slice()[..MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY.len()].copy_from_slice(MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY);
+    // Record
+    let (_, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // Token2022 Program
+    let (token2022_program, token2022_program_data) = mollusk_svm_programs_token::token2022::keyed_account();
+   
+    let instruction = DeleteRecord {
+        authority: owner,
+        payer: owner,
+        record,
+        class,
+        token2022_program: Some(token2022_program),
+        mint: Some(mint),
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (mint, mint_data),
+            (token2022_program, token2022_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&[0xff]).build(),
+            Check::account(&mint).data(&[]).build(),
+        ],
+    );
+}
+
+#[test]
+fn close_expired_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 100, b"test", b"test");
+
+    let instruction = CloseExpiredRecord {
+        record,
+        owner,
+        class,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+    mollusk.sysvars.clock.unix_timestamp = 200;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (record, record_data),
+            (owner, owner_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&[0xff]).build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the record has not expired yet
+fn fail_close_expired_record_not_expired() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 300, b"test", b"test");
+
+    let instruction = CloseExpiredRecord {
+        record,
+        owner,
+        class,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+    mollusk.sysvars.clock.unix_timestamp = 200;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (record, record_data),
+            (owner, owner_data),
+            (class, class_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::RecordNotExpired as u32,
+        ))],
+    );
+}
+
+#[test]
+fn freeze_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record frozen
+    let (_, record_data_frozen) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+
+    let instruction = FreezeRecord {
+        authority,
+        record,
+        class,
+        class_delegate: None,
+    }
+    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(authority, authority_data), (record, record_data), (class, class_data)],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_frozen.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn batch_freeze_records() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Records
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let (record_2, record_2_data) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test2", b"test2");
+    // Records frozen
+    let (_, record_data_frozen) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+    let (_, record_2_data_frozen) =
+        keyed_account_for_record(class, 0, NEW_OWNER, true, 0, b"test2", b"test2");
+
+    let instruction = BatchFreezeRecords {
+        authority,
+        class,
+        class_delegate: None,
+    }
+    .instruction_with_remaining_accounts(
+        BatchFreezeRecordsInstructionArgs { is_frozen: true },
+        &[
+            AccountMeta::new(record, false),
+            AccountMeta::new(record_2, false),
+        ],
+    );
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (record, record_data),
+            (record_2, record_2_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_frozen.data)
+                .build(),
+            Check::account(&record_2)
+                .data(&record_2_data_frozen.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn batch_delete_records() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class, letting the authority delete records
+    let (class, class_data) = keyed_account_for_class_with_policy(
+        AUTHORITY,
+        false,
+        false,
+        "test",
+        "test",
+        ClassPolicy {
+            delete: Policy::Either,
+            ..make_default_class_policy(false)
+        },
+    );
+    // Records
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let (record_2, record_2_data) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test2", b"test2");
+
+    let instruction = BatchDeleteRecords {
+        authority,
+        payer,
+        class,
+        class_delegate: None,
+    }
+    .instruction_with_remaining_accounts(&[
+        AccountMeta::new(record, false),
+        AccountMeta::new(record_2, false),
+    ]);
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (class, class_data),
+            (record, record_data),
+            (record_2, record_2_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&[0xff]).build(),
+            Check::account(&record_2).data(&[0xff]).build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_batch_delete_records_owner_policy() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class, only letting owners delete records
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    let instruction = BatchDeleteRecords {
+        authority,
+        payer,
+        class,
+        class_delegate: None,
+    }
+    .instruction_with_remaining_accounts(&[AccountMeta::new(record, false)]);
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (class, class_data),
+            (record, record_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::NotOwnerOrDelegate as u32,
+        ))],
+    );
+}
+
+#[test]
+fn freeze_record_already_frozen() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+    // Record frozen
+    let (_, record_data_frozen) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+
+    let instruction = FreezeRecord {
+        authority,
+        record,
+        class,
+        class_delegate: None,
+    }
+    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_frozen.data)
+                .build(),
+        ],
+    );
+}
+
+// `[1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 20, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 21, 0, 80, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0]`,
+// `[1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 20, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 21, 0, 80, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255]`
+
+#[test]
+fn mint_record_token() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, mint_data) = keyed_account_for_mint(record);
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(MintTokenizedRecordInstructionArgs {
+        mint_bump: make_mint_bump(&record),
+        group_bump: make_group_bump(&class),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint).data(&mint_data.data).build(),
+            Check::account(&group).data(&group_data.data).build(),
+            Check::account(&token_account)
+                .data(&token_account_data.data)
+                .build(),
+            Check::account(&group).rent_exempt().build(),
+        ],
+    );
+}
+
+#[test]
+fn mint_record_token_with_additional_metadata() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) = keyed_account_for_record_with_metadata_and_additional_metadata(
+        class, 0, owner, false, 0, "test",
+    );
+    // Mint
+    let (mint, mint_data) = keyed_account_for_mint_with_additional_metadata(record);
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(MintTokenizedRecordInstructionArgs {
+        mint_bump: make_mint_bump(&record),
+        group_bump: make_group_bump(&class),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint).data(&mint_data.data).build(),
+            Check::account(&group).data(&group_data.data).build(),
+            Check::account(&token_account)
+                .data(&token_account_data.data)
+                .build(),
+            Check::account(&mint).rent_exempt().build(),
+        ],
+    );
+}
+
+#[test]
+fn mint_record_token_with_multiple_additional_metadata() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata_and_multiple_additional_metadata(
+            class, 0, owner, false, 0, "test",
+        );
+    // Mint
+    let (mint, mint_data) = keyed_account_for_mint_with_multiple_additional_metadata(record);
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(MintTokenizedRecordInstructionArgs {
+        mint_bump: make_mint_bump(&record),
+        group_bump: make_group_bump(&class),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint).data(&mint_data.data).build(),
+            Check::account(&group).data(&group_data.data).build(),
+            Check::account(&token_account)
+                .data(&token_account_data.data)
+                .build(),
+            Check::account(&mint).rent_exempt().build(),
+        ],
+    );
+}
+
+#[test]
+fn mint_record_token_with_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, mint_data) = keyed_account_for_mint(record);
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: authority,
+        authority,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(MintTokenizedRecordInstructionArgs {
+        mint_bump: make_mint_bump(&record),
+        group_bump: make_group_bump(&class),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (authority, authority_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint).data(&mint_data.data).build(),
+            Check::account(&group).data(&group_data.data).build(),
+            Check::account(&token_account)
+                .data(&token_account_data.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn freeze_tokenized_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+    // ATA updated
+    let (_, token_account_data_updated) = keyed_account_for_token(OWNER, mint, true);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = FreezeTokenizedRecord {
+        authority,
+        record,
+        mint,
+        token_account,
+        class,
+        token2022,
+        class_delegate: None,
+    }
+    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (class, class_data),
+            (token2022, token2022_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&token_account)
+                .data(&token_account_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+/// Build the program from the tree once, so that the compute units are
+/// measured on the current code rather than on a stale artifact
+static BUILD_PROGRAM: Once = Once::new();
+
+/// Mollusk with the program built from the tree and the token programs
+fn mollusk_with_built_program() -> Mollusk {
+    BUILD_PROGRAM.call_once(|| {
+        let status = Command::new("cargo")
+            .arg("build-sbf")
+            .arg("--manifest-path")
+            .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+            .status()
+            .expect("cargo build-sbf is required to measure compute units");
+        assert!(status.success(), "Failed to build the program");
+    });
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk
+}
+
+/// Seed whose PDA has the first bump, and seed whose PDA has a bump found
+/// after at least two more tries, `bump` giving the bump of a seed or 0 to
+/// skip it
+fn make_compute_units_seeds(bump: impl Fn(&str) -> u8) -> (String, String) {
+    let seeds: Vec<String> = (0..u8::MAX).map(|i| format!("test{i:03}")).collect();
+
+    let first_bump_seed = seeds.iter().find(|seed| bump(seed) == u8::MAX).unwrap();
+    let later_bump_seed = seeds
+        .iter()
+        .find(|seed| (1..=u8::MAX - 2).contains(&bump(seed)))
+        .unwrap();
+
+    (first_bump_seed.clone(), later_bump_seed.clone())
+}
+
+/// Bump of the mint of the record with `seed`, or 0 if the token account of
+/// the owner has a later bump, which would add the cost of deriving it
+fn make_compute_units_mint_bump(class: &Pubkey, seed: &str) -> u8 {
+    let (record, _) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), seed.as_bytes()],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, _) = keyed_account_for_mint(record);
+
+    let (_, token_account_bump) = Pubkey::find_program_address(
+        &[
+            OWNER.as_ref(),
+            mollusk_svm_programs_token::token2022::ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &mollusk_svm_programs_token::associated_token::ID,
+    );
+
+    if token_account_bump == u8::MAX {
+        make_mint_bump(&record)
+    } else {
+        0
+    }
+}
+
+/// Process the instruction and return the compute units consumed
+fn measure_compute_units(
+    mollusk: &Mollusk,
+    instruction: &Instruction,
+    accounts: &[(Pubkey, Account)],
+) -> u64 {
+    mollusk
+        .process_and_validate_instruction(instruction, accounts, &[Check::success()])
+        .compute_units_consumed
+}
+
+fn measure_create_class(mollusk: &Mollusk, name: &str) -> u64 {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, _) = keyed_account_for_class(authority, false, false, name, "test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateClass {
+        authority,
+        payer: authority,
+        class,
+        system_program,
+    }
+    .instruction(CreateClassInstructionArgs {
+        is_permissioned: false,
+        is_frozen: false,
+        is_non_transferable: false,
+        max_records: 0,
+        bump: make_class_bump(&authority, name),
+        name: make_u8prefix_string(name),
+        metadata: make_remainder_str("test"),
+    });
+
+    measure_compute_units(
+        mollusk,
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, Account::default()),
+            (system_program, system_program_data),
+        ],
+    )
+}
+
+fn measure_create_record(mollusk: &Mollusk, seed: &str) -> u64 {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, _) =
+        keyed_account_for_record(class, 0, owner, false, 0, seed.as_bytes(), b"test");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = CreateRecord {
+        owner,
+        payer: owner,
+        class,
+        record,
+        system_program,
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
+    }
+    .instruction(CreateRecordInstructionArgs {
+        expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, seed.as_bytes()),
+        seed: make_u8prefix_vec_u8(seed.as_bytes()),
+        data: make_remainder_vec(b"test"),
+    });
+
+    measure_compute_units(
+        mollusk,
+        &instruction,
+        &[
+            (owner, owner_data),
+            (class, class_data),
+            (record, Account::default()),
+            (system_program, system_program_data),
+        ],
+    )
+}
+
+fn measure_mint_tokenized_record(mollusk: &Mollusk, seed: &str) -> u64 {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, seed, None);
+    // Mint
+    let (mint, _) = keyed_account_for_mint(record);
+    // Group
+    let (group, _) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, _) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(MintTokenizedRecordInstructionArgs {
+        mint_bump: make_mint_bump(&record),
+        group_bump: make_group_bump(&class),
+    });
+
+    measure_compute_units(
+        mollusk,
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+    )
+}
+
+fn measure_transfer_tokenized_record(mollusk: &Mollusk, seed: &str) -> u64 {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), seed.as_bytes()],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, seed.as_bytes(), b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class,
+        class_delegate: None,
+    }
+    .instruction();
+
+    measure_compute_units(
+        mollusk,
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+        ],
+    )
+}
+
+fn measure_freeze_tokenized_record(mollusk: &Mollusk, seed: &str) -> u64 {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), seed.as_bytes()],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, seed.as_bytes(), b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = FreezeTokenizedRecord {
+        authority,
+        record,
+        mint,
+        token_account,
+        class,
+        token2022,
+        class_delegate: None,
+    }
+    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+
+    measure_compute_units(
+        mollusk,
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (class, class_data),
+            (token2022, token2022_data),
+        ],
+    )
+}
+
+fn measure_burn_tokenized_record(mollusk: &Mollusk, seed: &str) -> u64 {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), seed.as_bytes()],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, seed.as_bytes(), b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = BurnTokenizedRecord {
+        authority: owner,
+        payer,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class,
+        class_delegate: None,
+    }
+    .instruction();
+
+    measure_compute_units(
+        mollusk,
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (token2022, token2022_data),
+        ],
+    )
+}
+
+fn measure_delete_tokenized_record(mollusk: &Mollusk, seed: &str) -> u64 {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint, its token was burned outside of BurnTokenizedRecord
+    let (record, _) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), seed.as_bytes()],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mut mint_data) = keyed_account_for_mint(record);
+    mint_data.data_as_mut_slice()[..MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY.len()]
+        .copy_from_slice(MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY);
+    // Record
+    let (_, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, seed.as_bytes(), b"test");
+    // Token2022 Program
+    let (token2022_program, token2022_program_data) =
+        mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = DeleteRecord {
+        authority: owner,
+        payer: owner,
+        record,
+        class,
+        token2022_program: Some(token2022_program),
+        mint: Some(mint),
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction();
+
+    measure_compute_units(
+        mollusk,
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (mint, mint_data),
+            (token2022_program, token2022_program_data),
+        ],
+    )
+}
+
+type MeasureComputeUnits = fn(&Mollusk, &str) -> u64;
+
+type SeedBump<'a> = &'a dyn Fn(&str) -> u8;
+
+#[test]
+/// Creating an account only pays for the canonical bump check, one
+/// `create_program_address` for every bump above the one passed in
+fn create_accounts_compute_units() {
+    let mollusk = mollusk_with_built_program();
+    let create_program_address_units = mollusk.compute_budget.create_program_address_units;
+
+    let (class, _) = keyed_account_for_class_default();
+
+    let class_bump = |name: &str| make_class_bump(&AUTHORITY, name);
+    let record_bump = |seed: &str| make_record_bump(&class, seed.as_bytes());
+    let mint_bump = |seed: &str| make_compute_units_mint_bump(&class, seed);
+
+    let instructions: [(&str, MeasureComputeUnits, SeedBump); 3] = [
+        ("CreateClass", measure_create_class, &class_bump),
+        ("CreateRecord", measure_create_record, &record_bump),
+        ("MintTokenizedRecord", measure_mint_tokenized_record, &mint_bump),
+    ];
+
+    for (name, measure, bump) in instructions {
+        let (first_bump_seed, later_bump_seed) = make_compute_units_seeds(bump);
+
+        let first_bump = measure(&mollusk, &first_bump_seed);
+        let later_bump = measure(&mollusk, &later_bump_seed);
+        let higher_bumps = u64::from(u8::MAX - bump(&later_bump_seed));
+
+        let report =
+            format!("{name}: {first_bump} CUs, {later_bump} CUs with {higher_bumps} higher bumps");
+        println!("{report}");
+
+        let canonical_check = later_bump.saturating_sub(first_bump);
+        assert!(
+            canonical_check >= higher_bumps * create_program_address_units
+                && canonical_check < (higher_bumps + 1) * create_program_address_units,
+            "{report}"
+        );
+    }
+}
+
+#[test]
+/// Signing with a stored bump costs the same whatever the bump, deriving it
+/// would cost at least two more `create_program_address` for the later bump
+fn stored_bumps_compute_units() {
+    let mollusk = mollusk_with_built_program();
+    let create_program_address_units = mollusk.compute_budget.create_program_address_units;
+
+    let (class, _) = keyed_account_for_class_default();
+    let (first_bump_seed, later_bump_seed) =
+        make_compute_units_seeds(|seed| make_compute_units_mint_bump(&class, seed));
+
+    let instructions: [(&str, MeasureComputeUnits); 4] = [
+        ("TransferTokenizedRecord", measure_transfer_tokenized_record),
+        ("FreezeTokenizedRecord", measure_freeze_tokenized_record),
+        ("BurnTokenizedRecord", measure_burn_tokenized_record),
+        ("DeleteRecord", measure_delete_tokenized_record),
+    ];
+
+    for (name, measure) in instructions {
+        let first_bump = measure(&mollusk, &first_bump_seed);
+        let later_bump = measure(&mollusk, &later_bump_seed);
+
+        let report = format!("{name}: {first_bump} CUs, {later_bump} CUs with a later bump");
+        println!("{report}");
+
+        assert!(
+            first_bump.abs_diff(later_bump) < create_program_address_units,
+            "{report}"
+        );
+    }
+}
+
+#[test]
+fn freeze_tokenized_record_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+    // ATA updated
+    let (_, token_account_data_updated) = keyed_account_for_token(OWNER, mint, true);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = FreezeTokenizedRecord {
+        authority,
+        record,
+        mint,
+        token_account,
+        class,
+        token2022,
+        class_delegate: None,
+    }
+    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&token_account)
+                .data(&token_account_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn transfer_tokenized_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = TransferTokenizedRecord {
+        authority: owner,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class,
+        class_delegate: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (class, class_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+        ],
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn transfer_tokenized_record_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = TransferTokenizedRecord {
+        authority,
+        record,
+        mint,
+        token_account,
+        new_token_account,
+        token2022,
+        class,
+        class_delegate: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+        ],
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn burn_tokenized_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = BurnTokenizedRecord {
+        authority: owner,
+        payer,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class,
+        class_delegate: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (token2022, token2022_data),
+        ],
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn burn_tokenized_record_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = BurnTokenizedRecord {
+        authority,
+        payer,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class,
+        class_delegate: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (payer, payer_data),
+            (record, record_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (token2022, token2022_data),
+            (class, class_data),
+        ],
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn mint_and_burn_tokenized_record() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, _) = keyed_account_for_mint(record);
+    // Group
+    let (group, _) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, _) = keyed_account_for_token(owner, mint, false);
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let mint_instruction = MintTokenizedRecord {
+        owner,
+        payer: owner,
+        authority: owner,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(MintTokenizedRecordInstructionArgs {
+        mint_bump: make_mint_bump(&record),
+        group_bump: make_group_bump(&class),
+    });
+
+    let burn_instruction = BurnTokenizedRecord {
+        authority: owner,
+        payer: owner,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class,
+        class_delegate: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction_chain(
+        &[
+            (&mint_instruction, &[Check::success()]),
+            (&burn_instruction, &[Check::success()]),
+        ],
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+    );
+}
+
+#[test]
+fn mint_and_burn_tokenized_record_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Class
+    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+    // Mint
+    let (mint, _) = keyed_account_for_mint(record);
+    // Group
+    let (group, _) = keyed_account_for_group(class);
+    // ATA
+    let (token_account, _) = keyed_account_for_token(owner, mint, false);
+
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let mint_instruction = MintTokenizedRecord {
+        owner,
+        payer: authority,
+        authority,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(MintTokenizedRecordInstructionArgs {
+        mint_bump: make_mint_bump(&record),
+        group_bump: make_group_bump(&class),
+    });
+
+    let burn_instruction = BurnTokenizedRecord {
+        authority,
+        payer: authority,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class,
+        class_delegate: None,
+    }
+    .instruction();
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction_chain(
+        &[
+            (&mint_instruction, &[Check::success()]),
+            (&burn_instruction, &[Check::success()]),
+        ],
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (authority, authority_data),
+            (record, record_data),
+            (mint, Account::default()),
+            (class, class_data),
+            (group, Account::default()),
+            (token_account, Account::default()),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+    );
+}
+
+#[test]
+fn update_tokenized_record() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_with_group(true);
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let burn_instruction = BurnTokenizedRecord {
+        authority,
+        payer,
+        record,
+        mint,
+        token_account,
+        token2022,
+        class,
+        class_delegate: None,
+    }
+    .instruction();
+
+    // New metadata
+    let new_metadata = Metadata {
+        name: make_u32prefix_string("test2"),
+        symbol: make_u32prefix_string("SRS"),
+        uri: make_u32prefix_string("test"),
+        additional_metadata: vec![],
+    };
+
+    // Record updated
+    let (_, mut record_data_updated) = keyed_account_for_record_with_metadata(
+        class,
+        0,
+        OWNER,
+        false,
+        0,
+        "test",
+        Some(&new_metadata.try_to_vec().unwrap()),
+    );
+    chain_record_update(
+        &mut record_data_updated,
+        &record_data,
+        0,
+        &new_metadata.try_to_vec().unwrap(),
+    );
+
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let update_instruction = UpdateRecordTokenizable {
+        authority,
+        payer,
+        record,
+        class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
+    }
+    .instruction(UpdateRecordTokenizableInstructionArgs {
+        metadata: Metadata {
+            name: make_u32prefix_string("test2"),
+            symbol: make_u32prefix_string("SRS"),
+            uri: make_u32prefix_string("test"),
+            additional_metadata: vec![],
+        },
+    });
+
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // Associated Token Program
+    let (associated_token_program, associated_token_program_data) =
+        mollusk_svm_programs_token::associated_token::keyed_account();
+
+    // New Mint
+    let (_, new_mint_data) = keyed_account_for_updated_mint(record);
+
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+
+    let mint_instruction = MintTokenizedRecord {
+        owner,
+        payer: authority,
+        authority,
+        record,
+        mint,
+        class,
+        group,
+        token_account,
+        associated_token_program,
+        token2022,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+    }
+    .instruction(MintTokenizedRecordInstructionArgs {
+        mint_bump: make_mint_bump(&record),
+        group_bump: make_group_bump(&class),
+    });
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
+    mollusk.process_and_validate_instruction_chain(
+        &[
+            (
+                &burn_instruction, 
+                &[Check::success()]
+            ),
+            (
+                &update_instruction,
+                &[
+                    Check::success(),
+                    Check::account(&record)
+                        .data(&record_data_updated.data)
+                        .build(),
+                ],
+            ),
+            (
+                &mint_instruction,
+                &[
+                    Check::success(),
+                    Check::account(&mint).data(&new_mint_data.data).build(),
+                ],
+            ),
+        ],
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (authority, authority_data),
+            (record, record_data),
+            (mint, mint_data),
+            (class, class_data),
+            (group, group_data),
+            (token_account, token_account_data),
+            (associated_token_program, associated_token_program_data),
+            (token2022, token2022_data),
+            (system_program, system_program_data),
+        ],
+    );
+}
+
+#[test]
+fn propose_class_authority() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // New Authority
+    let (new_authority, _) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Pending Class Authority
+    let (pending_class_authority, pending_class_authority_data) =
+        keyed_account_for_pending_class_authority(class, authority, new_authority);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = ProposeClassAuthority {
+        authority,
+        payer: authority,
+        class,
+        pending_class_authority,
+        system_program,
+    }
+    .instruction(ProposeClassAuthorityInstructionArgs {
+        new_authority,
+        bump: make_pending_class_authority_bump(&class),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data.clone()),
+            (pending_class_authority, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            // The class authority only changes once the transfer is accepted
+            Check::account(&class).data(&class_data.data).build(),
+            Check::account(&pending_class_authority)
+                .data(&pending_class_authority_data.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn accept_class_authority() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // New Authority
+    let (new_authority, new_authority_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Pending Class Authority
+    let (pending_class_authority, pending_class_authority_data) =
+        keyed_account_for_pending_class_authority(class, authority, new_authority);
+
+    let instruction = AcceptClassAuthority {
+        new_authority,
+        authority,
+        class,
+        pending_class_authority,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    // Class Updated
+    let (_, class_data_updated) = keyed_account_for_class(new_authority, false, false, "test", "test");
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (new_authority, new_authority_data),
+            (authority, authority_data),
+            (class, class_data),
+            (pending_class_authority, pending_class_authority_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class).data(&class_data_updated.data).build(),
+            Check::account(&pending_class_authority).closed().build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the signer is not the proposed authority
+fn fail_accept_class_authority_incorrect_pending_authority() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // New Authority
+    let (new_authority, new_authority_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Pending Class Authority
+    let (pending_class_authority, pending_class_authority_data) =
+        keyed_account_for_pending_class_authority(class, authority, OWNER);
+
+    let instruction = AcceptClassAuthority {
+        new_authority,
+        authority,
+        class,
+        pending_class_authority,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (new_authority, new_authority_data),
+            (authority, authority_data),
+            (class, class_data),
+            (pending_class_authority, pending_class_authority_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidPendingAuthority as u32,
+        ))],
+    );
+}
+
+#[test]
+fn cancel_class_authority_transfer() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // New Authority
+    let (new_authority, _) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Pending Class Authority
+    let (pending_class_authority, pending_class_authority_data) =
+        keyed_account_for_pending_class_authority(class, authority, new_authority);
+
+    let instruction = CancelClassAuthorityTransfer {
+        authority,
+        class,
+        pending_class_authority,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (pending_class_authority, pending_class_authority_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&pending_class_authority).closed().build(),
+        ],
+    );
+}
+
+#[test]
+fn add_class_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Delegate
+    let (delegate, _) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class Delegate with the freeze permission
+    let (class_delegate, class_delegate_data) =
+        keyed_account_for_class_delegate(class, delegate, 1 << 3);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = AddClassDelegate {
+        authority,
+        payer: authority,
+        class,
+        class_delegate,
+        system_program,
+    }
+    .instruction(AddClassDelegateInstructionArgs {
+        delegate,
+        permissions: 1 << 3,
+        bump: make_class_delegate_bump(&class, &delegate),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (class_delegate, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class_delegate)
+                .data(&class_delegate_data.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the bump is valid but not the canonical one
+fn fail_add_class_delegate_non_canonical_bump() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Delegate
+    let (delegate, _) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class Delegate at the address of another valid bump
+    let (class_delegate, bump) = make_non_canonical_program_address(&[
+        b"class_delegate",
+        class.as_ref(),
+        delegate.as_ref(),
+    ]);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = AddClassDelegate {
+        authority,
+        payer: authority,
+        class,
+        class_delegate,
+        system_program,
+    }
+    .instruction(AddClassDelegateInstructionArgs {
+        delegate,
+        permissions: 1 << 3,
+        bump,
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (class_delegate, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidPda as u32,
+        ))],
+    );
+}
+
+#[test]
+fn freeze_record_with_class_delegate() {
+    // Delegate
+    let (delegate, delegate_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class Delegate with the freeze permission
+    let (class_delegate, class_delegate_data) =
+        keyed_account_for_class_delegate(class, delegate, 1 << 3);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record frozen
+    let (_, record_data_frozen) =
+        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+
+    let instruction = FreezeRecord {
+        authority: delegate,
+        record,
+        class,
+        class_delegate: Some(class_delegate),
+    }
+    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (delegate, delegate_data),
+            (record, record_data),
+            (class, class_data),
+            (class_delegate, class_delegate_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_frozen.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+/// Fails because the class delegate can only update record data
+fn fail_freeze_record_with_class_delegate_missing_permission() {
+    // Delegate
+    let (delegate, delegate_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class Delegate with the update data permission
+    let (class_delegate, class_delegate_data) =
+        keyed_account_for_class_delegate(class, delegate, 1 << 1);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+
+    let instruction = FreezeRecord {
+        authority: delegate,
+        record,
+        class,
+        class_delegate: Some(class_delegate),
+    }
+    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (delegate, delegate_data),
+            (record, record_data),
+            (class, class_data),
+            (class_delegate, class_delegate_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::MissingPermission as u32,
+        ))],
+    );
+}
+
+#[test]
+fn revoke_class_delegate() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Delegate
+    let (delegate, _) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class Delegate
+    let (class_delegate, class_delegate_data) =
+        keyed_account_for_class_delegate(class, delegate, 1 << 3);
+
+    let instruction = RevokeClassDelegate {
+        authority,
+        class,
+        class_delegate,
+    }
+    .instruction();
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (class_delegate, class_delegate_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class_delegate).closed().build(),
+        ],
+    );
+}
+
+#[test]
+fn approve_record_delegate() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Delegate
+    let (delegate, _) = keyed_account_for_random_authority();
+    // Class
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Record Delegate with the transfer permission
+    let (record_delegate, record_delegate_data) =
+        keyed_account_for_record_delegate(record, owner, delegate, 1 << 4, 1000);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = ApproveRecordDelegate {
+        owner,
+        payer: owner,
+        record,
+        record_delegate,
+        system_program,
+    }
+    .instruction(ApproveRecordDelegateInstructionArgs {
+        delegate,
+        permissions: 1 << 4,
+        expiry: 1000,
+        bump: make_record_delegate_bump(&record),
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (record_delegate, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record_delegate)
+                .data(&record_delegate_data.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn transfer_record_with_record_delegate() {
+    // Delegate
+    let (delegate, delegate_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record updated
+    let (_, record_data_updated) =
+        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+    // Record Delegate with the transfer permission
+    let (record_delegate, record_delegate_data) =
+        keyed_account_for_record_delegate(record, OWNER, delegate, 1 << 4, 0);
+
+    let instruction = TransferRecord {
+        authority: delegate,
+        record,
+        class,
+        class_delegate: None,
+        record_delegate: Some(record_delegate),
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: NEW_OWNER,
+    });
+
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
     );
-    group_account
-        .data_as_mut_slice()
-        .copy_from_slice(&group_account_data);
 
-    (address, group_account)
-}
-
-fn keyed_account_for_token(owner: Pubkey, mint: Pubkey, is_frozen: bool) -> (Pubkey, Account) {
-    let (address, _bump) = Pubkey::find_program_address(
+    mollusk.process_and_validate_instruction(
+        &instruction,
         &[
-            owner.as_ref(),
-            mollusk_svm_programs_token::token2022::ID.as_ref(),
-            mint.as_ref(),
+            (delegate, delegate_data),
+            (record, record_data),
+            (class, class_data),
+            (record_delegate, record_delegate_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record)
+                .data(&record_data_updated.data)
+                .build(),
         ],
-        &mollusk_svm_programs_token::associated_token::ID,
     );
+}
 
-    let mut token_account_data = [0u8; 170];
-    // Mint
-    token_account_data[0..32].copy_from_slice(&mint.to_bytes());
-    // Owner
-    token_account_data[32..64].copy_from_slice(&owner.to_bytes());
-    // Amount
-    token_account_data[64..72].copy_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]);
+#[test]
+/// Fails because the record delegate is expired
+fn fail_transfer_record_with_expired_record_delegate() {
     // Delegate
-    token_account_data[72..108].copy_from_slice(&[0; 36]);
-    // State
-    token_account_data[108] = if is_frozen { 2 } else { 1 };
-    // IsNative
-    token_account_data[109..121].copy_from_slice(&[0; 12]);
-    token_account_data[121..129].copy_from_slice(&[0; 8]);
-    token_account_data[129..165].copy_from_slice(&[0; 36]);
-    // Account type
-    token_account_data[165] = 2;
-    // Extension
-    token_account_data[166..170].copy_from_slice(&[7, 0, 0, 0]);
+    let (delegate, delegate_data) = keyed_account_for_random_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record Delegate with the transfer permission
+    let (record_delegate, record_delegate_data) =
+        keyed_account_for_record_delegate(record, OWNER, delegate, 1 << 4, 100);
 
-    // Create the mint account
-    let mut token_account = Account::new(
-        100_000_000u64,
-        token_account_data.len(),
-        &TOKEN_2022_PROGRAM_ID,
+    let instruction = TransferRecord {
+        authority: delegate,
+        record,
+        class,
+        class_delegate: None,
+        record_delegate: Some(record_delegate),
+    }
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: NEW_OWNER,
+    });
+
+    let mut mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
     );
-    token_account
-        .data_as_mut_slice()
-        .copy_from_slice(&token_account_data);
+    mollusk.sysvars.clock.unix_timestamp = 200;
 
-    (address, token_account)
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (delegate, delegate_data),
+            (record, record_data),
+            (class, class_data),
+            (record_delegate, record_delegate_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::RecordDelegateExpired as u32,
+        ))],
+    );
 }
 
-/* Tests */
-
 #[test]
-fn create_class() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
+/// Fails because the record delegate was approved for the record deleted
+/// before this one was created at the same address
+fn fail_transfer_record_with_record_delegate_of_deleted_record() {
+    // Delegate
+    let (delegate, delegate_data) = keyed_account_for_random_authority();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
+    // Record, created again after a deletion
+    let (record, record_data) = keyed_account_for_record_with_generation(class, OWNER, 1);
+    // Record Delegate approved for the first generation of the record
+    let (record_delegate, record_delegate_data) =
+        keyed_account_for_record_delegate(record, OWNER, delegate, 1 << 4, 0);
 
-    //System Program
-    let (system_program, system_program_data) = keyed_account_for_system_program();
-
-    let instruction = CreateClass {
-        authority,
-        payer: authority,
+    let instruction = TransferRecord {
+        authority: delegate,
+        record,
         class,
-        system_program,
+        class_delegate: None,
+        record_delegate: Some(record_delegate),
     }
-    .instruction(CreateClassInstructionArgs {
-        is_permissioned: false,
-        is_frozen: false,
-        name: make_u8prefix_string("test"),
-        metadata: make_remainder_str("test"),
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: NEW_OWNER,
     });
 
     let mollusk = Mollusk::new(
@@ -770,39 +6869,48 @@ fn create_class() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (authority, authority_data),
-            (class, Account::default()),
-            (system_program, system_program_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&class).data(&class_data.data).build(),
+            (delegate, delegate_data),
+            (record, record_data),
+            (class, class_data),
+            (record_delegate, record_delegate_data),
         ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidRecordDelegate as u32,
+        ))],
     );
 }
 
 #[test]
-fn update_class_metadata() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
+fn update_record_with_record_delegate() {
+    // Delegate
+    let (delegate, delegate_data) = keyed_account_for_random_authority();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-
-    // Class Updated
-    let (_, class_data_updated) = keyed_account_for_class(authority, false, false, "test", "test2");
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record updated
+    let (_, mut record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
+    chain_record_update(&mut record_data_updated, &record_data, 0, b"test2");
+    // Record Delegate with the update data permission
+    let (record_delegate, record_delegate_data) =
+        keyed_account_for_record_delegate(record, OWNER, delegate, 1 << 1, 0);
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = UpdateClassMetadata {
-        authority,
-        payer,
+    let instruction = UpdateRecord {
+        authority: delegate,
+        payer: delegate,
+        record,
         class,
         system_program,
+        class_delegate: None,
+        record_delegate: Some(record_delegate),
+        schema: None,
     }
-    .instruction(UpdateClassMetadataInstructionArgs {
-        metadata: RemainderStr::from_str("test2").unwrap(),
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
     });
 
     let mollusk = Mollusk::new(
@@ -813,38 +6921,49 @@ fn update_class_metadata() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (authority, authority_data),
-            (payer, payer_data),
+            (delegate, delegate_data),
+            (record, record_data),
             (class, class_data),
             (system_program, system_program_data),
+            (record_delegate, record_delegate_data),
         ],
         &[
             Check::success(),
-            Check::account(&class)
-                .data(&class_data_updated.data)
+            Check::account(&record)
+                .data(&record_data_updated.data)
                 .build(),
         ],
     );
 }
 
 #[test]
-/// Fails because the class_authority != authority of the instruction
-fn update_class_metadata_incorrect_authority() {
-    // Authority
-    let (random_authority, random_authority_data) = keyed_account_for_random_authority();
+/// Fails because the record delegate only has the transfer permission
+fn fail_update_record_with_record_delegate_missing_permission() {
+    // Delegate
+    let (delegate, delegate_data) = keyed_account_for_random_authority();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record Delegate with the transfer permission
+    let (record_delegate, record_delegate_data) =
+        keyed_account_for_record_delegate(record, OWNER, delegate, 1 << 4, 0);
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = UpdateClassMetadata {
-        authority: random_authority,
-        payer: random_authority,
+    let instruction = UpdateRecord {
+        authority: delegate,
+        payer: delegate,
+        record,
         class,
         system_program,
+        class_delegate: None,
+        record_delegate: Some(record_delegate),
+        schema: None,
     }
-    .instruction(UpdateClassMetadataInstructionArgs {
-        metadata: RemainderStr::from_str("test2").unwrap(),
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
     });
 
     let mollusk = Mollusk::new(
@@ -855,65 +6974,94 @@ fn update_class_metadata_incorrect_authority() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (random_authority, random_authority_data),
+            (delegate, delegate_data),
+            (record, record_data),
             (class, class_data),
             (system_program, system_program_data),
+            (record_delegate, record_delegate_data),
         ],
-        &[Check::err(ProgramError::InvalidAccountData)],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::MissingPermission as u32,
+        ))],
     );
 }
 
 #[test]
-fn update_class_authority() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
-    // New Authority
-    let (new_authority, _) = keyed_account_for_random_authority();
+fn delete_record_with_record_delegate() {
+    // Delegate
+    let (delegate, delegate_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
-    //System Program
-    let (system_program, system_program_data) = keyed_account_for_system_program();
+    let (class, class_data) = keyed_account_for_class_with_counts(1, 0, 0);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record Delegate with the delete permission
+    let (record_delegate, record_delegate_data) =
+        keyed_account_for_record_delegate(record, OWNER, delegate, 1 << 5, 0);
 
-    let instruction = UpdateClassAuthority { 
-        authority: authority,
-        payer: authority,
+    let instruction = DeleteRecord {
+        authority: delegate,
+        payer: delegate,
+        record,
         class,
-        system_program, 
-    }.instruction(UpdateClassAuthorityInstructionArgs { new_authority });
+        token2022_program: None,
+        mint: None,
+        class_delegate: None,
+        record_delegate: Some(record_delegate),
+    }
+    .instruction();
 
     let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    // Class Updated
-    let (_, class_data_updated) = keyed_account_for_class(new_authority, false, false, "test", "test");
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (authority, authority_data),
+            (delegate, delegate_data),
+            (record, record_data),
             (class, class_data),
-            (system_program, system_program_data),
+            (record_delegate, record_delegate_data),
         ],
         &[
             Check::success(),
-            Check::account(&class).data(&class_data_updated.data).build(),
+            Check::account(&record).data(&[0xff]).build(),
         ],
     );
 }
 
 #[test]
-fn update_class_frozen() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
+/// Fails because the record delegate account is not the PDA of the record
+fn fail_approve_record_delegate_invalid_pda() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Delegate
+    let (delegate, _) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
-    // Class frozen
-    let (_, class_data_frozen) = keyed_account_for_class(authority, false, true, "test", "test");
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Record Delegate of another record
+    let (record_delegate, _) =
+        keyed_account_for_record_delegate(RANDOM_PUBKEY, owner, delegate, 1 << 4, 0);
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = FreezeClass { authority, class }
-        .instruction(FreezeClassInstructionArgs { is_frozen: true });
+    let instruction = ApproveRecordDelegate {
+        owner,
+        payer: owner,
+        record,
+        record_delegate,
+        system_program,
+    }
+    .instruction(ApproveRecordDelegateInstructionArgs {
+        delegate,
+        permissions: 1 << 4,
+        expiry: 0,
+        bump: make_record_delegate_bump(&record),
+    });
 
     let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
@@ -922,25 +7070,39 @@ fn update_class_frozen() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[(authority, authority_data), (class, class_data)],
         &[
-            Check::success(),
-            Check::account(&class).data(&class_data_frozen.data).build(),
+            (owner, owner_data),
+            (record, record_data),
+            (record_delegate, Account::default()),
+            (system_program, system_program_data),
         ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidPda as u32,
+        ))],
     );
 }
 
 #[test]
-fn update_class_frozen_already_frozen() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
+fn revoke_record_delegate() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Delegate
+    let (delegate, _) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, false, true, "test", "test");
-    // Class frozen
-    let (_, class_data_frozen) = keyed_account_for_class(authority, false, true, "test", "test");
+    let (class, _class_data) = keyed_account_for_class_default();
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Record Delegate
+    let (record_delegate, record_delegate_data) =
+        keyed_account_for_record_delegate(record, owner, delegate, 1 << 4, 0);
 
-    let instruction = FreezeClass { authority, class }
-        .instruction(FreezeClassInstructionArgs { is_frozen: true });
+    let instruction = RevokeRecordDelegate {
+        owner,
+        record,
+        record_delegate,
+    }
+    .instruction();
 
     let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
@@ -949,38 +7111,48 @@ fn update_class_frozen_already_frozen() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[(authority, authority_data), (class, class_data)],
+        &[
+            (owner, owner_data),
+            (record, record_data),
+            (record_delegate, record_delegate_data),
+        ],
         &[
             Check::success(),
-            Check::account(&class).data(&class_data_frozen.data).build(),
+            Check::account(&record_delegate).closed().build(),
         ],
     );
 }
 
+fn schema_fields() -> Vec<SchemaField> {
+    vec![
+        make_schema_field(SchemaFieldType::U64, true, 0, "score"),
+        make_schema_field(SchemaFieldType::String, false, 8, "nickname"),
+    ]
+}
+
 #[test]
-fn create_record() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+fn set_class_schema() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class with the schema bump
+    let (_, class_data_updated) = keyed_account_for_class_with_schema();
+    // Schema
+    let (schema, schema_data) = keyed_account_for_class_schema(class, &schema_fields());
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = CreateRecord {
-        owner,
-        payer: owner,
+    let instruction = SetClassSchema {
+        authority,
+        payer: authority,
         class,
-        record,
+        schema,
         system_program,
-        authority: None,
     }
-    .instruction(CreateRecordInstructionArgs {
-        expiration: 0,
-        seed: make_u8prefix_vec_u8(b"test"),
-        data: make_remainder_vec(b"test"),
+    .instruction(SetClassSchemaInstructionArgs {
+        bump: make_schema_bump(&class),
+        fields: make_schema_fields(&schema_fields()),
     });
 
     let mollusk = Mollusk::new(
@@ -991,47 +7163,60 @@ fn create_record() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
+            (authority, authority_data),
             (class, class_data),
-            (record, Account::default()),
+            (schema, Account::default()),
             (system_program, system_program_data),
         ],
         &[
             Check::success(),
-            Check::account(&record).data(&record_data.data).build(),
+            Check::account(&schema).data(&schema_data.data).build(),
+            Check::account(&class)
+                .data(&class_data_updated.data)
+                .build(),
         ],
     );
 }
 
 #[test]
-fn create_record_with_metadata() {
+fn create_record_with_schema() {
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
+    let (class, class_data) = keyed_account_for_class_with_schema();
+    // Schema
+    let (schema, schema_data) = keyed_account_for_class_schema(class, &schema_fields());
+    // Data: score = 42, nickname = Some("bob")
+    let data = [
+        &42u64.to_le_bytes()[..],
+        &[1],
+        &3u32.to_le_bytes(),
+        b"bob",
+    ]
+    .concat();
     // Record
     let (record, record_data) =
-        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", &data);
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = CreateRecordTokenizable {
+    let instruction = CreateRecord {
         owner,
         payer: owner,
         class,
         record,
         system_program,
         authority: None,
+        class_delegate: None,
+        schema: Some(schema),
+        treasury: None,
     }
-    .instruction(CreateRecordTokenizableInstructionArgs {
+    .instruction(CreateRecordInstructionArgs {
         expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
         seed: make_u8prefix_vec_u8(b"test"),
-        metadata: Metadata {
-            name: make_u32prefix_string("test"),
-            symbol: make_u32prefix_string("SRS"),
-            uri: make_u32prefix_string("test"),
-            additional_metadata: vec![],
-        },
+        data: make_remainder_vec(&data),
     });
 
     let mollusk = Mollusk::new(
@@ -1046,6 +7231,7 @@ fn create_record_with_metadata() {
             (class, class_data),
             (record, Account::default()),
             (system_program, system_program_data),
+            (schema, schema_data),
         ],
         &[
             Check::success(),
@@ -1055,38 +7241,43 @@ fn create_record_with_metadata() {
 }
 
 #[test]
-fn create_record_with_metadata_and_additional_metadata() {
+fn fail_create_record_schema_mismatch() {
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
+    let (class, class_data) = keyed_account_for_class_with_schema();
+    // Schema
+    let (schema, schema_data) = keyed_account_for_class_schema(class, &schema_fields());
+    // Data: the nickname exceeds its maximum length
+    let data = [
+        &42u64.to_le_bytes()[..],
+        &[1],
+        &9u32.to_le_bytes(),
+        b"bobbobbob",
+    ]
+    .concat();
     // Record
-    let (record, record_data) = keyed_account_for_record_with_metadata_and_additional_metadata(
-        class, 0, owner, false, 0, "test",
-    );
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", &data);
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = CreateRecordTokenizable {
+    let instruction = CreateRecord {
         owner,
         payer: owner,
         class,
         record,
         system_program,
         authority: None,
+        class_delegate: None,
+        schema: Some(schema),
+        treasury: None,
     }
-    .instruction(CreateRecordTokenizableInstructionArgs {
+    .instruction(CreateRecordInstructionArgs {
         expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
         seed: make_u8prefix_vec_u8(b"test"),
-        metadata: Metadata {
-            name: make_u32prefix_string("test"),
-            symbol: make_u32prefix_string("SRS"),
-            uri: make_u32prefix_string("test"),
-            additional_metadata: vec![AdditionalMetadata {
-                label: make_u32prefix_string("test"),
-                value: make_u32prefix_string("test"),
-            }],
-        },
+        data: make_remainder_vec(&data),
     });
 
     let mollusk = Mollusk::new(
@@ -1101,38 +7292,40 @@ fn create_record_with_metadata_and_additional_metadata() {
             (class, class_data),
             (record, Account::default()),
             (system_program, system_program_data),
+            (schema, schema_data),
         ],
-        &[
-            Check::success(),
-            Check::account(&record).data(&record_data.data).build(),
-        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::SchemaMismatch as u32,
+        ))],
     );
 }
 
 #[test]
-fn create_permissioned_record() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
+fn fail_create_record_without_schema_account() {
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
+    let (class, class_data) = keyed_account_for_class_with_schema();
     // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    let (record, _) = keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
     let instruction = CreateRecord {
         owner,
-        payer: authority,
+        payer: owner,
         class,
         record,
         system_program,
-        authority: Some(authority),
+        authority: None,
+        class_delegate: None,
+        schema: None,
+        treasury: None,
     }
     .instruction(CreateRecordInstructionArgs {
         expiration: 0,
+        content_type: 0,
+        bump: make_record_bump(&class, b"test"),
         seed: make_u8prefix_vec_u8(b"test"),
         data: make_remainder_vec(b"test"),
     });
@@ -1149,42 +7342,42 @@ fn create_permissioned_record() {
             (class, class_data),
             (record, Account::default()),
             (system_program, system_program_data),
-            (authority, authority_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&record).data(&record_data.data).build(),
         ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidSchema as u32,
+        ))],
     );
 }
 
 #[test]
-fn update_record() {
+fn fail_update_record_schema_mismatch() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
     // Payer
     let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
+    let (class, class_data) = keyed_account_for_class_with_schema();
+    // Schema
+    let (schema, schema_data) = keyed_account_for_class_schema(class, &schema_fields());
     // Record
     let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
-    // Record updated
-    let (_, record_data_updated) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
-
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", &[0; 9]);
     //System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
+    // Data: the required score is missing
     let instruction = UpdateRecord {
         authority,
         payer,
         record,
         class,
         system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: Some(schema),
     }
     .instruction(UpdateRecordInstructionArgs {
-        data: make_remainder_vec(b"test2"),
+        data: make_remainder_vec(&[0]),
     });
 
     let mollusk = Mollusk::new(
@@ -1200,64 +7393,41 @@ fn update_record() {
             (record, record_data),
             (class, class_data),
             (system_program, system_program_data),
+            (schema, schema_data),
         ],
-        &[
-            Check::success(),
-            Check::account(&record)
-                .data(&record_data_updated.data)
-                .build(),
-        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::SchemaMismatch as u32,
+        ))],
     );
 }
 
 #[test]
-fn update_record_with_metadata() {
+fn update_class_policy() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
 
-    // New metadata
-    let new_metadata = Metadata {
-        name: make_u32prefix_string("test2"),
-        symbol: make_u32prefix_string("SRS"),
-        uri: make_u32prefix_string("test"),
-        additional_metadata: vec![],
+    let policy = ClassPolicy {
+        update_data: Policy::Owner,
+        update_expiry: Policy::Either,
+        transfer: Policy::Owner,
+        delete: Policy::Authority,
+        tokenize: Policy::Either,
     };
-    // Record updated
-    let (_, record_data_updated) = keyed_account_for_record_with_metadata(
-        class,
-        0,
-        OWNER,
+
+    // Class updated
+    let (_, class_data_updated) = keyed_account_for_class_with_policy(
+        authority,
+        false,
         false,
-        0,
         "test",
-        Some(&new_metadata.try_to_vec().unwrap()),
+        "test",
+        policy.clone(),
     );
 
-    //System Program
-    let (system_program, system_program_data) = keyed_account_for_system_program();
-
-    let instruction = UpdateRecordTokenizable {
-        authority,
-        payer,
-        record,
-        class,
-        system_program,
-    }
-    .instruction(UpdateRecordTokenizableInstructionArgs {
-        metadata: Metadata {
-            name: make_u32prefix_string("test2"),
-            symbol: make_u32prefix_string("SRS"),
-            uri: make_u32prefix_string("test"),
-            additional_metadata: vec![],
-        },
-    });
+    let instruction = UpdateClassPolicy { authority, class }
+        .instruction(UpdateClassPolicyInstructionArgs { policy });
 
     let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
@@ -1266,48 +7436,64 @@ fn update_record_with_metadata() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[
-            (authority, authority_data),
-            (payer, payer_data),
-            (record, record_data),
-            (class, class_data),
-            (system_program, system_program_data),
-        ],
+        &[(authority, authority_data), (class, class_data)],
         &[
             Check::success(),
-            Check::account(&record)
-                .data(&record_data_updated.data)
+            Check::account(&class)
+                .data(&class_data_updated.data)
                 .build(),
         ],
     );
 }
 
 #[test]
-/// Fails because class authority != from authority of the instruction
-fn update_record_with_delegate_incorrect_authority() {
+fn update_class_fee() {
     // Authority
-    let (random_authority, random_authority_data) = keyed_account_for_random_authority();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
+    let (authority, authority_data) = keyed_account_for_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_default();
+    // Class updated
+    let (_, class_data_updated) = keyed_account_for_class_with_fee(1_000_000, RANDOM_PUBKEY);
 
-    //System Program
-    let (system_program, system_program_data) = keyed_account_for_system_program();
+    let instruction =
+        UpdateClassFee { authority, class }.instruction(UpdateClassFeeInstructionArgs {
+            creation_fee: 1_000_000,
+            treasury: RANDOM_PUBKEY,
+        });
 
-    let instruction = UpdateRecord {
-        authority: random_authority,
-        payer,
-        record,
-        class,
-        system_program,
-    }
-    .instruction(UpdateRecordInstructionArgs {
-        data: make_remainder_vec(b"test2"),
-    });
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[(authority, authority_data), (class, class_data)],
+        &[
+            Check::success(),
+            Check::account(&class)
+                .data(&class_data_updated.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn update_class_merkle_root() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Allowlist
+    let tree = AllowlistTree::new(&[OWNER, NEW_OWNER]);
+    // Class updated
+    let (_, class_data_updated) = keyed_account_for_class_with_merkle_root(false, tree.root());
+
+    let instruction = UpdateClassMerkleRoot { authority, class }.instruction(
+        UpdateClassMerkleRootInstructionArgs {
+            merkle_root: tree.root(),
+        },
+    );
 
     let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
@@ -1316,128 +7502,120 @@ fn update_record_with_delegate_incorrect_authority() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
+        &[(authority, authority_data), (class, class_data)],
         &[
-            (random_authority, random_authority_data),
-            (payer, payer_data),
-            (record, record_data),
-            (system_program, system_program_data),
-            (class, class_data),
+            Check::success(),
+            Check::account(&class)
+                .data(&class_data_updated.data)
+                .build(),
         ],
-        &[Check::err(ProgramError::InvalidAccountData)],
     );
 }
 
 #[test]
-fn update_class_expiry() {
+fn close_class() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(AUTHORITY, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
-    //System Program
-    let (system_program, system_program_data) = keyed_account_for_system_program();
+    let (class, class_data) = keyed_account_for_class_default();
+    // Group, the class was never tokenized
+    let (group, _) = keyed_account_for_group(class);
 
-    let instruction = UpdateRecordExpiry {
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = CloseClass {
         authority,
-        payer,
-        record,
+        destination: authority,
         class,
-        system_program,
+        group,
+        token2022,
     }
-    .instruction(UpdateRecordExpiryInstructionArgs {
-        expiry: 1000,
-    });
+    .instruction();
 
-    let mollusk = Mollusk::new(
+    let mut mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    // Record updated
-    let (_, record_data_updated) = keyed_account_for_record(class, 0, OWNER, false, 1000, b"test", b"test");
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
 
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
             (authority, authority_data),
-            (payer, payer_data),
-            (record, record_data),
-            (system_program, system_program_data),
             (class, class_data),
+            (group, Account::default()),
+            (token2022, token2022_data),
         ],
         &[
             Check::success(),
-            Check::account(&record)
-                .data(&record_data_updated.data)
-                .build(),
+            Check::account(&class).data(&[0xff]).build(),
         ],
     );
 }
 
 #[test]
-fn transfer_record() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+fn close_class_with_group() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
     // Class
-    let (class, _class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
-    // Record updated
-    let (_, record_data_updated) =
-        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+    let (class, class_data) = keyed_account_for_class_with_group(false);
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
 
-    let instruction = TransferRecord {
-        authority: owner,
-        record,
-        class: None,
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = CloseClass {
+        authority,
+        destination: authority,
+        class,
+        group,
+        token2022,
     }
-    .instruction(TransferRecordInstructionArgs {
-        new_owner: Pubkey::new_from_array([0xcc; 32]),
-    });
+    .instruction();
 
-    let mollusk = Mollusk::new(
+    let mut mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[(owner, owner_data), (record, record_data)],
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (group, group_data),
+            (token2022, token2022_data),
+        ],
         &[
             Check::success(),
-            Check::account(&record)
-                .data(&record_data_updated.data)
-                .build(),
+            Check::account(&class).data(&[0xff]).build(),
+            Check::account(&group).closed().build(),
         ],
     );
 }
 
 #[test]
-fn transfer_record_with_delegate() {
+fn fail_close_class_not_empty() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
-    // Record updated
-    let (_, record_data_updated) =
-        keyed_account_for_record(class, 0, NEW_OWNER, false, 0, b"test", b"test");
+    // Class with a live record
+    let (class, class_data) = keyed_account_for_class_with_counts(1, 0, 0);
+    // Group
+    let (group, _) = keyed_account_for_group(class);
 
-    let instruction = TransferRecord {
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = CloseClass {
         authority,
-        record,
-        class: Some(class),
+        destination: authority,
+        class,
+        group,
+        token2022,
     }
-    .instruction(TransferRecordInstructionArgs {
-        new_owner: Pubkey::new_from_array([0xcc; 32]),
-    });
+    .instruction();
 
     let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
@@ -1448,37 +7626,37 @@ fn transfer_record_with_delegate() {
         &instruction,
         &[
             (authority, authority_data),
-            (record, record_data),
             (class, class_data),
+            (group, Account::default()),
+            (token2022, token2022_data),
         ],
         &[
-            Check::success(),
-            Check::account(&record)
-                .data(&record_data_updated.data)
-                .build(),
+            Check::err(ProgramError::Custom(
+                TrezoaRecordServiceError::ClassNotEmpty as u32,
+            )),
         ],
     );
 }
 
 #[test]
-/// Fails because the record is frozen
-fn fail_transfer_record_frozen() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
-    // Class
-    let (class, _class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+fn fail_close_class_with_legacy_records() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Class with a legacy record left to migrate
+    let (class, class_data) = keyed_account_for_class_with_legacy_records(1);
+    // Group
+    let (group, _) = keyed_account_for_group(class);
 
-    let instruction = TransferRecord {
-        authority: owner,
-        record,
-        class: None,
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = CloseClass {
+        authority,
+        destination: authority,
+        class,
+        group,
+        token2022,
     }
-    .instruction(TransferRecordInstructionArgs {
-        new_owner: Pubkey::new_from_array([0xcc; 32]),
-    });
+    .instruction();
 
     let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
@@ -1487,32 +7665,61 @@ fn fail_transfer_record_frozen() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[(owner, owner_data), (record, record_data)],
-        &[Check::err(ProgramError::InvalidAccountData)],
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (group, Account::default()),
+            (token2022, token2022_data),
+        ],
+        &[
+            Check::err(ProgramError::Custom(
+                TrezoaRecordServiceError::ClassNotEmpty as u32,
+            )),
+        ],
     );
 }
 
 #[test]
-fn delete_record() {
+fn migrate_record_class() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // New Authority
+    let (new_authority, new_authority_data) = keyed_account_for_random_authority();
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, _class_data) = keyed_account_for_class_default();
+    let (class, class_data) = keyed_account_for_class_default();
+    // New Class
+    let (new_class, new_class_data) =
+        keyed_account_for_class(new_authority, false, false, "new", "test");
     // Record
     let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Record in the new class
+    let (new_record, new_record_data) =
+        keyed_account_for_record(new_class, 0, owner, false, 0, b"test", b"test");
+    // System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = DeleteRecord {
-        authority: owner,
-        payer,
+    let instruction = MigrateRecordClass {
+        authority,
+        new_authority,
+        payer: owner,
         record,
-        class: None,
-        token2022_program: None,
+        class,
+        new_record,
+        new_class,
+        system_program,
+        schema: None,
+        class_delegate: None,
+        new_class_delegate: None,
         mint: None,
+        token_account: None,
+        token2022: None,
     }
-    .instruction();
+    .instruction(MigrateRecordClassInstructionArgs {
+        bump: make_record_bump(&new_class, b"test"),
+    });
 
     let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
@@ -1522,130 +7729,215 @@ fn delete_record() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
+            (authority, authority_data),
+            (new_authority, new_authority_data),
             (owner, owner_data),
-            (payer, payer_data),
             (record, record_data),
+            (class, class_data),
+            (new_record, Account::default()),
+            (new_class, new_class_data),
+            (system_program, system_program_data),
         ],
         &[
             Check::success(),
             Check::account(&record).data(&[0xff]).build(),
+            Check::account(&new_record)
+                .data(&new_record_data.data)
+                .build(),
         ],
     );
 }
 
 #[test]
-fn delete_record_with_delegate() {
+fn migrate_tokenized_record_class() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
+    // New Authority
+    let (new_authority, new_authority_data) = keyed_account_for_random_authority();
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    let (class, class_data) = keyed_account_for_class_default();
+    // New Class
+    let (new_class, new_class_data) =
+        keyed_account_for_class(new_authority, false, false, "new", "test");
+    // Mint
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
     // Record
     let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
+    // ATA
+    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // Record in the new class, owned by the token owner
+    let (new_record, new_record_data) =
+        keyed_account_for_record(new_class, 0, owner, false, 0, b"test", b"test");
+    // System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = DeleteRecord {
+    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+
+    let instruction = MigrateRecordClass {
         authority,
-        payer,
+        new_authority,
+        payer: authority,
         record,
-        class: Some(class),
-        token2022_program: None,
-        mint: None,
+        class,
+        new_record,
+        new_class,
+        system_program,
+        schema: None,
+        class_delegate: None,
+        new_class_delegate: None,
+        mint: Some(mint),
+        token_account: Some(token_account),
+        token2022: Some(token2022),
     }
-    .instruction();
+    .instruction(MigrateRecordClassInstructionArgs {
+        bump: make_record_bump(&new_class, b"test"),
+    });
 
-    let mollusk = Mollusk::new(
+    let mut mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
+    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
             (authority, authority_data),
-            (payer, payer_data),
+            (new_authority, new_authority_data),
+            (owner, owner_data),
             (record, record_data),
             (class, class_data),
+            (new_record, Account::default()),
+            (new_class, new_class_data),
+            (system_program, system_program_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (token2022, token2022_data),
         ],
         &[
             Check::success(),
             Check::account(&record).data(&[0xff]).build(),
+            Check::account(&mint).closed().build(),
+            Check::account(&new_record)
+                .data(&new_record_data.data)
+                .build(),
         ],
     );
 }
 
 #[test]
-fn delete_tokenized_record_with_no_supply() {
+fn fail_migrate_record_class_wrong_new_authority() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, _) = keyed_account_for_class_default();
-    // Mint
-    let (record, _bump) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &TREZOA_RECORD_SERVICE_ID,
-    );
-    let (mint, mut mint_data) = keyed_account_for_mint(record);
-    mint_data.data_as_mut_slice()[..MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY.len()].copy_from_slice(MINT_DATA_WITH_EXTENSIONS_AND_NO_SUPPLY);
+    let (class, class_data) = keyed_account_for_class_default();
+    // New Class, the authority is not its authority
+    let (new_class, new_class_data) =
+        keyed_account_for_class(RANDOM_PUBKEY, false, false, "new", "test");
     // Record
-    let (_, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // Token2022 Program
-    let (token2022_program, token2022_program_data) = mollusk_svm_programs_token::token2022::keyed_account();
-   
-    let instruction = DeleteRecord {
-        authority: owner,
+    let (record, record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // Record in the new class
+    let (new_record, _) =
+        keyed_account_for_record(new_class, 0, owner, false, 0, b"test", b"test");
+    // System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MigrateRecordClass {
+        authority,
+        new_authority: authority,
         payer: owner,
         record,
-        class: None,
-        token2022_program: Some(token2022_program),
-        mint: Some(mint),
+        class,
+        new_record,
+        new_class,
+        system_program,
+        schema: None,
+        class_delegate: None,
+        new_class_delegate: None,
+        mint: None,
+        token_account: None,
+        token2022: None,
     }
-    .instruction();
+    .instruction(MigrateRecordClassInstructionArgs {
+        bump: make_record_bump(&new_class, b"test"),
+    });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
+            (authority, authority_data),
             (owner, owner_data),
             (record, record_data),
-            (mint, mint_data),
-            (token2022_program, token2022_program_data),
+            (class, class_data),
+            (new_record, Account::default()),
+            (new_class, new_class_data),
+            (system_program, system_program_data),
         ],
         &[
-            Check::success(),
-            Check::account(&record).data(&[0xff]).build(),
-            Check::account(&mint).data(&[]).build(),
+            Check::err(ProgramError::Custom(
+                TrezoaRecordServiceError::InvalidAuthority as u32,
+            )),
         ],
     );
 }
 
 #[test]
-fn freeze_record() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
-    // Class
-    let (class, class_data) = keyed_account_for_class_default();
+fn update_record_by_owner_with_owner_policy() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
+    // Class letting the owner update the data
+    let (class, class_data) = keyed_account_for_class_with_policy(
+        AUTHORITY,
+        false,
+        false,
+        "test",
+        "test",
+        ClassPolicy {
+            update_data: Policy::Owner,
+            ..make_default_class_policy(false)
+        },
+    );
     // Record
     let (record, record_data) =
         keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
-    // Record frozen
-    let (_, record_data_frozen) =
-        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+    // Record updated
+    let (_, mut record_data_updated) =
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test2");
+    chain_record_update(&mut record_data_updated, &record_data, 0, b"test2");
+    //System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = FreezeRecord {
-        authority,
+    let instruction = UpdateRecord {
+        authority: owner,
+        payer,
         record,
         class,
+        system_program,
+        class_delegate: None,
+        record_delegate: None,
+        schema: None,
     }
-    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+    .instruction(UpdateRecordInstructionArgs {
+        data: make_remainder_vec(b"test2"),
+    });
 
     let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
@@ -1654,35 +7946,56 @@ fn freeze_record() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[(authority, authority_data), (record, record_data), (class, class_data)],
+        &[
+            (owner, owner_data),
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
         &[
             Check::success(),
             Check::account(&record)
-                .data(&record_data_frozen.data)
+                .data(&record_data_updated.data)
                 .build(),
         ],
     );
 }
 
 #[test]
-fn freeze_record_already_frozen() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
+/// Fails because the class policy only lets the class authority delete records
+fn fail_delete_record_by_owner_with_authority_policy() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
+    // Payer
+    let (payer, payer_data) = keyed_account_for_random_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    let (class, class_data) = keyed_account_for_class_with_policy(
+        AUTHORITY,
+        false,
+        false,
+        "test",
+        "test",
+        ClassPolicy {
+            delete: Policy::Authority,
+            ..make_default_class_policy(false)
+        },
+    );
     // Record
     let (record, record_data) =
-        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
-    // Record frozen
-    let (_, record_data_frozen) =
-        keyed_account_for_record(class, 0, OWNER, true, 0, b"test", b"test");
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
 
-    let instruction = FreezeRecord {
-        authority,
+    let instruction = DeleteRecord {
+        authority: owner,
+        payer,
         record,
         class,
+        token2022_program: None,
+        mint: None,
+        class_delegate: None,
+        record_delegate: None,
     }
-    .instruction(FreezeRecordInstructionArgs { is_frozen: true });
+    .instruction();
 
     let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
@@ -1692,125 +8005,86 @@ fn freeze_record_already_frozen() {
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (authority, authority_data),
+            (owner, owner_data),
+            (payer, payer_data),
             (record, record_data),
             (class, class_data),
         ],
-        &[
-            Check::success(),
-            Check::account(&record)
-                .data(&record_data_frozen.data)
-                .build(),
-        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidAuthority as u32,
+        ))],
     );
 }
 
-// `[1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 20, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 21, 0, 80, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0]`,
-// `[1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 20, 0, 64, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 21, 0, 80, 0, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 52, 137, 177, 136, 59, 205, 145, 103, 193, 194, 30, 23, 233, 253, 189, 51, 87, 188, 182, 87, 172, 35, 137, 100, 211, 23, 123, 152, 136, 141, 87, 92, 1, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 255]`
-
 #[test]
-fn mint_record_token() {
+/// Fails because the records of the class are non-transferable
+fn fail_transfer_record_non_transferable() {
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
+    let (class, class_data) = keyed_account_for_non_transferable_class();
     // Record
     let (record, record_data) =
-        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
-    // Mint
-    let (mint, mint_data) = keyed_account_for_mint(record);
-    // Group
-    let (group, group_data) = keyed_account_for_group(class);
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
-
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-    let (system_program, system_program_data) = keyed_account_for_system_program();
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
 
-    let instruction = MintTokenizedRecord {
-        owner,
-        payer: owner,
+    let instruction = TransferRecord {
         authority: owner,
         record,
-        mint,
         class,
-        group,
-        token_account,
-        associated_token_program,
-        token2022,
-        system_program,
+        class_delegate: None,
+        record_delegate: None,
     }
-    .instruction();
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: NEW_OWNER,
+    });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[
-            (owner, owner_data),
-            (record, record_data),
-            (mint, Account::default()),
-            (class, class_data),
-            (group, Account::default()),
-            (token_account, Account::default()),
-            (associated_token_program, associated_token_program_data),
-            (token2022, token2022_data),
-            (system_program, system_program_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&mint).data(&mint_data.data).build(),
-            Check::account(&group).data(&group_data.data).build(),
-            Check::account(&token_account)
-                .data(&token_account_data.data)
-                .build(),
-            Check::account(&group).rent_exempt().build(),
-        ],
+        &[(owner, owner_data), (record, record_data), (class, class_data)],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::NonTransferable as u32,
+        ))],
     );
 }
 
 #[test]
-fn mint_record_token_with_additional_metadata() {
+/// Fails because the records of the class are non-transferable
+fn fail_transfer_tokenized_record_non_transferable() {
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) = keyed_account_for_record_with_metadata_and_additional_metadata(
-        class, 0, owner, false, 0, "test",
-    );
+    let (class, class_data) = keyed_account_for_non_transferable_class();
     // Mint
-    let (mint, mint_data) = keyed_account_for_mint_with_additional_metadata(record);
-    // Group
-    let (group, group_data) = keyed_account_for_group(class);
+    let (record_address, _) = Pubkey::find_program_address(
+        &[b"record", &class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    // Record
+    let (record, record_data) =
+        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
     // ATA
     let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    // New ATA
+    let (new_token_account, new_token_account_data) =
+        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
 
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
     let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = MintTokenizedRecord {
-        owner,
-        payer: owner,
+    let instruction = TransferTokenizedRecord {
         authority: owner,
         record,
         mint,
-        class,
-        group,
         token_account,
-        associated_token_program,
+        new_token_account,
         token2022,
-        system_program,
+        class,
+        class_delegate: None,
     }
     .instruction();
 
@@ -1819,7 +8093,6 @@ fn mint_record_token_with_additional_metadata() {
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
     mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
 
     mollusk.process_and_validate_instruction(
@@ -1827,43 +8100,33 @@ fn mint_record_token_with_additional_metadata() {
         &[
             (owner, owner_data),
             (record, record_data),
-            (mint, Account::default()),
             (class, class_data),
-            (group, Account::default()),
-            (token_account, Account::default()),
-            (associated_token_program, associated_token_program_data),
+            (mint, mint_data),
+            (token_account, token_account_data),
+            (new_token_account, new_token_account_data),
             (token2022, token2022_data),
-            (system_program, system_program_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&mint).data(&mint_data.data).build(),
-            Check::account(&group).data(&group_data.data).build(),
-            Check::account(&token_account)
-                .data(&token_account_data.data)
-                .build(),
-            Check::account(&mint).rent_exempt().build(),
         ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::NonTransferable as u32,
+        ))],
     );
 }
 
 #[test]
-fn mint_record_token_with_multiple_additional_metadata() {
+fn mint_record_token_non_transferable() {
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class_default();
+    let (class, class_data) = keyed_account_for_non_transferable_class();
     // Record
     let (record, record_data) =
-        keyed_account_for_record_with_metadata_and_multiple_additional_metadata(
-            class, 0, owner, false, 0, "test",
-        );
+        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
     // Mint
-    let (mint, mint_data) = keyed_account_for_mint_with_multiple_additional_metadata(record);
+    let (mint, _) = keyed_account_for_mint(record);
     // Group
-    let (group, group_data) = keyed_account_for_group(class);
+    let (group, _) = keyed_account_for_group(class);
     // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
+    let (token_account, _) = keyed_account_for_token(owner, mint, false);
 
     let (associated_token_program, associated_token_program_data) =
         mollusk_svm_programs_token::associated_token::keyed_account();
@@ -1882,8 +8145,13 @@ fn mint_record_token_with_multiple_additional_metadata() {
         associated_token_program,
         token2022,
         system_program,
+        class_delegate: None,
+        record_delegate: None,
     }
-    .instruction();
+    .instruction(MintTokenizedRecordInstructionArgs {
+        mint_bump: make_mint_bump(&record),
+        group_bump: make_group_bump(&class),
+    });
 
     let mut mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
@@ -1908,722 +8176,594 @@ fn mint_record_token_with_multiple_additional_metadata() {
         ],
         &[
             Check::success(),
-            Check::account(&mint).data(&mint_data.data).build(),
-            Check::account(&group).data(&group_data.data).build(),
-            Check::account(&token_account)
-                .data(&token_account_data.data)
-                .build(),
             Check::account(&mint).rent_exempt().build(),
+            Check::account(&token_account).rent_exempt().build(),
         ],
     );
 }
 
 #[test]
-fn mint_record_token_with_delegate() {
+fn revoke_record() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
-    // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    // Class with the record
+    let (class, class_data) = keyed_account_for_class_with_counts(1, 0, 0);
+    // Class without the revoked record
+    let (_, class_data_updated) = keyed_account_for_class_default();
     // Record
     let (record, record_data) =
-        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
-    // Mint
-    let (mint, mint_data) = keyed_account_for_mint(record);
-    // Group
-    let (group, group_data) = keyed_account_for_group(class);
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
-
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-    let (system_program, system_program_data) = keyed_account_for_system_program();
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
+    // Record revoked
+    let (_, record_data_revoked) = keyed_account_for_revoked_record(class, OWNER, 7, 100);
 
-    let instruction = MintTokenizedRecord {
-        owner,
-        payer: authority,
+    let instruction = RevokeRecord {
         authority,
         record,
-        mint,
         class,
-        group,
-        token_account,
-        associated_token_program,
-        token2022,
-        system_program,
+        class_delegate: None,
     }
-    .instruction();
+    .instruction(RevokeRecordInstructionArgs { reason: 7 });
 
     let mut mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
-
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
+    mollusk.sysvars.clock.unix_timestamp = 100;
 
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
             (authority, authority_data),
             (record, record_data),
-            (mint, Account::default()),
             (class, class_data),
-            (group, Account::default()),
-            (token_account, Account::default()),
-            (associated_token_program, associated_token_program_data),
-            (token2022, token2022_data),
-            (system_program, system_program_data),
         ],
         &[
             Check::success(),
-            Check::account(&mint).data(&mint_data.data).build(),
-            Check::account(&group).data(&group_data.data).build(),
-            Check::account(&token_account)
-                .data(&token_account_data.data)
+            Check::account(&record)
+                .data(&record_data_revoked.data)
                 .build(),
+            Check::account(&class).data(&class_data_updated.data).build(),
         ],
     );
 }
 
 #[test]
-fn freeze_tokenized_record() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
+/// Fails because the owner is not the class authority
+fn fail_revoke_record_by_owner() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
     // Class
     let (class, class_data) = keyed_account_for_class_default();
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &TREZOA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
     // Record
     let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
-    // ATA updated
-    let (_, token_account_data_updated) = keyed_account_for_token(OWNER, mint, true);
-
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+        keyed_account_for_record(class, 0, OWNER, false, 0, b"test", b"test");
 
-    let instruction = FreezeTokenizedRecord {
-        authority,
+    let instruction = RevokeRecord {
+        authority: owner,
         record,
-        mint,
-        token_account,
         class,
-        token2022,
+        class_delegate: None,
     }
-    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+    .instruction(RevokeRecordInstructionArgs { reason: 7 });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[
-            (authority, authority_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (class, class_data),
-            (token2022, token2022_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&token_account)
-                .data(&token_account_data_updated.data)
-                .build(),
-        ],
+        &[(owner, owner_data), (record, record_data), (class, class_data)],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidAuthority as u32,
+        ))],
     );
 }
 
 #[test]
-fn freeze_tokenized_record_delegate() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
+/// Fails because revoked records can't change hands
+fn fail_transfer_record_revoked() {
+    // Owner
+    let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &TREZOA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    let (class, class_data) = keyed_account_for_class_default();
     // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
-    // ATA updated
-    let (_, token_account_data_updated) = keyed_account_for_token(OWNER, mint, true);
+    let (record, record_data) = keyed_account_for_revoked_record(class, OWNER, 7, 100);
 
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-
-    let instruction = FreezeTokenizedRecord {
-        authority,
+    let instruction = TransferRecord {
+        authority: owner,
         record,
-        mint,
-        token_account,
         class,
-        token2022,
+        class_delegate: None,
+        record_delegate: None,
     }
-    .instruction(FreezeTokenizedRecordInstructionArgs { is_frozen: true });
+    .instruction(TransferRecordInstructionArgs {
+        new_owner: NEW_OWNER,
+    });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[
-            (authority, authority_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (token2022, token2022_data),
-            (class, class_data),
-        ],
-        &[
-            Check::success(),
-            Check::account(&token_account)
-                .data(&token_account_data_updated.data)
-                .build(),
-        ],
+        &[(owner, owner_data), (record, record_data), (class, class_data)],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::RecordRevoked as u32,
+        ))],
     );
 }
 
 #[test]
-fn transfer_tokenized_record() {
+/// Fails because revoked records are kept as evidence
+fn fail_delete_record_revoked() {
     // Owner
     let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, _class_data) = keyed_account_for_class_default();
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &TREZOA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
+    let (class, class_data) = keyed_account_for_class_default();
     // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
-    // New ATA
-    let (new_token_account, new_token_account_data) =
-        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
-
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    let (record, record_data) = keyed_account_for_revoked_record(class, OWNER, 7, 100);
 
-    let instruction = TransferTokenizedRecord {
+    let instruction = DeleteRecord {
         authority: owner,
+        payer: owner,
         record,
-        mint,
-        token_account,
-        new_token_account,
-        token2022,
-        class: None,
+        class,
+        token2022_program: None,
+        mint: None,
+        class_delegate: None,
+        record_delegate: None,
     }
     .instruction();
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[
-            (owner, owner_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (new_token_account, new_token_account_data),
-            (token2022, token2022_data),
-        ],
-        &[Check::success()],
+        &[(owner, owner_data), (record, record_data), (class, class_data)],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::RecordRevoked as u32,
+        ))],
     );
 }
 
 #[test]
-fn transfer_tokenized_record_delegate() {
+fn decode_legacy_record() {
+    // Baseline layout: discriminator | class | owner_type | owner | is_frozen | expiry | seed_len | seed | data
+    let mut legacy_record_data = vec![2];
+    legacy_record_data.extend_from_slice(RANDOM_PUBKEY.as_ref());
+    legacy_record_data.push(0);
+    legacy_record_data.extend_from_slice(OWNER.as_ref());
+    legacy_record_data.push(0);
+    legacy_record_data.extend_from_slice(&0i64.to_le_bytes());
+    legacy_record_data.push(4);
+    legacy_record_data.extend_from_slice(b"test");
+    legacy_record_data.extend_from_slice(b"data");
+
+    let (_, record_data) = keyed_account_for_legacy_record(RANDOM_PUBKEY, 0, OWNER, false, 0, b"test", b"data");
+    assert_eq!(record_data.data, legacy_record_data);
+
+    let record = LegacyRecord::from_bytes(&legacy_record_data).expect("Invalid record");
+    assert_eq!(record.class, RANDOM_PUBKEY);
+    assert_eq!(record.owner, OWNER);
+    assert_eq!(record.seed.as_slice(), b"test");
+    assert_eq!(record.data.as_slice(), b"data");
+}
+
+#[test]
+fn migrate_class_layout() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &TREZOA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
-    // New ATA
-    let (new_token_account, new_token_account_data) =
-        keyed_account_for_token(RANDOM_PUBKEY, mint, false);
-
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // Legacy Class
+    let (class, class_data) =
+        keyed_account_for_legacy_class(authority, true, false, "test", "test");
+    // Migrated Class
+    let (_, migrated_class_data) =
+        keyed_account_for_class(authority, true, false, "test", "test");
+    // Group, the class was never tokenized
+    let (group, _) = keyed_account_for_group(class);
+    // System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = TransferTokenizedRecord {
+    let instruction = MigrateClassLayout {
         authority,
-        record,
-        mint,
-        token_account,
-        new_token_account,
-        token2022,
-        class: Some(class),
+        payer: authority,
+        class,
+        group,
+        system_program,
     }
-    .instruction();
+    .instruction(MigrateClassLayoutInstructionArgs {
+        group_bump: make_group_bump(&class),
+        legacy_record_count: 0,
+    });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
             (authority, authority_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (new_token_account, new_token_account_data),
-            (token2022, token2022_data),
             (class, class_data),
+            (group, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class).data(&migrated_class_data.data).build(),
         ],
-        &[Check::success()],
     );
 }
 
 #[test]
-fn burn_tokenized_record() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
-    // Class
-    let (class, _class_data) = keyed_account_for_class_default();
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &TREZOA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(owner, mint, false);
-
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+fn migrate_class_layout_with_group() {
+    // Authority
+    let (authority, authority_data) = keyed_account_for_authority();
+    // Legacy Class
+    let (class, class_data) =
+        keyed_account_for_legacy_class(authority, false, false, "test", "test");
+    // Migrated Class
+    let (_, migrated_class_data) = keyed_account_for_class_with_group(false);
+    // Group
+    let (group, group_data) = keyed_account_for_group(class);
+    // System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = BurnTokenizedRecord {
-        authority: owner,
-        payer,
-        record,
-        mint,
-        token_account,
-        token2022,
-        class: None,
+    let instruction = MigrateClassLayout {
+        authority,
+        payer: authority,
+        class,
+        group,
+        system_program,
     }
-    .instruction();
+    .instruction(MigrateClassLayoutInstructionArgs {
+        group_bump: make_group_bump(&class),
+        legacy_record_count: 0,
+    });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (owner, owner_data),
-            (payer, payer_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (token2022, token2022_data),
+            (authority, authority_data),
+            (class, class_data),
+            (group, group_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class).data(&migrated_class_data.data).build(),
         ],
-        &[Check::success()],
     );
 }
 
 #[test]
-fn burn_tokenized_record_delegate() {
+fn migrate_class_layout_with_legacy_records() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
-    // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
-        &TREZOA_RECORD_SERVICE_ID,
-    );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
-
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // Legacy Class
+    let (class, class_data) =
+        keyed_account_for_legacy_class(authority, false, false, "test", "test");
+    // Migrated Class, with its legacy records left to migrate
+    let (_, migrated_class_data) = keyed_account_for_class_with_legacy_records(2);
+    // Group, the class was never tokenized
+    let (group, _) = keyed_account_for_group(class);
+    // System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let instruction = BurnTokenizedRecord {
+    let instruction = MigrateClassLayout {
         authority,
-        payer,
-        record,
-        mint,
-        token_account,
-        token2022,
-        class: Some(class),
+        payer: authority,
+        class,
+        group,
+        system_program,
     }
-    .instruction();
+    .instruction(MigrateClassLayoutInstructionArgs {
+        group_bump: make_group_bump(&class),
+        legacy_record_count: 2,
+    });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
     mollusk.process_and_validate_instruction(
         &instruction,
         &[
             (authority, authority_data),
-            (payer, payer_data),
-            (record, record_data),
-            (mint, mint_data),
-            (token_account, token_account_data),
-            (token2022, token2022_data),
             (class, class_data),
+            (group, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&class).data(&migrated_class_data.data).build(),
         ],
-        &[Check::success()],
     );
 }
 
 #[test]
-fn mint_and_burn_tokenized_record() {
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
-    // Class
-    let (class, class_data) = keyed_account_for_class_default();
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
-    // Mint
-    let (mint, _) = keyed_account_for_mint(record);
+fn fail_migrate_class_layout_invalid_authority() {
+    // Random Authority
+    let (authority, authority_data) = keyed_account_for_random_authority();
+    // Legacy Class
+    let (class, class_data) =
+        keyed_account_for_legacy_class(AUTHORITY, false, false, "test", "test");
     // Group
     let (group, _) = keyed_account_for_group(class);
-    // ATA
-    let (token_account, _) = keyed_account_for_token(owner, mint, false);
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
+    // System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let mint_instruction = MintTokenizedRecord {
-        owner,
-        payer: owner,
-        authority: owner,
-        record,
-        mint,
+    let instruction = MigrateClassLayout {
+        authority,
+        payer: authority,
         class,
         group,
-        token_account,
-        associated_token_program,
-        token2022,
         system_program,
     }
-    .instruction();
-
-    let burn_instruction = BurnTokenizedRecord {
-        authority: owner,
-        payer: owner,
-        record,
-        mint,
-        token_account,
-        token2022,
-        class: None,
-    }
-    .instruction();
+    .instruction(MigrateClassLayoutInstructionArgs {
+        group_bump: make_group_bump(&class),
+        legacy_record_count: 0,
+    });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
-    mollusk.process_and_validate_instruction_chain(
-        &[
-            (&mint_instruction, &[Check::success()]),
-            (&burn_instruction, &[Check::success()]),
-        ],
+    mollusk.process_and_validate_instruction(
+        &instruction,
         &[
-            (owner, owner_data),
-            (record, record_data),
-            (mint, Account::default()),
+            (authority, authority_data),
             (class, class_data),
             (group, Account::default()),
-            (token_account, Account::default()),
-            (associated_token_program, associated_token_program_data),
-            (token2022, token2022_data),
             (system_program, system_program_data),
         ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidAuthority as u32,
+        ))],
     );
 }
 
 #[test]
-fn mint_and_burn_tokenized_record_delegate() {
+fn fail_migrate_class_layout_already_migrated() {
     // Authority
     let (authority, authority_data) = keyed_account_for_authority();
-    // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
-    // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
-    // Record
-    let (record, record_data) =
-        keyed_account_for_record_with_metadata(class, 0, owner, false, 0, "test", None);
-    // Mint
-    let (mint, _) = keyed_account_for_mint(record);
+    let (class, class_data) = keyed_account_for_class_default();
     // Group
     let (group, _) = keyed_account_for_group(class);
-    // ATA
-    let (token_account, _) = keyed_account_for_token(owner, mint, false);
-
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let mint_instruction = MintTokenizedRecord {
-        owner,
-        payer: authority,
+    let instruction = MigrateClassLayout {
         authority,
-        record,
-        mint,
+        payer: authority,
         class,
         group,
-        token_account,
-        associated_token_program,
-        token2022,
         system_program,
     }
-    .instruction();
+    .instruction(MigrateClassLayoutInstructionArgs {
+        group_bump: make_group_bump(&class),
+        legacy_record_count: 0,
+    });
 
-    let burn_instruction = BurnTokenizedRecord {
-        authority,
-        payer: authority,
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (authority, authority_data),
+            (class, class_data),
+            (group, Account::default()),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidAccountDiscriminator as u32,
+        ))],
+    );
+}
+
+#[test]
+fn migrate_record_layout() {
+    // Payer
+    let (payer, payer_data) = keyed_account_for_authority();
+    // Owner
+    let (owner, _) = keyed_account_for_owner();
+    // Class, with the record left to migrate
+    let (class, class_data) = keyed_account_for_class_with_legacy_records(1);
+    // Migrated Class
+    let (_, migrated_class_data) = keyed_account_for_class_with_counts(1, 0, 0);
+    // Legacy Record
+    let (record, record_data) =
+        keyed_account_for_legacy_record(class, 0, owner, false, 0, b"test", b"test");
+    // Migrated Record
+    let (_, migrated_record_data) =
+        keyed_account_for_record(class, 0, owner, false, 0, b"test", b"test");
+    // System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
+
+    let instruction = MigrateRecordLayout {
+        payer,
         record,
-        mint,
-        token_account,
-        token2022,
-        class: Some(class),
+        class,
+        system_program,
     }
-    .instruction();
+    .instruction(MigrateRecordLayoutInstructionArgs { mint_bump: 0 });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
-    mollusk.process_and_validate_instruction_chain(
-        &[
-            (&mint_instruction, &[Check::success()]),
-            (&burn_instruction, &[Check::success()]),
-        ],
+    mollusk.process_and_validate_instruction(
+        &instruction,
         &[
-            (owner, owner_data),
             (payer, payer_data),
-            (authority, authority_data),
             (record, record_data),
-            (mint, Account::default()),
             (class, class_data),
-            (group, Account::default()),
-            (token_account, Account::default()),
-            (associated_token_program, associated_token_program_data),
-            (token2022, token2022_data),
             (system_program, system_program_data),
         ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&migrated_record_data.data).build(),
+            Check::account(&class).data(&migrated_class_data.data).build(),
+        ],
     );
 }
 
 #[test]
-fn update_tokenized_record() {
-    // Authority
-    let (authority, authority_data) = keyed_account_for_authority();
+fn migrate_record_layout_tokenized() {
     // Payer
-    let (payer, payer_data) = keyed_account_for_random_authority();
+    let (payer, payer_data) = keyed_account_for_authority();
     // Class
-    let (class, class_data) = keyed_account_for_class(authority, true, false, "test", "test");
+    let (class, class_data) = keyed_account_for_class_default();
+    // Migrated Class
+    let (_, migrated_class_data) = keyed_account_for_class_with_counts(1, 1, 0);
     // Mint
-    let (record_address, _) = Pubkey::find_program_address(
-        &[b"record", &class.as_ref(), b"test"],
+    let (record, _bump) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), b"test"],
         &TREZOA_RECORD_SERVICE_ID,
     );
-    let (mint, mint_data) = keyed_account_for_mint(record_address);
-    // Record
-    let (record, record_data) =
+    let (mint, _) = keyed_account_for_mint(record);
+    // Legacy Record
+    let (_, record_data) =
+        keyed_account_for_legacy_record(class, 1, mint, false, 0, b"test", b"test");
+    // Migrated Record
+    let (_, migrated_record_data) =
         keyed_account_for_record(class, 1, mint, false, 0, b"test", b"test");
-    // ATA
-    let (token_account, token_account_data) = keyed_account_for_token(OWNER, mint, false);
-    let (token2022, token2022_data) = mollusk_svm_programs_token::token2022::keyed_account();
+    // System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let burn_instruction = BurnTokenizedRecord {
-        authority,
+    let instruction = MigrateRecordLayout {
         payer,
         record,
-        mint,
-        token_account,
-        token2022,
-        class: Some(class),
+        class,
+        system_program,
     }
-    .instruction();
+    .instruction(MigrateRecordLayoutInstructionArgs {
+        mint_bump: make_mint_bump(&record),
+    });
 
-    // New metadata
-    let new_metadata = Metadata {
-        name: make_u32prefix_string("test2"),
-        symbol: make_u32prefix_string("SRS"),
-        uri: make_u32prefix_string("test"),
-        additional_metadata: vec![],
-    };
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
 
-    // Record updated
-    let (_, record_data_updated) = keyed_account_for_record_with_metadata(
-        class,
-        0,
-        OWNER,
-        false,
-        0,
-        "test",
-        Some(&new_metadata.try_to_vec().unwrap()),
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[
+            Check::success(),
+            Check::account(&record).data(&migrated_record_data.data).build(),
+            Check::account(&class).data(&migrated_class_data.data).build(),
+        ],
     );
+}
 
-    //System Program
+#[test]
+fn fail_migrate_record_layout_invalid_mint_bump() {
+    // Payer
+    let (payer, payer_data) = keyed_account_for_authority();
+    // Class
+    let (class, class_data) = keyed_account_for_class_default();
+    // Mint
+    let (record, _bump) = Pubkey::find_program_address(
+        &[b"record", class.as_ref(), b"test"],
+        &TREZOA_RECORD_SERVICE_ID,
+    );
+    let (mint, _) = keyed_account_for_mint(record);
+    // Legacy Record
+    let (_, record_data) =
+        keyed_account_for_legacy_record(class, 1, mint, false, 0, b"test", b"test");
+    // System Program
     let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let update_instruction = UpdateRecordTokenizable {
-        authority,
+    let instruction = MigrateRecordLayout {
         payer,
         record,
         class,
         system_program,
     }
-    .instruction(UpdateRecordTokenizableInstructionArgs {
-        metadata: Metadata {
-            name: make_u32prefix_string("test2"),
-            symbol: make_u32prefix_string("SRS"),
-            uri: make_u32prefix_string("test"),
-            additional_metadata: vec![],
-        },
+    .instruction(MigrateRecordLayoutInstructionArgs {
+        mint_bump: make_mint_bump(&record).wrapping_sub(1),
     });
 
-    // Group
-    let (group, group_data) = keyed_account_for_group(class);
-    // Associated Token Program
-    let (associated_token_program, associated_token_program_data) =
-        mollusk_svm_programs_token::associated_token::keyed_account();
+    let mollusk = Mollusk::new(
+        &TREZOA_RECORD_SERVICE_ID,
+        "../target/deploy/trezoa_record_service",
+    );
 
-    // New Mint
-    let (_, new_mint_data) = keyed_account_for_updated_mint(record);
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (payer, payer_data),
+            (record, record_data),
+            (class, class_data),
+            (system_program, system_program_data),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidMint as u32,
+        ))],
+    );
+}
 
+#[test]
+fn fail_migrate_record_layout_legacy_class() {
+    // Payer
+    let (payer, payer_data) = keyed_account_for_authority();
     // Owner
-    let (owner, owner_data) = keyed_account_for_owner();
+    let (owner, _) = keyed_account_for_owner();
+    // Legacy Class
+    let (class, class_data) = keyed_account_for_legacy_class(AUTHORITY, false, false, "test", "test");
+    // Legacy Record
+    let (record, record_data) =
+        keyed_account_for_legacy_record(class, 0, owner, false, 0, b"test", b"test");
+    // System Program
+    let (system_program, system_program_data) = keyed_account_for_system_program();
 
-    let mint_instruction = MintTokenizedRecord {
-        owner,
-        payer: authority,
-        authority,
+    let instruction = MigrateRecordLayout {
+        payer,
         record,
-        mint,
         class,
-        group,
-        token_account,
-        associated_token_program,
-        token2022,
         system_program,
     }
-    .instruction();
+    .instruction(MigrateRecordLayoutInstructionArgs { mint_bump: 0 });
 
-    let mut mollusk = Mollusk::new(
+    let mollusk = Mollusk::new(
         &TREZOA_RECORD_SERVICE_ID,
         "../target/deploy/trezoa_record_service",
     );
 
-    mollusk_svm_programs_token::associated_token::add_program(&mut mollusk);
-    mollusk_svm_programs_token::token2022::add_program(&mut mollusk);
-
-    mollusk.process_and_validate_instruction_chain(
-        &[
-            (
-                &burn_instruction, 
-                &[Check::success()]
-            ),
-            (
-                &update_instruction,
-                &[
-                    Check::success(),
-                    Check::account(&record)
-                        .data(&record_data_updated.data)
-                        .build(),
-                ],
-            ),
-            (
-                &mint_instruction,
-                &[
-                    Check::success(),
-                    Check::account(&mint).data(&new_mint_data.data).build(),
-                ],
-            ),
-        ],
+    mollusk.process_and_validate_instruction(
+        &instruction,
         &[
-            (owner, owner_data),
             (payer, payer_data),
-            (authority, authority_data),
             (record, record_data),
-            (mint, mint_data),
             (class, class_data),
-            (group, group_data),
-            (token_account, token_account_data),
-            (associated_token_program, associated_token_program_data),
-            (token2022, token2022_data),
             (system_program, system_program_data),
         ],
+        &[Check::err(ProgramError::Custom(
+            TrezoaRecordServiceError::InvalidAccountDiscriminator as u32,
+        ))],
     );
 }
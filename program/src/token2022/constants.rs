@@ -5,6 +5,7 @@ pub const TOKEN_2022_MINT_LEN: usize = 0x52;
 pub const TOKEN_2022_MINT_BASE_LEN: usize = 0x54;
 pub const TOKEN_2022_PERMANENT_DELEGATE_LEN: usize = 0x24;
 pub const TOKEN_2022_CLOSE_MINT_AUTHORITY_LEN: usize = 0x24;
+pub const TOKEN_2022_NON_TRANSFERABLE_LEN: usize = 0x04;
 pub const TOKEN_2022_METADATA_POINTER_LEN: usize = 0x44;
 pub const TOKEN_2022_METADATA_LEN: usize = 0x44;
 pub const TOKEN_2022_GROUP_POINTER_LEN: usize = 0x44;
@@ -9,6 +9,7 @@ use pinocchio::{
 };
 
 use crate::{
+    error::RecordServiceError,
     token2022::constants::TOKEN_2022_PROGRAM_ID,
     utils::{write_bytes, UNINIT_BYTE},
 };
@@ -41,6 +42,8 @@ impl InitializeMetadata<'_> {
 
     const DISCRIMINATOR_OFFSET: usize = 0;
     const METADATA_DATA_OFFSET: usize = Self::DISCRIMINATOR_OFFSET + size_of::<[u8; 8]>();
+    /// Size of the stack buffer holding the instruction data
+    const MAX_INSTRUCTION_DATA_LEN: usize = 2_000;
 
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
         const INITIALIZE_METADATA_DISCRIMINATOR: [u8; 8] = [0xd2, 0xe1, 0x1e, 0xa2, 0x58, 0xb8, 0x4d, 0x8d];
@@ -60,7 +63,11 @@ impl InitializeMetadata<'_> {
         // - [0]: instruction discriminator (8 bytes, [u8;8])
         // - [8..]: metadata data
         let instruction_data_size = INITIALIZE_METADATA_DISCRIMINATOR.len() + self.metadata_data.len();
-        let mut instruction_data = [UNINIT_BYTE; 2_000];
+        if instruction_data_size > Self::MAX_INSTRUCTION_DATA_LEN {
+            return Err(RecordServiceError::MetadataTooLong.into());
+        }
+
+        let mut instruction_data = [UNINIT_BYTE; Self::MAX_INSTRUCTION_DATA_LEN];
 
         write_bytes(
             &mut instruction_data[Self::DISCRIMINATOR_OFFSET..],
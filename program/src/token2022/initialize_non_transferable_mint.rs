@@ -0,0 +1,50 @@
+use core::slice::from_raw_parts;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    ProgramResult,
+};
+
+use crate::{
+    token2022::constants::TOKEN_2022_PROGRAM_ID,
+    utils::{write_bytes, UNINIT_BYTE},
+};
+
+/// Initializes the Non Transferable extension of a mint.
+///
+/// ### Accounts:
+///   0. `[WRITE]`  The mint account to initialize as non transferable.
+pub struct InitializeNonTransferableMint<'a> {
+    /// Mint Account.
+    pub mint: &'a AccountInfo,
+}
+
+impl InitializeNonTransferableMint<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        const DISCRIMINATOR: u8 = 0x20;
+
+        // Account metadata
+        let account_metas: [AccountMeta; 1] = [AccountMeta::writable(self.mint.key())];
+
+        // instruction data
+        // -  [0]: instruction discriminator (1 byte, u8)
+        let mut instruction_data = [UNINIT_BYTE; 1];
+
+        write_bytes(&mut instruction_data, &[DISCRIMINATOR]);
+
+        let instruction = Instruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: &account_metas,
+            data: unsafe { from_raw_parts(instruction_data.as_ptr() as _, instruction_data.len()) },
+        };
+
+        invoke_signed(&instruction, &[self.mint], signers)
+    }
+}
@@ -4,6 +4,9 @@ pub use initialize_mint_close_authority::*;
 pub mod initialize_permanent_delegate;
 pub use initialize_permanent_delegate::*;
 
+pub mod initialize_non_transferable_mint;
+pub use initialize_non_transferable_mint::*;
+
 pub mod initialize_metadata_pointer;
 pub use initialize_metadata_pointer::*;
 
@@ -1,4 +1,4 @@
-use crate::token2022::constants::TOKEN_2022_PROGRAM_ID;
+use crate::{error::RecordServiceError, token2022::constants::TOKEN_2022_PROGRAM_ID};
 use core::mem::size_of;
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
@@ -7,6 +7,8 @@ const TOKEN_IS_FROZEN_FLAG: u8 = 2;
 const MINT_DISCRIMINATOR: u8 = 0x01;
 const TOKEN_ACCOUNT_DISCRIMINATOR: u8 = 0x02;
 const TOKEN_ACCOUNT_SUPPLY_OFFSET: usize = 36;
+const TOKEN_2022_EXTENSIONS_OFFSET: usize = TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET + 1;
+const MINT_CLOSE_AUTHORITY_EXTENSION_TYPE: u16 = 3;
 
 #[repr(C)]
 pub struct Mint<'info> {
@@ -26,7 +28,7 @@ impl<'info> Mint<'info> {
     /// Token Program ID is not checked
     pub unsafe fn check_discriminator_unchecked(data: &[u8]) -> Result<(), ProgramError> {
         if data[TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET].ne(&MINT_DISCRIMINATOR) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::InvalidMint.into());
         }
 
         Ok(())
@@ -46,9 +48,34 @@ impl<'info> Mint<'info> {
         Ok(true)
     }
 
+    /// Check if the mint was initialized with the MintCloseAuthority extension,
+    /// mints without it can't be closed
+    pub fn has_close_authority(account_info: &AccountInfo) -> Result<bool, ProgramError> {
+        if !Self::check_discriminator(account_info)? {
+            return Ok(false);
+        }
+
+        let data = account_info.try_borrow_data()?;
+
+        // Walk the TLV entries of the extensions
+        let mut offset = TOKEN_2022_EXTENSIONS_OFFSET;
+        while offset + 2 * size_of::<u16>() <= data.len() {
+            let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+            let length = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+
+            if extension_type == MINT_CLOSE_AUTHORITY_EXTENSION_TYPE {
+                return Ok(true);
+            }
+
+            offset += 2 * size_of::<u16>() + length;
+        }
+
+        Ok(false)
+    }
+
     pub fn get_supply(account_info: &AccountInfo) -> Result<u64, ProgramError> {
         if unsafe { account_info.owner().ne(&TOKEN_2022_PROGRAM_ID) } {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::InvalidMint.into());
         }
 
         let data = account_info.try_borrow_data()?;
@@ -89,7 +116,7 @@ impl<'info> Token<'info> {
     /// Token Program ID is not checked
     pub unsafe fn check_discriminator_unchecked(data: &[u8]) -> Result<(), ProgramError> {
         if data[TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET].ne(&TOKEN_ACCOUNT_DISCRIMINATOR) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(RecordServiceError::InvalidTokenAccount.into());
         }
 
         Ok(())
@@ -9,6 +9,7 @@ use pinocchio::{
 };
 
 use crate::{
+    error::RecordServiceError,
     token2022::constants::TOKEN_2022_PROGRAM_ID,
     utils::{write_bytes, UNINIT_BYTE},
 };
@@ -32,13 +33,19 @@ pub struct UpdateMetadata<'a> {
     pub metadata: &'a AccountInfo,
     /// Update Authority Account [signer]
     pub update_authority: &'a AccountInfo,
-    /// URI to update the metadata to
-    pub additional_metadata: &'a [u8],
+    /// Key of the additional metadata field to update
+    pub key: &'a [u8],
+    /// Value to write for the additional metadata field
+    pub value: &'a [u8],
 }
 
 const DISCRIMINATOR_OFFSET: usize = 0;
 const FIELD_OFFSET: usize = DISCRIMINATOR_OFFSET + size_of::<[u8; 8]>();
-const ADDITIONAL_METADATA_LENGTH_OFFSET: usize = FIELD_OFFSET + size_of::<u8>();
+const KEY_LENGTH_OFFSET: usize = FIELD_OFFSET + size_of::<u8>();
+const KEY_OFFSET: usize = KEY_LENGTH_OFFSET + size_of::<u32>();
+
+/// Size of the stack buffer holding the instruction data
+const MAX_INSTRUCTION_DATA_LEN: usize = 2_000;
 
 impl UpdateMetadata<'_> {
     #[inline(always)]
@@ -48,6 +55,8 @@ impl UpdateMetadata<'_> {
 
     pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
         const DISCRIMINATOR: [u8; 8] = [0xdd, 0xe9, 0x31, 0x2d, 0xb5, 0xca, 0xdc, 0xc8];
+        // The field is `Field::Key`, an additional metadata field
+        const KEY_FIELD: u8 = 3;
 
         // Account metadata
         let account_metas: [AccountMeta; 2] = [
@@ -58,10 +67,19 @@ impl UpdateMetadata<'_> {
         // instruction data
         // - [0]: instruction discriminator (8 bytes, [u8;8])
         // - [8]: field (u8)
-        // - [9..13]: additional metadata length (u32)
-        // - [13..13+additional_metadata.len()]: additional metadata bytes
-        let instruction_data_size = DISCRIMINATOR.len() + size_of::<u8>() + self.additional_metadata.len();
-        let mut instruction_data = [UNINIT_BYTE; 2_000];
+        // - [9..13]: key length (u32)
+        // - [13..13+key.len()]: key bytes
+        // - [..]: value length (u32)
+        // - [..]: value bytes
+        let value_length_offset = KEY_OFFSET + self.key.len();
+        let value_offset = value_length_offset + size_of::<u32>();
+        let instruction_data_size = value_offset + self.value.len();
+
+        if instruction_data_size > MAX_INSTRUCTION_DATA_LEN {
+            return Err(RecordServiceError::MetadataTooLong.into());
+        }
+
+        let mut instruction_data = [UNINIT_BYTE; MAX_INSTRUCTION_DATA_LEN];
 
         write_bytes(
             &mut instruction_data[DISCRIMINATOR_OFFSET..],
@@ -69,13 +87,21 @@ impl UpdateMetadata<'_> {
         );
 
         // Write field at offset [8]
-        write_bytes(&mut instruction_data[FIELD_OFFSET..], &[3]);
+        write_bytes(&mut instruction_data[FIELD_OFFSET..], &[KEY_FIELD]);
+
+        // Write key length and key at offset [9..]
+        write_bytes(
+            &mut instruction_data[KEY_LENGTH_OFFSET..],
+            &(self.key.len() as u32).to_le_bytes(),
+        );
+        write_bytes(&mut instruction_data[KEY_OFFSET..], self.key);
 
-        // Write additional metadata length at offset [9..13]
+        // Write value length and value after the key
         write_bytes(
-            &mut instruction_data[ADDITIONAL_METADATA_LENGTH_OFFSET..],
-            self.additional_metadata,
+            &mut instruction_data[value_length_offset..],
+            &(self.value.len() as u32).to_le_bytes(),
         );
+        write_bytes(&mut instruction_data[value_offset..], self.value);
 
         let instruction = Instruction {
             program_id: &TOKEN_2022_PROGRAM_ID,
@@ -1,11 +1,17 @@
+use crate::{
+    constants::{MAX_ACCOUNT_SIZE, MERKLE_LEAF_PREFIX, MERKLE_NODE_PREFIX},
+    error::RecordServiceError,
+};
 use core::mem::size_of;
 use pinocchio::{
     account_info::{AccountInfo, RefMut},
+    instruction::Signer,
     program_error::ProgramError,
+    pubkey::{create_program_address, MAX_SEEDS},
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
-use pinocchio_system::instructions::Transfer;
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 pub struct Context<'info> {
     pub accounts: &'info [AccountInfo],
     pub data: &'info [u8],
@@ -29,9 +35,10 @@ pub fn resize_account(
     new_size: usize,
     zero_out: bool,
 ) -> ProgramResult {
-    // Check if the new size is bigger than 10KB
-    if new_size > 1024 * 10 {
-        return Err(ProgramError::InvalidAccountData);
+    // Check if the new size is bigger than the runtime limit, the growth of an
+    // account in a single instruction is also limited by the runtime
+    if new_size > MAX_ACCOUNT_SIZE {
+        return Err(RecordServiceError::AccountTooLarge.into());
     }
 
     // If the account is already the correct size, return early
@@ -75,6 +82,133 @@ pub fn resize_account(
     Ok(())
 }
 
+/// Create a program owned PDA account
+///
+/// This function will:
+/// 1. Create the account if it has no lamports
+/// 2. Otherwise allocate and assign the pre-funded account, topping up the rent if needed
+///
+/// # Arguments
+/// * `account` - The PDA account to create
+/// * `payer` - The account that will pay for the rent
+/// * `space` - The size of the account data
+/// * `signers` - The PDA signer seeds of the account
+pub fn create_pda_account(
+    account: &AccountInfo,
+    payer: &AccountInfo,
+    space: usize,
+    signers: &[Signer],
+) -> ProgramResult {
+    let rent = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() > 0 {
+        Allocate {
+            account,
+            space: space as u64,
+        }
+        .invoke_signed(signers)?;
+
+        Assign {
+            account,
+            owner: &crate::ID,
+        }
+        .invoke_signed(signers)?;
+
+        if account.lamports() < rent {
+            Transfer {
+                from: payer,
+                to: account,
+                lamports: rent - account.lamports(),
+            }
+            .invoke()?;
+        }
+    } else {
+        CreateAccount {
+            from: payer,
+            to: account,
+            lamports: rent,
+            space: space as u64,
+            owner: &crate::ID,
+        }
+        .invoke_signed(signers)?;
+    }
+
+    Ok(())
+}
+
+/// Check that an account is the program PDA of `seeds`, the last seed being
+/// the bump passed in the instruction data
+///
+/// The bump must be the canonical one, so that an account has a single
+/// address for the same seeds. Once the account is created, its bump is read
+/// from the stored state instead.
+///
+/// # Arguments
+/// * `account` - The account expected at the PDA
+/// * `seeds` - The seeds of the PDA, including the bump
+/// * `error` - The error returned if the account is not at the PDA
+pub fn check_program_address(
+    account: &AccountInfo,
+    seeds: &[&[u8]],
+    error: RecordServiceError,
+) -> ProgramResult {
+    let address = create_program_address(seeds, &crate::ID).map_err(|_| error)?;
+
+    if address.ne(account.key()) {
+        return Err(error.into());
+    }
+
+    check_canonical_bump(seeds, error)
+}
+
+/// Check that the bump of a program PDA is the canonical one, which means that
+/// no higher bump gives a valid address for the same seeds
+///
+/// # Arguments
+/// * `seeds` - The seeds of the PDA, including the bump as the last seed
+/// * `error` - The error returned if the bump is not the canonical one
+pub fn check_canonical_bump(seeds: &[&[u8]], error: RecordServiceError) -> ProgramResult {
+    let (bump, seeds) = seeds.split_last().ok_or(error)?;
+
+    let [bump] = bump else {
+        return Err(error.into());
+    };
+
+    if seeds.len() >= MAX_SEEDS {
+        return Err(error.into());
+    }
+
+    // Every bump above the one passed in must give an invalid address
+    for previous_bump in *bump..u8::MAX {
+        let higher_bump = [previous_bump + 1];
+
+        let mut higher_seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+        higher_seeds[..seeds.len()].copy_from_slice(seeds);
+        higher_seeds[seeds.len()] = &higher_bump;
+
+        if create_program_address(&higher_seeds[..=seeds.len()], &crate::ID).is_ok() {
+            return Err(error.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Close a program owned account and send its lamports to the destination
+///
+/// Unlike records, these accounts are closed completely so that they can be
+/// created again with the same address.
+///
+/// # Arguments
+/// * `account` - The account to close
+/// * `destination` - The account that will receive the lamports
+pub fn close_account(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    *destination.try_borrow_mut_lamports()? =
+        destination.lamports().saturating_add(account.lamports());
+
+    account.close()
+}
+
 pub struct ByteReader<'info> {
     data: &'info [u8],
     offset: usize,
@@ -239,3 +373,67 @@ pub fn write_bytes(destination: &mut [core::mem::MaybeUninit<u8>], source: &[u8]
         d.write(*s);
     }
 }
+
+/// Compute the sha256 hash of the concatenation of `values`
+///
+/// # Arguments
+/// * `values` - The byte slices to hash, in order
+#[inline(always)]
+pub fn hashv(values: &[&[u8]]) -> [u8; 32] {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash = [0u8; 32];
+
+        unsafe {
+            pinocchio::syscalls::sol_sha256(
+                values as *const _ as *const u8,
+                values.len() as u64,
+                hash.as_mut_ptr(),
+            );
+        }
+
+        hash
+    }
+
+    // Off-chain builds (tests and clients) hash with the same algorithm as the syscall
+    #[cfg(not(target_os = "solana"))]
+    {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        for value in values {
+            hasher.update(value);
+        }
+
+        hasher.finalize().into()
+    }
+}
+
+/// Check if the hash of `leaf` is a leaf of the merkle tree of `root`
+///
+/// Leaves and nodes are domain separated, and the two children of a node are
+/// hashed in sorted order, so the proof only holds the sibling hashes.
+///
+/// # Arguments
+/// * `root` - The root of the merkle tree
+/// * `leaf` - The leaf value, hashed before being checked
+/// * `proof` - The concatenated 32 bytes sibling hashes, from the leaf to the root
+pub fn verify_merkle_proof(root: &[u8], leaf: &[u8], proof: &[u8]) -> bool {
+    let siblings = proof.chunks_exact(32);
+
+    if !siblings.remainder().is_empty() {
+        return false;
+    }
+
+    let mut hash = hashv(&[MERKLE_LEAF_PREFIX, leaf]);
+
+    for sibling in siblings {
+        hash = if hash.as_slice() <= sibling {
+            hashv(&[MERKLE_NODE_PREFIX, &hash, sibling])
+        } else {
+            hashv(&[MERKLE_NODE_PREFIX, sibling, &hash])
+        };
+    }
+
+    hash == root
+}
@@ -0,0 +1,31 @@
+//! Message signed by the class authority for `CreateRecordFromSignature`.
+//!
+//! The authority signs it with its Ed25519 key and the signature is verified
+//! by an Ed25519 precompile instruction of the same transaction.
+
+use trezoa_program::{hash::hash, pubkey::Pubkey};
+
+/// Message attesting the record to be created, the class authority signs
+/// class | seed length (u8) | seed | bump (u8) | owner | expiry (i64) |
+/// content type (u8) | sha256 of the data
+pub fn record_attestation(
+    class: &Pubkey,
+    seed: &[u8],
+    bump: u8,
+    owner: &Pubkey,
+    expiry: i64,
+    content_type: u8,
+    data: &[u8],
+) -> Vec<u8> {
+    [
+        class.as_ref(),
+        &[seed.len() as u8],
+        seed,
+        &[bump],
+        owner.as_ref(),
+        &expiry.to_le_bytes(),
+        &[content_type],
+        hash(data).as_ref(),
+    ]
+    .concat()
+}
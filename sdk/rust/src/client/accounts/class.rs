@@ -5,6 +5,7 @@
 //! <https://github.com/trzledgerfoundation-idl/codoma>
 //!
 
+use crate::types::ClassPolicy;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use kaigan::types::RemainderStr;
@@ -22,6 +23,21 @@ pub struct Class {
     pub authority: Pubkey,
     pub is_permissioned: bool,
     pub is_frozen: bool,
+    pub is_non_transferable: bool,
+    pub policy: ClassPolicy,
+    pub record_count: u64,
+    pub tokenized_count: u64,
+    pub legacy_record_count: u64,
+    pub max_records: u64,
+    pub creation_fee: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub treasury: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub group_bump: u8,
+    pub schema_bump: u8,
     pub name: U8PrefixString,
     pub metadata: RemainderStr,
 }
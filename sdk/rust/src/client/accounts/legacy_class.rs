@@ -0,0 +1,141 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use kaigan::types::RemainderStr;
+use kaigan::types::U8PrefixString;
+use trezoa_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LegacyClass {
+    pub discriminator: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub authority: Pubkey,
+    pub is_permissioned: bool,
+    pub is_frozen: bool,
+    pub name: U8PrefixString,
+    pub metadata: RemainderStr,
+}
+
+impl LegacyClass {
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&trezoa_program::account_info::AccountInfo<'a>> for LegacyClass {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &trezoa_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
+
+#[cfg(feature = "fetch")]
+pub fn fetch_legacy_class(
+    rpc: &trezoa_client::rpc_client::RpcClient,
+    address: &trezoa_program::pubkey::Pubkey,
+) -> Result<crate::shared::DecodedAccount<LegacyClass>, std::io::Error> {
+    let accounts = fetch_all_legacy_class(rpc, &[*address])?;
+    Ok(accounts[0].clone())
+}
+
+#[cfg(feature = "fetch")]
+pub fn fetch_all_legacy_class(
+    rpc: &trezoa_client::rpc_client::RpcClient,
+    addresses: &[trezoa_program::pubkey::Pubkey],
+) -> Result<Vec<crate::shared::DecodedAccount<LegacyClass>>, std::io::Error> {
+    let accounts = rpc
+        .get_multiple_accounts(addresses)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+    let mut decoded_accounts: Vec<crate::shared::DecodedAccount<LegacyClass>> = Vec::new();
+    for i in 0..addresses.len() {
+        let address = addresses[i];
+        let account = accounts[i].as_ref().ok_or(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!("Account not found: {}", address),
+        ))?;
+        let data = LegacyClass::from_bytes(&account.data)?;
+        decoded_accounts.push(crate::shared::DecodedAccount {
+            address,
+            account: account.clone(),
+            data,
+        });
+    }
+    Ok(decoded_accounts)
+}
+
+#[cfg(feature = "fetch")]
+pub fn fetch_maybe_legacy_class(
+    rpc: &trezoa_client::rpc_client::RpcClient,
+    address: &trezoa_program::pubkey::Pubkey,
+) -> Result<crate::shared::MaybeAccount<LegacyClass>, std::io::Error> {
+    let accounts = fetch_all_maybe_legacy_class(rpc, &[*address])?;
+    Ok(accounts[0].clone())
+}
+
+#[cfg(feature = "fetch")]
+pub fn fetch_all_maybe_legacy_class(
+    rpc: &trezoa_client::rpc_client::RpcClient,
+    addresses: &[trezoa_program::pubkey::Pubkey],
+) -> Result<Vec<crate::shared::MaybeAccount<LegacyClass>>, std::io::Error> {
+    let accounts = rpc
+        .get_multiple_accounts(addresses)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+    let mut decoded_accounts: Vec<crate::shared::MaybeAccount<LegacyClass>> = Vec::new();
+    for i in 0..addresses.len() {
+        let address = addresses[i];
+        if let Some(account) = accounts[i].as_ref() {
+            let data = LegacyClass::from_bytes(&account.data)?;
+            decoded_accounts.push(crate::shared::MaybeAccount::Exists(
+                crate::shared::DecodedAccount {
+                    address,
+                    account: account.clone(),
+                    data,
+                },
+            ));
+        } else {
+            decoded_accounts.push(crate::shared::MaybeAccount::NotFound(address));
+        }
+    }
+    Ok(decoded_accounts)
+}
+
+#[cfg(feature = "trezoaanchor")]
+impl trezoaanchor_lang::AccountDeserialize for LegacyClass {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> trezoaanchor_lang::Result<Self> {
+        Ok(Self::deserialize(buf)?)
+    }
+}
+
+#[cfg(feature = "trezoaanchor")]
+impl trezoaanchor_lang::AccountSerialize for LegacyClass {}
+
+#[cfg(feature = "trezoaanchor")]
+impl trezoaanchor_lang::Owner for LegacyClass {
+    fn owner() -> Pubkey {
+        crate::TREZOA_RECORD_SERVICE_ID
+    }
+}
+
+#[cfg(feature = "trezoaanchor-idl-build")]
+impl trezoaanchor_lang::IdlBuild for LegacyClass {}
+
+#[cfg(feature = "trezoaanchor-idl-build")]
+impl trezoaanchor_lang::Discriminator for LegacyClass {
+    const DISCRIMINATOR: [u8; 8] = [0; 8];
+}
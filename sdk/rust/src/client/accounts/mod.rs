@@ -6,7 +6,19 @@
 //!
 
 pub(crate) mod r#class;
+pub(crate) mod r#class_delegate;
+pub(crate) mod r#class_schema;
+pub(crate) mod r#legacy_class;
+pub(crate) mod r#legacy_record;
+pub(crate) mod r#pending_class_authority;
 pub(crate) mod r#record;
+pub(crate) mod r#record_delegate;
 
 pub use self::r#class::*;
+pub use self::r#class_delegate::*;
+pub use self::r#class_schema::*;
+pub use self::r#legacy_class::*;
+pub use self::r#legacy_record::*;
+pub use self::r#pending_class_authority::*;
 pub use self::r#record::*;
+pub use self::r#record_delegate::*;
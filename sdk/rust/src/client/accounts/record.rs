@@ -28,6 +28,21 @@ pub struct Record {
     pub owner: Pubkey,
     pub is_frozen: bool,
     pub expiry: i64,
+    pub is_revoked: bool,
+    pub revocation_reason: u16,
+    pub revoked_at: i64,
+    pub version: u64,
+    pub generation: u32,
+    pub hash: [u8; 32],
+    pub write_state: u8,
+    pub staged_len: u32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub staged_payer: Pubkey,
+    pub content_type: u8,
+    pub mint_bump: u8,
     pub seed: U8PrefixVec<u8>,
     pub data: RemainderVec<u8>,
 }
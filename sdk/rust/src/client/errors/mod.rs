@@ -4,3 +4,7 @@
 //!
 //! <https://github.com/trzledgerfoundation-idl/codoma>
 //!
+
+pub(crate) mod r#trezoa_record_service;
+
+pub use self::r#trezoa_record_service::TrezoaRecordServiceError;
@@ -0,0 +1,156 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use num_derive::FromPrimitive;
+use thiserror::Error;
+
+#[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
+pub enum TrezoaRecordServiceError {
+    /// 0 - The account discriminator does not match the expected account type
+    #[error("The account discriminator does not match the expected account type")]
+    InvalidAccountDiscriminator = 0x0,
+    /// 1 - The authority is not the class authority
+    #[error("The authority is not the class authority")]
+    InvalidAuthority = 0x1,
+    /// 2 - The class is frozen
+    #[error("The class is frozen")]
+    ClassFrozen = 0x2,
+    /// 3 - The class is not permissioned
+    #[error("The class is not permissioned")]
+    ClassNotPermissioned = 0x3,
+    /// 4 - The record is frozen
+    #[error("The record is frozen")]
+    RecordFrozen = 0x4,
+    /// 5 - The class does not match the class of the record
+    #[error("The class does not match the class of the record")]
+    ClassMismatch = 0x5,
+    /// 6 - The authority is neither the record owner nor a valid delegate
+    #[error("The authority is neither the record owner nor a valid delegate")]
+    NotOwnerOrDelegate = 0x6,
+    /// 7 - The owner account does not match the owner of the record
+    #[error("The owner account does not match the owner of the record")]
+    InvalidOwner = 0x7,
+    /// 8 - The record owner type does not allow this operation
+    #[error("The record owner type does not allow this operation")]
+    InvalidOwnerType = 0x8,
+    /// 9 - The class account is required but was not provided
+    #[error("The class account is required but was not provided")]
+    MissingClass = 0x9,
+    /// 10 - The mint account is required but was not provided
+    #[error("The mint account is required but was not provided")]
+    MissingMint = 0xA,
+    /// 11 - The mint account is not the mint of the record
+    #[error("The mint account is not the mint of the record")]
+    InvalidMint = 0xB,
+    /// 12 - The mint supply is not zero
+    #[error("The mint supply is not zero")]
+    MintSupplyNotZero = 0xC,
+    /// 13 - The token account is not valid for the record
+    #[error("The token account is not valid for the record")]
+    InvalidTokenAccount = 0xD,
+    /// 14 - The group account is not the group of the class
+    #[error("The group account is not the group of the class")]
+    InvalidGroup = 0xE,
+    /// 15 - The program derived address could not be derived
+    #[error("The program derived address could not be derived")]
+    InvalidPda = 0xF,
+    /// 16 - The record is expired
+    #[error("The record is expired")]
+    RecordExpired = 0x10,
+    /// 17 - The record is not expired
+    #[error("The record is not expired")]
+    RecordNotExpired = 0x11,
+    /// 18 - The seed exceeds the maximum length
+    #[error("The seed exceeds the maximum length")]
+    SeedTooLong = 0x12,
+    /// 19 - The name exceeds the maximum length
+    #[error("The name exceeds the maximum length")]
+    NameTooLong = 0x13,
+    /// 20 - The metadata exceeds the maximum length
+    #[error("The metadata exceeds the maximum length")]
+    MetadataTooLong = 0x14,
+    /// 21 - The account is too small for the data it has to hold
+    #[error("The account is too small for the data it has to hold")]
+    AccountTooSmall = 0x15,
+    /// 22 - The account would exceed the maximum account size
+    #[error("The account would exceed the maximum account size")]
+    AccountTooLarge = 0x16,
+    /// 23 - The signer is not the pending authority of the class
+    #[error("The signer is not the pending authority of the class")]
+    InvalidPendingAuthority = 0x17,
+    /// 24 - The signer is not the delegate of the class delegate account
+    #[error("The signer is not the delegate of the class delegate account")]
+    InvalidClassDelegate = 0x18,
+    /// 25 - The class delegate does not have the required permission
+    #[error("The class delegate does not have the required permission")]
+    MissingPermission = 0x19,
+    /// 26 - The record delegate account does not match the record, its owner or the signer
+    #[error("The record delegate account does not match the record, its owner or the signer")]
+    InvalidRecordDelegate = 0x1A,
+    /// 27 - The record delegate is expired
+    #[error("The record delegate is expired")]
+    RecordDelegateExpired = 0x1B,
+    /// 28 - The schema account or its field definitions are invalid
+    #[error("The schema account or its field definitions are invalid")]
+    InvalidSchema = 0x1C,
+    /// 29 - The record data does not match the class schema
+    #[error("The record data does not match the class schema")]
+    SchemaMismatch = 0x1D,
+    /// 30 - The class policy is invalid
+    #[error("The class policy is invalid")]
+    InvalidPolicy = 0x1E,
+    /// 31 - The records of the class are non-transferable
+    #[error("The records of the class are non-transferable")]
+    NonTransferable = 0x1F,
+    /// 32 - The record is revoked
+    #[error("The record is revoked")]
+    RecordRevoked = 0x20,
+    /// 33 - The record changed since the expected version
+    #[error("The record changed since the expected version")]
+    RecordVersionMismatch = 0x21,
+    /// 34 - The record data is being written
+    #[error("The record data is being written")]
+    RecordWriting = 0x22,
+    /// 35 - The record data is not being written
+    #[error("The record data is not being written")]
+    RecordNotWriting = 0x23,
+    /// 36 - The class reached its maximum number of records
+    #[error("The class reached its maximum number of records")]
+    MaxRecordsReached = 0x24,
+    /// 37 - The treasury account does not match the class treasury
+    #[error("The treasury account does not match the class treasury")]
+    InvalidTreasury = 0x25,
+    /// 38 - The record owner is not in the class allowlist
+    #[error("The record owner is not in the class allowlist")]
+    NotInAllowlist = 0x26,
+    /// 39 - The signature of the class authority is missing or does not match the record
+    #[error("The signature of the class authority is missing or does not match the record")]
+    InvalidSignature = 0x27,
+    /// 40 - The class still has live records
+    #[error("The class still has live records")]
+    ClassNotEmpty = 0x28,
+    /// 41 - The record account is not the tombstone of a deleted record
+    #[error("The record account is not the tombstone of a deleted record")]
+    RecordNotDeleted = 0x29,
+    /// 42 - The payer account is not the payer of the buffered record
+    #[error("The payer account is not the payer of the buffered record")]
+    InvalidPayer = 0x2A,
+}
+
+#[allow(deprecated)]
+impl trezoa_program::program_error::PrintProgramError for TrezoaRecordServiceError {
+    fn print<E>(&self) {
+        trezoa_program::msg!(&self.to_string());
+    }
+}
+
+#[allow(deprecated)]
+impl<T> trezoa_program::decode_error::DecodeError<T> for TrezoaRecordServiceError {
+    fn type_of() -> &'static str {
+        "TrezoaRecordServiceError"
+    }
+}
@@ -0,0 +1,418 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct AcceptClassAuthority {
+    /// Proposed authority of the class
+    pub new_authority: trezoa_program::pubkey::Pubkey,
+    /// Current authority of the class that will get refunded for the pending authority account
+    pub authority: trezoa_program::pubkey::Pubkey,
+    /// Class account whose authority is being transferred
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// Pending authority account of the class
+    pub pending_class_authority: trezoa_program::pubkey::Pubkey,
+}
+
+impl AcceptClassAuthority {
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.new_authority,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.authority,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.pending_class_authority,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&AcceptClassAuthorityInstructionData::new()).unwrap();
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcceptClassAuthorityInstructionData {
+    discriminator: u8,
+}
+
+impl AcceptClassAuthorityInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 16 }
+    }
+}
+
+impl Default for AcceptClassAuthorityInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `AcceptClassAuthority`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` new_authority
+///   1. `[writable]` authority
+///   2. `[writable]` class
+///   3. `[writable]` pending_class_authority
+#[derive(Clone, Debug, Default)]
+pub struct AcceptClassAuthorityBuilder {
+    new_authority: Option<trezoa_program::pubkey::Pubkey>,
+    authority: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    pending_class_authority: Option<trezoa_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl AcceptClassAuthorityBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Proposed authority of the class
+    #[inline(always)]
+    pub fn new_authority(&mut self, new_authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.new_authority = Some(new_authority);
+        self
+    }
+    /// Current authority of the class that will get refunded for the pending authority account
+    #[inline(always)]
+    pub fn authority(&mut self, authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Class account whose authority is being transferred
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// Pending authority account of the class
+    #[inline(always)]
+    pub fn pending_class_authority(
+        &mut self,
+        pending_class_authority: trezoa_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.pending_class_authority = Some(pending_class_authority);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = AcceptClassAuthority {
+            new_authority: self.new_authority.expect("new_authority is not set"),
+            authority: self.authority.expect("authority is not set"),
+            class: self.class.expect("class is not set"),
+            pending_class_authority: self
+                .pending_class_authority
+                .expect("pending_class_authority is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `accept_class_authority` CPI accounts.
+pub struct AcceptClassAuthorityCpiAccounts<'a, 'b> {
+    /// Proposed authority of the class
+    pub new_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Current authority of the class that will get refunded for the pending authority account
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account whose authority is being transferred
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Pending authority account of the class
+    pub pending_class_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `accept_class_authority` CPI instruction.
+pub struct AcceptClassAuthorityCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Proposed authority of the class
+    pub new_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Current authority of the class that will get refunded for the pending authority account
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account whose authority is being transferred
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Pending authority account of the class
+    pub pending_class_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> AcceptClassAuthorityCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: AcceptClassAuthorityCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            new_authority: accounts.new_authority,
+            authority: accounts.authority,
+            class: accounts.class,
+            pending_class_authority: accounts.pending_class_authority,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.new_authority.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.authority.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.pending_class_authority.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&AcceptClassAuthorityInstructionData::new()).unwrap();
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.new_authority.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.class.clone());
+        account_infos.push(self.pending_class_authority.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AcceptClassAuthority` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` new_authority
+///   1. `[writable]` authority
+///   2. `[writable]` class
+///   3. `[writable]` pending_class_authority
+#[derive(Clone, Debug)]
+pub struct AcceptClassAuthorityCpiBuilder<'a, 'b> {
+    instruction: Box<AcceptClassAuthorityCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AcceptClassAuthorityCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AcceptClassAuthorityCpiBuilderInstruction {
+            __program: program,
+            new_authority: None,
+            authority: None,
+            class: None,
+            pending_class_authority: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Proposed authority of the class
+    #[inline(always)]
+    pub fn new_authority(
+        &mut self,
+        new_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_authority = Some(new_authority);
+        self
+    }
+    /// Current authority of the class that will get refunded for the pending authority account
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Class account whose authority is being transferred
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// Pending authority account of the class
+    #[inline(always)]
+    pub fn pending_class_authority(
+        &mut self,
+        pending_class_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pending_class_authority = Some(pending_class_authority);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let instruction = AcceptClassAuthorityCpi {
+            __program: self.instruction.__program,
+
+            new_authority: self
+                .instruction
+                .new_authority
+                .expect("new_authority is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+
+            pending_class_authority: self
+                .instruction
+                .pending_class_authority
+                .expect("pending_class_authority is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AcceptClassAuthorityCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    new_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    pending_class_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
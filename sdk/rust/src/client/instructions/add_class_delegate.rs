@@ -0,0 +1,530 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use trezoa_program::pubkey::Pubkey;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct AddClassDelegate {
+    /// Authority of the class
+    pub authority: trezoa_program::pubkey::Pubkey,
+    /// Account that will pay for the class delegate account
+    pub payer: trezoa_program::pubkey::Pubkey,
+    /// Class account the delegate acts on
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// Class delegate account of the delegate
+    pub class_delegate: trezoa_program::pubkey::Pubkey,
+    /// System Program used to create the class delegate account
+    pub system_program: trezoa_program::pubkey::Pubkey,
+}
+
+impl AddClassDelegate {
+    pub fn instruction(
+        &self,
+        args: AddClassDelegateInstructionArgs,
+    ) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AddClassDelegateInstructionArgs,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.class, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class_delegate,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&AddClassDelegateInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddClassDelegateInstructionData {
+    discriminator: u8,
+}
+
+impl AddClassDelegateInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 18 }
+    }
+}
+
+impl Default for AddClassDelegateInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddClassDelegateInstructionArgs {
+    pub delegate: Pubkey,
+    pub permissions: u8,
+    pub bump: u8,
+}
+
+/// Instruction builder for `AddClassDelegate`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable, signer]` payer
+///   2. `[]` class
+///   3. `[writable]` class_delegate
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Clone, Debug, Default)]
+pub struct AddClassDelegateBuilder {
+    authority: Option<trezoa_program::pubkey::Pubkey>,
+    payer: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    system_program: Option<trezoa_program::pubkey::Pubkey>,
+    delegate: Option<Pubkey>,
+    permissions: Option<u8>,
+    bump: Option<u8>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl AddClassDelegateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Authority of the class
+    #[inline(always)]
+    pub fn authority(&mut self, authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Account that will pay for the class delegate account
+    #[inline(always)]
+    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Class account the delegate acts on
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// Class delegate account of the delegate
+    #[inline(always)]
+    pub fn class_delegate(&mut self, class_delegate: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class_delegate = Some(class_delegate);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program used to create the class delegate account
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: Pubkey) -> &mut Self {
+        self.delegate = Some(delegate);
+        self
+    }
+    #[inline(always)]
+    pub fn permissions(&mut self, permissions: u8) -> &mut Self {
+        self.permissions = Some(permissions);
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = AddClassDelegate {
+            authority: self.authority.expect("authority is not set"),
+            payer: self.payer.expect("payer is not set"),
+            class: self.class.expect("class is not set"),
+            class_delegate: self.class_delegate.expect("class_delegate is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = AddClassDelegateInstructionArgs {
+            delegate: self.delegate.clone().expect("delegate is not set"),
+            permissions: self.permissions.clone().expect("permissions is not set"),
+            bump: self.bump.clone().expect("bump is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `add_class_delegate` CPI accounts.
+pub struct AddClassDelegateCpiAccounts<'a, 'b> {
+    /// Authority of the class
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the class delegate account
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account the delegate acts on
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class delegate account of the delegate
+    pub class_delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to create the class delegate account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `add_class_delegate` CPI instruction.
+pub struct AddClassDelegateCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Authority of the class
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the class delegate account
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account the delegate acts on
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class delegate account of the delegate
+    pub class_delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to create the class delegate account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AddClassDelegateInstructionArgs,
+}
+
+impl<'a, 'b> AddClassDelegateCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: AddClassDelegateCpiAccounts<'a, 'b>,
+        args: AddClassDelegateInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            authority: accounts.authority,
+            payer: accounts.payer,
+            class: accounts.class,
+            class_delegate: accounts.class_delegate,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.class.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class_delegate.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&AddClassDelegateInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.class.clone());
+        account_infos.push(self.class_delegate.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AddClassDelegate` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable, signer]` payer
+///   2. `[]` class
+///   3. `[writable]` class_delegate
+///   4. `[]` system_program
+#[derive(Clone, Debug)]
+pub struct AddClassDelegateCpiBuilder<'a, 'b> {
+    instruction: Box<AddClassDelegateCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AddClassDelegateCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AddClassDelegateCpiBuilderInstruction {
+            __program: program,
+            authority: None,
+            payer: None,
+            class: None,
+            class_delegate: None,
+            system_program: None,
+            delegate: None,
+            permissions: None,
+            bump: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Authority of the class
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Account that will pay for the class delegate account
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Class account the delegate acts on
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// Class delegate account of the delegate
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = Some(class_delegate);
+        self
+    }
+    /// System Program used to create the class delegate account
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: Pubkey) -> &mut Self {
+        self.instruction.delegate = Some(delegate);
+        self
+    }
+    #[inline(always)]
+    pub fn permissions(&mut self, permissions: u8) -> &mut Self {
+        self.instruction.permissions = Some(permissions);
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let args = AddClassDelegateInstructionArgs {
+            delegate: self
+                .instruction
+                .delegate
+                .clone()
+                .expect("delegate is not set"),
+            permissions: self
+                .instruction
+                .permissions
+                .clone()
+                .expect("permissions is not set"),
+            bump: self.instruction.bump.clone().expect("bump is not set"),
+        };
+        let instruction = AddClassDelegateCpi {
+            __program: self.instruction.__program,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+
+            class_delegate: self
+                .instruction
+                .class_delegate
+                .expect("class_delegate is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AddClassDelegateCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegate: Option<Pubkey>,
+    permissions: Option<u8>,
+    bump: Option<u8>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
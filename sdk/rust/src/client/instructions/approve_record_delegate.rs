@@ -0,0 +1,549 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use trezoa_program::pubkey::Pubkey;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct ApproveRecordDelegate {
+    /// Owner of the record
+    pub owner: trezoa_program::pubkey::Pubkey,
+    /// Account that will pay for the record delegate account
+    pub payer: trezoa_program::pubkey::Pubkey,
+    /// Record account the delegate acts on
+    pub record: trezoa_program::pubkey::Pubkey,
+    /// Record delegate account of the record
+    pub record_delegate: trezoa_program::pubkey::Pubkey,
+    /// System Program used to create the record delegate account
+    pub system_program: trezoa_program::pubkey::Pubkey,
+}
+
+impl ApproveRecordDelegate {
+    pub fn instruction(
+        &self,
+        args: ApproveRecordDelegateInstructionArgs,
+    ) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: ApproveRecordDelegateInstructionArgs,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.owner, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.record,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.record_delegate,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&ApproveRecordDelegateInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApproveRecordDelegateInstructionData {
+    discriminator: u8,
+}
+
+impl ApproveRecordDelegateInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 20 }
+    }
+}
+
+impl Default for ApproveRecordDelegateInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApproveRecordDelegateInstructionArgs {
+    pub delegate: Pubkey,
+    pub permissions: u8,
+    pub expiry: i64,
+    pub bump: u8,
+}
+
+/// Instruction builder for `ApproveRecordDelegate`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` owner
+///   1. `[writable, signer]` payer
+///   2. `[]` record
+///   3. `[writable]` record_delegate
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Clone, Debug, Default)]
+pub struct ApproveRecordDelegateBuilder {
+    owner: Option<trezoa_program::pubkey::Pubkey>,
+    payer: Option<trezoa_program::pubkey::Pubkey>,
+    record: Option<trezoa_program::pubkey::Pubkey>,
+    record_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    system_program: Option<trezoa_program::pubkey::Pubkey>,
+    delegate: Option<Pubkey>,
+    permissions: Option<u8>,
+    expiry: Option<i64>,
+    bump: Option<u8>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl ApproveRecordDelegateBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Owner of the record
+    #[inline(always)]
+    pub fn owner(&mut self, owner: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// Account that will pay for the record delegate account
+    #[inline(always)]
+    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Record account the delegate acts on
+    #[inline(always)]
+    pub fn record(&mut self, record: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.record = Some(record);
+        self
+    }
+    /// Record delegate account of the record
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: trezoa_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.record_delegate = Some(record_delegate);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program used to create the record delegate account
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: Pubkey) -> &mut Self {
+        self.delegate = Some(delegate);
+        self
+    }
+    #[inline(always)]
+    pub fn permissions(&mut self, permissions: u8) -> &mut Self {
+        self.permissions = Some(permissions);
+        self
+    }
+    #[inline(always)]
+    pub fn expiry(&mut self, expiry: i64) -> &mut Self {
+        self.expiry = Some(expiry);
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = ApproveRecordDelegate {
+            owner: self.owner.expect("owner is not set"),
+            payer: self.payer.expect("payer is not set"),
+            record: self.record.expect("record is not set"),
+            record_delegate: self.record_delegate.expect("record_delegate is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = ApproveRecordDelegateInstructionArgs {
+            delegate: self.delegate.clone().expect("delegate is not set"),
+            permissions: self.permissions.clone().expect("permissions is not set"),
+            expiry: self.expiry.clone().expect("expiry is not set"),
+            bump: self.bump.clone().expect("bump is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `approve_record_delegate` CPI accounts.
+pub struct ApproveRecordDelegateCpiAccounts<'a, 'b> {
+    /// Owner of the record
+    pub owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the record delegate account
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record account the delegate acts on
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record delegate account of the record
+    pub record_delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to create the record delegate account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `approve_record_delegate` CPI instruction.
+pub struct ApproveRecordDelegateCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Owner of the record
+    pub owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the record delegate account
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record account the delegate acts on
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record delegate account of the record
+    pub record_delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to create the record delegate account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: ApproveRecordDelegateInstructionArgs,
+}
+
+impl<'a, 'b> ApproveRecordDelegateCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: ApproveRecordDelegateCpiAccounts<'a, 'b>,
+        args: ApproveRecordDelegateInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            owner: accounts.owner,
+            payer: accounts.payer,
+            record: accounts.record,
+            record_delegate: accounts.record_delegate,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.owner.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.record.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.record_delegate.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&ApproveRecordDelegateInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.owner.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.record.clone());
+        account_infos.push(self.record_delegate.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ApproveRecordDelegate` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` owner
+///   1. `[writable, signer]` payer
+///   2. `[]` record
+///   3. `[writable]` record_delegate
+///   4. `[]` system_program
+#[derive(Clone, Debug)]
+pub struct ApproveRecordDelegateCpiBuilder<'a, 'b> {
+    instruction: Box<ApproveRecordDelegateCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ApproveRecordDelegateCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ApproveRecordDelegateCpiBuilderInstruction {
+            __program: program,
+            owner: None,
+            payer: None,
+            record: None,
+            record_delegate: None,
+            system_program: None,
+            delegate: None,
+            permissions: None,
+            expiry: None,
+            bump: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Owner of the record
+    #[inline(always)]
+    pub fn owner(&mut self, owner: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.owner = Some(owner);
+        self
+    }
+    /// Account that will pay for the record delegate account
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Record account the delegate acts on
+    #[inline(always)]
+    pub fn record(
+        &mut self,
+        record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.record = Some(record);
+        self
+    }
+    /// Record delegate account of the record
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.record_delegate = Some(record_delegate);
+        self
+    }
+    /// System Program used to create the record delegate account
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: Pubkey) -> &mut Self {
+        self.instruction.delegate = Some(delegate);
+        self
+    }
+    #[inline(always)]
+    pub fn permissions(&mut self, permissions: u8) -> &mut Self {
+        self.instruction.permissions = Some(permissions);
+        self
+    }
+    #[inline(always)]
+    pub fn expiry(&mut self, expiry: i64) -> &mut Self {
+        self.instruction.expiry = Some(expiry);
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let args = ApproveRecordDelegateInstructionArgs {
+            delegate: self
+                .instruction
+                .delegate
+                .clone()
+                .expect("delegate is not set"),
+            permissions: self
+                .instruction
+                .permissions
+                .clone()
+                .expect("permissions is not set"),
+            expiry: self.instruction.expiry.clone().expect("expiry is not set"),
+            bump: self.instruction.bump.clone().expect("bump is not set"),
+        };
+        let instruction = ApproveRecordDelegateCpi {
+            __program: self.instruction.__program,
+
+            owner: self.instruction.owner.expect("owner is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            record: self.instruction.record.expect("record is not set"),
+
+            record_delegate: self
+                .instruction
+                .record_delegate
+                .expect("record_delegate is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ApproveRecordDelegateCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    owner: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegate: Option<Pubkey>,
+    permissions: Option<u8>,
+    expiry: Option<i64>,
+    bump: Option<u8>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
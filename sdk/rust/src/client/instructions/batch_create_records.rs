@@ -0,0 +1,642 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use crate::types::BatchRecord;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use kaigan::types::U8PrefixVec;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct BatchCreateRecords {
+    /// Account that will pay for the record accounts
+    pub payer: trezoa_program::pubkey::Pubkey,
+    /// Class account for the records to be created
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// System Program used to create our record accounts
+    pub system_program: trezoa_program::pubkey::Pubkey,
+    /// Optional authority for permissioned classes
+    pub authority: Option<trezoa_program::pubkey::Pubkey>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// Schema account of the class, required if the class has a schema
+    pub schema: Option<trezoa_program::pubkey::Pubkey>,
+    /// Treasury account of the class, required if the class charges a creation fee
+    pub treasury: Option<trezoa_program::pubkey::Pubkey>,
+}
+
+impl BatchCreateRecords {
+    pub fn instruction(
+        &self,
+        args: BatchCreateRecordsInstructionArgs,
+    ) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: BatchCreateRecordsInstructionArgs,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                authority, true,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(schema) = self.schema {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                schema, false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(treasury) = self.treasury {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                treasury, false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&BatchCreateRecordsInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchCreateRecordsInstructionData {
+    discriminator: u8,
+}
+
+impl BatchCreateRecordsInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 36 }
+    }
+}
+
+impl Default for BatchCreateRecordsInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchCreateRecordsInstructionArgs {
+    pub records: U8PrefixVec<BatchRecord>,
+}
+
+/// Instruction builder for `BatchCreateRecords`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[writable]` class
+///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[signer, optional]` authority
+///   4. `[optional]` class_delegate
+///   5. `[optional]` schema
+///   6. `[writable, optional]` treasury
+#[derive(Clone, Debug, Default)]
+pub struct BatchCreateRecordsBuilder {
+    payer: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    system_program: Option<trezoa_program::pubkey::Pubkey>,
+    authority: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    schema: Option<trezoa_program::pubkey::Pubkey>,
+    treasury: Option<trezoa_program::pubkey::Pubkey>,
+    records: Option<U8PrefixVec<BatchRecord>>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl BatchCreateRecordsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Account that will pay for the record accounts
+    #[inline(always)]
+    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Class account for the records to be created
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program used to create our record accounts
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Optional authority for permissioned classes
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Schema account of the class, required if the class has a schema
+    #[inline(always)]
+    pub fn schema(&mut self, schema: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.schema = schema;
+        self
+    }
+    /// `[optional account]`
+    /// Treasury account of the class, required if the class charges a creation fee
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.treasury = treasury;
+        self
+    }
+    #[inline(always)]
+    pub fn records(&mut self, records: U8PrefixVec<BatchRecord>) -> &mut Self {
+        self.records = Some(records);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = BatchCreateRecords {
+            payer: self.payer.expect("payer is not set"),
+            class: self.class.expect("class is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+            authority: self.authority,
+            class_delegate: self.class_delegate,
+            schema: self.schema,
+            treasury: self.treasury,
+        };
+        let args = BatchCreateRecordsInstructionArgs {
+            records: self.records.clone().expect("records is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `batch_create_records` CPI accounts.
+pub struct BatchCreateRecordsCpiAccounts<'a, 'b> {
+    /// Account that will pay for the record accounts
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account for the records to be created
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to create our record accounts
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional authority for permissioned classes
+    pub authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Schema account of the class, required if the class has a schema
+    pub schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Treasury account of the class, required if the class charges a creation fee
+    pub treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+}
+
+/// `batch_create_records` CPI instruction.
+pub struct BatchCreateRecordsCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the record accounts
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account for the records to be created
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to create our record accounts
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional authority for permissioned classes
+    pub authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Schema account of the class, required if the class has a schema
+    pub schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Treasury account of the class, required if the class charges a creation fee
+    pub treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: BatchCreateRecordsInstructionArgs,
+}
+
+impl<'a, 'b> BatchCreateRecordsCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: BatchCreateRecordsCpiAccounts<'a, 'b>,
+        args: BatchCreateRecordsInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            payer: accounts.payer,
+            class: accounts.class,
+            system_program: accounts.system_program,
+            authority: accounts.authority,
+            class_delegate: accounts.class_delegate,
+            schema: accounts.schema,
+            treasury: accounts.treasury,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(schema) = self.schema {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *schema.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(treasury) = self.treasury {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *treasury.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&BatchCreateRecordsInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.class.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(authority) = self.authority {
+            account_infos.push(authority.clone());
+        }
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
+        if let Some(schema) = self.schema {
+            account_infos.push(schema.clone());
+        }
+        if let Some(treasury) = self.treasury {
+            account_infos.push(treasury.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `BatchCreateRecords` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[writable]` class
+///   2. `[]` system_program
+///   3. `[signer, optional]` authority
+///   4. `[optional]` class_delegate
+///   5. `[optional]` schema
+///   6. `[writable, optional]` treasury
+#[derive(Clone, Debug)]
+pub struct BatchCreateRecordsCpiBuilder<'a, 'b> {
+    instruction: Box<BatchCreateRecordsCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> BatchCreateRecordsCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(BatchCreateRecordsCpiBuilderInstruction {
+            __program: program,
+            payer: None,
+            class: None,
+            system_program: None,
+            authority: None,
+            class_delegate: None,
+            schema: None,
+            treasury: None,
+            records: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Account that will pay for the record accounts
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Class account for the records to be created
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// System Program used to create our record accounts
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Optional authority for permissioned classes
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority = authority;
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Schema account of the class, required if the class has a schema
+    #[inline(always)]
+    pub fn schema(
+        &mut self,
+        schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.schema = schema;
+        self
+    }
+    /// `[optional account]`
+    /// Treasury account of the class, required if the class charges a creation fee
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury = treasury;
+        self
+    }
+    #[inline(always)]
+    pub fn records(&mut self, records: U8PrefixVec<BatchRecord>) -> &mut Self {
+        self.instruction.records = Some(records);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let args = BatchCreateRecordsInstructionArgs {
+            records: self
+                .instruction
+                .records
+                .clone()
+                .expect("records is not set"),
+        };
+        let instruction = BatchCreateRecordsCpi {
+            __program: self.instruction.__program,
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            authority: self.instruction.authority,
+
+            class_delegate: self.instruction.class_delegate,
+
+            schema: self.instruction.schema,
+
+            treasury: self.instruction.treasury,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct BatchCreateRecordsCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    payer: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    records: Option<U8PrefixVec<BatchRecord>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,431 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct BatchFreezeRecords {
+    /// Class authority or a class delegate with the freeze permission
+    pub authority: trezoa_program::pubkey::Pubkey,
+    /// Class account of the records
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+}
+
+impl BatchFreezeRecords {
+    pub fn instruction(
+        &self,
+        args: BatchFreezeRecordsInstructionArgs,
+    ) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: BatchFreezeRecordsInstructionArgs,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.class, false,
+        ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&BatchFreezeRecordsInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchFreezeRecordsInstructionData {
+    discriminator: u8,
+}
+
+impl BatchFreezeRecordsInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 37 }
+    }
+}
+
+impl Default for BatchFreezeRecordsInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchFreezeRecordsInstructionArgs {
+    pub is_frozen: bool,
+}
+
+/// Instruction builder for `BatchFreezeRecords`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[]` class
+///   2. `[optional]` class_delegate
+#[derive(Clone, Debug, Default)]
+pub struct BatchFreezeRecordsBuilder {
+    authority: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    is_frozen: Option<bool>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl BatchFreezeRecordsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Class authority or a class delegate with the freeze permission
+    #[inline(always)]
+    pub fn authority(&mut self, authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Class account of the records
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
+    #[inline(always)]
+    pub fn is_frozen(&mut self, is_frozen: bool) -> &mut Self {
+        self.is_frozen = Some(is_frozen);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = BatchFreezeRecords {
+            authority: self.authority.expect("authority is not set"),
+            class: self.class.expect("class is not set"),
+            class_delegate: self.class_delegate,
+        };
+        let args = BatchFreezeRecordsInstructionArgs {
+            is_frozen: self.is_frozen.clone().expect("is_frozen is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `batch_freeze_records` CPI accounts.
+pub struct BatchFreezeRecordsCpiAccounts<'a, 'b> {
+    /// Class authority or a class delegate with the freeze permission
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account of the records
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+}
+
+/// `batch_freeze_records` CPI instruction.
+pub struct BatchFreezeRecordsCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class authority or a class delegate with the freeze permission
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account of the records
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: BatchFreezeRecordsInstructionArgs,
+}
+
+impl<'a, 'b> BatchFreezeRecordsCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: BatchFreezeRecordsCpiAccounts<'a, 'b>,
+        args: BatchFreezeRecordsInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            authority: accounts.authority,
+            class: accounts.class,
+            class_delegate: accounts.class_delegate,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.class.key,
+            false,
+        ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&BatchFreezeRecordsInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.class.clone());
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `BatchFreezeRecords` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[]` class
+///   2. `[optional]` class_delegate
+#[derive(Clone, Debug)]
+pub struct BatchFreezeRecordsCpiBuilder<'a, 'b> {
+    instruction: Box<BatchFreezeRecordsCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> BatchFreezeRecordsCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(BatchFreezeRecordsCpiBuilderInstruction {
+            __program: program,
+            authority: None,
+            class: None,
+            class_delegate: None,
+            is_frozen: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Class authority or a class delegate with the freeze permission
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Class account of the records
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
+    #[inline(always)]
+    pub fn is_frozen(&mut self, is_frozen: bool) -> &mut Self {
+        self.instruction.is_frozen = Some(is_frozen);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let args = BatchFreezeRecordsInstructionArgs {
+            is_frozen: self
+                .instruction
+                .is_frozen
+                .clone()
+                .expect("is_frozen is not set"),
+        };
+        let instruction = BatchFreezeRecordsCpi {
+            __program: self.instruction.__program,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+
+            class_delegate: self.instruction.class_delegate,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct BatchFreezeRecordsCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    is_frozen: Option<bool>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -24,7 +24,9 @@ pub struct BurnTokenizedRecord {
     /// Token2022 Program used to burn the tokenized record
     pub token2022: trezoa_program::pubkey::Pubkey,
     /// Class account of the record
-    pub class: Option<trezoa_program::pubkey::Pubkey>,
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 impl BurnTokenizedRecord {
@@ -37,7 +39,7 @@ impl BurnTokenizedRecord {
         &self,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -60,9 +62,13 @@ impl BurnTokenizedRecord {
             self.token2022,
             false,
         ));
-        if let Some(class) = self.class {
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class, false,
+        ));
+        if let Some(class_delegate) = self.class_delegate {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
-                class, false,
+                class_delegate,
+                false,
             ));
         } else {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
@@ -109,7 +115,8 @@ impl Default for BurnTokenizedRecordInstructionData {
 ///   3. `[writable]` token_account
 ///   4. `[writable]` record
 ///   5. `[optional]` token2022 (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
-///   6. `[optional]` class
+///   6. `[writable]` class
+///   7. `[optional]` class_delegate
 #[derive(Clone, Debug, Default)]
 pub struct BurnTokenizedRecordBuilder {
     authority: Option<trezoa_program::pubkey::Pubkey>,
@@ -119,6 +126,7 @@ pub struct BurnTokenizedRecordBuilder {
     record: Option<trezoa_program::pubkey::Pubkey>,
     token2022: Option<trezoa_program::pubkey::Pubkey>,
     class: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
 
@@ -163,11 +171,20 @@ impl BurnTokenizedRecordBuilder {
         self.token2022 = Some(token2022);
         self
     }
-    /// `[optional account]`
     /// Class account of the record
     #[inline(always)]
-    pub fn class(&mut self, class: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
-        self.class = class;
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
         self
     }
     /// Add an additional account to the instruction.
@@ -199,7 +216,8 @@ impl BurnTokenizedRecordBuilder {
             token2022: self.token2022.unwrap_or(trezoa_program::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
-            class: self.class,
+            class: self.class.expect("class is not set"),
+            class_delegate: self.class_delegate,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -221,7 +239,9 @@ pub struct BurnTokenizedRecordCpiAccounts<'a, 'b> {
     /// Token2022 Program used to burn the tokenized record
     pub token2022: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Class account of the record
-    pub class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `burn_tokenized_record` CPI instruction.
@@ -241,7 +261,9 @@ pub struct BurnTokenizedRecordCpi<'a, 'b> {
     /// Token2022 Program used to burn the tokenized record
     pub token2022: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Class account of the record
-    pub class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> BurnTokenizedRecordCpi<'a, 'b> {
@@ -258,6 +280,7 @@ impl<'a, 'b> BurnTokenizedRecordCpi<'a, 'b> {
             record: accounts.record,
             token2022: accounts.token2022,
             class: accounts.class,
+            class_delegate: accounts.class_delegate,
         }
     }
     #[inline(always)]
@@ -294,7 +317,7 @@ impl<'a, 'b> BurnTokenizedRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -319,9 +342,14 @@ impl<'a, 'b> BurnTokenizedRecordCpi<'a, 'b> {
             *self.token2022.key,
             false,
         ));
-        if let Some(class) = self.class {
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class.key,
+            false,
+        ));
+        if let Some(class_delegate) = self.class_delegate {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
-                *class.key, false,
+                *class_delegate.key,
+                false,
             ));
         } else {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
@@ -343,7 +371,7 @@ impl<'a, 'b> BurnTokenizedRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.payer.clone());
@@ -351,8 +379,9 @@ impl<'a, 'b> BurnTokenizedRecordCpi<'a, 'b> {
         account_infos.push(self.token_account.clone());
         account_infos.push(self.record.clone());
         account_infos.push(self.token2022.clone());
-        if let Some(class) = self.class {
-            account_infos.push(class.clone());
+        account_infos.push(self.class.clone());
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
         }
         remaining_accounts
             .iter()
@@ -376,7 +405,8 @@ impl<'a, 'b> BurnTokenizedRecordCpi<'a, 'b> {
 ///   3. `[writable]` token_account
 ///   4. `[writable]` record
 ///   5. `[]` token2022
-///   6. `[optional]` class
+///   6. `[writable]` class
+///   7. `[optional]` class_delegate
 #[derive(Clone, Debug)]
 pub struct BurnTokenizedRecordCpiBuilder<'a, 'b> {
     instruction: Box<BurnTokenizedRecordCpiBuilderInstruction<'a, 'b>>,
@@ -393,6 +423,7 @@ impl<'a, 'b> BurnTokenizedRecordCpiBuilder<'a, 'b> {
             record: None,
             token2022: None,
             class: None,
+            class_delegate: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -445,14 +476,20 @@ impl<'a, 'b> BurnTokenizedRecordCpiBuilder<'a, 'b> {
         self.instruction.token2022 = Some(token2022);
         self
     }
-    /// `[optional account]`
     /// Class account of the record
     #[inline(always)]
-    pub fn class(
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
         &mut self,
-        class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.class = class;
+        self.instruction.class_delegate = class_delegate;
         self
     }
     /// Add an additional account to the instruction.
@@ -514,7 +551,9 @@ impl<'a, 'b> BurnTokenizedRecordCpiBuilder<'a, 'b> {
 
             token2022: self.instruction.token2022.expect("token2022 is not set"),
 
-            class: self.instruction.class,
+            class: self.instruction.class.expect("class is not set"),
+
+            class_delegate: self.instruction.class_delegate,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -533,6 +572,7 @@ struct BurnTokenizedRecordCpiBuilderInstruction<'a, 'b> {
     record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     token2022: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b trezoa_program::account_info::AccountInfo<'a>,
@@ -0,0 +1,376 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct CancelClassAuthorityTransfer {
+    /// Current authority of the class that will get refunded for the pending authority account
+    pub authority: trezoa_program::pubkey::Pubkey,
+    /// Class account with a pending authority transfer
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// Pending authority account of the class
+    pub pending_class_authority: trezoa_program::pubkey::Pubkey,
+}
+
+impl CancelClassAuthorityTransfer {
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.authority,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.class, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.pending_class_authority,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&CancelClassAuthorityTransferInstructionData::new()).unwrap();
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CancelClassAuthorityTransferInstructionData {
+    discriminator: u8,
+}
+
+impl CancelClassAuthorityTransferInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 17 }
+    }
+}
+
+impl Default for CancelClassAuthorityTransferInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `CancelClassAuthorityTransfer`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` authority
+///   1. `[]` class
+///   2. `[writable]` pending_class_authority
+#[derive(Clone, Debug, Default)]
+pub struct CancelClassAuthorityTransferBuilder {
+    authority: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    pending_class_authority: Option<trezoa_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl CancelClassAuthorityTransferBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Current authority of the class that will get refunded for the pending authority account
+    #[inline(always)]
+    pub fn authority(&mut self, authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Class account with a pending authority transfer
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// Pending authority account of the class
+    #[inline(always)]
+    pub fn pending_class_authority(
+        &mut self,
+        pending_class_authority: trezoa_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.pending_class_authority = Some(pending_class_authority);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = CancelClassAuthorityTransfer {
+            authority: self.authority.expect("authority is not set"),
+            class: self.class.expect("class is not set"),
+            pending_class_authority: self
+                .pending_class_authority
+                .expect("pending_class_authority is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `cancel_class_authority_transfer` CPI accounts.
+pub struct CancelClassAuthorityTransferCpiAccounts<'a, 'b> {
+    /// Current authority of the class that will get refunded for the pending authority account
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account with a pending authority transfer
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Pending authority account of the class
+    pub pending_class_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `cancel_class_authority_transfer` CPI instruction.
+pub struct CancelClassAuthorityTransferCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Current authority of the class that will get refunded for the pending authority account
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account with a pending authority transfer
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Pending authority account of the class
+    pub pending_class_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CancelClassAuthorityTransferCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: CancelClassAuthorityTransferCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            authority: accounts.authority,
+            class: accounts.class,
+            pending_class_authority: accounts.pending_class_authority,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.class.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.pending_class_authority.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&CancelClassAuthorityTransferInstructionData::new()).unwrap();
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.class.clone());
+        account_infos.push(self.pending_class_authority.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CancelClassAuthorityTransfer` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` authority
+///   1. `[]` class
+///   2. `[writable]` pending_class_authority
+#[derive(Clone, Debug)]
+pub struct CancelClassAuthorityTransferCpiBuilder<'a, 'b> {
+    instruction: Box<CancelClassAuthorityTransferCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CancelClassAuthorityTransferCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CancelClassAuthorityTransferCpiBuilderInstruction {
+            __program: program,
+            authority: None,
+            class: None,
+            pending_class_authority: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Current authority of the class that will get refunded for the pending authority account
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Class account with a pending authority transfer
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// Pending authority account of the class
+    #[inline(always)]
+    pub fn pending_class_authority(
+        &mut self,
+        pending_class_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pending_class_authority = Some(pending_class_authority);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let instruction = CancelClassAuthorityTransferCpi {
+            __program: self.instruction.__program,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+
+            pending_class_authority: self
+                .instruction
+                .pending_class_authority
+                .expect("pending_class_authority is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CancelClassAuthorityTransferCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    pending_class_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
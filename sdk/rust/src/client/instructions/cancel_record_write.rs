@@ -0,0 +1,568 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct CancelRecordWrite {
+    /// Record owner or class authority for permissioned classes
+    pub authority: trezoa_program::pubkey::Pubkey,
+    /// Account that will get refunded for the record account or the staged data
+    pub payer: trezoa_program::pubkey::Pubkey,
+    /// Record account being written
+    pub record: trezoa_program::pubkey::Pubkey,
+    /// Class account of the record
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// System Program used to resize our record account
+    pub system_program: trezoa_program::pubkey::Pubkey,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<trezoa_program::pubkey::Pubkey>,
+}
+
+impl CancelRecordWrite {
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.authority,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.payer, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.record,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                record_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&CancelRecordWriteInstructionData::new()).unwrap();
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CancelRecordWriteInstructionData {
+    discriminator: u8,
+}
+
+impl CancelRecordWriteInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 42 }
+    }
+}
+
+impl Default for CancelRecordWriteInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `CancelRecordWrite`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` authority
+///   1. `[writable]` payer
+///   2. `[writable]` record
+///   3. `[writable]` class
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` class_delegate
+///   6. `[optional]` record_delegate
+#[derive(Clone, Debug, Default)]
+pub struct CancelRecordWriteBuilder {
+    authority: Option<trezoa_program::pubkey::Pubkey>,
+    payer: Option<trezoa_program::pubkey::Pubkey>,
+    record: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    system_program: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    record_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl CancelRecordWriteBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Record owner or class authority for permissioned classes
+    #[inline(always)]
+    pub fn authority(&mut self, authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Account that will get refunded for the record account or the staged data
+    #[inline(always)]
+    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Record account being written
+    #[inline(always)]
+    pub fn record(&mut self, record: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.record = Some(record);
+        self
+    }
+    /// Class account of the record
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program used to resize our record account
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional record delegate account of the authority
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.record_delegate = record_delegate;
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = CancelRecordWrite {
+            authority: self.authority.expect("authority is not set"),
+            payer: self.payer.expect("payer is not set"),
+            record: self.record.expect("record is not set"),
+            class: self.class.expect("class is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+            class_delegate: self.class_delegate,
+            record_delegate: self.record_delegate,
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `cancel_record_write` CPI accounts.
+pub struct CancelRecordWriteCpiAccounts<'a, 'b> {
+    /// Record owner or class authority for permissioned classes
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will get refunded for the record account or the staged data
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record account being written
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account of the record
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to resize our record account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+}
+
+/// `cancel_record_write` CPI instruction.
+pub struct CancelRecordWriteCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record owner or class authority for permissioned classes
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will get refunded for the record account or the staged data
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record account being written
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account of the record
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to resize our record account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+}
+
+impl<'a, 'b> CancelRecordWriteCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: CancelRecordWriteCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            authority: accounts.authority,
+            payer: accounts.payer,
+            record: accounts.record,
+            class: accounts.class,
+            system_program: accounts.system_program,
+            class_delegate: accounts.class_delegate,
+            record_delegate: accounts.record_delegate,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.record.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *record_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&CancelRecordWriteInstructionData::new()).unwrap();
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.record.clone());
+        account_infos.push(self.class.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            account_infos.push(record_delegate.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CancelRecordWrite` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` authority
+///   1. `[writable]` payer
+///   2. `[writable]` record
+///   3. `[writable]` class
+///   4. `[]` system_program
+///   5. `[optional]` class_delegate
+///   6. `[optional]` record_delegate
+#[derive(Clone, Debug)]
+pub struct CancelRecordWriteCpiBuilder<'a, 'b> {
+    instruction: Box<CancelRecordWriteCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CancelRecordWriteCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CancelRecordWriteCpiBuilderInstruction {
+            __program: program,
+            authority: None,
+            payer: None,
+            record: None,
+            class: None,
+            system_program: None,
+            class_delegate: None,
+            record_delegate: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Record owner or class authority for permissioned classes
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Account that will get refunded for the record account or the staged data
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Record account being written
+    #[inline(always)]
+    pub fn record(
+        &mut self,
+        record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.record = Some(record);
+        self
+    }
+    /// Class account of the record
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// System Program used to resize our record account
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional record delegate account of the authority
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.record_delegate = record_delegate;
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let instruction = CancelRecordWriteCpi {
+            __program: self.instruction.__program,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            record: self.instruction.record.expect("record is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            class_delegate: self.instruction.class_delegate,
+
+            record_delegate: self.instruction.record_delegate,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CancelRecordWriteCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,448 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct CloseClass {
+    /// Authority of the class
+    pub authority: trezoa_program::pubkey::Pubkey,
+    /// Account that will get refunded for the class and group accounts
+    pub destination: trezoa_program::pubkey::Pubkey,
+    /// Class account to be closed
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// Group mint account of the class
+    pub group: trezoa_program::pubkey::Pubkey,
+    /// Token2022 Program used to close the group mint
+    pub token2022: trezoa_program::pubkey::Pubkey,
+}
+
+impl CloseClass {
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.destination,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.group, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.token2022,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&CloseClassInstructionData::new()).unwrap();
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CloseClassInstructionData {
+    discriminator: u8,
+}
+
+impl CloseClassInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 39 }
+    }
+}
+
+impl Default for CloseClassInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `CloseClass`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` destination
+///   2. `[writable]` class
+///   3. `[writable]` group
+///   4. `[optional]` token2022 (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+#[derive(Clone, Debug, Default)]
+pub struct CloseClassBuilder {
+    authority: Option<trezoa_program::pubkey::Pubkey>,
+    destination: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    group: Option<trezoa_program::pubkey::Pubkey>,
+    token2022: Option<trezoa_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl CloseClassBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Authority of the class
+    #[inline(always)]
+    pub fn authority(&mut self, authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Account that will get refunded for the class and group accounts
+    #[inline(always)]
+    pub fn destination(&mut self, destination: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+    /// Class account to be closed
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// Group mint account of the class
+    #[inline(always)]
+    pub fn group(&mut self, group: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.group = Some(group);
+        self
+    }
+    /// `[optional account, default to 'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb']`
+    /// Token2022 Program used to close the group mint
+    #[inline(always)]
+    pub fn token2022(&mut self, token2022: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.token2022 = Some(token2022);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = CloseClass {
+            authority: self.authority.expect("authority is not set"),
+            destination: self.destination.expect("destination is not set"),
+            class: self.class.expect("class is not set"),
+            group: self.group.expect("group is not set"),
+            token2022: self.token2022.unwrap_or(trezoa_program::pubkey!(
+                "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+            )),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `close_class` CPI accounts.
+pub struct CloseClassCpiAccounts<'a, 'b> {
+    /// Authority of the class
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will get refunded for the class and group accounts
+    pub destination: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account to be closed
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Group mint account of the class
+    pub group: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Token2022 Program used to close the group mint
+    pub token2022: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `close_class` CPI instruction.
+pub struct CloseClassCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Authority of the class
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will get refunded for the class and group accounts
+    pub destination: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account to be closed
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Group mint account of the class
+    pub group: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Token2022 Program used to close the group mint
+    pub token2022: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CloseClassCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: CloseClassCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            authority: accounts.authority,
+            destination: accounts.destination,
+            class: accounts.class,
+            group: accounts.group,
+            token2022: accounts.token2022,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.destination.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.group.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.token2022.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&CloseClassInstructionData::new()).unwrap();
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.destination.clone());
+        account_infos.push(self.class.clone());
+        account_infos.push(self.group.clone());
+        account_infos.push(self.token2022.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CloseClass` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable]` destination
+///   2. `[writable]` class
+///   3. `[writable]` group
+///   4. `[]` token2022
+#[derive(Clone, Debug)]
+pub struct CloseClassCpiBuilder<'a, 'b> {
+    instruction: Box<CloseClassCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CloseClassCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CloseClassCpiBuilderInstruction {
+            __program: program,
+            authority: None,
+            destination: None,
+            class: None,
+            group: None,
+            token2022: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Authority of the class
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Account that will get refunded for the class and group accounts
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination = Some(destination);
+        self
+    }
+    /// Class account to be closed
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// Group mint account of the class
+    #[inline(always)]
+    pub fn group(&mut self, group: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.group = Some(group);
+        self
+    }
+    /// Token2022 Program used to close the group mint
+    #[inline(always)]
+    pub fn token2022(
+        &mut self,
+        token2022: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token2022 = Some(token2022);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let instruction = CloseClassCpi {
+            __program: self.instruction.__program,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            destination: self
+                .instruction
+                .destination
+                .expect("destination is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+
+            group: self.instruction.group.expect("group is not set"),
+
+            token2022: self.instruction.token2022.expect("token2022 is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CloseClassCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    group: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    token2022: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
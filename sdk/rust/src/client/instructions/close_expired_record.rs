@@ -0,0 +1,364 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct CloseExpiredRecord {
+    /// The expired record account to be closed
+    pub record: trezoa_program::pubkey::Pubkey,
+    /// The owner of the record that will get refunded
+    pub owner: trezoa_program::pubkey::Pubkey,
+    /// Class account of the record
+    pub class: trezoa_program::pubkey::Pubkey,
+}
+
+impl CloseExpiredRecord {
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.record,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.owner, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class, false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&CloseExpiredRecordInstructionData::new()).unwrap();
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CloseExpiredRecordInstructionData {
+    discriminator: u8,
+}
+
+impl CloseExpiredRecordInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 14 }
+    }
+}
+
+impl Default for CloseExpiredRecordInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Instruction builder for `CloseExpiredRecord`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` record
+///   1. `[writable]` owner
+///   2. `[writable]` class
+#[derive(Clone, Debug, Default)]
+pub struct CloseExpiredRecordBuilder {
+    record: Option<trezoa_program::pubkey::Pubkey>,
+    owner: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl CloseExpiredRecordBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The expired record account to be closed
+    #[inline(always)]
+    pub fn record(&mut self, record: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.record = Some(record);
+        self
+    }
+    /// The owner of the record that will get refunded
+    #[inline(always)]
+    pub fn owner(&mut self, owner: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// Class account of the record
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = CloseExpiredRecord {
+            record: self.record.expect("record is not set"),
+            owner: self.owner.expect("owner is not set"),
+            class: self.class.expect("class is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `close_expired_record` CPI accounts.
+pub struct CloseExpiredRecordCpiAccounts<'a, 'b> {
+    /// The expired record account to be closed
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The owner of the record that will get refunded
+    pub owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account of the record
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `close_expired_record` CPI instruction.
+pub struct CloseExpiredRecordCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The expired record account to be closed
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The owner of the record that will get refunded
+    pub owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account of the record
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CloseExpiredRecordCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: CloseExpiredRecordCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            record: accounts.record,
+            owner: accounts.owner,
+            class: accounts.class,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.record.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.owner.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&CloseExpiredRecordInstructionData::new()).unwrap();
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.record.clone());
+        account_infos.push(self.owner.clone());
+        account_infos.push(self.class.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CloseExpiredRecord` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` record
+///   1. `[writable]` owner
+///   2. `[writable]` class
+#[derive(Clone, Debug)]
+pub struct CloseExpiredRecordCpiBuilder<'a, 'b> {
+    instruction: Box<CloseExpiredRecordCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CloseExpiredRecordCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CloseExpiredRecordCpiBuilderInstruction {
+            __program: program,
+            record: None,
+            owner: None,
+            class: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The expired record account to be closed
+    #[inline(always)]
+    pub fn record(
+        &mut self,
+        record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.record = Some(record);
+        self
+    }
+    /// The owner of the record that will get refunded
+    #[inline(always)]
+    pub fn owner(&mut self, owner: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.owner = Some(owner);
+        self
+    }
+    /// Class account of the record
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let instruction = CloseExpiredRecordCpi {
+            __program: self.instruction.__program,
+
+            record: self.instruction.record.expect("record is not set"),
+
+            owner: self.instruction.owner.expect("owner is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CloseExpiredRecordCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    owner: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
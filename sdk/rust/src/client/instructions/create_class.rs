@@ -88,6 +88,9 @@ impl Default for CreateClassInstructionData {
 pub struct CreateClassInstructionArgs {
     pub is_permissioned: bool,
     pub is_frozen: bool,
+    pub is_non_transferable: bool,
+    pub max_records: u64,
+    pub bump: u8,
     pub name: U8PrefixString,
     pub metadata: RemainderStr,
 }
@@ -108,6 +111,9 @@ pub struct CreateClassBuilder {
     system_program: Option<trezoa_program::pubkey::Pubkey>,
     is_permissioned: Option<bool>,
     is_frozen: Option<bool>,
+    is_non_transferable: Option<bool>,
+    max_records: Option<u64>,
+    bump: Option<u8>,
     name: Option<U8PrefixString>,
     metadata: Option<RemainderStr>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
@@ -153,6 +159,21 @@ impl CreateClassBuilder {
         self
     }
     #[inline(always)]
+    pub fn is_non_transferable(&mut self, is_non_transferable: bool) -> &mut Self {
+        self.is_non_transferable = Some(is_non_transferable);
+        self
+    }
+    #[inline(always)]
+    pub fn max_records(&mut self, max_records: u64) -> &mut Self {
+        self.max_records = Some(max_records);
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: U8PrefixString) -> &mut Self {
         self.name = Some(name);
         self
@@ -196,6 +217,12 @@ impl CreateClassBuilder {
                 .clone()
                 .expect("is_permissioned is not set"),
             is_frozen: self.is_frozen.clone().expect("is_frozen is not set"),
+            is_non_transferable: self
+                .is_non_transferable
+                .clone()
+                .expect("is_non_transferable is not set"),
+            max_records: self.max_records.clone().expect("max_records is not set"),
+            bump: self.bump.clone().expect("bump is not set"),
             name: self.name.clone().expect("name is not set"),
             metadata: self.metadata.clone().expect("metadata is not set"),
         };
@@ -355,6 +382,9 @@ impl<'a, 'b> CreateClassCpiBuilder<'a, 'b> {
             system_program: None,
             is_permissioned: None,
             is_frozen: None,
+            is_non_transferable: None,
+            max_records: None,
+            bump: None,
             name: None,
             metadata: None,
             __remaining_accounts: Vec::new(),
@@ -402,6 +432,21 @@ impl<'a, 'b> CreateClassCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn is_non_transferable(&mut self, is_non_transferable: bool) -> &mut Self {
+        self.instruction.is_non_transferable = Some(is_non_transferable);
+        self
+    }
+    #[inline(always)]
+    pub fn max_records(&mut self, max_records: u64) -> &mut Self {
+        self.instruction.max_records = Some(max_records);
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: U8PrefixString) -> &mut Self {
         self.instruction.name = Some(name);
         self
@@ -463,6 +508,17 @@ impl<'a, 'b> CreateClassCpiBuilder<'a, 'b> {
                 .is_frozen
                 .clone()
                 .expect("is_frozen is not set"),
+            is_non_transferable: self
+                .instruction
+                .is_non_transferable
+                .clone()
+                .expect("is_non_transferable is not set"),
+            max_records: self
+                .instruction
+                .max_records
+                .clone()
+                .expect("max_records is not set"),
+            bump: self.instruction.bump.clone().expect("bump is not set"),
             name: self.instruction.name.clone().expect("name is not set"),
             metadata: self
                 .instruction
@@ -501,6 +557,9 @@ struct CreateClassCpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     is_permissioned: Option<bool>,
     is_frozen: Option<bool>,
+    is_non_transferable: Option<bool>,
+    max_records: Option<u64>,
+    bump: Option<u8>,
     name: Option<U8PrefixString>,
     metadata: Option<RemainderStr>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -0,0 +1,716 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use kaigan::types::RemainderVec;
+use kaigan::types::U8PrefixVec;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct CreateRecordFromSignature {
+    /// Owner of the new record
+    pub owner: trezoa_program::pubkey::Pubkey,
+    /// Account that will pay for the record account
+    pub payer: trezoa_program::pubkey::Pubkey,
+    /// Class account for the record to be created
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// Record account to be created
+    pub record: trezoa_program::pubkey::Pubkey,
+    /// System Program used to create our record account
+    pub system_program: trezoa_program::pubkey::Pubkey,
+    /// Instructions sysvar used to read the Ed25519 instruction
+    pub instructions_sysvar: trezoa_program::pubkey::Pubkey,
+    /// Schema account of the class, required if the class has a schema
+    pub schema: Option<trezoa_program::pubkey::Pubkey>,
+    /// Treasury account of the class, required if the class charges a creation fee
+    pub treasury: Option<trezoa_program::pubkey::Pubkey>,
+}
+
+impl CreateRecordFromSignature {
+    pub fn instruction(
+        &self,
+        args: CreateRecordFromSignatureInstructionArgs,
+    ) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: CreateRecordFromSignatureInstructionArgs,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.owner, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.record,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.instructions_sysvar,
+            false,
+        ));
+        if let Some(schema) = self.schema {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                schema, false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(treasury) = self.treasury {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                treasury, false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&CreateRecordFromSignatureInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateRecordFromSignatureInstructionData {
+    discriminator: u8,
+}
+
+impl CreateRecordFromSignatureInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 35 }
+    }
+}
+
+impl Default for CreateRecordFromSignatureInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateRecordFromSignatureInstructionArgs {
+    pub expiration: i64,
+    pub content_type: u8,
+    pub bump: u8,
+    pub seed: U8PrefixVec<u8>,
+    pub data: RemainderVec<u8>,
+}
+
+/// Instruction builder for `CreateRecordFromSignature`.
+///
+/// ### Accounts:
+///
+///   0. `[]` owner
+///   1. `[writable, signer]` payer
+///   2. `[writable]` class
+///   3. `[writable]` record
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` instructions_sysvar (default to `Sysvar1nstructions1111111111111111111111111`)
+///   6. `[optional]` schema
+///   7. `[writable, optional]` treasury
+#[derive(Clone, Debug, Default)]
+pub struct CreateRecordFromSignatureBuilder {
+    owner: Option<trezoa_program::pubkey::Pubkey>,
+    payer: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    record: Option<trezoa_program::pubkey::Pubkey>,
+    system_program: Option<trezoa_program::pubkey::Pubkey>,
+    instructions_sysvar: Option<trezoa_program::pubkey::Pubkey>,
+    schema: Option<trezoa_program::pubkey::Pubkey>,
+    treasury: Option<trezoa_program::pubkey::Pubkey>,
+    expiration: Option<i64>,
+    content_type: Option<u8>,
+    bump: Option<u8>,
+    seed: Option<U8PrefixVec<u8>>,
+    data: Option<RemainderVec<u8>>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl CreateRecordFromSignatureBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Owner of the new record
+    #[inline(always)]
+    pub fn owner(&mut self, owner: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.owner = Some(owner);
+        self
+    }
+    /// Account that will pay for the record account
+    #[inline(always)]
+    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Class account for the record to be created
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// Record account to be created
+    #[inline(always)]
+    pub fn record(&mut self, record: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.record = Some(record);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program used to create our record account
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to 'Sysvar1nstructions1111111111111111111111111']`
+    /// Instructions sysvar used to read the Ed25519 instruction
+    #[inline(always)]
+    pub fn instructions_sysvar(
+        &mut self,
+        instructions_sysvar: trezoa_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.instructions_sysvar = Some(instructions_sysvar);
+        self
+    }
+    /// `[optional account]`
+    /// Schema account of the class, required if the class has a schema
+    #[inline(always)]
+    pub fn schema(&mut self, schema: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.schema = schema;
+        self
+    }
+    /// `[optional account]`
+    /// Treasury account of the class, required if the class charges a creation fee
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.treasury = treasury;
+        self
+    }
+    #[inline(always)]
+    pub fn expiration(&mut self, expiration: i64) -> &mut Self {
+        self.expiration = Some(expiration);
+        self
+    }
+    #[inline(always)]
+    pub fn content_type(&mut self, content_type: u8) -> &mut Self {
+        self.content_type = Some(content_type);
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
+    #[inline(always)]
+    pub fn seed(&mut self, seed: U8PrefixVec<u8>) -> &mut Self {
+        self.seed = Some(seed);
+        self
+    }
+    #[inline(always)]
+    pub fn data(&mut self, data: RemainderVec<u8>) -> &mut Self {
+        self.data = Some(data);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = CreateRecordFromSignature {
+            owner: self.owner.expect("owner is not set"),
+            payer: self.payer.expect("payer is not set"),
+            class: self.class.expect("class is not set"),
+            record: self.record.expect("record is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+            instructions_sysvar: self.instructions_sysvar.unwrap_or(trezoa_program::pubkey!(
+                "Sysvar1nstructions1111111111111111111111111"
+            )),
+            schema: self.schema,
+            treasury: self.treasury,
+        };
+        let args = CreateRecordFromSignatureInstructionArgs {
+            expiration: self.expiration.clone().expect("expiration is not set"),
+            content_type: self.content_type.clone().expect("content_type is not set"),
+            bump: self.bump.clone().expect("bump is not set"),
+            seed: self.seed.clone().expect("seed is not set"),
+            data: self.data.clone().expect("data is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `create_record_from_signature` CPI accounts.
+pub struct CreateRecordFromSignatureCpiAccounts<'a, 'b> {
+    /// Owner of the new record
+    pub owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the record account
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account for the record to be created
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record account to be created
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to create our record account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Instructions sysvar used to read the Ed25519 instruction
+    pub instructions_sysvar: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Schema account of the class, required if the class has a schema
+    pub schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Treasury account of the class, required if the class charges a creation fee
+    pub treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+}
+
+/// `create_record_from_signature` CPI instruction.
+pub struct CreateRecordFromSignatureCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Owner of the new record
+    pub owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the record account
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account for the record to be created
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record account to be created
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to create our record account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Instructions sysvar used to read the Ed25519 instruction
+    pub instructions_sysvar: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Schema account of the class, required if the class has a schema
+    pub schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Treasury account of the class, required if the class charges a creation fee
+    pub treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: CreateRecordFromSignatureInstructionArgs,
+}
+
+impl<'a, 'b> CreateRecordFromSignatureCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: CreateRecordFromSignatureCpiAccounts<'a, 'b>,
+        args: CreateRecordFromSignatureInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            owner: accounts.owner,
+            payer: accounts.payer,
+            class: accounts.class,
+            record: accounts.record,
+            system_program: accounts.system_program,
+            instructions_sysvar: accounts.instructions_sysvar,
+            schema: accounts.schema,
+            treasury: accounts.treasury,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.owner.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.record.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.instructions_sysvar.key,
+            false,
+        ));
+        if let Some(schema) = self.schema {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *schema.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(treasury) = self.treasury {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *treasury.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&CreateRecordFromSignatureInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.owner.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.class.clone());
+        account_infos.push(self.record.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.instructions_sysvar.clone());
+        if let Some(schema) = self.schema {
+            account_infos.push(schema.clone());
+        }
+        if let Some(treasury) = self.treasury {
+            account_infos.push(treasury.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CreateRecordFromSignature` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` owner
+///   1. `[writable, signer]` payer
+///   2. `[writable]` class
+///   3. `[writable]` record
+///   4. `[]` system_program
+///   5. `[]` instructions_sysvar
+///   6. `[optional]` schema
+///   7. `[writable, optional]` treasury
+#[derive(Clone, Debug)]
+pub struct CreateRecordFromSignatureCpiBuilder<'a, 'b> {
+    instruction: Box<CreateRecordFromSignatureCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CreateRecordFromSignatureCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CreateRecordFromSignatureCpiBuilderInstruction {
+            __program: program,
+            owner: None,
+            payer: None,
+            class: None,
+            record: None,
+            system_program: None,
+            instructions_sysvar: None,
+            schema: None,
+            treasury: None,
+            expiration: None,
+            content_type: None,
+            bump: None,
+            seed: None,
+            data: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Owner of the new record
+    #[inline(always)]
+    pub fn owner(&mut self, owner: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.owner = Some(owner);
+        self
+    }
+    /// Account that will pay for the record account
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Class account for the record to be created
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// Record account to be created
+    #[inline(always)]
+    pub fn record(
+        &mut self,
+        record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.record = Some(record);
+        self
+    }
+    /// System Program used to create our record account
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Instructions sysvar used to read the Ed25519 instruction
+    #[inline(always)]
+    pub fn instructions_sysvar(
+        &mut self,
+        instructions_sysvar: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.instructions_sysvar = Some(instructions_sysvar);
+        self
+    }
+    /// `[optional account]`
+    /// Schema account of the class, required if the class has a schema
+    #[inline(always)]
+    pub fn schema(
+        &mut self,
+        schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.schema = schema;
+        self
+    }
+    /// `[optional account]`
+    /// Treasury account of the class, required if the class charges a creation fee
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury = treasury;
+        self
+    }
+    #[inline(always)]
+    pub fn expiration(&mut self, expiration: i64) -> &mut Self {
+        self.instruction.expiration = Some(expiration);
+        self
+    }
+    #[inline(always)]
+    pub fn content_type(&mut self, content_type: u8) -> &mut Self {
+        self.instruction.content_type = Some(content_type);
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
+    #[inline(always)]
+    pub fn seed(&mut self, seed: U8PrefixVec<u8>) -> &mut Self {
+        self.instruction.seed = Some(seed);
+        self
+    }
+    #[inline(always)]
+    pub fn data(&mut self, data: RemainderVec<u8>) -> &mut Self {
+        self.instruction.data = Some(data);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let args = CreateRecordFromSignatureInstructionArgs {
+            expiration: self
+                .instruction
+                .expiration
+                .clone()
+                .expect("expiration is not set"),
+            content_type: self
+                .instruction
+                .content_type
+                .clone()
+                .expect("content_type is not set"),
+            bump: self.instruction.bump.clone().expect("bump is not set"),
+            seed: self.instruction.seed.clone().expect("seed is not set"),
+            data: self.instruction.data.clone().expect("data is not set"),
+        };
+        let instruction = CreateRecordFromSignatureCpi {
+            __program: self.instruction.__program,
+
+            owner: self.instruction.owner.expect("owner is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+
+            record: self.instruction.record.expect("record is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            instructions_sysvar: self
+                .instruction
+                .instructions_sysvar
+                .expect("instructions_sysvar is not set"),
+
+            schema: self.instruction.schema,
+
+            treasury: self.instruction.treasury,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CreateRecordFromSignatureCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    owner: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    instructions_sysvar: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    expiration: Option<i64>,
+    content_type: Option<u8>,
+    bump: Option<u8>,
+    seed: Option<U8PrefixVec<u8>>,
+    data: Option<RemainderVec<u8>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -25,6 +25,12 @@ pub struct CreateRecordTokenizable {
     pub system_program: trezoa_program::pubkey::Pubkey,
     /// Optional authority for permissioned classes
     pub authority: Option<trezoa_program::pubkey::Pubkey>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// Schema account of the class, required if the class has a schema
+    pub schema: Option<trezoa_program::pubkey::Pubkey>,
+    /// Treasury account of the class, required if the class charges a creation fee
+    pub treasury: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 impl CreateRecordTokenizable {
@@ -41,7 +47,7 @@ impl CreateRecordTokenizable {
         args: CreateRecordTokenizableInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
             self.owner, true,
         ));
@@ -69,6 +75,37 @@ impl CreateRecordTokenizable {
                 false,
             ));
         }
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(schema) = self.schema {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                schema, false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(treasury) = self.treasury {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                treasury, false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&CreateRecordTokenizableInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -104,6 +141,8 @@ impl Default for CreateRecordTokenizableInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateRecordTokenizableInstructionArgs {
     pub expiration: i64,
+    pub content_type: u8,
+    pub bump: u8,
     pub seed: U8PrefixVec<u8>,
     pub metadata: Metadata,
 }
@@ -118,6 +157,9 @@ pub struct CreateRecordTokenizableInstructionArgs {
 ///   3. `[writable]` record
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   5. `[signer, optional]` authority
+///   6. `[optional]` class_delegate
+///   7. `[optional]` schema
+///   8. `[writable, optional]` treasury
 #[derive(Clone, Debug, Default)]
 pub struct CreateRecordTokenizableBuilder {
     owner: Option<trezoa_program::pubkey::Pubkey>,
@@ -126,7 +168,12 @@ pub struct CreateRecordTokenizableBuilder {
     record: Option<trezoa_program::pubkey::Pubkey>,
     system_program: Option<trezoa_program::pubkey::Pubkey>,
     authority: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    schema: Option<trezoa_program::pubkey::Pubkey>,
+    treasury: Option<trezoa_program::pubkey::Pubkey>,
     expiration: Option<i64>,
+    content_type: Option<u8>,
+    bump: Option<u8>,
     seed: Option<U8PrefixVec<u8>>,
     metadata: Option<Metadata>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
@@ -174,12 +221,46 @@ impl CreateRecordTokenizableBuilder {
         self.authority = authority;
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Schema account of the class, required if the class has a schema
+    #[inline(always)]
+    pub fn schema(&mut self, schema: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.schema = schema;
+        self
+    }
+    /// `[optional account]`
+    /// Treasury account of the class, required if the class charges a creation fee
+    #[inline(always)]
+    pub fn treasury(&mut self, treasury: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.treasury = treasury;
+        self
+    }
     #[inline(always)]
     pub fn expiration(&mut self, expiration: i64) -> &mut Self {
         self.expiration = Some(expiration);
         self
     }
     #[inline(always)]
+    pub fn content_type(&mut self, content_type: u8) -> &mut Self {
+        self.content_type = Some(content_type);
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
+    #[inline(always)]
     pub fn seed(&mut self, seed: U8PrefixVec<u8>) -> &mut Self {
         self.seed = Some(seed);
         self
@@ -218,9 +299,14 @@ impl CreateRecordTokenizableBuilder {
                 .system_program
                 .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
             authority: self.authority,
+            class_delegate: self.class_delegate,
+            schema: self.schema,
+            treasury: self.treasury,
         };
         let args = CreateRecordTokenizableInstructionArgs {
             expiration: self.expiration.clone().expect("expiration is not set"),
+            content_type: self.content_type.clone().expect("content_type is not set"),
+            bump: self.bump.clone().expect("bump is not set"),
             seed: self.seed.clone().expect("seed is not set"),
             metadata: self.metadata.clone().expect("metadata is not set"),
         };
@@ -243,6 +329,12 @@ pub struct CreateRecordTokenizableCpiAccounts<'a, 'b> {
     pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Optional authority for permissioned classes
     pub authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Schema account of the class, required if the class has a schema
+    pub schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Treasury account of the class, required if the class charges a creation fee
+    pub treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `create_record_tokenizable` CPI instruction.
@@ -261,6 +353,12 @@ pub struct CreateRecordTokenizableCpi<'a, 'b> {
     pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Optional authority for permissioned classes
     pub authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Schema account of the class, required if the class has a schema
+    pub schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Treasury account of the class, required if the class charges a creation fee
+    pub treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: CreateRecordTokenizableInstructionArgs,
 }
@@ -279,6 +377,9 @@ impl<'a, 'b> CreateRecordTokenizableCpi<'a, 'b> {
             record: accounts.record,
             system_program: accounts.system_program,
             authority: accounts.authority,
+            class_delegate: accounts.class_delegate,
+            schema: accounts.schema,
+            treasury: accounts.treasury,
             __args: args,
         }
     }
@@ -316,7 +417,7 @@ impl<'a, 'b> CreateRecordTokenizableCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
             *self.owner.key,
             true,
@@ -348,6 +449,39 @@ impl<'a, 'b> CreateRecordTokenizableCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(schema) = self.schema {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *schema.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(treasury) = self.treasury {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *treasury.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -364,7 +498,7 @@ impl<'a, 'b> CreateRecordTokenizableCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.payer.clone());
@@ -374,6 +508,15 @@ impl<'a, 'b> CreateRecordTokenizableCpi<'a, 'b> {
         if let Some(authority) = self.authority {
             account_infos.push(authority.clone());
         }
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
+        if let Some(schema) = self.schema {
+            account_infos.push(schema.clone());
+        }
+        if let Some(treasury) = self.treasury {
+            account_infos.push(treasury.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -396,6 +539,9 @@ impl<'a, 'b> CreateRecordTokenizableCpi<'a, 'b> {
 ///   3. `[writable]` record
 ///   4. `[]` system_program
 ///   5. `[signer, optional]` authority
+///   6. `[optional]` class_delegate
+///   7. `[optional]` schema
+///   8. `[writable, optional]` treasury
 #[derive(Clone, Debug)]
 pub struct CreateRecordTokenizableCpiBuilder<'a, 'b> {
     instruction: Box<CreateRecordTokenizableCpiBuilderInstruction<'a, 'b>>,
@@ -411,7 +557,12 @@ impl<'a, 'b> CreateRecordTokenizableCpiBuilder<'a, 'b> {
             record: None,
             system_program: None,
             authority: None,
+            class_delegate: None,
+            schema: None,
+            treasury: None,
             expiration: None,
+            content_type: None,
+            bump: None,
             seed: None,
             metadata: None,
             __remaining_accounts: Vec::new(),
@@ -464,12 +615,52 @@ impl<'a, 'b> CreateRecordTokenizableCpiBuilder<'a, 'b> {
         self.instruction.authority = authority;
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Schema account of the class, required if the class has a schema
+    #[inline(always)]
+    pub fn schema(
+        &mut self,
+        schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.schema = schema;
+        self
+    }
+    /// `[optional account]`
+    /// Treasury account of the class, required if the class charges a creation fee
+    #[inline(always)]
+    pub fn treasury(
+        &mut self,
+        treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.treasury = treasury;
+        self
+    }
     #[inline(always)]
     pub fn expiration(&mut self, expiration: i64) -> &mut Self {
         self.instruction.expiration = Some(expiration);
         self
     }
     #[inline(always)]
+    pub fn content_type(&mut self, content_type: u8) -> &mut Self {
+        self.instruction.content_type = Some(content_type);
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
+    #[inline(always)]
     pub fn seed(&mut self, seed: U8PrefixVec<u8>) -> &mut Self {
         self.instruction.seed = Some(seed);
         self
@@ -526,6 +717,12 @@ impl<'a, 'b> CreateRecordTokenizableCpiBuilder<'a, 'b> {
                 .expiration
                 .clone()
                 .expect("expiration is not set"),
+            content_type: self
+                .instruction
+                .content_type
+                .clone()
+                .expect("content_type is not set"),
+            bump: self.instruction.bump.clone().expect("bump is not set"),
             seed: self.instruction.seed.clone().expect("seed is not set"),
             metadata: self
                 .instruction
@@ -550,6 +747,12 @@ impl<'a, 'b> CreateRecordTokenizableCpiBuilder<'a, 'b> {
                 .expect("system_program is not set"),
 
             authority: self.instruction.authority,
+
+            class_delegate: self.instruction.class_delegate,
+
+            schema: self.instruction.schema,
+
+            treasury: self.instruction.treasury,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -568,7 +771,12 @@ struct CreateRecordTokenizableCpiBuilderInstruction<'a, 'b> {
     record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    treasury: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     expiration: Option<i64>,
+    content_type: Option<u8>,
+    bump: Option<u8>,
     seed: Option<U8PrefixVec<u8>>,
     metadata: Option<Metadata>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -18,11 +18,15 @@ pub struct DeleteRecord {
     /// Record account to be updated
     pub record: trezoa_program::pubkey::Pubkey,
     /// Class account of the record
-    pub class: Option<trezoa_program::pubkey::Pubkey>,
+    pub class: trezoa_program::pubkey::Pubkey,
     /// Token2022 Program used to close the mint account
     pub token2022_program: Option<trezoa_program::pubkey::Pubkey>,
     /// Mint account for the tokenized record
     pub mint: Option<trezoa_program::pubkey::Pubkey>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 impl DeleteRecord {
@@ -35,7 +39,7 @@ impl DeleteRecord {
         &self,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -47,9 +51,13 @@ impl DeleteRecord {
             self.record,
             false,
         ));
-        if let Some(class) = self.class {
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class, false,
+        ));
+        if let Some(token2022_program) = self.token2022_program {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
-                class, false,
+                token2022_program,
+                false,
             ));
         } else {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
@@ -57,9 +65,17 @@ impl DeleteRecord {
                 false,
             ));
         }
-        if let Some(token2022_program) = self.token2022_program {
+        if let Some(mint) = self.mint {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(mint, false));
+        } else {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
-                token2022_program,
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                class_delegate,
                 false,
             ));
         } else {
@@ -68,8 +84,11 @@ impl DeleteRecord {
                 false,
             ));
         }
-        if let Some(mint) = self.mint {
-            accounts.push(trezoa_program::instruction::AccountMeta::new(mint, false));
+        if let Some(record_delegate) = self.record_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                record_delegate,
+                false,
+            ));
         } else {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
                 crate::TREZOA_RECORD_SERVICE_ID,
@@ -112,9 +131,11 @@ impl Default for DeleteRecordInstructionData {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable, signer]` payer
 ///   2. `[writable]` record
-///   3. `[optional]` class
+///   3. `[writable]` class
 ///   4. `[optional]` token2022_program
 ///   5. `[writable, optional]` mint
+///   6. `[optional]` class_delegate
+///   7. `[optional]` record_delegate
 #[derive(Clone, Debug, Default)]
 pub struct DeleteRecordBuilder {
     authority: Option<trezoa_program::pubkey::Pubkey>,
@@ -123,6 +144,8 @@ pub struct DeleteRecordBuilder {
     class: Option<trezoa_program::pubkey::Pubkey>,
     token2022_program: Option<trezoa_program::pubkey::Pubkey>,
     mint: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    record_delegate: Option<trezoa_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
 
@@ -148,11 +171,10 @@ impl DeleteRecordBuilder {
         self.record = Some(record);
         self
     }
-    /// `[optional account]`
     /// Class account of the record
     #[inline(always)]
-    pub fn class(&mut self, class: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
-        self.class = class;
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
         self
     }
     /// `[optional account]`
@@ -172,6 +194,26 @@ impl DeleteRecordBuilder {
         self.mint = mint;
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional record delegate account of the authority
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.record_delegate = record_delegate;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -196,9 +238,11 @@ impl DeleteRecordBuilder {
             authority: self.authority.expect("authority is not set"),
             payer: self.payer.expect("payer is not set"),
             record: self.record.expect("record is not set"),
-            class: self.class,
+            class: self.class.expect("class is not set"),
             token2022_program: self.token2022_program,
             mint: self.mint,
+            class_delegate: self.class_delegate,
+            record_delegate: self.record_delegate,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -214,11 +258,15 @@ pub struct DeleteRecordCpiAccounts<'a, 'b> {
     /// Record account to be updated
     pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Class account of the record
-    pub class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Token2022 Program used to close the mint account
     pub token2022_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// Mint account for the tokenized record
     pub mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `delete_record` CPI instruction.
@@ -232,11 +280,15 @@ pub struct DeleteRecordCpi<'a, 'b> {
     /// Record account to be updated
     pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Class account of the record
-    pub class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Token2022 Program used to close the mint account
     pub token2022_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// Mint account for the tokenized record
     pub mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
@@ -252,6 +304,8 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
             class: accounts.class,
             token2022_program: accounts.token2022_program,
             mint: accounts.mint,
+            class_delegate: accounts.class_delegate,
+            record_delegate: accounts.record_delegate,
         }
     }
     #[inline(always)]
@@ -288,7 +342,7 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -301,9 +355,24 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
             *self.record.key,
             false,
         ));
-        if let Some(class) = self.class {
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class.key,
+            false,
+        ));
+        if let Some(token2022_program) = self.token2022_program {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *token2022_program.key,
+                false,
+            ));
+        } else {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
-                *class.key, false,
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(mint) = self.mint {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *mint.key, false,
             ));
         } else {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
@@ -311,9 +380,9 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
                 false,
             ));
         }
-        if let Some(token2022_program) = self.token2022_program {
+        if let Some(class_delegate) = self.class_delegate {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
-                *token2022_program.key,
+                *class_delegate.key,
                 false,
             ));
         } else {
@@ -322,9 +391,10 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
                 false,
             ));
         }
-        if let Some(mint) = self.mint {
-            accounts.push(trezoa_program::instruction::AccountMeta::new(
-                *mint.key, false,
+        if let Some(record_delegate) = self.record_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *record_delegate.key,
+                false,
             ));
         } else {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
@@ -346,20 +416,24 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.record.clone());
-        if let Some(class) = self.class {
-            account_infos.push(class.clone());
-        }
+        account_infos.push(self.class.clone());
         if let Some(token2022_program) = self.token2022_program {
             account_infos.push(token2022_program.clone());
         }
         if let Some(mint) = self.mint {
             account_infos.push(mint.clone());
         }
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            account_infos.push(record_delegate.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -379,9 +453,11 @@ impl<'a, 'b> DeleteRecordCpi<'a, 'b> {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable, signer]` payer
 ///   2. `[writable]` record
-///   3. `[optional]` class
+///   3. `[writable]` class
 ///   4. `[optional]` token2022_program
 ///   5. `[writable, optional]` mint
+///   6. `[optional]` class_delegate
+///   7. `[optional]` record_delegate
 #[derive(Clone, Debug)]
 pub struct DeleteRecordCpiBuilder<'a, 'b> {
     instruction: Box<DeleteRecordCpiBuilderInstruction<'a, 'b>>,
@@ -397,6 +473,8 @@ impl<'a, 'b> DeleteRecordCpiBuilder<'a, 'b> {
             class: None,
             token2022_program: None,
             mint: None,
+            class_delegate: None,
+            record_delegate: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -425,14 +503,10 @@ impl<'a, 'b> DeleteRecordCpiBuilder<'a, 'b> {
         self.instruction.record = Some(record);
         self
     }
-    /// `[optional account]`
     /// Class account of the record
     #[inline(always)]
-    pub fn class(
-        &mut self,
-        class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
-    ) -> &mut Self {
-        self.instruction.class = class;
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
         self
     }
     /// `[optional account]`
@@ -455,6 +529,26 @@ impl<'a, 'b> DeleteRecordCpiBuilder<'a, 'b> {
         self.instruction.mint = mint;
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional record delegate account of the authority
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.record_delegate = record_delegate;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -505,11 +599,15 @@ impl<'a, 'b> DeleteRecordCpiBuilder<'a, 'b> {
 
             record: self.instruction.record.expect("record is not set"),
 
-            class: self.instruction.class,
+            class: self.instruction.class.expect("class is not set"),
 
             token2022_program: self.instruction.token2022_program,
 
             mint: self.instruction.mint,
+
+            class_delegate: self.instruction.class_delegate,
+
+            record_delegate: self.instruction.record_delegate,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -527,6 +625,8 @@ struct DeleteRecordCpiBuilderInstruction<'a, 'b> {
     class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     token2022_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b trezoa_program::account_info::AccountInfo<'a>,
@@ -17,6 +17,8 @@ pub struct FreezeRecord {
     pub record: trezoa_program::pubkey::Pubkey,
     /// Class account of the record
     pub class: trezoa_program::pubkey::Pubkey,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 impl FreezeRecord {
@@ -33,7 +35,7 @@ impl FreezeRecord {
         args: FreezeRecordInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -45,6 +47,17 @@ impl FreezeRecord {
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
             self.class, false,
         ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&FreezeRecordInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -89,11 +102,13 @@ pub struct FreezeRecordInstructionArgs {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` record
 ///   2. `[]` class
+///   3. `[optional]` class_delegate
 #[derive(Clone, Debug, Default)]
 pub struct FreezeRecordBuilder {
     authority: Option<trezoa_program::pubkey::Pubkey>,
     record: Option<trezoa_program::pubkey::Pubkey>,
     class: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
     is_frozen: Option<bool>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
@@ -120,6 +135,16 @@ impl FreezeRecordBuilder {
         self.class = Some(class);
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
     #[inline(always)]
     pub fn is_frozen(&mut self, is_frozen: bool) -> &mut Self {
         self.is_frozen = Some(is_frozen);
@@ -149,6 +174,7 @@ impl FreezeRecordBuilder {
             authority: self.authority.expect("authority is not set"),
             record: self.record.expect("record is not set"),
             class: self.class.expect("class is not set"),
+            class_delegate: self.class_delegate,
         };
         let args = FreezeRecordInstructionArgs {
             is_frozen: self.is_frozen.clone().expect("is_frozen is not set"),
@@ -166,6 +192,8 @@ pub struct FreezeRecordCpiAccounts<'a, 'b> {
     pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Class account of the record
     pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `freeze_record` CPI instruction.
@@ -178,6 +206,8 @@ pub struct FreezeRecordCpi<'a, 'b> {
     pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Class account of the record
     pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: FreezeRecordInstructionArgs,
 }
@@ -193,6 +223,7 @@ impl<'a, 'b> FreezeRecordCpi<'a, 'b> {
             authority: accounts.authority,
             record: accounts.record,
             class: accounts.class,
+            class_delegate: accounts.class_delegate,
             __args: args,
         }
     }
@@ -230,7 +261,7 @@ impl<'a, 'b> FreezeRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -243,6 +274,17 @@ impl<'a, 'b> FreezeRecordCpi<'a, 'b> {
             *self.class.key,
             false,
         ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -259,11 +301,14 @@ impl<'a, 'b> FreezeRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.record.clone());
         account_infos.push(self.class.clone());
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -283,6 +328,7 @@ impl<'a, 'b> FreezeRecordCpi<'a, 'b> {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` record
 ///   2. `[]` class
+///   3. `[optional]` class_delegate
 #[derive(Clone, Debug)]
 pub struct FreezeRecordCpiBuilder<'a, 'b> {
     instruction: Box<FreezeRecordCpiBuilderInstruction<'a, 'b>>,
@@ -295,6 +341,7 @@ impl<'a, 'b> FreezeRecordCpiBuilder<'a, 'b> {
             authority: None,
             record: None,
             class: None,
+            class_delegate: None,
             is_frozen: None,
             __remaining_accounts: Vec::new(),
         });
@@ -324,6 +371,16 @@ impl<'a, 'b> FreezeRecordCpiBuilder<'a, 'b> {
         self.instruction.class = Some(class);
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
     #[inline(always)]
     pub fn is_frozen(&mut self, is_frozen: bool) -> &mut Self {
         self.instruction.is_frozen = Some(is_frozen);
@@ -385,6 +442,8 @@ impl<'a, 'b> FreezeRecordCpiBuilder<'a, 'b> {
             record: self.instruction.record.expect("record is not set"),
 
             class: self.instruction.class.expect("class is not set"),
+
+            class_delegate: self.instruction.class_delegate,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -400,6 +459,7 @@ struct FreezeRecordCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     is_frozen: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
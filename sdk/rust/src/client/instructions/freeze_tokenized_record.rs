@@ -23,6 +23,8 @@ pub struct FreezeTokenizedRecord {
     pub class: trezoa_program::pubkey::Pubkey,
     /// Token2022 Program used to freeze/unfreeze the tokenized record
     pub token2022: trezoa_program::pubkey::Pubkey,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 impl FreezeTokenizedRecord {
@@ -39,7 +41,7 @@ impl FreezeTokenizedRecord {
         args: FreezeTokenizedRecordInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
             self.authority,
             true,
@@ -62,6 +64,17 @@ impl FreezeTokenizedRecord {
             self.token2022,
             false,
         ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&FreezeTokenizedRecordInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -109,6 +122,7 @@ pub struct FreezeTokenizedRecordInstructionArgs {
 ///   3. `[]` record
 ///   4. `[]` class
 ///   5. `[optional]` token2022 (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
+///   6. `[optional]` class_delegate
 #[derive(Clone, Debug, Default)]
 pub struct FreezeTokenizedRecordBuilder {
     authority: Option<trezoa_program::pubkey::Pubkey>,
@@ -117,6 +131,7 @@ pub struct FreezeTokenizedRecordBuilder {
     record: Option<trezoa_program::pubkey::Pubkey>,
     class: Option<trezoa_program::pubkey::Pubkey>,
     token2022: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
     is_frozen: Option<bool>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
@@ -162,6 +177,16 @@ impl FreezeTokenizedRecordBuilder {
         self.token2022 = Some(token2022);
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
     #[inline(always)]
     pub fn is_frozen(&mut self, is_frozen: bool) -> &mut Self {
         self.is_frozen = Some(is_frozen);
@@ -196,6 +221,7 @@ impl FreezeTokenizedRecordBuilder {
             token2022: self.token2022.unwrap_or(trezoa_program::pubkey!(
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
+            class_delegate: self.class_delegate,
         };
         let args = FreezeTokenizedRecordInstructionArgs {
             is_frozen: self.is_frozen.clone().expect("is_frozen is not set"),
@@ -219,6 +245,8 @@ pub struct FreezeTokenizedRecordCpiAccounts<'a, 'b> {
     pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Token2022 Program used to freeze/unfreeze the tokenized record
     pub token2022: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `freeze_tokenized_record` CPI instruction.
@@ -237,6 +265,8 @@ pub struct FreezeTokenizedRecordCpi<'a, 'b> {
     pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Token2022 Program used to freeze/unfreeze the tokenized record
     pub token2022: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: FreezeTokenizedRecordInstructionArgs,
 }
@@ -255,6 +285,7 @@ impl<'a, 'b> FreezeTokenizedRecordCpi<'a, 'b> {
             record: accounts.record,
             class: accounts.class,
             token2022: accounts.token2022,
+            class_delegate: accounts.class_delegate,
             __args: args,
         }
     }
@@ -292,7 +323,7 @@ impl<'a, 'b> FreezeTokenizedRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
             *self.authority.key,
             true,
@@ -317,6 +348,17 @@ impl<'a, 'b> FreezeTokenizedRecordCpi<'a, 'b> {
             *self.token2022.key,
             false,
         ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -333,7 +375,7 @@ impl<'a, 'b> FreezeTokenizedRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.mint.clone());
@@ -341,6 +383,9 @@ impl<'a, 'b> FreezeTokenizedRecordCpi<'a, 'b> {
         account_infos.push(self.record.clone());
         account_infos.push(self.class.clone());
         account_infos.push(self.token2022.clone());
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -363,6 +408,7 @@ impl<'a, 'b> FreezeTokenizedRecordCpi<'a, 'b> {
 ///   3. `[]` record
 ///   4. `[]` class
 ///   5. `[]` token2022
+///   6. `[optional]` class_delegate
 #[derive(Clone, Debug)]
 pub struct FreezeTokenizedRecordCpiBuilder<'a, 'b> {
     instruction: Box<FreezeTokenizedRecordCpiBuilderInstruction<'a, 'b>>,
@@ -378,6 +424,7 @@ impl<'a, 'b> FreezeTokenizedRecordCpiBuilder<'a, 'b> {
             record: None,
             class: None,
             token2022: None,
+            class_delegate: None,
             is_frozen: None,
             __remaining_accounts: Vec::new(),
         });
@@ -431,6 +478,16 @@ impl<'a, 'b> FreezeTokenizedRecordCpiBuilder<'a, 'b> {
         self.instruction.token2022 = Some(token2022);
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
     #[inline(always)]
     pub fn is_frozen(&mut self, is_frozen: bool) -> &mut Self {
         self.instruction.is_frozen = Some(is_frozen);
@@ -501,6 +558,8 @@ impl<'a, 'b> FreezeTokenizedRecordCpiBuilder<'a, 'b> {
             class: self.instruction.class.expect("class is not set"),
 
             token2022: self.instruction.token2022.expect("token2022 is not set"),
+
+            class_delegate: self.instruction.class_delegate,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -519,6 +578,7 @@ struct FreezeTokenizedRecordCpiBuilderInstruction<'a, 'b> {
     record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     token2022: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     is_frozen: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
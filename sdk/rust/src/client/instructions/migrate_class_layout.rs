@@ -0,0 +1,509 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct MigrateClassLayout {
+    /// Authority of the class
+    pub authority: trezoa_program::pubkey::Pubkey,
+    /// Account that will pay for the larger class account
+    pub payer: trezoa_program::pubkey::Pubkey,
+    /// Legacy class account to be migrated
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// Group mint account of the class, it may not be initialized
+    pub group: trezoa_program::pubkey::Pubkey,
+    /// System Program used to resize our class account
+    pub system_program: trezoa_program::pubkey::Pubkey,
+}
+
+impl MigrateClassLayout {
+    pub fn instruction(
+        &self,
+        args: MigrateClassLayoutInstructionArgs,
+    ) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MigrateClassLayoutInstructionArgs,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.group, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&MigrateClassLayoutInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MigrateClassLayoutInstructionData {
+    discriminator: u8,
+}
+
+impl MigrateClassLayoutInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 43 }
+    }
+}
+
+impl Default for MigrateClassLayoutInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MigrateClassLayoutInstructionArgs {
+    pub group_bump: u8,
+    pub legacy_record_count: u64,
+}
+
+/// Instruction builder for `MigrateClassLayout`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable, signer]` payer
+///   2. `[writable]` class
+///   3. `[]` group
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Clone, Debug, Default)]
+pub struct MigrateClassLayoutBuilder {
+    authority: Option<trezoa_program::pubkey::Pubkey>,
+    payer: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    group: Option<trezoa_program::pubkey::Pubkey>,
+    system_program: Option<trezoa_program::pubkey::Pubkey>,
+    group_bump: Option<u8>,
+    legacy_record_count: Option<u64>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl MigrateClassLayoutBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Authority of the class
+    #[inline(always)]
+    pub fn authority(&mut self, authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Account that will pay for the larger class account
+    #[inline(always)]
+    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Legacy class account to be migrated
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// Group mint account of the class, it may not be initialized
+    #[inline(always)]
+    pub fn group(&mut self, group: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.group = Some(group);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program used to resize our class account
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn group_bump(&mut self, group_bump: u8) -> &mut Self {
+        self.group_bump = Some(group_bump);
+        self
+    }
+    #[inline(always)]
+    pub fn legacy_record_count(&mut self, legacy_record_count: u64) -> &mut Self {
+        self.legacy_record_count = Some(legacy_record_count);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = MigrateClassLayout {
+            authority: self.authority.expect("authority is not set"),
+            payer: self.payer.expect("payer is not set"),
+            class: self.class.expect("class is not set"),
+            group: self.group.expect("group is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = MigrateClassLayoutInstructionArgs {
+            group_bump: self.group_bump.clone().expect("group_bump is not set"),
+            legacy_record_count: self
+                .legacy_record_count
+                .clone()
+                .expect("legacy_record_count is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `migrate_class_layout` CPI accounts.
+pub struct MigrateClassLayoutCpiAccounts<'a, 'b> {
+    /// Authority of the class
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the larger class account
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Legacy class account to be migrated
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Group mint account of the class, it may not be initialized
+    pub group: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to resize our class account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `migrate_class_layout` CPI instruction.
+pub struct MigrateClassLayoutCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Authority of the class
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the larger class account
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Legacy class account to be migrated
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Group mint account of the class, it may not be initialized
+    pub group: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to resize our class account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: MigrateClassLayoutInstructionArgs,
+}
+
+impl<'a, 'b> MigrateClassLayoutCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: MigrateClassLayoutCpiAccounts<'a, 'b>,
+        args: MigrateClassLayoutInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            authority: accounts.authority,
+            payer: accounts.payer,
+            class: accounts.class,
+            group: accounts.group,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.group.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&MigrateClassLayoutInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.class.clone());
+        account_infos.push(self.group.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MigrateClassLayout` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[writable, signer]` payer
+///   2. `[writable]` class
+///   3. `[]` group
+///   4. `[]` system_program
+#[derive(Clone, Debug)]
+pub struct MigrateClassLayoutCpiBuilder<'a, 'b> {
+    instruction: Box<MigrateClassLayoutCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MigrateClassLayoutCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MigrateClassLayoutCpiBuilderInstruction {
+            __program: program,
+            authority: None,
+            payer: None,
+            class: None,
+            group: None,
+            system_program: None,
+            group_bump: None,
+            legacy_record_count: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Authority of the class
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Account that will pay for the larger class account
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Legacy class account to be migrated
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// Group mint account of the class, it may not be initialized
+    #[inline(always)]
+    pub fn group(&mut self, group: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.group = Some(group);
+        self
+    }
+    /// System Program used to resize our class account
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn group_bump(&mut self, group_bump: u8) -> &mut Self {
+        self.instruction.group_bump = Some(group_bump);
+        self
+    }
+    #[inline(always)]
+    pub fn legacy_record_count(&mut self, legacy_record_count: u64) -> &mut Self {
+        self.instruction.legacy_record_count = Some(legacy_record_count);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let args = MigrateClassLayoutInstructionArgs {
+            group_bump: self
+                .instruction
+                .group_bump
+                .clone()
+                .expect("group_bump is not set"),
+            legacy_record_count: self
+                .instruction
+                .legacy_record_count
+                .clone()
+                .expect("legacy_record_count is not set"),
+        };
+        let instruction = MigrateClassLayoutCpi {
+            __program: self.instruction.__program,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+
+            group: self.instruction.group.expect("group is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MigrateClassLayoutCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    group: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    group_bump: Option<u8>,
+    legacy_record_count: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,951 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+#[derive(Debug)]
+pub struct MigrateRecordClass {
+    /// Authority of the current class or a class delegate with the delete permission
+    pub authority: trezoa_program::pubkey::Pubkey,
+    /// Authority of the new class or a class delegate with the create permission
+    pub new_authority: trezoa_program::pubkey::Pubkey,
+    /// Account that will pay for the new record account and get refunded for the old one
+    pub payer: trezoa_program::pubkey::Pubkey,
+    /// Record account to be migrated
+    pub record: trezoa_program::pubkey::Pubkey,
+    /// Current class account of the record
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// Record account to be created in the new class
+    pub new_record: trezoa_program::pubkey::Pubkey,
+    /// Class account the record is migrated to
+    pub new_class: trezoa_program::pubkey::Pubkey,
+    /// System Program used to create the new record account
+    pub system_program: trezoa_program::pubkey::Pubkey,
+    /// Schema account of the new class, required if the new class has a schema
+    pub schema: Option<trezoa_program::pubkey::Pubkey>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// Optional class delegate account of the new authority
+    pub new_class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// Mint account of the record token, required if the record is tokenized
+    pub mint: Option<trezoa_program::pubkey::Pubkey>,
+    /// Token account holding the record token
+    pub token_account: Option<trezoa_program::pubkey::Pubkey>,
+    /// Token2022 Program used to burn the record token
+    pub token2022: Option<trezoa_program::pubkey::Pubkey>,
+}
+
+impl MigrateRecordClass {
+    pub fn instruction(
+        &self,
+        args: MigrateRecordClassInstructionArgs,
+    ) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MigrateRecordClassInstructionArgs,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.new_authority,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.record,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.class, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.new_record,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.new_class,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(schema) = self.schema {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                schema, false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(new_class_delegate) = self.new_class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                new_class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(mint) = self.mint {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(mint, false));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(token_account) = self.token_account {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                token_account,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(token2022) = self.token2022 {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                token2022, false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&MigrateRecordClassInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MigrateRecordClassInstructionData {
+    discriminator: u8,
+}
+
+impl MigrateRecordClassInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 40 }
+    }
+}
+
+impl Default for MigrateRecordClassInstructionData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MigrateRecordClassInstructionArgs {
+    pub bump: u8,
+}
+
+/// Instruction builder for `MigrateRecordClass`.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[signer]` new_authority
+///   2. `[writable, signer]` payer
+///   3. `[writable]` record
+///   4. `[writable]` class
+///   5. `[writable]` new_record
+///   6. `[writable]` new_class
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[optional]` schema
+///   9. `[optional]` class_delegate
+///   10. `[optional]` new_class_delegate
+///   11. `[writable, optional]` mint
+///   12. `[writable, optional]` token_account
+///   13. `[optional]` token2022
+#[derive(Clone, Debug, Default)]
+pub struct MigrateRecordClassBuilder {
+    authority: Option<trezoa_program::pubkey::Pubkey>,
+    new_authority: Option<trezoa_program::pubkey::Pubkey>,
+    payer: Option<trezoa_program::pubkey::Pubkey>,
+    record: Option<trezoa_program::pubkey::Pubkey>,
+    class: Option<trezoa_program::pubkey::Pubkey>,
+    new_record: Option<trezoa_program::pubkey::Pubkey>,
+    new_class: Option<trezoa_program::pubkey::Pubkey>,
+    system_program: Option<trezoa_program::pubkey::Pubkey>,
+    schema: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    new_class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    mint: Option<trezoa_program::pubkey::Pubkey>,
+    token_account: Option<trezoa_program::pubkey::Pubkey>,
+    token2022: Option<trezoa_program::pubkey::Pubkey>,
+    bump: Option<u8>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+impl MigrateRecordClassBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Authority of the current class or a class delegate with the delete permission
+    #[inline(always)]
+    pub fn authority(&mut self, authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Authority of the new class or a class delegate with the create permission
+    #[inline(always)]
+    pub fn new_authority(&mut self, new_authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.new_authority = Some(new_authority);
+        self
+    }
+    /// Account that will pay for the new record account and get refunded for the old one
+    #[inline(always)]
+    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Record account to be migrated
+    #[inline(always)]
+    pub fn record(&mut self, record: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.record = Some(record);
+        self
+    }
+    /// Current class account of the record
+    #[inline(always)]
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// Record account to be created in the new class
+    #[inline(always)]
+    pub fn new_record(&mut self, new_record: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.new_record = Some(new_record);
+        self
+    }
+    /// Class account the record is migrated to
+    #[inline(always)]
+    pub fn new_class(&mut self, new_class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.new_class = Some(new_class);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// System Program used to create the new record account
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Schema account of the new class, required if the new class has a schema
+    #[inline(always)]
+    pub fn schema(&mut self, schema: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.schema = schema;
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the new authority
+    #[inline(always)]
+    pub fn new_class_delegate(
+        &mut self,
+        new_class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.new_class_delegate = new_class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Mint account of the record token, required if the record is tokenized
+    #[inline(always)]
+    pub fn mint(&mut self, mint: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    /// Token account holding the record token
+    #[inline(always)]
+    pub fn token_account(
+        &mut self,
+        token_account: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_account = token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Token2022 Program used to burn the record token
+    #[inline(always)]
+    pub fn token2022(&mut self, token2022: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.token2022 = token2022;
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = MigrateRecordClass {
+            authority: self.authority.expect("authority is not set"),
+            new_authority: self.new_authority.expect("new_authority is not set"),
+            payer: self.payer.expect("payer is not set"),
+            record: self.record.expect("record is not set"),
+            class: self.class.expect("class is not set"),
+            new_record: self.new_record.expect("new_record is not set"),
+            new_class: self.new_class.expect("new_class is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+            schema: self.schema,
+            class_delegate: self.class_delegate,
+            new_class_delegate: self.new_class_delegate,
+            mint: self.mint,
+            token_account: self.token_account,
+            token2022: self.token2022,
+        };
+        let args = MigrateRecordClassInstructionArgs {
+            bump: self.bump.clone().expect("bump is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `migrate_record_class` CPI accounts.
+pub struct MigrateRecordClassCpiAccounts<'a, 'b> {
+    /// Authority of the current class or a class delegate with the delete permission
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Authority of the new class or a class delegate with the create permission
+    pub new_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the new record account and get refunded for the old one
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record account to be migrated
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Current class account of the record
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record account to be created in the new class
+    pub new_record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account the record is migrated to
+    pub new_class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to create the new record account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Schema account of the new class, required if the new class has a schema
+    pub schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional class delegate account of the new authority
+    pub new_class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Mint account of the record token, required if the record is tokenized
+    pub mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Token account holding the record token
+    pub token_account: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Token2022 Program used to burn the record token
+    pub token2022: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+}
+
+/// `migrate_record_class` CPI instruction.
+pub struct MigrateRecordClassCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Authority of the current class or a class delegate with the delete permission
+    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Authority of the new class or a class delegate with the create permission
+    pub new_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Account that will pay for the new record account and get refunded for the old one
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record account to be migrated
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Current class account of the record
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Record account to be created in the new class
+    pub new_record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account the record is migrated to
+    pub new_class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// System Program used to create the new record account
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Schema account of the new class, required if the new class has a schema
+    pub schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional class delegate account of the new authority
+    pub new_class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Mint account of the record token, required if the record is tokenized
+    pub mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Token account holding the record token
+    pub token_account: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Token2022 Program used to burn the record token
+    pub token2022: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: MigrateRecordClassInstructionArgs,
+}
+
+impl<'a, 'b> MigrateRecordClassCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: MigrateRecordClassCpiAccounts<'a, 'b>,
+        args: MigrateRecordClassInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            authority: accounts.authority,
+            new_authority: accounts.new_authority,
+            payer: accounts.payer,
+            record: accounts.record,
+            class: accounts.class,
+            new_record: accounts.new_record,
+            new_class: accounts.new_class,
+            system_program: accounts.system_program,
+            schema: accounts.schema,
+            class_delegate: accounts.class_delegate,
+            new_class_delegate: accounts.new_class_delegate,
+            mint: accounts.mint,
+            token_account: accounts.token_account,
+            token2022: accounts.token2022,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::arithmetic_side_effects)]
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(14 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.new_authority.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.record.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.class.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.new_record.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.new_class.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(schema) = self.schema {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *schema.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(new_class_delegate) = self.new_class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *new_class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(mint) = self.mint {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *mint.key, false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(token_account) = self.token_account {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(token2022) = self.token2022 {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *token2022.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&MigrateRecordClassInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TREZOA_RECORD_SERVICE_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(15 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.new_authority.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.record.clone());
+        account_infos.push(self.class.clone());
+        account_infos.push(self.new_record.clone());
+        account_infos.push(self.new_class.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(schema) = self.schema {
+            account_infos.push(schema.clone());
+        }
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
+        if let Some(new_class_delegate) = self.new_class_delegate {
+            account_infos.push(new_class_delegate.clone());
+        }
+        if let Some(mint) = self.mint {
+            account_infos.push(mint.clone());
+        }
+        if let Some(token_account) = self.token_account {
+            account_infos.push(token_account.clone());
+        }
+        if let Some(token2022) = self.token2022 {
+            account_infos.push(token2022.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MigrateRecordClass` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[signer]` authority
+///   1. `[signer]` new_authority
+///   2. `[writable, signer]` payer
+///   3. `[writable]` record
+///   4. `[writable]` class
+///   5. `[writable]` new_record
+///   6. `[writable]` new_class
+///   7. `[]` system_program
+///   8. `[optional]` schema
+///   9. `[optional]` class_delegate
+///   10. `[optional]` new_class_delegate
+///   11. `[writable, optional]` mint
+///   12. `[writable, optional]` token_account
+///   13. `[optional]` token2022
+#[derive(Clone, Debug)]
+pub struct MigrateRecordClassCpiBuilder<'a, 'b> {
+    instruction: Box<MigrateRecordClassCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MigrateRecordClassCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MigrateRecordClassCpiBuilderInstruction {
+            __program: program,
+            authority: None,
+            new_authority: None,
+            payer: None,
+            record: None,
+            class: None,
+            new_record: None,
+            new_class: None,
+            system_program: None,
+            schema: None,
+            class_delegate: None,
+            new_class_delegate: None,
+            mint: None,
+            token_account: None,
+            token2022: None,
+            bump: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Authority of the current class or a class delegate with the delete permission
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Authority of the new class or a class delegate with the create permission
+    #[inline(always)]
+    pub fn new_authority(
+        &mut self,
+        new_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_authority = Some(new_authority);
+        self
+    }
+    /// Account that will pay for the new record account and get refunded for the old one
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Record account to be migrated
+    #[inline(always)]
+    pub fn record(
+        &mut self,
+        record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.record = Some(record);
+        self
+    }
+    /// Current class account of the record
+    #[inline(always)]
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// Record account to be created in the new class
+    #[inline(always)]
+    pub fn new_record(
+        &mut self,
+        new_record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_record = Some(new_record);
+        self
+    }
+    /// Class account the record is migrated to
+    #[inline(always)]
+    pub fn new_class(
+        &mut self,
+        new_class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_class = Some(new_class);
+        self
+    }
+    /// System Program used to create the new record account
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// Schema account of the new class, required if the new class has a schema
+    #[inline(always)]
+    pub fn schema(
+        &mut self,
+        schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.schema = schema;
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the new authority
+    #[inline(always)]
+    pub fn new_class_delegate(
+        &mut self,
+        new_class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.new_class_delegate = new_class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Mint account of the record token, required if the record is tokenized
+    #[inline(always)]
+    pub fn mint(
+        &mut self,
+        mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint = mint;
+        self
+    }
+    /// `[optional account]`
+    /// Token account holding the record token
+    #[inline(always)]
+    pub fn token_account(
+        &mut self,
+        token_account: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_account = token_account;
+        self
+    }
+    /// `[optional account]`
+    /// Token2022 Program used to burn the record token
+    #[inline(always)]
+    pub fn token2022(
+        &mut self,
+        token2022: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token2022 = token2022;
+        self
+    }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let args = MigrateRecordClassInstructionArgs {
+            bump: self.instruction.bump.clone().expect("bump is not set"),
+        };
+        let instruction = MigrateRecordClassCpi {
+            __program: self.instruction.__program,
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            new_authority: self
+                .instruction
+                .new_authority
+                .expect("new_authority is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            record: self.instruction.record.expect("record is not set"),
+
+            class: self.instruction.class.expect("class is not set"),
+
+            new_record: self.instruction.new_record.expect("new_record is not set"),
+
+            new_class: self.instruction.new_class.expect("new_class is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            schema: self.instruction.schema,
+
+            class_delegate: self.instruction.class_delegate,
+
+            new_class_delegate: self.instruction.new_class_delegate,
+
+            mint: self.instruction.mint,
+
+            token_account: self.instruction.token_account,
+
+            token2022: self.instruction.token2022,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MigrateRecordClassCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    new_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    new_record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    new_class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    schema: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    new_class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    token_account: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    token2022: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    bump: Option<u8>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -7,25 +7,24 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
-use trezoa_program::pubkey::Pubkey;
 
 /// Accounts.
 #[derive(Debug)]
-pub struct UpdateClassAuthority {
-    /// Authority used to update a class
-    pub authority: trezoa_program::pubkey::Pubkey,
-    /// Account that will pay of get refunded for the class update
+pub struct MigrateRecordLayout {
+    /// Account that will pay for the larger record account
     pub payer: trezoa_program::pubkey::Pubkey,
-    /// Class account to be updated
+    /// Legacy record account to be migrated
+    pub record: trezoa_program::pubkey::Pubkey,
+    /// Class account of the record, already migrated
     pub class: trezoa_program::pubkey::Pubkey,
-    /// System Program used to extend our class account
+    /// System Program used to resize our record account
     pub system_program: trezoa_program::pubkey::Pubkey,
 }
 
-impl UpdateClassAuthority {
+impl MigrateRecordLayout {
     pub fn instruction(
         &self,
-        args: UpdateClassAuthorityInstructionArgs,
+        args: MigrateRecordLayoutInstructionArgs,
     ) -> trezoa_program::instruction::Instruction {
         self.instruction_with_remaining_accounts(args, &[])
     }
@@ -33,16 +32,16 @@ impl UpdateClassAuthority {
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
-        args: UpdateClassAuthorityInstructionArgs,
+        args: MigrateRecordLayoutInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
         let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
-            self.authority,
-            true,
+            self.payer, true,
         ));
         accounts.push(trezoa_program::instruction::AccountMeta::new(
-            self.payer, true,
+            self.record,
+            false,
         ));
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.class, false,
@@ -52,7 +51,7 @@ impl UpdateClassAuthority {
             false,
         ));
         accounts.extend_from_slice(remaining_accounts);
-        let mut data = borsh::to_vec(&UpdateClassAuthorityInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&MigrateRecordLayoutInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
         data.append(&mut args);
 
@@ -66,17 +65,17 @@ impl UpdateClassAuthority {
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct UpdateClassAuthorityInstructionData {
+pub struct MigrateRecordLayoutInstructionData {
     discriminator: u8,
 }
 
-impl UpdateClassAuthorityInstructionData {
+impl MigrateRecordLayoutInstructionData {
     pub fn new() -> Self {
-        Self { discriminator: 2 }
+        Self { discriminator: 44 }
     }
 }
 
-impl Default for UpdateClassAuthorityInstructionData {
+impl Default for MigrateRecordLayoutInstructionData {
     fn default() -> Self {
         Self::new()
     }
@@ -84,60 +83,60 @@ impl Default for UpdateClassAuthorityInstructionData {
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct UpdateClassAuthorityInstructionArgs {
-    pub new_authority: Pubkey,
+pub struct MigrateRecordLayoutInstructionArgs {
+    pub mint_bump: u8,
 }
 
-/// Instruction builder for `UpdateClassAuthority`.
+/// Instruction builder for `MigrateRecordLayout`.
 ///
 /// ### Accounts:
 ///
-///   0. `[writable, signer]` authority
-///   1. `[writable, signer]` payer
+///   0. `[writable, signer]` payer
+///   1. `[writable]` record
 ///   2. `[writable]` class
 ///   3. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
-pub struct UpdateClassAuthorityBuilder {
-    authority: Option<trezoa_program::pubkey::Pubkey>,
+pub struct MigrateRecordLayoutBuilder {
     payer: Option<trezoa_program::pubkey::Pubkey>,
+    record: Option<trezoa_program::pubkey::Pubkey>,
     class: Option<trezoa_program::pubkey::Pubkey>,
     system_program: Option<trezoa_program::pubkey::Pubkey>,
-    new_authority: Option<Pubkey>,
+    mint_bump: Option<u8>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
 
-impl UpdateClassAuthorityBuilder {
+impl MigrateRecordLayoutBuilder {
     pub fn new() -> Self {
         Self::default()
     }
-    /// Authority used to update a class
+    /// Account that will pay for the larger record account
     #[inline(always)]
-    pub fn authority(&mut self, authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
-        self.authority = Some(authority);
+    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
         self
     }
-    /// Account that will pay of get refunded for the class update
+    /// Legacy record account to be migrated
     #[inline(always)]
-    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
-        self.payer = Some(payer);
+    pub fn record(&mut self, record: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.record = Some(record);
         self
     }
-    /// Class account to be updated
+    /// Class account of the record, already migrated
     #[inline(always)]
     pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
         self.class = Some(class);
         self
     }
     /// `[optional account, default to '11111111111111111111111111111111']`
-    /// System Program used to extend our class account
+    /// System Program used to resize our record account
     #[inline(always)]
     pub fn system_program(&mut self, system_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
         self.system_program = Some(system_program);
         self
     }
     #[inline(always)]
-    pub fn new_authority(&mut self, new_authority: Pubkey) -> &mut Self {
-        self.new_authority = Some(new_authority);
+    pub fn mint_bump(&mut self, mint_bump: u8) -> &mut Self {
+        self.mint_bump = Some(mint_bump);
         self
     }
     /// Add an additional account to the instruction.
@@ -160,63 +159,60 @@ impl UpdateClassAuthorityBuilder {
     }
     #[allow(clippy::clone_on_copy)]
     pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
-        let accounts = UpdateClassAuthority {
-            authority: self.authority.expect("authority is not set"),
+        let accounts = MigrateRecordLayout {
             payer: self.payer.expect("payer is not set"),
+            record: self.record.expect("record is not set"),
             class: self.class.expect("class is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
         };
-        let args = UpdateClassAuthorityInstructionArgs {
-            new_authority: self
-                .new_authority
-                .clone()
-                .expect("new_authority is not set"),
+        let args = MigrateRecordLayoutInstructionArgs {
+            mint_bump: self.mint_bump.clone().expect("mint_bump is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
-/// `update_class_authority` CPI accounts.
-pub struct UpdateClassAuthorityCpiAccounts<'a, 'b> {
-    /// Authority used to update a class
-    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// Account that will pay of get refunded for the class update
+/// `migrate_record_layout` CPI accounts.
+pub struct MigrateRecordLayoutCpiAccounts<'a, 'b> {
+    /// Account that will pay for the larger record account
     pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// Class account to be updated
+    /// Legacy record account to be migrated
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account of the record, already migrated
     pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// System Program used to extend our class account
+    /// System Program used to resize our record account
     pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
 }
 
-/// `update_class_authority` CPI instruction.
-pub struct UpdateClassAuthorityCpi<'a, 'b> {
+/// `migrate_record_layout` CPI instruction.
+pub struct MigrateRecordLayoutCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// Authority used to update a class
-    pub authority: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// Account that will pay of get refunded for the class update
+    /// Account that will pay for the larger record account
     pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// Class account to be updated
+    /// Legacy record account to be migrated
+    pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Class account of the record, already migrated
     pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// System Program used to extend our class account
+    /// System Program used to resize our record account
     pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
-    pub __args: UpdateClassAuthorityInstructionArgs,
+    pub __args: MigrateRecordLayoutInstructionArgs,
 }
 
-impl<'a, 'b> UpdateClassAuthorityCpi<'a, 'b> {
+impl<'a, 'b> MigrateRecordLayoutCpi<'a, 'b> {
     pub fn new(
         program: &'b trezoa_program::account_info::AccountInfo<'a>,
-        accounts: UpdateClassAuthorityCpiAccounts<'a, 'b>,
-        args: UpdateClassAuthorityInstructionArgs,
+        accounts: MigrateRecordLayoutCpiAccounts<'a, 'b>,
+        args: MigrateRecordLayoutInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
-            authority: accounts.authority,
             payer: accounts.payer,
+            record: accounts.record,
             class: accounts.class,
             system_program: accounts.system_program,
             __args: args,
@@ -258,12 +254,12 @@ impl<'a, 'b> UpdateClassAuthorityCpi<'a, 'b> {
     ) -> trezoa_program::entrypoint::ProgramResult {
         let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
-            *self.authority.key,
+            *self.payer.key,
             true,
         ));
         accounts.push(trezoa_program::instruction::AccountMeta::new(
-            *self.payer.key,
-            true,
+            *self.record.key,
+            false,
         ));
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.class.key,
@@ -280,7 +276,7 @@ impl<'a, 'b> UpdateClassAuthorityCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let mut data = borsh::to_vec(&UpdateClassAuthorityInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&MigrateRecordLayoutInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&self.__args).unwrap();
         data.append(&mut args);
 
@@ -291,8 +287,8 @@ impl<'a, 'b> UpdateClassAuthorityCpi<'a, 'b> {
         };
         let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
-        account_infos.push(self.authority.clone());
         account_infos.push(self.payer.clone());
+        account_infos.push(self.record.clone());
         account_infos.push(self.class.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
@@ -307,54 +303,54 @@ impl<'a, 'b> UpdateClassAuthorityCpi<'a, 'b> {
     }
 }
 
-/// Instruction builder for `UpdateClassAuthority` via CPI.
+/// Instruction builder for `MigrateRecordLayout` via CPI.
 ///
 /// ### Accounts:
 ///
-///   0. `[writable, signer]` authority
-///   1. `[writable, signer]` payer
+///   0. `[writable, signer]` payer
+///   1. `[writable]` record
 ///   2. `[writable]` class
 ///   3. `[]` system_program
 #[derive(Clone, Debug)]
-pub struct UpdateClassAuthorityCpiBuilder<'a, 'b> {
-    instruction: Box<UpdateClassAuthorityCpiBuilderInstruction<'a, 'b>>,
+pub struct MigrateRecordLayoutCpiBuilder<'a, 'b> {
+    instruction: Box<MigrateRecordLayoutCpiBuilderInstruction<'a, 'b>>,
 }
 
-impl<'a, 'b> UpdateClassAuthorityCpiBuilder<'a, 'b> {
+impl<'a, 'b> MigrateRecordLayoutCpiBuilder<'a, 'b> {
     pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
-        let instruction = Box::new(UpdateClassAuthorityCpiBuilderInstruction {
+        let instruction = Box::new(MigrateRecordLayoutCpiBuilderInstruction {
             __program: program,
-            authority: None,
             payer: None,
+            record: None,
             class: None,
             system_program: None,
-            new_authority: None,
+            mint_bump: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
     }
-    /// Authority used to update a class
+    /// Account that will pay for the larger record account
     #[inline(always)]
-    pub fn authority(
-        &mut self,
-        authority: &'b trezoa_program::account_info::AccountInfo<'a>,
-    ) -> &mut Self {
-        self.instruction.authority = Some(authority);
+    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
         self
     }
-    /// Account that will pay of get refunded for the class update
+    /// Legacy record account to be migrated
     #[inline(always)]
-    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
-        self.instruction.payer = Some(payer);
+    pub fn record(
+        &mut self,
+        record: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.record = Some(record);
         self
     }
-    /// Class account to be updated
+    /// Class account of the record, already migrated
     #[inline(always)]
     pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.class = Some(class);
         self
     }
-    /// System Program used to extend our class account
+    /// System Program used to resize our record account
     #[inline(always)]
     pub fn system_program(
         &mut self,
@@ -364,8 +360,8 @@ impl<'a, 'b> UpdateClassAuthorityCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn new_authority(&mut self, new_authority: Pubkey) -> &mut Self {
-        self.instruction.new_authority = Some(new_authority);
+    pub fn mint_bump(&mut self, mint_bump: u8) -> &mut Self {
+        self.instruction.mint_bump = Some(mint_bump);
         self
     }
     /// Add an additional account to the instruction.
@@ -409,20 +405,20 @@ impl<'a, 'b> UpdateClassAuthorityCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let args = UpdateClassAuthorityInstructionArgs {
-            new_authority: self
+        let args = MigrateRecordLayoutInstructionArgs {
+            mint_bump: self
                 .instruction
-                .new_authority
+                .mint_bump
                 .clone()
-                .expect("new_authority is not set"),
+                .expect("mint_bump is not set"),
         };
-        let instruction = UpdateClassAuthorityCpi {
+        let instruction = MigrateRecordLayoutCpi {
             __program: self.instruction.__program,
 
-            authority: self.instruction.authority.expect("authority is not set"),
-
             payer: self.instruction.payer.expect("payer is not set"),
 
+            record: self.instruction.record.expect("record is not set"),
+
             class: self.instruction.class.expect("class is not set"),
 
             system_program: self
@@ -439,13 +435,13 @@ impl<'a, 'b> UpdateClassAuthorityCpiBuilder<'a, 'b> {
 }
 
 #[derive(Clone, Debug)]
-struct UpdateClassAuthorityCpiBuilderInstruction<'a, 'b> {
+struct MigrateRecordLayoutCpiBuilderInstruction<'a, 'b> {
     __program: &'b trezoa_program::account_info::AccountInfo<'a>,
-    authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     payer: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
-    new_authority: Option<Pubkey>,
+    mint_bump: Option<u8>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b trezoa_program::account_info::AccountInfo<'a>,
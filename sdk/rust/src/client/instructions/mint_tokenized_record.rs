@@ -33,19 +33,27 @@ pub struct MintTokenizedRecord {
     pub token2022: trezoa_program::pubkey::Pubkey,
     /// System Program used to create our token
     pub system_program: trezoa_program::pubkey::Pubkey,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 impl MintTokenizedRecord {
-    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: MintTokenizedRecordInstructionArgs,
+    ) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: MintTokenizedRecordInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
             self.owner, false,
         ));
@@ -63,7 +71,7 @@ impl MintTokenizedRecord {
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.mint, false,
         ));
-        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.class, false,
         ));
         accounts.push(trezoa_program::instruction::AccountMeta::new(
@@ -85,8 +93,32 @@ impl MintTokenizedRecord {
             self.system_program,
             false,
         ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                record_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
-        let data = borsh::to_vec(&MintTokenizedRecordInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&MintTokenizedRecordInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
 
         trezoa_program::instruction::Instruction {
             program_id: crate::TREZOA_RECORD_SERVICE_ID,
@@ -114,6 +146,13 @@ impl Default for MintTokenizedRecordInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MintTokenizedRecordInstructionArgs {
+    pub mint_bump: u8,
+    pub group_bump: u8,
+}
+
 /// Instruction builder for `MintTokenizedRecord`.
 ///
 /// ### Accounts:
@@ -123,12 +162,14 @@ impl Default for MintTokenizedRecordInstructionData {
 ///   2. `[signer]` authority
 ///   3. `[writable]` record
 ///   4. `[writable]` mint
-///   5. `[]` class
+///   5. `[writable]` class
 ///   6. `[writable]` group
 ///   7. `[writable]` token_account
 ///   8. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
 ///   9. `[optional]` token2022 (default to `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`)
 ///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   11. `[optional]` class_delegate
+///   12. `[optional]` record_delegate
 #[derive(Clone, Debug, Default)]
 pub struct MintTokenizedRecordBuilder {
     owner: Option<trezoa_program::pubkey::Pubkey>,
@@ -142,6 +183,10 @@ pub struct MintTokenizedRecordBuilder {
     associated_token_program: Option<trezoa_program::pubkey::Pubkey>,
     token2022: Option<trezoa_program::pubkey::Pubkey>,
     system_program: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    record_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    mint_bump: Option<u8>,
+    group_bump: Option<u8>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
 
@@ -221,6 +266,36 @@ impl MintTokenizedRecordBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional record delegate account of the authority
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.record_delegate = record_delegate;
+        self
+    }
+    #[inline(always)]
+    pub fn mint_bump(&mut self, mint_bump: u8) -> &mut Self {
+        self.mint_bump = Some(mint_bump);
+        self
+    }
+    #[inline(always)]
+    pub fn group_bump(&mut self, group_bump: u8) -> &mut Self {
+        self.group_bump = Some(group_bump);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -259,9 +334,15 @@ impl MintTokenizedRecordBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+            class_delegate: self.class_delegate,
+            record_delegate: self.record_delegate,
+        };
+        let args = MintTokenizedRecordInstructionArgs {
+            mint_bump: self.mint_bump.clone().expect("mint_bump is not set"),
+            group_bump: self.group_bump.clone().expect("group_bump is not set"),
         };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -289,6 +370,10 @@ pub struct MintTokenizedRecordCpiAccounts<'a, 'b> {
     pub token2022: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// System Program used to create our token
     pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `mint_tokenized_record` CPI instruction.
@@ -317,12 +402,19 @@ pub struct MintTokenizedRecordCpi<'a, 'b> {
     pub token2022: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// System Program used to create our token
     pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: MintTokenizedRecordInstructionArgs,
 }
 
 impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
     pub fn new(
         program: &'b trezoa_program::account_info::AccountInfo<'a>,
         accounts: MintTokenizedRecordCpiAccounts<'a, 'b>,
+        args: MintTokenizedRecordInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -337,6 +429,9 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
             associated_token_program: accounts.associated_token_program,
             token2022: accounts.token2022,
             system_program: accounts.system_program,
+            class_delegate: accounts.class_delegate,
+            record_delegate: accounts.record_delegate,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -373,7 +468,7 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
             *self.owner.key,
             false,
@@ -394,7 +489,7 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
             *self.mint.key,
             false,
         ));
-        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.class.key,
             false,
         ));
@@ -418,6 +513,28 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *record_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -425,14 +542,16 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = borsh::to_vec(&MintTokenizedRecordInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&MintTokenizedRecordInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
 
         let instruction = trezoa_program::instruction::Instruction {
             program_id: crate::TREZOA_RECORD_SERVICE_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.payer.clone());
@@ -445,6 +564,12 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
         account_infos.push(self.associated_token_program.clone());
         account_infos.push(self.token2022.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            account_infos.push(record_delegate.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -466,12 +591,14 @@ impl<'a, 'b> MintTokenizedRecordCpi<'a, 'b> {
 ///   2. `[signer]` authority
 ///   3. `[writable]` record
 ///   4. `[writable]` mint
-///   5. `[]` class
+///   5. `[writable]` class
 ///   6. `[writable]` group
 ///   7. `[writable]` token_account
 ///   8. `[]` associated_token_program
 ///   9. `[]` token2022
 ///   10. `[]` system_program
+///   11. `[optional]` class_delegate
+///   12. `[optional]` record_delegate
 #[derive(Clone, Debug)]
 pub struct MintTokenizedRecordCpiBuilder<'a, 'b> {
     instruction: Box<MintTokenizedRecordCpiBuilderInstruction<'a, 'b>>,
@@ -492,6 +619,10 @@ impl<'a, 'b> MintTokenizedRecordCpiBuilder<'a, 'b> {
             associated_token_program: None,
             token2022: None,
             system_program: None,
+            class_delegate: None,
+            record_delegate: None,
+            mint_bump: None,
+            group_bump: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -580,6 +711,36 @@ impl<'a, 'b> MintTokenizedRecordCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional record delegate account of the authority
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.record_delegate = record_delegate;
+        self
+    }
+    #[inline(always)]
+    pub fn mint_bump(&mut self, mint_bump: u8) -> &mut Self {
+        self.instruction.mint_bump = Some(mint_bump);
+        self
+    }
+    #[inline(always)]
+    pub fn group_bump(&mut self, group_bump: u8) -> &mut Self {
+        self.instruction.group_bump = Some(group_bump);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -621,6 +782,18 @@ impl<'a, 'b> MintTokenizedRecordCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> trezoa_program::entrypoint::ProgramResult {
+        let args = MintTokenizedRecordInstructionArgs {
+            mint_bump: self
+                .instruction
+                .mint_bump
+                .clone()
+                .expect("mint_bump is not set"),
+            group_bump: self
+                .instruction
+                .group_bump
+                .clone()
+                .expect("group_bump is not set"),
+        };
         let instruction = MintTokenizedRecordCpi {
             __program: self.instruction.__program,
 
@@ -654,6 +827,11 @@ impl<'a, 'b> MintTokenizedRecordCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            class_delegate: self.instruction.class_delegate,
+
+            record_delegate: self.instruction.record_delegate,
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -676,6 +854,10 @@ struct MintTokenizedRecordCpiBuilderInstruction<'a, 'b> {
     associated_token_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     token2022: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    mint_bump: Option<u8>,
+    group_bump: Option<u8>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b trezoa_program::account_info::AccountInfo<'a>,
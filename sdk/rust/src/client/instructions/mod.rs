@@ -5,36 +5,96 @@
 //! <https://github.com/trzledgerfoundation-idl/codoma>
 //!
 
+pub(crate) mod r#accept_class_authority;
+pub(crate) mod r#add_class_delegate;
+pub(crate) mod r#approve_record_delegate;
+pub(crate) mod r#batch_create_records;
+pub(crate) mod r#batch_delete_records;
+pub(crate) mod r#batch_freeze_records;
+pub(crate) mod r#begin_record_write;
 pub(crate) mod r#burn_tokenized_record;
+pub(crate) mod r#cancel_class_authority_transfer;
+pub(crate) mod r#cancel_record_write;
+pub(crate) mod r#close_class;
+pub(crate) mod r#close_expired_record;
+pub(crate) mod r#compare_and_swap_record_data;
+pub(crate) mod r#compare_and_swap_record_expiry;
+pub(crate) mod r#create_buffered_record;
 pub(crate) mod r#create_class;
 pub(crate) mod r#create_record;
+pub(crate) mod r#create_record_from_signature;
 pub(crate) mod r#create_record_tokenizable;
+pub(crate) mod r#create_record_with_proof;
 pub(crate) mod r#delete_record;
+pub(crate) mod r#finalize_record_write;
 pub(crate) mod r#freeze_class;
 pub(crate) mod r#freeze_record;
 pub(crate) mod r#freeze_tokenized_record;
+pub(crate) mod r#migrate_class_layout;
+pub(crate) mod r#migrate_record_class;
+pub(crate) mod r#migrate_record_layout;
 pub(crate) mod r#mint_tokenized_record;
+pub(crate) mod r#patch_record_data;
+pub(crate) mod r#propose_class_authority;
+pub(crate) mod r#recreate_record;
+pub(crate) mod r#revoke_class_delegate;
+pub(crate) mod r#revoke_record;
+pub(crate) mod r#revoke_record_delegate;
+pub(crate) mod r#set_class_schema;
 pub(crate) mod r#transfer_record;
 pub(crate) mod r#transfer_tokenized_record;
-pub(crate) mod r#update_class_authority;
+pub(crate) mod r#update_class_fee;
+pub(crate) mod r#update_class_merkle_root;
 pub(crate) mod r#update_class_metadata;
+pub(crate) mod r#update_class_policy;
 pub(crate) mod r#update_record;
 pub(crate) mod r#update_record_expiry;
 pub(crate) mod r#update_record_tokenizable;
+pub(crate) mod r#write_record_chunk;
 
+pub use self::r#accept_class_authority::*;
+pub use self::r#add_class_delegate::*;
+pub use self::r#approve_record_delegate::*;
+pub use self::r#batch_create_records::*;
+pub use self::r#batch_delete_records::*;
+pub use self::r#batch_freeze_records::*;
+pub use self::r#begin_record_write::*;
 pub use self::r#burn_tokenized_record::*;
+pub use self::r#cancel_class_authority_transfer::*;
+pub use self::r#cancel_record_write::*;
+pub use self::r#close_class::*;
+pub use self::r#close_expired_record::*;
+pub use self::r#compare_and_swap_record_data::*;
+pub use self::r#compare_and_swap_record_expiry::*;
+pub use self::r#create_buffered_record::*;
 pub use self::r#create_class::*;
 pub use self::r#create_record::*;
+pub use self::r#create_record_from_signature::*;
 pub use self::r#create_record_tokenizable::*;
+pub use self::r#create_record_with_proof::*;
 pub use self::r#delete_record::*;
+pub use self::r#finalize_record_write::*;
 pub use self::r#freeze_class::*;
 pub use self::r#freeze_record::*;
 pub use self::r#freeze_tokenized_record::*;
+pub use self::r#migrate_class_layout::*;
+pub use self::r#migrate_record_class::*;
+pub use self::r#migrate_record_layout::*;
 pub use self::r#mint_tokenized_record::*;
+pub use self::r#patch_record_data::*;
+pub use self::r#propose_class_authority::*;
+pub use self::r#recreate_record::*;
+pub use self::r#revoke_class_delegate::*;
+pub use self::r#revoke_record::*;
+pub use self::r#revoke_record_delegate::*;
+pub use self::r#set_class_schema::*;
 pub use self::r#transfer_record::*;
 pub use self::r#transfer_tokenized_record::*;
-pub use self::r#update_class_authority::*;
+pub use self::r#update_class_fee::*;
+pub use self::r#update_class_merkle_root::*;
 pub use self::r#update_class_metadata::*;
+pub use self::r#update_class_policy::*;
 pub use self::r#update_record::*;
 pub use self::r#update_record_expiry::*;
 pub use self::r#update_record_tokenizable::*;
+pub use self::r#write_record_chunk::*;
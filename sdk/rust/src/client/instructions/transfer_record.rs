@@ -17,7 +17,11 @@ pub struct TransferRecord {
     /// Record account to be updated
     pub record: trezoa_program::pubkey::Pubkey,
     /// Class account of the record
-    pub class: Option<trezoa_program::pubkey::Pubkey>,
+    pub class: trezoa_program::pubkey::Pubkey,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 impl TransferRecord {
@@ -34,7 +38,7 @@ impl TransferRecord {
         args: TransferRecordInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -43,9 +47,24 @@ impl TransferRecord {
             self.record,
             false,
         ));
-        if let Some(class) = self.class {
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.class, false,
+        ));
+        if let Some(class_delegate) = self.class_delegate {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
-                class, false,
+                class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                record_delegate,
+                false,
             ));
         } else {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
@@ -96,12 +115,16 @@ pub struct TransferRecordInstructionArgs {
 ///
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` record
-///   2. `[optional]` class
+///   2. `[]` class
+///   3. `[optional]` class_delegate
+///   4. `[optional]` record_delegate
 #[derive(Clone, Debug, Default)]
 pub struct TransferRecordBuilder {
     authority: Option<trezoa_program::pubkey::Pubkey>,
     record: Option<trezoa_program::pubkey::Pubkey>,
     class: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    record_delegate: Option<trezoa_program::pubkey::Pubkey>,
     new_owner: Option<Pubkey>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
@@ -122,11 +145,30 @@ impl TransferRecordBuilder {
         self.record = Some(record);
         self
     }
-    /// `[optional account]`
     /// Class account of the record
     #[inline(always)]
-    pub fn class(&mut self, class: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
-        self.class = class;
+    pub fn class(&mut self, class: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.class = Some(class);
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional record delegate account of the authority
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.record_delegate = record_delegate;
         self
     }
     #[inline(always)]
@@ -157,7 +199,9 @@ impl TransferRecordBuilder {
         let accounts = TransferRecord {
             authority: self.authority.expect("authority is not set"),
             record: self.record.expect("record is not set"),
-            class: self.class,
+            class: self.class.expect("class is not set"),
+            class_delegate: self.class_delegate,
+            record_delegate: self.record_delegate,
         };
         let args = TransferRecordInstructionArgs {
             new_owner: self.new_owner.clone().expect("new_owner is not set"),
@@ -174,7 +218,11 @@ pub struct TransferRecordCpiAccounts<'a, 'b> {
     /// Record account to be updated
     pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Class account of the record
-    pub class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `transfer_record` CPI instruction.
@@ -186,7 +234,11 @@ pub struct TransferRecordCpi<'a, 'b> {
     /// Record account to be updated
     pub record: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// Class account of the record
-    pub class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: TransferRecordInstructionArgs,
 }
@@ -202,6 +254,8 @@ impl<'a, 'b> TransferRecordCpi<'a, 'b> {
             authority: accounts.authority,
             record: accounts.record,
             class: accounts.class,
+            class_delegate: accounts.class_delegate,
+            record_delegate: accounts.record_delegate,
             __args: args,
         }
     }
@@ -239,7 +293,7 @@ impl<'a, 'b> TransferRecordCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -248,9 +302,25 @@ impl<'a, 'b> TransferRecordCpi<'a, 'b> {
             *self.record.key,
             false,
         ));
-        if let Some(class) = self.class {
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.class.key,
+            false,
+        ));
+        if let Some(class_delegate) = self.class_delegate {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
-                *class.key, false,
+                *class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *record_delegate.key,
+                false,
             ));
         } else {
             accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
@@ -274,12 +344,16 @@ impl<'a, 'b> TransferRecordCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.record.clone());
-        if let Some(class) = self.class {
-            account_infos.push(class.clone());
+        account_infos.push(self.class.clone());
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            account_infos.push(record_delegate.clone());
         }
         remaining_accounts
             .iter()
@@ -299,7 +373,9 @@ impl<'a, 'b> TransferRecordCpi<'a, 'b> {
 ///
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` record
-///   2. `[optional]` class
+///   2. `[]` class
+///   3. `[optional]` class_delegate
+///   4. `[optional]` record_delegate
 #[derive(Clone, Debug)]
 pub struct TransferRecordCpiBuilder<'a, 'b> {
     instruction: Box<TransferRecordCpiBuilderInstruction<'a, 'b>>,
@@ -312,6 +388,8 @@ impl<'a, 'b> TransferRecordCpiBuilder<'a, 'b> {
             authority: None,
             record: None,
             class: None,
+            class_delegate: None,
+            record_delegate: None,
             new_owner: None,
             __remaining_accounts: Vec::new(),
         });
@@ -335,14 +413,30 @@ impl<'a, 'b> TransferRecordCpiBuilder<'a, 'b> {
         self.instruction.record = Some(record);
         self
     }
-    /// `[optional account]`
     /// Class account of the record
     #[inline(always)]
-    pub fn class(
+    pub fn class(&mut self, class: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.class = Some(class);
+        self
+    }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional record delegate account of the authority
+    #[inline(always)]
+    pub fn record_delegate(
         &mut self,
-        class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+        record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.class = class;
+        self.instruction.record_delegate = record_delegate;
         self
     }
     #[inline(always)]
@@ -405,7 +499,11 @@ impl<'a, 'b> TransferRecordCpiBuilder<'a, 'b> {
 
             record: self.instruction.record.expect("record is not set"),
 
-            class: self.instruction.class,
+            class: self.instruction.class.expect("class is not set"),
+
+            class_delegate: self.instruction.class_delegate,
+
+            record_delegate: self.instruction.record_delegate,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -421,6 +519,8 @@ struct TransferRecordCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     new_owner: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -21,6 +21,10 @@ pub struct UpdateRecordExpiry {
     pub class: trezoa_program::pubkey::Pubkey,
     /// System Program used to extend our record account
     pub system_program: trezoa_program::pubkey::Pubkey,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 impl UpdateRecordExpiry {
@@ -37,7 +41,7 @@ impl UpdateRecordExpiry {
         args: UpdateRecordExpiryInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -56,6 +60,28 @@ impl UpdateRecordExpiry {
             self.system_program,
             false,
         ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                class_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                record_delegate,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&UpdateRecordExpiryInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -102,6 +128,8 @@ pub struct UpdateRecordExpiryInstructionArgs {
 ///   2. `[writable]` record
 ///   3. `[]` class
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` class_delegate
+///   6. `[optional]` record_delegate
 #[derive(Clone, Debug, Default)]
 pub struct UpdateRecordExpiryBuilder {
     authority: Option<trezoa_program::pubkey::Pubkey>,
@@ -109,6 +137,8 @@ pub struct UpdateRecordExpiryBuilder {
     record: Option<trezoa_program::pubkey::Pubkey>,
     class: Option<trezoa_program::pubkey::Pubkey>,
     system_program: Option<trezoa_program::pubkey::Pubkey>,
+    class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    record_delegate: Option<trezoa_program::pubkey::Pubkey>,
     expiry: Option<i64>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
@@ -148,6 +178,26 @@ impl UpdateRecordExpiryBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional record delegate account of the authority
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.record_delegate = record_delegate;
+        self
+    }
     #[inline(always)]
     pub fn expiry(&mut self, expiry: i64) -> &mut Self {
         self.expiry = Some(expiry);
@@ -181,6 +231,8 @@ impl UpdateRecordExpiryBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+            class_delegate: self.class_delegate,
+            record_delegate: self.record_delegate,
         };
         let args = UpdateRecordExpiryInstructionArgs {
             expiry: self.expiry.clone().expect("expiry is not set"),
@@ -202,6 +254,10 @@ pub struct UpdateRecordExpiryCpiAccounts<'a, 'b> {
     pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// System Program used to extend our record account
     pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `update_record_expiry` CPI instruction.
@@ -218,6 +274,10 @@ pub struct UpdateRecordExpiryCpi<'a, 'b> {
     pub class: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// System Program used to extend our record account
     pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Optional class delegate account of the authority
+    pub class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Optional record delegate account of the authority
+    pub record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: UpdateRecordExpiryInstructionArgs,
 }
@@ -235,6 +295,8 @@ impl<'a, 'b> UpdateRecordExpiryCpi<'a, 'b> {
             record: accounts.record,
             class: accounts.class,
             system_program: accounts.system_program,
+            class_delegate: accounts.class_delegate,
+            record_delegate: accounts.record_delegate,
             __args: args,
         }
     }
@@ -272,7 +334,7 @@ impl<'a, 'b> UpdateRecordExpiryCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -293,6 +355,28 @@ impl<'a, 'b> UpdateRecordExpiryCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(class_delegate) = self.class_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *class_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *record_delegate.key,
+                false,
+            ));
+        } else {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                crate::TREZOA_RECORD_SERVICE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -309,13 +393,19 @@ impl<'a, 'b> UpdateRecordExpiryCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.record.clone());
         account_infos.push(self.class.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(class_delegate) = self.class_delegate {
+            account_infos.push(class_delegate.clone());
+        }
+        if let Some(record_delegate) = self.record_delegate {
+            account_infos.push(record_delegate.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -337,6 +427,8 @@ impl<'a, 'b> UpdateRecordExpiryCpi<'a, 'b> {
 ///   2. `[writable]` record
 ///   3. `[]` class
 ///   4. `[]` system_program
+///   5. `[optional]` class_delegate
+///   6. `[optional]` record_delegate
 #[derive(Clone, Debug)]
 pub struct UpdateRecordExpiryCpiBuilder<'a, 'b> {
     instruction: Box<UpdateRecordExpiryCpiBuilderInstruction<'a, 'b>>,
@@ -351,6 +443,8 @@ impl<'a, 'b> UpdateRecordExpiryCpiBuilder<'a, 'b> {
             record: None,
             class: None,
             system_program: None,
+            class_delegate: None,
+            record_delegate: None,
             expiry: None,
             __remaining_accounts: Vec::new(),
         });
@@ -395,6 +489,26 @@ impl<'a, 'b> UpdateRecordExpiryCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// Optional class delegate account of the authority
+    #[inline(always)]
+    pub fn class_delegate(
+        &mut self,
+        class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.class_delegate = class_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// Optional record delegate account of the authority
+    #[inline(always)]
+    pub fn record_delegate(
+        &mut self,
+        record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.record_delegate = record_delegate;
+        self
+    }
     #[inline(always)]
     pub fn expiry(&mut self, expiry: i64) -> &mut Self {
         self.instruction.expiry = Some(expiry);
@@ -459,6 +573,10 @@ impl<'a, 'b> UpdateRecordExpiryCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            class_delegate: self.instruction.class_delegate,
+
+            record_delegate: self.instruction.record_delegate,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -476,6 +594,8 @@ struct UpdateRecordExpiryCpiBuilderInstruction<'a, 'b> {
     record: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     class: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    class_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    record_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     expiry: Option<i64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -0,0 +1,21 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use kaigan::types::U8PrefixVec;
+
+/// Record created by BatchCreateRecords, with its owner and record accounts as remaining accounts
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchRecord {
+    pub expiration: i64,
+    pub content_type: u8,
+    pub bump: u8,
+    pub seed: U8PrefixVec<u8>,
+    pub data: Vec<u8>,
+}
@@ -0,0 +1,21 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use crate::types::Policy;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Policy of each action on the records of a class
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClassPolicy {
+    pub update_data: Policy,
+    pub update_expiry: Policy,
+    pub transfer: Policy,
+    pub delete: Policy,
+    pub tokenize: Policy,
+}
@@ -6,7 +6,19 @@
 //!
 
 pub(crate) mod r#additional_metadata;
+pub(crate) mod r#batch_record;
+pub(crate) mod r#class_policy;
 pub(crate) mod r#metadata;
+pub(crate) mod r#policy;
+pub(crate) mod r#record_service_event;
+pub(crate) mod r#schema_field;
+pub(crate) mod r#schema_field_type;
 
 pub use self::r#additional_metadata::*;
+pub use self::r#batch_record::*;
+pub use self::r#class_policy::*;
 pub use self::r#metadata::*;
+pub use self::r#policy::*;
+pub use self::r#record_service_event::*;
+pub use self::r#schema_field::*;
+pub use self::r#schema_field_type::*;
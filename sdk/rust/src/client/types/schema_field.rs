@@ -0,0 +1,21 @@
+//! This code was AUTOGENERATED using the codoma library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun codoma to update it.
+//!
+//! <https://github.com/trzledgerfoundation-idl/codoma>
+//!
+
+use crate::types::SchemaFieldType;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use kaigan::types::U8PrefixString;
+
+/// Field of a class schema, string and bytes fields are limited to maxLen bytes
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaField {
+    pub field_type: SchemaFieldType,
+    pub is_required: bool,
+    pub max_len: u16,
+    pub name: U8PrefixString,
+}
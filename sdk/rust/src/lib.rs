@@ -4,5 +4,8 @@
 //!
 //! <https://github.com/trzledgerfoundation-idl/codoma>
 //!
+pub mod attestation;
 pub mod client;
 pub use client::*;
+
+pub mod merkle;
@@ -0,0 +1,72 @@
+//! Merkle tree of the owners allowed to create records in a class.
+//!
+//! The root is stored in the class with `UpdateClassMerkleRoot` and the proof
+//! of an owner is passed to `CreateRecordWithProof`.
+
+use trezoa_program::{hash::hashv, pubkey::Pubkey};
+
+const LEAF_PREFIX: &[u8] = &[0x00];
+const NODE_PREFIX: &[u8] = &[0x01];
+
+/// Merkle tree of an allowlist of record owners
+pub struct AllowlistTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl AllowlistTree {
+    /// Build the tree of the given owners
+    pub fn new(owners: &[Pubkey]) -> Self {
+        let mut layers = vec![owners.iter().map(hash_leaf).collect::<Vec<_>>()];
+
+        while layers[layers.len() - 1].len() > 1 {
+            let layer = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_node(left, right),
+                    // The last node of an odd layer is moved up unchanged
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(layer);
+        }
+
+        Self { layers }
+    }
+
+    /// Root of the tree, zeroed if the allowlist is empty
+    pub fn root(&self) -> [u8; 32] {
+        self.layers[self.layers.len() - 1]
+            .first()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Sibling hashes from the leaf of `owner` to the root, if it is in the allowlist
+    pub fn proof(&self, owner: &Pubkey) -> Option<Vec<[u8; 32]>> {
+        let leaf = hash_leaf(owner);
+        let mut index = self.layers[0].iter().position(|node| *node == leaf)?;
+
+        let mut proof = Vec::with_capacity(self.layers.len() - 1);
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+
+        Some(proof)
+    }
+}
+
+fn hash_leaf(owner: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, owner.as_ref()]).to_bytes()
+}
+
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    if left <= right {
+        hashv(&[NODE_PREFIX, left, right]).to_bytes()
+    } else {
+        hashv(&[NODE_PREFIX, right, left]).to_bytes()
+    }
+}
@@ -22,12 +22,19 @@ import {
 import {
   Serializer,
   bool,
+  bytes,
   mapSerializer,
   publicKey as publicKeySerializer,
   string,
   struct,
+  u64,
   u8,
 } from '@trezoaplex-foundation/umi/serializers';
+import {
+  ClassPolicy,
+  ClassPolicyArgs,
+  getClassPolicySerializer,
+} from '../types';
 
 export type Class = Account<ClassAccountData>;
 
@@ -36,6 +43,17 @@ export type ClassAccountData = {
   authority: PublicKey;
   isPermissioned: boolean;
   isFrozen: boolean;
+  isNonTransferable: boolean;
+  policy: ClassPolicy;
+  recordCount: bigint;
+  tokenizedCount: bigint;
+  legacyRecordCount: bigint;
+  maxRecords: bigint;
+  creationFee: bigint;
+  treasury: PublicKey;
+  merkleRoot: Uint8Array;
+  groupBump: number;
+  schemaBump: number;
   name: string;
   metadata: string;
 };
@@ -44,6 +62,17 @@ export type ClassAccountDataArgs = {
   authority: PublicKey;
   isPermissioned: boolean;
   isFrozen: boolean;
+  isNonTransferable: boolean;
+  policy: ClassPolicyArgs;
+  recordCount: number | bigint;
+  tokenizedCount: number | bigint;
+  legacyRecordCount: number | bigint;
+  maxRecords: number | bigint;
+  creationFee: number | bigint;
+  treasury: PublicKey;
+  merkleRoot: Uint8Array;
+  groupBump: number;
+  schemaBump: number;
   name: string;
   metadata: string;
 };
@@ -59,12 +88,23 @@ export function getClassAccountDataSerializer(): Serializer<
         ['authority', publicKeySerializer()],
         ['isPermissioned', bool()],
         ['isFrozen', bool()],
+        ['isNonTransferable', bool()],
+        ['policy', getClassPolicySerializer()],
+        ['recordCount', u64()],
+        ['tokenizedCount', u64()],
+        ['legacyRecordCount', u64()],
+        ['maxRecords', u64()],
+        ['creationFee', u64()],
+        ['treasury', publicKeySerializer()],
+        ['merkleRoot', bytes({ size: 32 })],
+        ['groupBump', u8()],
+        ['schemaBump', u8()],
         ['name', string({ size: u8() })],
         ['metadata', string({ size: 'variable' })],
       ],
       { description: 'ClassAccountData' }
     ),
-    (value) => ({ ...value, discriminator: 1 })
+    (value) => ({ ...value, discriminator: 7 })
   ) as Serializer<ClassAccountDataArgs, ClassAccountData>;
 }
 
@@ -137,6 +177,17 @@ export function getClassGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
       authority: PublicKey;
       isPermissioned: boolean;
       isFrozen: boolean;
+      isNonTransferable: boolean;
+      policy: ClassPolicyArgs;
+      recordCount: number | bigint;
+      tokenizedCount: number | bigint;
+      legacyRecordCount: number | bigint;
+      maxRecords: number | bigint;
+      creationFee: number | bigint;
+      treasury: PublicKey;
+      merkleRoot: Uint8Array;
+      groupBump: number;
+      schemaBump: number;
       name: string;
       metadata: string;
     }>({
@@ -144,7 +195,18 @@ export function getClassGpaBuilder(context: Pick<Context, 'rpc' | 'programs'>) {
       authority: [1, publicKeySerializer()],
       isPermissioned: [33, bool()],
       isFrozen: [34, bool()],
-      name: [35, string({ size: u8() })],
+      isNonTransferable: [35, bool()],
+      policy: [36, getClassPolicySerializer()],
+      recordCount: [41, u64()],
+      tokenizedCount: [49, u64()],
+      legacyRecordCount: [57, u64()],
+      maxRecords: [65, u64()],
+      creationFee: [73, u64()],
+      treasury: [81, publicKeySerializer()],
+      merkleRoot: [113, bytes({ size: 32 })],
+      groupBump: [145, u8()],
+      schemaBump: [146, u8()],
+      name: [147, string({ size: u8() })],
       metadata: [null, string({ size: 'variable' })],
     })
     .deserializeUsing<Class>((account) => deserializeClass(account));
@@ -0,0 +1,145 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type ClassDelegate = Account<ClassDelegateAccountData>;
+
+export type ClassDelegateAccountData = {
+  discriminator: number;
+  class: PublicKey;
+  delegate: PublicKey;
+  permissions: number;
+};
+
+export type ClassDelegateAccountDataArgs = {
+  class: PublicKey;
+  delegate: PublicKey;
+  permissions: number;
+};
+
+export function getClassDelegateAccountDataSerializer(): Serializer<
+  ClassDelegateAccountDataArgs,
+  ClassDelegateAccountData
+> {
+  return mapSerializer<
+    ClassDelegateAccountDataArgs,
+    any,
+    ClassDelegateAccountData
+  >(
+    struct<ClassDelegateAccountData>(
+      [
+        ['discriminator', u8()],
+        ['class', publicKeySerializer()],
+        ['delegate', publicKeySerializer()],
+        ['permissions', u8()],
+      ],
+      { description: 'ClassDelegateAccountData' }
+    ),
+    (value) => ({ ...value, discriminator: 4 })
+  ) as Serializer<ClassDelegateAccountDataArgs, ClassDelegateAccountData>;
+}
+
+export function deserializeClassDelegate(rawAccount: RpcAccount): ClassDelegate {
+  return deserializeAccount(rawAccount, getClassDelegateAccountDataSerializer());
+}
+
+export async function fetchClassDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ClassDelegate> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ClassDelegate');
+  return deserializeClassDelegate(maybeAccount);
+}
+
+export async function safeFetchClassDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ClassDelegate | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeClassDelegate(maybeAccount) : null;
+}
+
+export async function fetchAllClassDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ClassDelegate[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ClassDelegate');
+    return deserializeClassDelegate(maybeAccount);
+  });
+}
+
+export async function safeFetchAllClassDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ClassDelegate[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeClassDelegate(maybeAccount as RpcAccount));
+}
+
+export function getClassDelegateGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: number;
+      class: PublicKey;
+      delegate: PublicKey;
+      permissions: number;
+    }>({
+      discriminator: [0, u8()],
+      class: [1, publicKeySerializer()],
+      delegate: [33, publicKeySerializer()],
+      permissions: [65, u8()],
+    })
+    .deserializeUsing<ClassDelegate>((account) => deserializeClassDelegate(account));
+}
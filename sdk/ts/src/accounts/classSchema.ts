@@ -0,0 +1,142 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  SchemaField,
+  SchemaFieldArgs,
+  getSchemaFieldSerializer,
+} from '../types';
+
+export type ClassSchema = Account<ClassSchemaAccountData>;
+
+export type ClassSchemaAccountData = {
+  discriminator: number;
+  class: PublicKey;
+  fields: Array<SchemaField>;
+};
+
+export type ClassSchemaAccountDataArgs = {
+  class: PublicKey;
+  fields: Array<SchemaFieldArgs>;
+};
+
+export function getClassSchemaAccountDataSerializer(): Serializer<
+  ClassSchemaAccountDataArgs,
+  ClassSchemaAccountData
+> {
+  return mapSerializer<ClassSchemaAccountDataArgs, any, ClassSchemaAccountData>(
+    struct<ClassSchemaAccountData>(
+      [
+        ['discriminator', u8()],
+        ['class', publicKeySerializer()],
+        ['fields', array(getSchemaFieldSerializer(), { size: u8() })],
+      ],
+      { description: 'ClassSchemaAccountData' }
+    ),
+    (value) => ({ ...value, discriminator: 6 })
+  ) as Serializer<ClassSchemaAccountDataArgs, ClassSchemaAccountData>;
+}
+
+export function deserializeClassSchema(rawAccount: RpcAccount): ClassSchema {
+  return deserializeAccount(rawAccount, getClassSchemaAccountDataSerializer());
+}
+
+export async function fetchClassSchema(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ClassSchema> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ClassSchema');
+  return deserializeClassSchema(maybeAccount);
+}
+
+export async function safeFetchClassSchema(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ClassSchema | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeClassSchema(maybeAccount) : null;
+}
+
+export async function fetchAllClassSchema(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ClassSchema[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ClassSchema');
+    return deserializeClassSchema(maybeAccount);
+  });
+}
+
+export async function safeFetchAllClassSchema(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ClassSchema[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeClassSchema(maybeAccount as RpcAccount));
+}
+
+export function getClassSchemaGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: number;
+      class: PublicKey;
+      fields: Array<SchemaFieldArgs>;
+    }>({
+      discriminator: [0, u8()],
+      class: [1, publicKeySerializer()],
+      fields: [33, array(getSchemaFieldSerializer(), { size: u8() })],
+    })
+    .deserializeUsing<ClassSchema>((account) => deserializeClassSchema(account));
+}
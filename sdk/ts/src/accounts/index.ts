@@ -7,4 +7,10 @@
  */
 
 export * from './class';
+export * from './classDelegate';
+export * from './classSchema';
+export * from './legacyClass';
+export * from './legacyRecord';
+export * from './pendingClassAuthority';
 export * from './record';
+export * from './recordDelegate';
@@ -0,0 +1,153 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type LegacyClass = Account<LegacyClassAccountData>;
+
+export type LegacyClassAccountData = {
+  discriminator: number;
+  authority: PublicKey;
+  isPermissioned: boolean;
+  isFrozen: boolean;
+  name: string;
+  metadata: string;
+};
+
+export type LegacyClassAccountDataArgs = {
+  authority: PublicKey;
+  isPermissioned: boolean;
+  isFrozen: boolean;
+  name: string;
+  metadata: string;
+};
+
+export function getLegacyClassAccountDataSerializer(): Serializer<
+  LegacyClassAccountDataArgs,
+  LegacyClassAccountData
+> {
+  return mapSerializer<LegacyClassAccountDataArgs, any, LegacyClassAccountData>(
+    struct<LegacyClassAccountData>(
+      [
+        ['discriminator', u8()],
+        ['authority', publicKeySerializer()],
+        ['isPermissioned', bool()],
+        ['isFrozen', bool()],
+        ['name', string({ size: u8() })],
+        ['metadata', string({ size: 'variable' })],
+      ],
+      { description: 'LegacyClassAccountData' }
+    ),
+    (value) => ({ ...value, discriminator: 1 })
+  ) as Serializer<LegacyClassAccountDataArgs, LegacyClassAccountData>;
+}
+
+export function deserializeLegacyClass(rawAccount: RpcAccount): LegacyClass {
+  return deserializeAccount(rawAccount, getLegacyClassAccountDataSerializer());
+}
+
+export async function fetchLegacyClass(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<LegacyClass> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'LegacyClass');
+  return deserializeLegacyClass(maybeAccount);
+}
+
+export async function safeFetchLegacyClass(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<LegacyClass | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeLegacyClass(maybeAccount) : null;
+}
+
+export async function fetchAllLegacyClass(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<LegacyClass[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'LegacyClass');
+    return deserializeLegacyClass(maybeAccount);
+  });
+}
+
+export async function safeFetchAllLegacyClass(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<LegacyClass[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeLegacyClass(maybeAccount as RpcAccount));
+}
+
+export function getLegacyClassGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: number;
+      authority: PublicKey;
+      isPermissioned: boolean;
+      isFrozen: boolean;
+      name: string;
+      metadata: string;
+    }>({
+      discriminator: [0, u8()],
+      authority: [1, publicKeySerializer()],
+      isPermissioned: [33, bool()],
+      isFrozen: [34, bool()],
+      name: [35, string({ size: u8() })],
+      metadata: [null, string({ size: 'variable' })],
+    })
+    .deserializeUsing<LegacyClass>((account) => deserializeLegacyClass(account));
+}
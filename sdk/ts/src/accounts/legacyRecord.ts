@@ -0,0 +1,167 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  bytes,
+  i64,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type LegacyRecord = Account<LegacyRecordAccountData>;
+
+export type LegacyRecordAccountData = {
+  discriminator: number;
+  class: PublicKey;
+  ownerType: number;
+  owner: PublicKey;
+  isFrozen: boolean;
+  expiry: bigint;
+  seed: Uint8Array;
+  data: Uint8Array;
+};
+
+export type LegacyRecordAccountDataArgs = {
+  class: PublicKey;
+  owner: PublicKey;
+  isFrozen: boolean;
+  expiry: number | bigint;
+  seed: Uint8Array;
+  data: Uint8Array;
+};
+
+export function getLegacyRecordAccountDataSerializer(): Serializer<
+  LegacyRecordAccountDataArgs,
+  LegacyRecordAccountData
+> {
+  return mapSerializer<
+    LegacyRecordAccountDataArgs,
+    any,
+    LegacyRecordAccountData
+  >(
+    struct<LegacyRecordAccountData>(
+      [
+        ['discriminator', u8()],
+        ['class', publicKeySerializer()],
+        ['ownerType', u8()],
+        ['owner', publicKeySerializer()],
+        ['isFrozen', bool()],
+        ['expiry', i64()],
+        ['seed', bytes({ size: u8() })],
+        ['data', bytes()],
+      ],
+      { description: 'LegacyRecordAccountData' }
+    ),
+    (value) => ({ ...value, discriminator: 2, ownerType: 0 })
+  ) as Serializer<LegacyRecordAccountDataArgs, LegacyRecordAccountData>;
+}
+
+export function deserializeLegacyRecord(rawAccount: RpcAccount): LegacyRecord {
+  return deserializeAccount(rawAccount, getLegacyRecordAccountDataSerializer());
+}
+
+export async function fetchLegacyRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<LegacyRecord> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'LegacyRecord');
+  return deserializeLegacyRecord(maybeAccount);
+}
+
+export async function safeFetchLegacyRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<LegacyRecord | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeLegacyRecord(maybeAccount) : null;
+}
+
+export async function fetchAllLegacyRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<LegacyRecord[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'LegacyRecord');
+    return deserializeLegacyRecord(maybeAccount);
+  });
+}
+
+export async function safeFetchAllLegacyRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<LegacyRecord[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeLegacyRecord(maybeAccount as RpcAccount));
+}
+
+export function getLegacyRecordGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: number;
+      class: PublicKey;
+      ownerType: number;
+      owner: PublicKey;
+      isFrozen: boolean;
+      expiry: number | bigint;
+      seed: Uint8Array;
+      data: Uint8Array;
+    }>({
+      discriminator: [0, u8()],
+      class: [1, publicKeySerializer()],
+      ownerType: [33, u8()],
+      owner: [34, publicKeySerializer()],
+      isFrozen: [66, bool()],
+      expiry: [67, i64()],
+      seed: [75, bytes({ size: u8() })],
+      data: [null, bytes()],
+    })
+    .deserializeUsing<LegacyRecord>((account) => deserializeLegacyRecord(account));
+}
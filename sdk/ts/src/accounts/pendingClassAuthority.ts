@@ -0,0 +1,148 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type PendingClassAuthority = Account<PendingClassAuthorityAccountData>;
+
+export type PendingClassAuthorityAccountData = {
+  discriminator: number;
+  class: PublicKey;
+  authority: PublicKey;
+  pendingAuthority: PublicKey;
+};
+
+export type PendingClassAuthorityAccountDataArgs = {
+  class: PublicKey;
+  authority: PublicKey;
+  pendingAuthority: PublicKey;
+};
+
+export function getPendingClassAuthorityAccountDataSerializer(): Serializer<
+  PendingClassAuthorityAccountDataArgs,
+  PendingClassAuthorityAccountData
+> {
+  return mapSerializer<
+    PendingClassAuthorityAccountDataArgs,
+    any,
+    PendingClassAuthorityAccountData
+  >(
+    struct<PendingClassAuthorityAccountData>(
+      [
+        ['discriminator', u8()],
+        ['class', publicKeySerializer()],
+        ['authority', publicKeySerializer()],
+        ['pendingAuthority', publicKeySerializer()],
+      ],
+      { description: 'PendingClassAuthorityAccountData' }
+    ),
+    (value) => ({ ...value, discriminator: 3 })
+  ) as Serializer<
+    PendingClassAuthorityAccountDataArgs,
+    PendingClassAuthorityAccountData
+  >;
+}
+
+export function deserializePendingClassAuthority(rawAccount: RpcAccount): PendingClassAuthority {
+  return deserializeAccount(rawAccount, getPendingClassAuthorityAccountDataSerializer());
+}
+
+export async function fetchPendingClassAuthority(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<PendingClassAuthority> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'PendingClassAuthority');
+  return deserializePendingClassAuthority(maybeAccount);
+}
+
+export async function safeFetchPendingClassAuthority(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<PendingClassAuthority | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializePendingClassAuthority(maybeAccount) : null;
+}
+
+export async function fetchAllPendingClassAuthority(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<PendingClassAuthority[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'PendingClassAuthority');
+    return deserializePendingClassAuthority(maybeAccount);
+  });
+}
+
+export async function safeFetchAllPendingClassAuthority(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<PendingClassAuthority[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializePendingClassAuthority(maybeAccount as RpcAccount));
+}
+
+export function getPendingClassAuthorityGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: number;
+      class: PublicKey;
+      authority: PublicKey;
+      pendingAuthority: PublicKey;
+    }>({
+      discriminator: [0, u8()],
+      class: [1, publicKeySerializer()],
+      authority: [33, publicKeySerializer()],
+      pendingAuthority: [65, publicKeySerializer()],
+    })
+    .deserializeUsing<PendingClassAuthority>((account) => deserializePendingClassAuthority(account));
+}
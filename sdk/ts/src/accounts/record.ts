@@ -27,6 +27,9 @@ import {
   mapSerializer,
   publicKey as publicKeySerializer,
   struct,
+  u16,
+  u32,
+  u64,
   u8,
 } from '@trezoaplex-foundation/umi/serializers';
 
@@ -39,6 +42,17 @@ export type RecordAccountData = {
   owner: PublicKey;
   isFrozen: boolean;
   expiry: bigint;
+  isRevoked: boolean;
+  revocationReason: number;
+  revokedAt: bigint;
+  version: bigint;
+  generation: number;
+  hash: Uint8Array;
+  writeState: number;
+  stagedLen: number;
+  stagedPayer: PublicKey;
+  contentType: number;
+  mintBump: number;
   seed: Uint8Array;
   data: Uint8Array;
 };
@@ -48,6 +62,17 @@ export type RecordAccountDataArgs = {
   owner: PublicKey;
   isFrozen: boolean;
   expiry: number | bigint;
+  isRevoked: boolean;
+  revocationReason: number;
+  revokedAt: number | bigint;
+  version: number | bigint;
+  generation: number;
+  hash: Uint8Array;
+  writeState: number;
+  stagedLen: number;
+  stagedPayer: PublicKey;
+  contentType: number;
+  mintBump: number;
   seed: Uint8Array;
   data: Uint8Array;
 };
@@ -65,12 +90,23 @@ export function getRecordAccountDataSerializer(): Serializer<
         ['owner', publicKeySerializer()],
         ['isFrozen', bool()],
         ['expiry', i64()],
+        ['isRevoked', bool()],
+        ['revocationReason', u16()],
+        ['revokedAt', i64()],
+        ['version', u64()],
+        ['generation', u32()],
+        ['hash', bytes({ size: 32 })],
+        ['writeState', u8()],
+        ['stagedLen', u32()],
+        ['stagedPayer', publicKeySerializer()],
+        ['contentType', u8()],
+        ['mintBump', u8()],
         ['seed', bytes({ size: u8() })],
         ['data', bytes()],
       ],
       { description: 'RecordAccountData' }
     ),
-    (value) => ({ ...value, discriminator: 2, ownerType: 0 })
+    (value) => ({ ...value, discriminator: 8, ownerType: 0 })
   ) as Serializer<RecordAccountDataArgs, RecordAccountData>;
 }
 
@@ -147,6 +183,17 @@ export function getRecordGpaBuilder(
       owner: PublicKey;
       isFrozen: boolean;
       expiry: number | bigint;
+      isRevoked: boolean;
+      revocationReason: number;
+      revokedAt: number | bigint;
+      version: number | bigint;
+      generation: number;
+      hash: Uint8Array;
+      writeState: number;
+      stagedLen: number;
+      stagedPayer: PublicKey;
+      contentType: number;
+      mintBump: number;
       seed: Uint8Array;
       data: Uint8Array;
     }>({
@@ -156,7 +203,18 @@ export function getRecordGpaBuilder(
       owner: [34, publicKeySerializer()],
       isFrozen: [66, bool()],
       expiry: [67, i64()],
-      seed: [75, bytes({ size: u8() })],
+      isRevoked: [75, bool()],
+      revocationReason: [76, u16()],
+      revokedAt: [78, i64()],
+      version: [86, u64()],
+      generation: [94, u32()],
+      hash: [98, bytes({ size: 32 })],
+      writeState: [130, u8()],
+      stagedLen: [131, u32()],
+      stagedPayer: [135, publicKeySerializer()],
+      contentType: [167, u8()],
+      mintBump: [168, u8()],
+      seed: [169, bytes({ size: u8() })],
       data: [null, bytes()],
     })
     .deserializeUsing<Record>((account) => deserializeRecord(account));
@@ -0,0 +1,162 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type RecordDelegate = Account<RecordDelegateAccountData>;
+
+export type RecordDelegateAccountData = {
+  discriminator: number;
+  record: PublicKey;
+  owner: PublicKey;
+  generation: number;
+  delegate: PublicKey;
+  permissions: number;
+  expiry: bigint;
+};
+
+export type RecordDelegateAccountDataArgs = {
+  record: PublicKey;
+  owner: PublicKey;
+  generation: number;
+  delegate: PublicKey;
+  permissions: number;
+  expiry: number | bigint;
+};
+
+export function getRecordDelegateAccountDataSerializer(): Serializer<
+  RecordDelegateAccountDataArgs,
+  RecordDelegateAccountData
+> {
+  return mapSerializer<
+    RecordDelegateAccountDataArgs,
+    any,
+    RecordDelegateAccountData
+  >(
+    struct<RecordDelegateAccountData>(
+      [
+        ['discriminator', u8()],
+        ['record', publicKeySerializer()],
+        ['owner', publicKeySerializer()],
+        ['generation', u32()],
+        ['delegate', publicKeySerializer()],
+        ['permissions', u8()],
+        ['expiry', i64()],
+      ],
+      { description: 'RecordDelegateAccountData' }
+    ),
+    (value) => ({ ...value, discriminator: 5 })
+  ) as Serializer<RecordDelegateAccountDataArgs, RecordDelegateAccountData>;
+}
+
+export function deserializeRecordDelegate(rawAccount: RpcAccount): RecordDelegate {
+  return deserializeAccount(rawAccount, getRecordDelegateAccountDataSerializer());
+}
+
+export async function fetchRecordDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RecordDelegate> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'RecordDelegate');
+  return deserializeRecordDelegate(maybeAccount);
+}
+
+export async function safeFetchRecordDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RecordDelegate | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeRecordDelegate(maybeAccount) : null;
+}
+
+export async function fetchAllRecordDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RecordDelegate[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'RecordDelegate');
+    return deserializeRecordDelegate(maybeAccount);
+  });
+}
+
+export async function safeFetchAllRecordDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RecordDelegate[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeRecordDelegate(maybeAccount as RpcAccount));
+}
+
+export function getRecordDelegateGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: number;
+      record: PublicKey;
+      owner: PublicKey;
+      generation: number;
+      delegate: PublicKey;
+      permissions: number;
+      expiry: number | bigint;
+    }>({
+      discriminator: [0, u8()],
+      record: [1, publicKeySerializer()],
+      owner: [33, publicKeySerializer()],
+      generation: [65, u32()],
+      delegate: [69, publicKeySerializer()],
+      permissions: [101, u8()],
+      expiry: [102, i64()],
+    })
+    .deserializeUsing<RecordDelegate>((account) => deserializeRecordDelegate(account));
+}
@@ -0,0 +1,46 @@
+import { createHash } from 'crypto';
+import { PublicKey, publicKeyBytes } from '@trezoaplex-foundation/umi';
+
+/**
+ * Message attesting the record to be created by `createRecordFromSignature`.
+ *
+ * The class authority signs it with its Ed25519 key and the signature is
+ * verified by an Ed25519 precompile instruction of the same transaction:
+ * class | seed length (u8) | seed | bump (u8) | owner | expiry (i64) |
+ * content type (u8) | sha256 of the data
+ */
+export function recordAttestation(
+  class_: PublicKey,
+  seed: Uint8Array,
+  bump: number,
+  owner: PublicKey,
+  expiry: number | bigint,
+  contentType: number,
+  data: Uint8Array
+): Uint8Array {
+  const expiryBytes = new Uint8Array(8);
+  new DataView(expiryBytes.buffer).setBigInt64(0, BigInt(expiry), true);
+
+  return concat([
+    publicKeyBytes(class_),
+    Uint8Array.of(seed.length),
+    seed,
+    Uint8Array.of(bump),
+    publicKeyBytes(owner),
+    expiryBytes,
+    Uint8Array.of(contentType),
+    new Uint8Array(createHash('sha256').update(data).digest()),
+  ]);
+}
+
+function concat(values: Uint8Array[]): Uint8Array {
+  const result = new Uint8Array(
+    values.reduce((length, value) => length + value.length, 0)
+  );
+  let offset = 0;
+  values.forEach((value) => {
+    result.set(value, offset);
+    offset += value.length;
+  });
+  return result;
+}
@@ -15,6 +15,624 @@ type ProgramErrorConstructor = new (
 const codeToErrorMap: Map<number, ProgramErrorConstructor> = new Map();
 const nameToErrorMap: Map<string, ProgramErrorConstructor> = new Map();
 
+/** InvalidAccountDiscriminator: The account discriminator does not match the expected account type */
+export class InvalidAccountDiscriminatorError extends ProgramError {
+  override readonly name: string = 'InvalidAccountDiscriminator';
+
+  readonly code: number = 0x0; // 0
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The account discriminator does not match the expected account type',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x0, InvalidAccountDiscriminatorError);
+nameToErrorMap.set(
+  'InvalidAccountDiscriminator',
+  InvalidAccountDiscriminatorError
+);
+
+/** InvalidAuthority: The authority is not the class authority */
+export class InvalidAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidAuthority';
+
+  readonly code: number = 0x1; // 1
+
+  constructor(program: Program, cause?: Error) {
+    super('The authority is not the class authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x1, InvalidAuthorityError);
+nameToErrorMap.set('InvalidAuthority', InvalidAuthorityError);
+
+/** ClassFrozen: The class is frozen */
+export class ClassFrozenError extends ProgramError {
+  override readonly name: string = 'ClassFrozen';
+
+  readonly code: number = 0x2; // 2
+
+  constructor(program: Program, cause?: Error) {
+    super('The class is frozen', program, cause);
+  }
+}
+codeToErrorMap.set(0x2, ClassFrozenError);
+nameToErrorMap.set('ClassFrozen', ClassFrozenError);
+
+/** ClassNotPermissioned: The class is not permissioned */
+export class ClassNotPermissionedError extends ProgramError {
+  override readonly name: string = 'ClassNotPermissioned';
+
+  readonly code: number = 0x3; // 3
+
+  constructor(program: Program, cause?: Error) {
+    super('The class is not permissioned', program, cause);
+  }
+}
+codeToErrorMap.set(0x3, ClassNotPermissionedError);
+nameToErrorMap.set('ClassNotPermissioned', ClassNotPermissionedError);
+
+/** RecordFrozen: The record is frozen */
+export class RecordFrozenError extends ProgramError {
+  override readonly name: string = 'RecordFrozen';
+
+  readonly code: number = 0x4; // 4
+
+  constructor(program: Program, cause?: Error) {
+    super('The record is frozen', program, cause);
+  }
+}
+codeToErrorMap.set(0x4, RecordFrozenError);
+nameToErrorMap.set('RecordFrozen', RecordFrozenError);
+
+/** ClassMismatch: The class does not match the class of the record */
+export class ClassMismatchError extends ProgramError {
+  override readonly name: string = 'ClassMismatch';
+
+  readonly code: number = 0x5; // 5
+
+  constructor(program: Program, cause?: Error) {
+    super('The class does not match the class of the record', program, cause);
+  }
+}
+codeToErrorMap.set(0x5, ClassMismatchError);
+nameToErrorMap.set('ClassMismatch', ClassMismatchError);
+
+/** NotOwnerOrDelegate: The authority is neither the record owner nor a valid delegate */
+export class NotOwnerOrDelegateError extends ProgramError {
+  override readonly name: string = 'NotOwnerOrDelegate';
+
+  readonly code: number = 0x6; // 6
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The authority is neither the record owner nor a valid delegate',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x6, NotOwnerOrDelegateError);
+nameToErrorMap.set('NotOwnerOrDelegate', NotOwnerOrDelegateError);
+
+/** InvalidOwner: The owner account does not match the owner of the record */
+export class InvalidOwnerError extends ProgramError {
+  override readonly name: string = 'InvalidOwner';
+
+  readonly code: number = 0x7; // 7
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The owner account does not match the owner of the record',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x7, InvalidOwnerError);
+nameToErrorMap.set('InvalidOwner', InvalidOwnerError);
+
+/** InvalidOwnerType: The record owner type does not allow this operation */
+export class InvalidOwnerTypeError extends ProgramError {
+  override readonly name: string = 'InvalidOwnerType';
+
+  readonly code: number = 0x8; // 8
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The record owner type does not allow this operation',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x8, InvalidOwnerTypeError);
+nameToErrorMap.set('InvalidOwnerType', InvalidOwnerTypeError);
+
+/** MissingClass: The class account is required but was not provided */
+export class MissingClassError extends ProgramError {
+  override readonly name: string = 'MissingClass';
+
+  readonly code: number = 0x9; // 9
+
+  constructor(program: Program, cause?: Error) {
+    super('The class account is required but was not provided', program, cause);
+  }
+}
+codeToErrorMap.set(0x9, MissingClassError);
+nameToErrorMap.set('MissingClass', MissingClassError);
+
+/** MissingMint: The mint account is required but was not provided */
+export class MissingMintError extends ProgramError {
+  override readonly name: string = 'MissingMint';
+
+  readonly code: number = 0xa; // 10
+
+  constructor(program: Program, cause?: Error) {
+    super('The mint account is required but was not provided', program, cause);
+  }
+}
+codeToErrorMap.set(0xa, MissingMintError);
+nameToErrorMap.set('MissingMint', MissingMintError);
+
+/** InvalidMint: The mint account is not the mint of the record */
+export class InvalidMintError extends ProgramError {
+  override readonly name: string = 'InvalidMint';
+
+  readonly code: number = 0xb; // 11
+
+  constructor(program: Program, cause?: Error) {
+    super('The mint account is not the mint of the record', program, cause);
+  }
+}
+codeToErrorMap.set(0xb, InvalidMintError);
+nameToErrorMap.set('InvalidMint', InvalidMintError);
+
+/** MintSupplyNotZero: The mint supply is not zero */
+export class MintSupplyNotZeroError extends ProgramError {
+  override readonly name: string = 'MintSupplyNotZero';
+
+  readonly code: number = 0xc; // 12
+
+  constructor(program: Program, cause?: Error) {
+    super('The mint supply is not zero', program, cause);
+  }
+}
+codeToErrorMap.set(0xc, MintSupplyNotZeroError);
+nameToErrorMap.set('MintSupplyNotZero', MintSupplyNotZeroError);
+
+/** InvalidTokenAccount: The token account is not valid for the record */
+export class InvalidTokenAccountError extends ProgramError {
+  override readonly name: string = 'InvalidTokenAccount';
+
+  readonly code: number = 0xd; // 13
+
+  constructor(program: Program, cause?: Error) {
+    super('The token account is not valid for the record', program, cause);
+  }
+}
+codeToErrorMap.set(0xd, InvalidTokenAccountError);
+nameToErrorMap.set('InvalidTokenAccount', InvalidTokenAccountError);
+
+/** InvalidGroup: The group account is not the group of the class */
+export class InvalidGroupError extends ProgramError {
+  override readonly name: string = 'InvalidGroup';
+
+  readonly code: number = 0xe; // 14
+
+  constructor(program: Program, cause?: Error) {
+    super('The group account is not the group of the class', program, cause);
+  }
+}
+codeToErrorMap.set(0xe, InvalidGroupError);
+nameToErrorMap.set('InvalidGroup', InvalidGroupError);
+
+/** InvalidPda: The program derived address could not be derived */
+export class InvalidPdaError extends ProgramError {
+  override readonly name: string = 'InvalidPda';
+
+  readonly code: number = 0xf; // 15
+
+  constructor(program: Program, cause?: Error) {
+    super('The program derived address could not be derived', program, cause);
+  }
+}
+codeToErrorMap.set(0xf, InvalidPdaError);
+nameToErrorMap.set('InvalidPda', InvalidPdaError);
+
+/** RecordExpired: The record is expired */
+export class RecordExpiredError extends ProgramError {
+  override readonly name: string = 'RecordExpired';
+
+  readonly code: number = 0x10; // 16
+
+  constructor(program: Program, cause?: Error) {
+    super('The record is expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x10, RecordExpiredError);
+nameToErrorMap.set('RecordExpired', RecordExpiredError);
+
+/** RecordNotExpired: The record is not expired */
+export class RecordNotExpiredError extends ProgramError {
+  override readonly name: string = 'RecordNotExpired';
+
+  readonly code: number = 0x11; // 17
+
+  constructor(program: Program, cause?: Error) {
+    super('The record is not expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x11, RecordNotExpiredError);
+nameToErrorMap.set('RecordNotExpired', RecordNotExpiredError);
+
+/** SeedTooLong: The seed exceeds the maximum length */
+export class SeedTooLongError extends ProgramError {
+  override readonly name: string = 'SeedTooLong';
+
+  readonly code: number = 0x12; // 18
+
+  constructor(program: Program, cause?: Error) {
+    super('The seed exceeds the maximum length', program, cause);
+  }
+}
+codeToErrorMap.set(0x12, SeedTooLongError);
+nameToErrorMap.set('SeedTooLong', SeedTooLongError);
+
+/** NameTooLong: The name exceeds the maximum length */
+export class NameTooLongError extends ProgramError {
+  override readonly name: string = 'NameTooLong';
+
+  readonly code: number = 0x13; // 19
+
+  constructor(program: Program, cause?: Error) {
+    super('The name exceeds the maximum length', program, cause);
+  }
+}
+codeToErrorMap.set(0x13, NameTooLongError);
+nameToErrorMap.set('NameTooLong', NameTooLongError);
+
+/** MetadataTooLong: The metadata exceeds the maximum length */
+export class MetadataTooLongError extends ProgramError {
+  override readonly name: string = 'MetadataTooLong';
+
+  readonly code: number = 0x14; // 20
+
+  constructor(program: Program, cause?: Error) {
+    super('The metadata exceeds the maximum length', program, cause);
+  }
+}
+codeToErrorMap.set(0x14, MetadataTooLongError);
+nameToErrorMap.set('MetadataTooLong', MetadataTooLongError);
+
+/** AccountTooSmall: The account is too small for the data it has to hold */
+export class AccountTooSmallError extends ProgramError {
+  override readonly name: string = 'AccountTooSmall';
+
+  readonly code: number = 0x15; // 21
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The account is too small for the data it has to hold',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x15, AccountTooSmallError);
+nameToErrorMap.set('AccountTooSmall', AccountTooSmallError);
+
+/** AccountTooLarge: The account would exceed the maximum account size */
+export class AccountTooLargeError extends ProgramError {
+  override readonly name: string = 'AccountTooLarge';
+
+  readonly code: number = 0x16; // 22
+
+  constructor(program: Program, cause?: Error) {
+    super('The account would exceed the maximum account size', program, cause);
+  }
+}
+codeToErrorMap.set(0x16, AccountTooLargeError);
+nameToErrorMap.set('AccountTooLarge', AccountTooLargeError);
+
+/** InvalidPendingAuthority: The signer is not the pending authority of the class */
+export class InvalidPendingAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidPendingAuthority';
+
+  readonly code: number = 0x17; // 23
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The signer is not the pending authority of the class',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17, InvalidPendingAuthorityError);
+nameToErrorMap.set('InvalidPendingAuthority', InvalidPendingAuthorityError);
+
+/** InvalidClassDelegate: The signer is not the delegate of the class delegate account */
+export class InvalidClassDelegateError extends ProgramError {
+  override readonly name: string = 'InvalidClassDelegate';
+
+  readonly code: number = 0x18; // 24
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The signer is not the delegate of the class delegate account',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x18, InvalidClassDelegateError);
+nameToErrorMap.set('InvalidClassDelegate', InvalidClassDelegateError);
+
+/** MissingPermission: The class delegate does not have the required permission */
+export class MissingPermissionError extends ProgramError {
+  override readonly name: string = 'MissingPermission';
+
+  readonly code: number = 0x19; // 25
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The class delegate does not have the required permission',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x19, MissingPermissionError);
+nameToErrorMap.set('MissingPermission', MissingPermissionError);
+
+/** InvalidRecordDelegate: The record delegate account does not match the record, its owner or the signer */
+export class InvalidRecordDelegateError extends ProgramError {
+  override readonly name: string = 'InvalidRecordDelegate';
+
+  readonly code: number = 0x1a; // 26
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The record delegate account does not match the record, its owner or the signer',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1a, InvalidRecordDelegateError);
+nameToErrorMap.set('InvalidRecordDelegate', InvalidRecordDelegateError);
+
+/** RecordDelegateExpired: The record delegate is expired */
+export class RecordDelegateExpiredError extends ProgramError {
+  override readonly name: string = 'RecordDelegateExpired';
+
+  readonly code: number = 0x1b; // 27
+
+  constructor(program: Program, cause?: Error) {
+    super('The record delegate is expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x1b, RecordDelegateExpiredError);
+nameToErrorMap.set('RecordDelegateExpired', RecordDelegateExpiredError);
+
+/** InvalidSchema: The schema account or its field definitions are invalid */
+export class InvalidSchemaError extends ProgramError {
+  override readonly name: string = 'InvalidSchema';
+
+  readonly code: number = 0x1c; // 28
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The schema account or its field definitions are invalid',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1c, InvalidSchemaError);
+nameToErrorMap.set('InvalidSchema', InvalidSchemaError);
+
+/** SchemaMismatch: The record data does not match the class schema */
+export class SchemaMismatchError extends ProgramError {
+  override readonly name: string = 'SchemaMismatch';
+
+  readonly code: number = 0x1d; // 29
+
+  constructor(program: Program, cause?: Error) {
+    super('The record data does not match the class schema', program, cause);
+  }
+}
+codeToErrorMap.set(0x1d, SchemaMismatchError);
+nameToErrorMap.set('SchemaMismatch', SchemaMismatchError);
+
+/** InvalidPolicy: The class policy is invalid */
+export class InvalidPolicyError extends ProgramError {
+  override readonly name: string = 'InvalidPolicy';
+
+  readonly code: number = 0x1e; // 30
+
+  constructor(program: Program, cause?: Error) {
+    super('The class policy is invalid', program, cause);
+  }
+}
+codeToErrorMap.set(0x1e, InvalidPolicyError);
+nameToErrorMap.set('InvalidPolicy', InvalidPolicyError);
+
+/** NonTransferable: The records of the class are non-transferable */
+export class NonTransferableError extends ProgramError {
+  override readonly name: string = 'NonTransferable';
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super('The records of the class are non-transferable', program, cause);
+  }
+}
+codeToErrorMap.set(0x1f, NonTransferableError);
+nameToErrorMap.set('NonTransferable', NonTransferableError);
+
+/** RecordRevoked: The record is revoked */
+export class RecordRevokedError extends ProgramError {
+  override readonly name: string = 'RecordRevoked';
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super('The record is revoked', program, cause);
+  }
+}
+codeToErrorMap.set(0x20, RecordRevokedError);
+nameToErrorMap.set('RecordRevoked', RecordRevokedError);
+
+/** RecordVersionMismatch: The record changed since the expected version */
+export class RecordVersionMismatchError extends ProgramError {
+  override readonly name: string = 'RecordVersionMismatch';
+
+  readonly code: number = 0x21; // 33
+
+  constructor(program: Program, cause?: Error) {
+    super('The record changed since the expected version', program, cause);
+  }
+}
+codeToErrorMap.set(0x21, RecordVersionMismatchError);
+nameToErrorMap.set('RecordVersionMismatch', RecordVersionMismatchError);
+
+/** RecordWriting: The record data is being written */
+export class RecordWritingError extends ProgramError {
+  override readonly name: string = 'RecordWriting';
+
+  readonly code: number = 0x22; // 34
+
+  constructor(program: Program, cause?: Error) {
+    super('The record data is being written', program, cause);
+  }
+}
+codeToErrorMap.set(0x22, RecordWritingError);
+nameToErrorMap.set('RecordWriting', RecordWritingError);
+
+/** RecordNotWriting: The record data is not being written */
+export class RecordNotWritingError extends ProgramError {
+  override readonly name: string = 'RecordNotWriting';
+
+  readonly code: number = 0x23; // 35
+
+  constructor(program: Program, cause?: Error) {
+    super('The record data is not being written', program, cause);
+  }
+}
+codeToErrorMap.set(0x23, RecordNotWritingError);
+nameToErrorMap.set('RecordNotWriting', RecordNotWritingError);
+
+/** MaxRecordsReached: The class reached its maximum number of records */
+export class MaxRecordsReachedError extends ProgramError {
+  override readonly name: string = 'MaxRecordsReached';
+
+  readonly code: number = 0x24; // 36
+
+  constructor(program: Program, cause?: Error) {
+    super('The class reached its maximum number of records', program, cause);
+  }
+}
+codeToErrorMap.set(0x24, MaxRecordsReachedError);
+nameToErrorMap.set('MaxRecordsReached', MaxRecordsReachedError);
+
+/** InvalidTreasury: The treasury account does not match the class treasury */
+export class InvalidTreasuryError extends ProgramError {
+  override readonly name: string = 'InvalidTreasury';
+
+  readonly code: number = 0x25; // 37
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The treasury account does not match the class treasury',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x25, InvalidTreasuryError);
+nameToErrorMap.set('InvalidTreasury', InvalidTreasuryError);
+
+/** NotInAllowlist: The record owner is not in the class allowlist */
+export class NotInAllowlistError extends ProgramError {
+  override readonly name: string = 'NotInAllowlist';
+
+  readonly code: number = 0x26; // 38
+
+  constructor(program: Program, cause?: Error) {
+    super('The record owner is not in the class allowlist', program, cause);
+  }
+}
+codeToErrorMap.set(0x26, NotInAllowlistError);
+nameToErrorMap.set('NotInAllowlist', NotInAllowlistError);
+
+/** InvalidSignature: The signature of the class authority is missing or does not match the record */
+export class InvalidSignatureError extends ProgramError {
+  override readonly name: string = 'InvalidSignature';
+
+  readonly code: number = 0x27; // 39
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The signature of the class authority is missing or does not match the record',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x27, InvalidSignatureError);
+nameToErrorMap.set('InvalidSignature', InvalidSignatureError);
+
+/** ClassNotEmpty: The class still has live records */
+export class ClassNotEmptyError extends ProgramError {
+  override readonly name: string = 'ClassNotEmpty';
+
+  readonly code: number = 0x28; // 40
+
+  constructor(program: Program, cause?: Error) {
+    super('The class still has live records', program, cause);
+  }
+}
+codeToErrorMap.set(0x28, ClassNotEmptyError);
+nameToErrorMap.set('ClassNotEmpty', ClassNotEmptyError);
+
+/** RecordNotDeleted: The record account is not the tombstone of a deleted record */
+export class RecordNotDeletedError extends ProgramError {
+  override readonly name: string = 'RecordNotDeleted';
+
+  readonly code: number = 0x29; // 41
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The record account is not the tombstone of a deleted record',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x29, RecordNotDeletedError);
+nameToErrorMap.set('RecordNotDeleted', RecordNotDeletedError);
+
+/** InvalidPayer: The payer account is not the payer of the buffered record */
+export class InvalidPayerError extends ProgramError {
+  override readonly name: string = 'InvalidPayer';
+
+  readonly code: number = 0x2a; // 42
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The payer account is not the payer of the buffered record',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x2a, InvalidPayerError);
+nameToErrorMap.set('InvalidPayer', InvalidPayerError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
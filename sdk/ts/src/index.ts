@@ -7,8 +7,10 @@
  */
 
 export * from './accounts';
+export * from './attestation';
 export * from './errors';
 export * from './instructions';
+export * from './merkle';
 export * from './programs';
 export * from './shared';
 export * from './types';
@@ -0,0 +1,121 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AcceptClassAuthorityInstructionAccounts = {
+  /** Proposed authority of the class */
+  newAuthority: Signer;
+  /** Current authority of the class that will get refunded for the pending authority account */
+  authority: PublicKey | Pda;
+  /** Class account whose authority is being transferred */
+  class: PublicKey | Pda;
+  /** Pending authority account of the class */
+  pendingClassAuthority: PublicKey | Pda;
+};
+
+// Data.
+export type AcceptClassAuthorityInstructionData = { discriminator: number };
+
+export type AcceptClassAuthorityInstructionDataArgs = {};
+
+export function getAcceptClassAuthorityInstructionDataSerializer(): Serializer<
+  AcceptClassAuthorityInstructionDataArgs,
+  AcceptClassAuthorityInstructionData
+> {
+  return mapSerializer<
+    AcceptClassAuthorityInstructionDataArgs,
+    any,
+    AcceptClassAuthorityInstructionData
+  >(
+    struct<AcceptClassAuthorityInstructionData>([['discriminator', u8()]], {
+      description: 'AcceptClassAuthorityInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 16 })
+  ) as Serializer<
+    AcceptClassAuthorityInstructionDataArgs,
+    AcceptClassAuthorityInstructionData
+  >;
+}
+
+// Instruction.
+export function acceptClassAuthority(
+  context: Pick<Context, 'programs'>,
+  input: AcceptClassAuthorityInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    newAuthority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.newAuthority ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    class: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+    pendingClassAuthority: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.pendingClassAuthority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAcceptClassAuthorityInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,162 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddClassDelegateInstructionAccounts = {
+  /** Authority of the class */
+  authority: Signer;
+  /** Account that will pay for the class delegate account */
+  payer: Signer;
+  /** Class account the delegate acts on */
+  class: PublicKey | Pda;
+  /** Class delegate account of the delegate */
+  classDelegate: PublicKey | Pda;
+  /** System Program used to create the class delegate account */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddClassDelegateInstructionData = {
+  discriminator: number;
+  delegate: PublicKey;
+  permissions: number;
+  bump: number;
+};
+
+export type AddClassDelegateInstructionDataArgs = {
+  delegate: PublicKey;
+  permissions: number;
+  bump: number;
+};
+
+export function getAddClassDelegateInstructionDataSerializer(): Serializer<
+  AddClassDelegateInstructionDataArgs,
+  AddClassDelegateInstructionData
+> {
+  return mapSerializer<
+    AddClassDelegateInstructionDataArgs,
+    any,
+    AddClassDelegateInstructionData
+  >(
+    struct<AddClassDelegateInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['delegate', publicKeySerializer()],
+        ['permissions', u8()],
+        ['bump', u8()],
+      ],
+      { description: 'AddClassDelegateInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 18 })
+  ) as Serializer<
+    AddClassDelegateInstructionDataArgs,
+    AddClassDelegateInstructionData
+  >;
+}
+
+// Args.
+export type AddClassDelegateInstructionArgs =
+  AddClassDelegateInstructionDataArgs;
+
+// Instruction.
+export function addClassDelegate(
+  context: Pick<Context, 'programs'>,
+  input: AddClassDelegateInstructionAccounts & AddClassDelegateInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    class: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.class ?? null,
+    },
+    classDelegate: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.classDelegate ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddClassDelegateInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddClassDelegateInstructionDataSerializer().serialize(
+    resolvedArgs as AddClassDelegateInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
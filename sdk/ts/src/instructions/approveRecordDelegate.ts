@@ -0,0 +1,167 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  i64,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ApproveRecordDelegateInstructionAccounts = {
+  /** Owner of the record */
+  owner: Signer;
+  /** Account that will pay for the record delegate account */
+  payer: Signer;
+  /** Record account the delegate acts on */
+  record: PublicKey | Pda;
+  /** Record delegate account of the record */
+  recordDelegate: PublicKey | Pda;
+  /** System Program used to create the record delegate account */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ApproveRecordDelegateInstructionData = {
+  discriminator: number;
+  delegate: PublicKey;
+  permissions: number;
+  expiry: bigint;
+  bump: number;
+};
+
+export type ApproveRecordDelegateInstructionDataArgs = {
+  delegate: PublicKey;
+  permissions: number;
+  expiry: number | bigint;
+  bump: number;
+};
+
+export function getApproveRecordDelegateInstructionDataSerializer(): Serializer<
+  ApproveRecordDelegateInstructionDataArgs,
+  ApproveRecordDelegateInstructionData
+> {
+  return mapSerializer<
+    ApproveRecordDelegateInstructionDataArgs,
+    any,
+    ApproveRecordDelegateInstructionData
+  >(
+    struct<ApproveRecordDelegateInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['delegate', publicKeySerializer()],
+        ['permissions', u8()],
+        ['expiry', i64()],
+        ['bump', u8()],
+      ],
+      { description: 'ApproveRecordDelegateInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 20 })
+  ) as Serializer<
+    ApproveRecordDelegateInstructionDataArgs,
+    ApproveRecordDelegateInstructionData
+  >;
+}
+
+// Args.
+export type ApproveRecordDelegateInstructionArgs =
+  ApproveRecordDelegateInstructionDataArgs;
+
+// Instruction.
+export function approveRecordDelegate(
+  context: Pick<Context, 'programs'>,
+  input: ApproveRecordDelegateInstructionAccounts &
+    ApproveRecordDelegateInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    owner: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    record: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.record ?? null,
+    },
+    recordDelegate: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.recordDelegate ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ApproveRecordDelegateInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getApproveRecordDelegateInstructionDataSerializer().serialize(
+    resolvedArgs as ApproveRecordDelegateInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
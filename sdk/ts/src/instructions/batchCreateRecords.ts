@@ -0,0 +1,176 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  BatchRecord,
+  BatchRecordArgs,
+  getBatchRecordSerializer,
+} from '../types';
+
+// Accounts.
+export type BatchCreateRecordsInstructionAccounts = {
+  /** Account that will pay for the record accounts */
+  payer: Signer;
+  /** Class account for the records to be created */
+  class: PublicKey | Pda;
+  /** System Program used to create our record accounts */
+  systemProgram?: PublicKey | Pda;
+  /** Optional authority for permissioned classes */
+  authority?: Signer;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Schema account of the class, required if the class has a schema */
+  schema?: PublicKey | Pda;
+  /** Treasury account of the class, required if the class charges a creation fee */
+  treasury?: PublicKey | Pda;
+};
+
+// Data.
+export type BatchCreateRecordsInstructionData = {
+  discriminator: number;
+  records: Array<BatchRecord>;
+};
+
+export type BatchCreateRecordsInstructionDataArgs = {
+  records: Array<BatchRecordArgs>;
+};
+
+export function getBatchCreateRecordsInstructionDataSerializer(): Serializer<
+  BatchCreateRecordsInstructionDataArgs,
+  BatchCreateRecordsInstructionData
+> {
+  return mapSerializer<
+    BatchCreateRecordsInstructionDataArgs,
+    any,
+    BatchCreateRecordsInstructionData
+  >(
+    struct<BatchCreateRecordsInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['records', array(getBatchRecordSerializer(), { size: u8() })],
+      ],
+      { description: 'BatchCreateRecordsInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 36 })
+  ) as Serializer<
+    BatchCreateRecordsInstructionDataArgs,
+    BatchCreateRecordsInstructionData
+  >;
+}
+
+// Args.
+export type BatchCreateRecordsInstructionArgs =
+  BatchCreateRecordsInstructionDataArgs;
+
+// Instruction.
+export function batchCreateRecords(
+  context: Pick<Context, 'programs'>,
+  input: BatchCreateRecordsInstructionAccounts &
+    BatchCreateRecordsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    payer: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    class: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+    systemProgram: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    classDelegate: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    schema: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.schema ?? null,
+    },
+    treasury: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: BatchCreateRecordsInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBatchCreateRecordsInstructionDataSerializer().serialize(
+    resolvedArgs as BatchCreateRecordsInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,121 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BatchDeleteRecordsInstructionAccounts = {
+  /** Class authority or a class delegate with the delete permission */
+  authority: Signer;
+  /** Account that will get refunded for the record accounts */
+  payer: PublicKey | Pda;
+  /** Class account of the records */
+  class: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+};
+
+// Data.
+export type BatchDeleteRecordsInstructionData = { discriminator: number };
+
+export type BatchDeleteRecordsInstructionDataArgs = {};
+
+export function getBatchDeleteRecordsInstructionDataSerializer(): Serializer<
+  BatchDeleteRecordsInstructionDataArgs,
+  BatchDeleteRecordsInstructionData
+> {
+  return mapSerializer<
+    BatchDeleteRecordsInstructionDataArgs,
+    any,
+    BatchDeleteRecordsInstructionData
+  >(
+    struct<BatchDeleteRecordsInstructionData>([['discriminator', u8()]], {
+      description: 'BatchDeleteRecordsInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 38 })
+  ) as Serializer<
+    BatchDeleteRecordsInstructionDataArgs,
+    BatchDeleteRecordsInstructionData
+  >;
+}
+
+// Instruction.
+export function batchDeleteRecords(
+  context: Pick<Context, 'programs'>,
+  input: BatchDeleteRecordsInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    class: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+    classDelegate: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBatchDeleteRecordsInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
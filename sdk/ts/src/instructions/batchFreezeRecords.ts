@@ -0,0 +1,132 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BatchFreezeRecordsInstructionAccounts = {
+  /** Class authority or a class delegate with the freeze permission */
+  authority: Signer;
+  /** Class account of the records */
+  class: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+};
+
+// Data.
+export type BatchFreezeRecordsInstructionData = {
+  discriminator: number;
+  isFrozen: boolean;
+};
+
+export type BatchFreezeRecordsInstructionDataArgs = { isFrozen: boolean };
+
+export function getBatchFreezeRecordsInstructionDataSerializer(): Serializer<
+  BatchFreezeRecordsInstructionDataArgs,
+  BatchFreezeRecordsInstructionData
+> {
+  return mapSerializer<
+    BatchFreezeRecordsInstructionDataArgs,
+    any,
+    BatchFreezeRecordsInstructionData
+  >(
+    struct<BatchFreezeRecordsInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['isFrozen', bool()],
+      ],
+      { description: 'BatchFreezeRecordsInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 37 })
+  ) as Serializer<
+    BatchFreezeRecordsInstructionDataArgs,
+    BatchFreezeRecordsInstructionData
+  >;
+}
+
+// Args.
+export type BatchFreezeRecordsInstructionArgs =
+  BatchFreezeRecordsInstructionDataArgs;
+
+// Instruction.
+export function batchFreezeRecords(
+  context: Pick<Context, 'programs'>,
+  input: BatchFreezeRecordsInstructionAccounts &
+    BatchFreezeRecordsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    class: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.class ?? null,
+    },
+    classDelegate: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: BatchFreezeRecordsInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBatchFreezeRecordsInstructionDataSerializer().serialize(
+    resolvedArgs as BatchFreezeRecordsInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
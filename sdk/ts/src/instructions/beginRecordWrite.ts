@@ -0,0 +1,151 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type BeginRecordWriteInstructionAccounts = {
+  /** Record owner or class authority for permissioned classes */
+  authority: Signer;
+  /** Account that will pay of get refunded for the record update */
+  payer: Signer;
+  /** Record account being written */
+  record: PublicKey | Pda;
+  /** Class account of the record */
+  class: PublicKey | Pda;
+  /** System Program used to resize our record account */
+  systemProgram?: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Optional record delegate account of the authority */
+  recordDelegate?: PublicKey | Pda;
+};
+
+// Data.
+export type BeginRecordWriteInstructionData = { discriminator: number };
+
+export type BeginRecordWriteInstructionDataArgs = {};
+
+export function getBeginRecordWriteInstructionDataSerializer(): Serializer<
+  BeginRecordWriteInstructionDataArgs,
+  BeginRecordWriteInstructionData
+> {
+  return mapSerializer<
+    BeginRecordWriteInstructionDataArgs,
+    any,
+    BeginRecordWriteInstructionData
+  >(
+    struct<BeginRecordWriteInstructionData>([['discriminator', u8()]], {
+      description: 'BeginRecordWriteInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 29 })
+  ) as Serializer<
+    BeginRecordWriteInstructionDataArgs,
+    BeginRecordWriteInstructionData
+  >;
+}
+
+// Instruction.
+export function beginRecordWrite(
+  context: Pick<Context, 'programs'>,
+  input: BeginRecordWriteInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    record: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.record ?? null,
+    },
+    class: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.class ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    classDelegate: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    recordDelegate: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.recordDelegate ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBeginRecordWriteInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -41,7 +41,9 @@ export type BurnTokenizedRecordInstructionAccounts = {
   /** Token2022 Program used to burn the tokenized record */
   token2022?: PublicKey | Pda;
   /** Class account of the record */
-  class?: PublicKey | Pda;
+  class: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
 };
 
 // Data.
@@ -109,9 +111,14 @@ export function burnTokenizedRecord(
     },
     class: {
       index: 6,
-      isWritable: false as boolean,
+      isWritable: true as boolean,
       value: input.class ?? null,
     },
+    classDelegate: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -0,0 +1,117 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CancelClassAuthorityTransferInstructionAccounts = {
+  /** Current authority of the class that will get refunded for the pending authority account */
+  authority: Signer;
+  /** Class account with a pending authority transfer */
+  class: PublicKey | Pda;
+  /** Pending authority account of the class */
+  pendingClassAuthority: PublicKey | Pda;
+};
+
+// Data.
+export type CancelClassAuthorityTransferInstructionData = {
+  discriminator: number;
+};
+
+export type CancelClassAuthorityTransferInstructionDataArgs = {};
+
+export function getCancelClassAuthorityTransferInstructionDataSerializer(): Serializer<
+  CancelClassAuthorityTransferInstructionDataArgs,
+  CancelClassAuthorityTransferInstructionData
+> {
+  return mapSerializer<
+    CancelClassAuthorityTransferInstructionDataArgs,
+    any,
+    CancelClassAuthorityTransferInstructionData
+  >(
+    struct<CancelClassAuthorityTransferInstructionData>([['discriminator', u8()]], {
+      description: 'CancelClassAuthorityTransferInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 17 })
+  ) as Serializer<
+    CancelClassAuthorityTransferInstructionDataArgs,
+    CancelClassAuthorityTransferInstructionData
+  >;
+}
+
+// Instruction.
+export function cancelClassAuthorityTransfer(
+  context: Pick<Context, 'programs'>,
+  input: CancelClassAuthorityTransferInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    class: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.class ?? null,
+    },
+    pendingClassAuthority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.pendingClassAuthority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getCancelClassAuthorityTransferInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,134 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseClassInstructionAccounts = {
+  /** Authority of the class */
+  authority: Signer;
+  /** Account that will get refunded for the class and group accounts */
+  destination: PublicKey | Pda;
+  /** Class account to be closed */
+  class: PublicKey | Pda;
+  /** Group mint account of the class */
+  group: PublicKey | Pda;
+  /** Token2022 Program used to close the group mint */
+  token2022?: PublicKey | Pda;
+};
+
+// Data.
+export type CloseClassInstructionData = { discriminator: number };
+
+export type CloseClassInstructionDataArgs = {};
+
+export function getCloseClassInstructionDataSerializer(): Serializer<
+  CloseClassInstructionDataArgs,
+  CloseClassInstructionData
+> {
+  return mapSerializer<
+    CloseClassInstructionDataArgs,
+    any,
+    CloseClassInstructionData
+  >(
+    struct<CloseClassInstructionData>([['discriminator', u8()]], {
+      description: 'CloseClassInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 39 })
+  ) as Serializer<CloseClassInstructionDataArgs, CloseClassInstructionData>;
+}
+
+// Instruction.
+export function closeClass(
+  context: Pick<Context, 'programs'>,
+  input: CloseClassInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    destination: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+    class: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+    group: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.group ?? null,
+    },
+    token2022: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.token2022 ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.token2022.value) {
+    resolvedAccounts.token2022.value = context.programs.getPublicKey(
+      'token2022',
+      'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'
+    );
+    resolvedAccounts.token2022.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseClassInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
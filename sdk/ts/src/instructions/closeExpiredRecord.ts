@@ -0,0 +1,113 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseExpiredRecordInstructionAccounts = {
+  /** The expired record account to be closed */
+  record: PublicKey | Pda;
+  /** The owner of the record that will get refunded */
+  owner: PublicKey | Pda;
+  /** Class account of the record */
+  class: PublicKey | Pda;
+};
+
+// Data.
+export type CloseExpiredRecordInstructionData = { discriminator: number };
+
+export type CloseExpiredRecordInstructionDataArgs = {};
+
+export function getCloseExpiredRecordInstructionDataSerializer(): Serializer<
+  CloseExpiredRecordInstructionDataArgs,
+  CloseExpiredRecordInstructionData
+> {
+  return mapSerializer<
+    CloseExpiredRecordInstructionDataArgs,
+    any,
+    CloseExpiredRecordInstructionData
+  >(
+    struct<CloseExpiredRecordInstructionData>([['discriminator', u8()]], {
+      description: 'CloseExpiredRecordInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 14 })
+  ) as Serializer<
+    CloseExpiredRecordInstructionDataArgs,
+    CloseExpiredRecordInstructionData
+  >;
+}
+
+// Instruction.
+export function closeExpiredRecord(
+  context: Pick<Context, 'programs'>,
+  input: CloseExpiredRecordInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    record: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.record ?? null,
+    },
+    owner: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.owner ?? null,
+    },
+    class: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseExpiredRecordInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,182 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CompareAndSwapRecordDataInstructionAccounts = {
+  /** Record owner or class authority for permissioned classes */
+  authority: Signer;
+  /** Account that will pay of get refunded for the record update */
+  payer: Signer;
+  /** Record account to be updated */
+  record: PublicKey | Pda;
+  /** Class account of the record */
+  class: PublicKey | Pda;
+  /** System Program used to extend our record account */
+  systemProgram?: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Optional record delegate account of the authority */
+  recordDelegate?: PublicKey | Pda;
+  /** Schema account of the class, required if the class has a schema */
+  schema?: PublicKey | Pda;
+};
+
+// Data.
+export type CompareAndSwapRecordDataInstructionData = {
+  discriminator: number;
+  expectedVersion: bigint;
+  data: Uint8Array;
+};
+
+export type CompareAndSwapRecordDataInstructionDataArgs = {
+  expectedVersion: number | bigint;
+  data: Uint8Array;
+};
+
+export function getCompareAndSwapRecordDataInstructionDataSerializer(): Serializer<
+  CompareAndSwapRecordDataInstructionDataArgs,
+  CompareAndSwapRecordDataInstructionData
+> {
+  return mapSerializer<
+    CompareAndSwapRecordDataInstructionDataArgs,
+    any,
+    CompareAndSwapRecordDataInstructionData
+  >(
+    struct<CompareAndSwapRecordDataInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['expectedVersion', u64()],
+        ['data', bytes()],
+      ],
+      { description: 'CompareAndSwapRecordDataInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 25 })
+  ) as Serializer<
+    CompareAndSwapRecordDataInstructionDataArgs,
+    CompareAndSwapRecordDataInstructionData
+  >;
+}
+
+// Args.
+export type CompareAndSwapRecordDataInstructionArgs =
+  CompareAndSwapRecordDataInstructionDataArgs;
+
+// Instruction.
+export function compareAndSwapRecordData(
+  context: Pick<Context, 'programs'>,
+  input: CompareAndSwapRecordDataInstructionAccounts &
+    CompareAndSwapRecordDataInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    record: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.record ?? null,
+    },
+    class: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.class ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    classDelegate: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    recordDelegate: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.recordDelegate ?? null,
+    },
+    schema: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.schema ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CompareAndSwapRecordDataInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCompareAndSwapRecordDataInstructionDataSerializer().serialize(
+    resolvedArgs as CompareAndSwapRecordDataInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -20,6 +20,7 @@ import {
   mapSerializer,
   string,
   struct,
+  u64,
   u8,
 } from '@trezoaplex-foundation/umi/serializers';
 import {
@@ -45,6 +46,9 @@ export type CreateClassInstructionData = {
   discriminator: number;
   isPermissioned: boolean;
   isFrozen: boolean;
+  isNonTransferable: boolean;
+  maxRecords: bigint;
+  bump: number;
   name: string;
   metadata: string;
 };
@@ -52,6 +56,9 @@ export type CreateClassInstructionData = {
 export type CreateClassInstructionDataArgs = {
   isPermissioned: boolean;
   isFrozen: boolean;
+  isNonTransferable: boolean;
+  maxRecords: number | bigint;
+  bump: number;
   name: string;
   metadata: string;
 };
@@ -70,6 +77,9 @@ export function getCreateClassInstructionDataSerializer(): Serializer<
         ['discriminator', u8()],
         ['isPermissioned', bool()],
         ['isFrozen', bool()],
+        ['isNonTransferable', bool()],
+        ['maxRecords', u64()],
+        ['bump', u8()],
         ['name', string({ size: u8() })],
         ['metadata', string({ size: 'variable' })],
       ],
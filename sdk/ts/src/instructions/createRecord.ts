@@ -42,18 +42,28 @@ export type CreateRecordInstructionAccounts = {
   systemProgram?: PublicKey | Pda;
   /** Optional authority for permissioned classes */
   authority?: Signer;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Schema account of the class, required if the class has a schema */
+  schema?: PublicKey | Pda;
+  /** Treasury account of the class, required if the class charges a creation fee */
+  treasury?: PublicKey | Pda;
 };
 
 // Data.
 export type CreateRecordInstructionData = {
   discriminator: number;
   expiration: bigint;
+  contentType: number;
+  bump: number;
   seed: Uint8Array;
   data: Uint8Array;
 };
 
 export type CreateRecordInstructionDataArgs = {
   expiration: number | bigint;
+  contentType: number;
+  bump: number;
   seed: Uint8Array;
   data: Uint8Array;
 };
@@ -71,6 +81,8 @@ export function getCreateRecordInstructionDataSerializer(): Serializer<
       [
         ['discriminator', u8()],
         ['expiration', i64()],
+        ['contentType', u8()],
+        ['bump', u8()],
         ['seed', bytes({ size: u8() })],
         ['data', bytes()],
       ],
@@ -126,6 +138,21 @@ export function createRecord(
       isWritable: false as boolean,
       value: input.authority ?? null,
     },
+    classDelegate: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    schema: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.schema ?? null,
+    },
+    treasury: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
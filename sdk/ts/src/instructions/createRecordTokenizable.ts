@@ -43,18 +43,28 @@ export type CreateRecordTokenizableInstructionAccounts = {
   systemProgram?: PublicKey | Pda;
   /** Optional authority for permissioned classes */
   authority?: Signer;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Schema account of the class, required if the class has a schema */
+  schema?: PublicKey | Pda;
+  /** Treasury account of the class, required if the class charges a creation fee */
+  treasury?: PublicKey | Pda;
 };
 
 // Data.
 export type CreateRecordTokenizableInstructionData = {
   discriminator: number;
   expiration: bigint;
+  contentType: number;
+  bump: number;
   seed: Uint8Array;
   metadata: Metadata;
 };
 
 export type CreateRecordTokenizableInstructionDataArgs = {
   expiration: number | bigint;
+  contentType: number;
+  bump: number;
   seed: Uint8Array;
   metadata: MetadataArgs;
 };
@@ -72,6 +82,8 @@ export function getCreateRecordTokenizableInstructionDataSerializer(): Serialize
       [
         ['discriminator', u8()],
         ['expiration', i64()],
+        ['contentType', u8()],
+        ['bump', u8()],
         ['seed', bytes({ size: u8() })],
         ['metadata', getMetadataSerializer()],
       ],
@@ -132,6 +144,21 @@ export function createRecordTokenizable(
       isWritable: false as boolean,
       value: input.authority ?? null,
     },
+    classDelegate: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    schema: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.schema ?? null,
+    },
+    treasury: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -0,0 +1,202 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  i64,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CreateRecordWithProofInstructionAccounts = {
+  /** Owner of the new record, in the class allowlist */
+  owner: Signer;
+  /** Account that will pay for the record account */
+  payer: Signer;
+  /** Class account for the record to be created */
+  class: PublicKey | Pda;
+  /** Record account to be created */
+  record: PublicKey | Pda;
+  /** System Program used to create our record account */
+  systemProgram?: PublicKey | Pda;
+  /** Unused authority for permissioned classes */
+  authority?: Signer;
+  /** Unused class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Schema account of the class, required if the class has a schema */
+  schema?: PublicKey | Pda;
+  /** Treasury account of the class, required if the class charges a creation fee */
+  treasury?: PublicKey | Pda;
+};
+
+// Data.
+export type CreateRecordWithProofInstructionData = {
+  discriminator: number;
+  proof: Array<Uint8Array>;
+  expiration: bigint;
+  contentType: number;
+  bump: number;
+  seed: Uint8Array;
+  data: Uint8Array;
+};
+
+export type CreateRecordWithProofInstructionDataArgs = {
+  proof: Array<Uint8Array>;
+  expiration: number | bigint;
+  contentType: number;
+  bump: number;
+  seed: Uint8Array;
+  data: Uint8Array;
+};
+
+export function getCreateRecordWithProofInstructionDataSerializer(): Serializer<
+  CreateRecordWithProofInstructionDataArgs,
+  CreateRecordWithProofInstructionData
+> {
+  return mapSerializer<
+    CreateRecordWithProofInstructionDataArgs,
+    any,
+    CreateRecordWithProofInstructionData
+  >(
+    struct<CreateRecordWithProofInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['proof', array(bytes({ size: 32 }), { size: u8() })],
+        ['expiration', i64()],
+        ['contentType', u8()],
+        ['bump', u8()],
+        ['seed', bytes({ size: u8() })],
+        ['data', bytes()],
+      ],
+      { description: 'CreateRecordWithProofInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 34 })
+  ) as Serializer<
+    CreateRecordWithProofInstructionDataArgs,
+    CreateRecordWithProofInstructionData
+  >;
+}
+
+// Args.
+export type CreateRecordWithProofInstructionArgs =
+  CreateRecordWithProofInstructionDataArgs;
+
+// Instruction.
+export function createRecordWithProof(
+  context: Pick<Context, 'programs'>,
+  input: CreateRecordWithProofInstructionAccounts &
+    CreateRecordWithProofInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    owner: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.owner ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    class: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+    record: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.record ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    authority: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    classDelegate: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    schema: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.schema ?? null,
+    },
+    treasury: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.treasury ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CreateRecordWithProofInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreateRecordWithProofInstructionDataSerializer().serialize(
+    resolvedArgs as CreateRecordWithProofInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
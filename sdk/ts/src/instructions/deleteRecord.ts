@@ -35,11 +35,15 @@ export type DeleteRecordInstructionAccounts = {
   /** Record account to be updated */
   record: PublicKey | Pda;
   /** Class account of the record */
-  class?: PublicKey | Pda;
+  class: PublicKey | Pda;
   /** Token2022 Program used to close the mint account */
   token2022Program?: PublicKey | Pda;
   /** Mint account for the tokenized record */
   mint?: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Optional record delegate account of the authority */
+  recordDelegate?: PublicKey | Pda;
 };
 
 // Data.
@@ -93,7 +97,7 @@ export function deleteRecord(
     },
     class: {
       index: 3,
-      isWritable: false as boolean,
+      isWritable: true as boolean,
       value: input.class ?? null,
     },
     token2022Program: {
@@ -102,6 +106,16 @@ export function deleteRecord(
       value: input.token2022Program ?? null,
     },
     mint: { index: 5, isWritable: true as boolean, value: input.mint ?? null },
+    classDelegate: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    recordDelegate: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.recordDelegate ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Accounts in order.
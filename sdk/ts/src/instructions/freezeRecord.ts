@@ -35,6 +35,8 @@ export type FreezeRecordInstructionAccounts = {
   record: PublicKey | Pda;
   /** Class account of the record */
   class: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
 };
 
 // Data.
@@ -96,6 +98,11 @@ export function freezeRecord(
       isWritable: false as boolean,
       value: input.class ?? null,
     },
+    classDelegate: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -41,6 +41,8 @@ export type FreezeTokenizedRecordInstructionAccounts = {
   class: PublicKey | Pda;
   /** Token2022 Program used to freeze/unfreeze the tokenized record */
   token2022?: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
 };
 
 // Data.
@@ -118,6 +120,11 @@ export function freezeTokenizedRecord(
       isWritable: false as boolean,
       value: input.token2022 ?? null,
     },
+    classDelegate: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
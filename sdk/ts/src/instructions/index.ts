@@ -6,19 +6,49 @@
  * @see https://github.com/trzledgerfoundation-idl/codoma
  */
 
+export * from './acceptClassAuthority';
+export * from './addClassDelegate';
+export * from './approveRecordDelegate';
+export * from './batchCreateRecords';
+export * from './batchDeleteRecords';
+export * from './batchFreezeRecords';
+export * from './beginRecordWrite';
 export * from './burnTokenizedRecord';
+export * from './cancelClassAuthorityTransfer';
+export * from './cancelRecordWrite';
+export * from './closeClass';
+export * from './closeExpiredRecord';
+export * from './compareAndSwapRecordData';
+export * from './compareAndSwapRecordExpiry';
+export * from './createBufferedRecord';
 export * from './createClass';
 export * from './createRecord';
+export * from './createRecordFromSignature';
 export * from './createRecordTokenizable';
+export * from './createRecordWithProof';
 export * from './deleteRecord';
+export * from './finalizeRecordWrite';
 export * from './freezeClass';
 export * from './freezeRecord';
 export * from './freezeTokenizedRecord';
+export * from './migrateClassLayout';
+export * from './migrateRecordClass';
+export * from './migrateRecordLayout';
 export * from './mintTokenizedRecord';
+export * from './patchRecordData';
+export * from './proposeClassAuthority';
+export * from './recreateRecord';
+export * from './revokeClassDelegate';
+export * from './revokeRecord';
+export * from './revokeRecordDelegate';
+export * from './setClassSchema';
 export * from './transferRecord';
 export * from './transferTokenizedRecord';
-export * from './updateClassAuthority';
+export * from './updateClassFee';
+export * from './updateClassMerkleRoot';
 export * from './updateClassMetadata';
+export * from './updateClassPolicy';
 export * from './updateRecord';
 export * from './updateRecordExpiry';
 export * from './updateRecordTokenizable';
+export * from './writeRecordChunk';
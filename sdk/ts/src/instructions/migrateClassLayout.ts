@@ -0,0 +1,160 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateClassLayoutInstructionAccounts = {
+  /** Authority of the class */
+  authority: Signer;
+  /** Account that will pay for the larger class account */
+  payer: Signer;
+  /** Legacy class account to be migrated */
+  class: PublicKey | Pda;
+  /** Group mint account of the class, it may not be initialized */
+  group: PublicKey | Pda;
+  /** System Program used to resize our class account */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateClassLayoutInstructionData = {
+  discriminator: number;
+  groupBump: number;
+  legacyRecordCount: bigint;
+};
+
+export type MigrateClassLayoutInstructionDataArgs = {
+  groupBump: number;
+  legacyRecordCount: number | bigint;
+};
+
+export function getMigrateClassLayoutInstructionDataSerializer(): Serializer<
+  MigrateClassLayoutInstructionDataArgs,
+  MigrateClassLayoutInstructionData
+> {
+  return mapSerializer<
+    MigrateClassLayoutInstructionDataArgs,
+    any,
+    MigrateClassLayoutInstructionData
+  >(
+    struct<MigrateClassLayoutInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['groupBump', u8()],
+        ['legacyRecordCount', u64()],
+      ],
+      { description: 'MigrateClassLayoutInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 43 })
+  ) as Serializer<
+    MigrateClassLayoutInstructionDataArgs,
+    MigrateClassLayoutInstructionData
+  >;
+}
+
+// Args.
+export type MigrateClassLayoutInstructionArgs =
+  MigrateClassLayoutInstructionDataArgs;
+
+// Instruction.
+export function migrateClassLayout(
+  context: Pick<Context, 'programs'>,
+  input: MigrateClassLayoutInstructionAccounts &
+    MigrateClassLayoutInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    class: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+    group: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.group ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MigrateClassLayoutInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigrateClassLayoutInstructionDataSerializer().serialize(
+    resolvedArgs as MigrateClassLayoutInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,213 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateRecordClassInstructionAccounts = {
+  /** Authority of the current class or a class delegate with the delete permission */
+  authority: Signer;
+  /** Authority of the new class or a class delegate with the create permission */
+  newAuthority: Signer;
+  /** Account that will pay for the new record account and get refunded for the old one */
+  payer: Signer;
+  /** Record account to be migrated */
+  record: PublicKey | Pda;
+  /** Current class account of the record */
+  class: PublicKey | Pda;
+  /** Record account to be created in the new class */
+  newRecord: PublicKey | Pda;
+  /** Class account the record is migrated to */
+  newClass: PublicKey | Pda;
+  /** System Program used to create the new record account */
+  systemProgram?: PublicKey | Pda;
+  /** Schema account of the new class, required if the new class has a schema */
+  schema?: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Optional class delegate account of the new authority */
+  newClassDelegate?: PublicKey | Pda;
+  /** Mint account of the record token, required if the record is tokenized */
+  mint?: PublicKey | Pda;
+  /** Token account holding the record token */
+  tokenAccount?: PublicKey | Pda;
+  /** Token2022 Program used to burn the record token */
+  token2022?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateRecordClassInstructionData = {
+  discriminator: number;
+  bump: number;
+};
+
+export type MigrateRecordClassInstructionDataArgs = { bump: number };
+
+export function getMigrateRecordClassInstructionDataSerializer(): Serializer<
+  MigrateRecordClassInstructionDataArgs,
+  MigrateRecordClassInstructionData
+> {
+  return mapSerializer<
+    MigrateRecordClassInstructionDataArgs,
+    any,
+    MigrateRecordClassInstructionData
+  >(
+    struct<MigrateRecordClassInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['bump', u8()],
+      ],
+      { description: 'MigrateRecordClassInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 40 })
+  ) as Serializer<
+    MigrateRecordClassInstructionDataArgs,
+    MigrateRecordClassInstructionData
+  >;
+}
+
+// Args.
+export type MigrateRecordClassInstructionArgs =
+  MigrateRecordClassInstructionDataArgs;
+
+// Instruction.
+export function migrateRecordClass(
+  context: Pick<Context, 'programs'>,
+  input: MigrateRecordClassInstructionAccounts &
+    MigrateRecordClassInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    newAuthority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.newAuthority ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    record: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.record ?? null,
+    },
+    class: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+    newRecord: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.newRecord ?? null,
+    },
+    newClass: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.newClass ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    schema: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.schema ?? null,
+    },
+    classDelegate: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    newClassDelegate: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.newClassDelegate ?? null,
+    },
+    mint: { index: 11, isWritable: true as boolean, value: input.mint ?? null },
+    tokenAccount: {
+      index: 12,
+      isWritable: true as boolean,
+      value: input.tokenAccount ?? null,
+    },
+    token2022: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.token2022 ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MigrateRecordClassInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigrateRecordClassInstructionDataSerializer().serialize(
+    resolvedArgs as MigrateRecordClassInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
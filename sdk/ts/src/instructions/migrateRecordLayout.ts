@@ -17,7 +17,6 @@ import {
 import {
   Serializer,
   mapSerializer,
-  publicKey as publicKeySerializer,
   struct,
   u8,
 } from '@trezoaplex-foundation/umi/serializers';
@@ -28,59 +27,57 @@ import {
 } from '../shared';
 
 // Accounts.
-export type UpdateClassAuthorityInstructionAccounts = {
-  /** Authority used to update a class */
-  authority: Signer;
-  /** Account that will pay of get refunded for the class update */
+export type MigrateRecordLayoutInstructionAccounts = {
+  /** Account that will pay for the larger record account */
   payer: Signer;
-  /** Class account to be updated */
+  /** Legacy record account to be migrated */
+  record: PublicKey | Pda;
+  /** Class account of the record, already migrated */
   class: PublicKey | Pda;
-  /** System Program used to extend our class account */
+  /** System Program used to resize our record account */
   systemProgram?: PublicKey | Pda;
 };
 
 // Data.
-export type UpdateClassAuthorityInstructionData = {
+export type MigrateRecordLayoutInstructionData = {
   discriminator: number;
-  newAuthority: PublicKey;
+  mintBump: number;
 };
 
-export type UpdateClassAuthorityInstructionDataArgs = {
-  newAuthority: PublicKey;
-};
+export type MigrateRecordLayoutInstructionDataArgs = { mintBump: number };
 
-export function getUpdateClassAuthorityInstructionDataSerializer(): Serializer<
-  UpdateClassAuthorityInstructionDataArgs,
-  UpdateClassAuthorityInstructionData
+export function getMigrateRecordLayoutInstructionDataSerializer(): Serializer<
+  MigrateRecordLayoutInstructionDataArgs,
+  MigrateRecordLayoutInstructionData
 > {
   return mapSerializer<
-    UpdateClassAuthorityInstructionDataArgs,
+    MigrateRecordLayoutInstructionDataArgs,
     any,
-    UpdateClassAuthorityInstructionData
+    MigrateRecordLayoutInstructionData
   >(
-    struct<UpdateClassAuthorityInstructionData>(
+    struct<MigrateRecordLayoutInstructionData>(
       [
         ['discriminator', u8()],
-        ['newAuthority', publicKeySerializer()],
+        ['mintBump', u8()],
       ],
-      { description: 'UpdateClassAuthorityInstructionData' }
+      { description: 'MigrateRecordLayoutInstructionData' }
     ),
-    (value) => ({ ...value, discriminator: 2 })
+    (value) => ({ ...value, discriminator: 44 })
   ) as Serializer<
-    UpdateClassAuthorityInstructionDataArgs,
-    UpdateClassAuthorityInstructionData
+    MigrateRecordLayoutInstructionDataArgs,
+    MigrateRecordLayoutInstructionData
   >;
 }
 
 // Args.
-export type UpdateClassAuthorityInstructionArgs =
-  UpdateClassAuthorityInstructionDataArgs;
+export type MigrateRecordLayoutInstructionArgs =
+  MigrateRecordLayoutInstructionDataArgs;
 
 // Instruction.
-export function updateClassAuthority(
+export function migrateRecordLayout(
   context: Pick<Context, 'programs'>,
-  input: UpdateClassAuthorityInstructionAccounts &
-    UpdateClassAuthorityInstructionArgs
+  input: MigrateRecordLayoutInstructionAccounts &
+    MigrateRecordLayoutInstructionArgs
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -90,15 +87,15 @@ export function updateClassAuthority(
 
   // Accounts.
   const resolvedAccounts = {
-    authority: {
+    payer: {
       index: 0,
       isWritable: true as boolean,
-      value: input.authority ?? null,
+      value: input.payer ?? null,
     },
-    payer: {
+    record: {
       index: 1,
       isWritable: true as boolean,
-      value: input.payer ?? null,
+      value: input.record ?? null,
     },
     class: {
       index: 2,
@@ -113,7 +110,7 @@ export function updateClassAuthority(
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
-  const resolvedArgs: UpdateClassAuthorityInstructionArgs = { ...input };
+  const resolvedArgs: MigrateRecordLayoutInstructionArgs = { ...input };
 
   // Default values.
   if (!resolvedAccounts.systemProgram.value) {
@@ -137,8 +134,8 @@ export function updateClassAuthority(
   );
 
   // Data.
-  const data = getUpdateClassAuthorityInstructionDataSerializer().serialize(
-    resolvedArgs as UpdateClassAuthorityInstructionDataArgs
+  const data = getMigrateRecordLayoutInstructionDataSerializer().serialize(
+    resolvedArgs as MigrateRecordLayoutInstructionDataArgs
   );
 
   // Bytes Created On Chain.
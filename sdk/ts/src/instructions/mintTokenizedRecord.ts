@@ -50,12 +50,23 @@ export type MintTokenizedRecordInstructionAccounts = {
   token2022?: PublicKey | Pda;
   /** System Program used to create our token */
   systemProgram?: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Optional record delegate account of the authority */
+  recordDelegate?: PublicKey | Pda;
 };
 
 // Data.
-export type MintTokenizedRecordInstructionData = { discriminator: number };
+export type MintTokenizedRecordInstructionData = {
+  discriminator: number;
+  mintBump: number;
+  groupBump: number;
+};
 
-export type MintTokenizedRecordInstructionDataArgs = {};
+export type MintTokenizedRecordInstructionDataArgs = {
+  mintBump: number;
+  groupBump: number;
+};
 
 export function getMintTokenizedRecordInstructionDataSerializer(): Serializer<
   MintTokenizedRecordInstructionDataArgs,
@@ -66,9 +77,14 @@ export function getMintTokenizedRecordInstructionDataSerializer(): Serializer<
     any,
     MintTokenizedRecordInstructionData
   >(
-    struct<MintTokenizedRecordInstructionData>([['discriminator', u8()]], {
-      description: 'MintTokenizedRecordInstructionData',
-    }),
+    struct<MintTokenizedRecordInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['mintBump', u8()],
+        ['groupBump', u8()],
+      ],
+      { description: 'MintTokenizedRecordInstructionData' }
+    ),
     (value) => ({ ...value, discriminator: 10 })
   ) as Serializer<
     MintTokenizedRecordInstructionDataArgs,
@@ -76,10 +92,15 @@ export function getMintTokenizedRecordInstructionDataSerializer(): Serializer<
   >;
 }
 
+// Args.
+export type MintTokenizedRecordInstructionArgs =
+  MintTokenizedRecordInstructionDataArgs;
+
 // Instruction.
 export function mintTokenizedRecord(
   context: Pick<Context, 'programs'>,
-  input: MintTokenizedRecordInstructionAccounts
+  input: MintTokenizedRecordInstructionAccounts &
+    MintTokenizedRecordInstructionArgs
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -112,7 +133,7 @@ export function mintTokenizedRecord(
     mint: { index: 4, isWritable: true as boolean, value: input.mint ?? null },
     class: {
       index: 5,
-      isWritable: false as boolean,
+      isWritable: true as boolean,
       value: input.class ?? null,
     },
     group: {
@@ -140,8 +161,21 @@ export function mintTokenizedRecord(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    classDelegate: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    recordDelegate: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.recordDelegate ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
+  // Arguments.
+  const resolvedArgs: MintTokenizedRecordInstructionArgs = { ...input };
+
   // Default values.
   if (!resolvedAccounts.associatedTokenProgram.value) {
     resolvedAccounts.associatedTokenProgram.value =
@@ -179,7 +213,9 @@ export function mintTokenizedRecord(
   );
 
   // Data.
-  const data = getMintTokenizedRecordInstructionDataSerializer().serialize({});
+  const data = getMintTokenizedRecordInstructionDataSerializer().serialize(
+    resolvedArgs as MintTokenizedRecordInstructionDataArgs
+  );
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
@@ -0,0 +1,160 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ProposeClassAuthorityInstructionAccounts = {
+  /** Current authority of the class */
+  authority: Signer;
+  /** Account that will pay for the pending authority account */
+  payer: Signer;
+  /** Class account whose authority is being transferred */
+  class: PublicKey | Pda;
+  /** Pending authority account of the class */
+  pendingClassAuthority: PublicKey | Pda;
+  /** System Program used to create the pending authority account */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ProposeClassAuthorityInstructionData = {
+  discriminator: number;
+  newAuthority: PublicKey;
+  bump: number;
+};
+
+export type ProposeClassAuthorityInstructionDataArgs = {
+  newAuthority: PublicKey;
+  bump: number;
+};
+
+export function getProposeClassAuthorityInstructionDataSerializer(): Serializer<
+  ProposeClassAuthorityInstructionDataArgs,
+  ProposeClassAuthorityInstructionData
+> {
+  return mapSerializer<
+    ProposeClassAuthorityInstructionDataArgs,
+    any,
+    ProposeClassAuthorityInstructionData
+  >(
+    struct<ProposeClassAuthorityInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['newAuthority', publicKeySerializer()],
+        ['bump', u8()],
+      ],
+      { description: 'ProposeClassAuthorityInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 15 })
+  ) as Serializer<
+    ProposeClassAuthorityInstructionDataArgs,
+    ProposeClassAuthorityInstructionData
+  >;
+}
+
+// Args.
+export type ProposeClassAuthorityInstructionArgs =
+  ProposeClassAuthorityInstructionDataArgs;
+
+// Instruction.
+export function proposeClassAuthority(
+  context: Pick<Context, 'programs'>,
+  input: ProposeClassAuthorityInstructionAccounts &
+    ProposeClassAuthorityInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    class: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.class ?? null,
+    },
+    pendingClassAuthority: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.pendingClassAuthority ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ProposeClassAuthorityInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getProposeClassAuthorityInstructionDataSerializer().serialize(
+    resolvedArgs as ProposeClassAuthorityInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,114 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RevokeClassDelegateInstructionAccounts = {
+  /** Authority of the class that will get refunded for the class delegate account */
+  authority: Signer;
+  /** Class account the delegate acts on */
+  class: PublicKey | Pda;
+  /** Class delegate account to be revoked */
+  classDelegate: PublicKey | Pda;
+};
+
+// Data.
+export type RevokeClassDelegateInstructionData = { discriminator: number };
+
+export type RevokeClassDelegateInstructionDataArgs = {};
+
+export function getRevokeClassDelegateInstructionDataSerializer(): Serializer<
+  RevokeClassDelegateInstructionDataArgs,
+  RevokeClassDelegateInstructionData
+> {
+  return mapSerializer<
+    RevokeClassDelegateInstructionDataArgs,
+    any,
+    RevokeClassDelegateInstructionData
+  >(
+    struct<RevokeClassDelegateInstructionData>([['discriminator', u8()]], {
+      description: 'RevokeClassDelegateInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 19 })
+  ) as Serializer<
+    RevokeClassDelegateInstructionDataArgs,
+    RevokeClassDelegateInstructionData
+  >;
+}
+
+// Instruction.
+export function revokeClassDelegate(
+  context: Pick<Context, 'programs'>,
+  input: RevokeClassDelegateInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    class: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.class ?? null,
+    },
+    classDelegate: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.classDelegate ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRevokeClassDelegateInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
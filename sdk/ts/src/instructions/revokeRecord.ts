@@ -0,0 +1,134 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u16,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RevokeRecordInstructionAccounts = {
+  /** Class authority or class delegate with the revoke permission */
+  authority: Signer;
+  /** Record account to be revoked */
+  record: PublicKey | Pda;
+  /** Class account of the record */
+  class: PublicKey | Pda;
+  /** Class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+};
+
+// Data.
+export type RevokeRecordInstructionData = {
+  discriminator: number;
+  reason: number;
+};
+
+export type RevokeRecordInstructionDataArgs = { reason: number };
+
+export function getRevokeRecordInstructionDataSerializer(): Serializer<
+  RevokeRecordInstructionDataArgs,
+  RevokeRecordInstructionData
+> {
+  return mapSerializer<
+    RevokeRecordInstructionDataArgs,
+    any,
+    RevokeRecordInstructionData
+  >(
+    struct<RevokeRecordInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['reason', u16()],
+      ],
+      { description: 'RevokeRecordInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 24 })
+  ) as Serializer<RevokeRecordInstructionDataArgs, RevokeRecordInstructionData>;
+}
+
+// Args.
+export type RevokeRecordInstructionArgs = RevokeRecordInstructionDataArgs;
+
+// Instruction.
+export function revokeRecord(
+  context: Pick<Context, 'programs'>,
+  input: RevokeRecordInstructionAccounts & RevokeRecordInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    record: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.record ?? null,
+    },
+    class: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+    classDelegate: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RevokeRecordInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRevokeRecordInstructionDataSerializer().serialize(
+    resolvedArgs as RevokeRecordInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
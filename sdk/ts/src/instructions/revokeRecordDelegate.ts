@@ -0,0 +1,114 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RevokeRecordDelegateInstructionAccounts = {
+  /** Record owner or owner that approved the delegate, it will get refunded for the record delegate account */
+  owner: Signer;
+  /** Record account the delegate acts on */
+  record: PublicKey | Pda;
+  /** Record delegate account to be revoked */
+  recordDelegate: PublicKey | Pda;
+};
+
+// Data.
+export type RevokeRecordDelegateInstructionData = { discriminator: number };
+
+export type RevokeRecordDelegateInstructionDataArgs = {};
+
+export function getRevokeRecordDelegateInstructionDataSerializer(): Serializer<
+  RevokeRecordDelegateInstructionDataArgs,
+  RevokeRecordDelegateInstructionData
+> {
+  return mapSerializer<
+    RevokeRecordDelegateInstructionDataArgs,
+    any,
+    RevokeRecordDelegateInstructionData
+  >(
+    struct<RevokeRecordDelegateInstructionData>([['discriminator', u8()]], {
+      description: 'RevokeRecordDelegateInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 21 })
+  ) as Serializer<
+    RevokeRecordDelegateInstructionDataArgs,
+    RevokeRecordDelegateInstructionData
+  >;
+}
+
+// Instruction.
+export function revokeRecordDelegate(
+  context: Pick<Context, 'programs'>,
+  input: RevokeRecordDelegateInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    owner: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.owner ?? null,
+    },
+    record: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.record ?? null,
+    },
+    recordDelegate: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.recordDelegate ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRevokeRecordDelegateInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
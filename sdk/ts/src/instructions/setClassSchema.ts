@@ -0,0 +1,163 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  SchemaField,
+  SchemaFieldArgs,
+  getSchemaFieldSerializer,
+} from '../types';
+
+// Accounts.
+export type SetClassSchemaInstructionAccounts = {
+  /** Authority of the class */
+  authority: Signer;
+  /** Account that will pay or get refunded for the schema account */
+  payer: Signer;
+  /** Class account the schema applies to */
+  class: PublicKey | Pda;
+  /** Schema account of the class */
+  schema: PublicKey | Pda;
+  /** System Program used to create or resize the schema account */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetClassSchemaInstructionData = {
+  discriminator: number;
+  bump: number;
+  fields: Array<SchemaField>;
+};
+
+export type SetClassSchemaInstructionDataArgs = {
+  bump: number;
+  fields: Array<SchemaFieldArgs>;
+};
+
+export function getSetClassSchemaInstructionDataSerializer(): Serializer<
+  SetClassSchemaInstructionDataArgs,
+  SetClassSchemaInstructionData
+> {
+  return mapSerializer<
+    SetClassSchemaInstructionDataArgs,
+    any,
+    SetClassSchemaInstructionData
+  >(
+    struct<SetClassSchemaInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['bump', u8()],
+        ['fields', array(getSchemaFieldSerializer(), { size: u8() })],
+      ],
+      { description: 'SetClassSchemaInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 22 })
+  ) as Serializer<
+    SetClassSchemaInstructionDataArgs,
+    SetClassSchemaInstructionData
+  >;
+}
+
+// Args.
+export type SetClassSchemaInstructionArgs = SetClassSchemaInstructionDataArgs;
+
+// Instruction.
+export function setClassSchema(
+  context: Pick<Context, 'programs'>,
+  input: SetClassSchemaInstructionAccounts & SetClassSchemaInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    class: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+    schema: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.schema ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetClassSchemaInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'systemProgram',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetClassSchemaInstructionDataSerializer().serialize(
+    resolvedArgs as SetClassSchemaInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -34,7 +34,11 @@ export type TransferRecordInstructionAccounts = {
   /** Record account to be updated */
   record: PublicKey | Pda;
   /** Class account of the record */
-  class?: PublicKey | Pda;
+  class: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Optional record delegate account of the authority */
+  recordDelegate?: PublicKey | Pda;
 };
 
 // Data.
@@ -99,6 +103,16 @@ export function transferRecord(
       isWritable: false as boolean,
       value: input.class ?? null,
     },
+    classDelegate: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    recordDelegate: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.recordDelegate ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -41,7 +41,9 @@ export type TransferTokenizedRecordInstructionAccounts = {
   /** Token2022 Program used to freeze/unfreeze the tokenized record */
   token2022?: PublicKey | Pda;
   /** Class account of the record */
-  class?: PublicKey | Pda;
+  class: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
 };
 
 // Data.
@@ -112,6 +114,11 @@ export function transferTokenizedRecord(
       isWritable: false as boolean,
       value: input.class ?? null,
     },
+    classDelegate: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -136,9 +143,8 @@ export function transferTokenizedRecord(
   );
 
   // Data.
-  const data = getTransferTokenizedRecordInstructionDataSerializer().serialize(
-    {}
-  );
+  const data =
+    getTransferTokenizedRecordInstructionDataSerializer().serialize({});
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
@@ -0,0 +1,129 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateClassFeeInstructionAccounts = {
+  /** Authority of the class */
+  authority: Signer;
+  /** Class account to be updated */
+  class: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateClassFeeInstructionData = {
+  discriminator: number;
+  creationFee: bigint;
+  treasury: PublicKey;
+};
+
+export type UpdateClassFeeInstructionDataArgs = {
+  creationFee: number | bigint;
+  treasury: PublicKey;
+};
+
+export function getUpdateClassFeeInstructionDataSerializer(): Serializer<
+  UpdateClassFeeInstructionDataArgs,
+  UpdateClassFeeInstructionData
+> {
+  return mapSerializer<
+    UpdateClassFeeInstructionDataArgs,
+    any,
+    UpdateClassFeeInstructionData
+  >(
+    struct<UpdateClassFeeInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['creationFee', u64()],
+        ['treasury', publicKeySerializer()],
+      ],
+      { description: 'UpdateClassFeeInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 32 })
+  ) as Serializer<
+    UpdateClassFeeInstructionDataArgs,
+    UpdateClassFeeInstructionData
+  >;
+}
+
+// Args.
+export type UpdateClassFeeInstructionArgs = UpdateClassFeeInstructionDataArgs;
+
+// Instruction.
+export function updateClassFee(
+  context: Pick<Context, 'programs'>,
+  input: UpdateClassFeeInstructionAccounts & UpdateClassFeeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    class: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateClassFeeInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateClassFeeInstructionDataSerializer().serialize(
+    resolvedArgs as UpdateClassFeeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
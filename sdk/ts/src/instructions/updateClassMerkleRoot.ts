@@ -0,0 +1,127 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateClassMerkleRootInstructionAccounts = {
+  /** Authority of the class */
+  authority: Signer;
+  /** Class account to be updated */
+  class: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateClassMerkleRootInstructionData = {
+  discriminator: number;
+  merkleRoot: Uint8Array;
+};
+
+export type UpdateClassMerkleRootInstructionDataArgs = {
+  merkleRoot: Uint8Array;
+};
+
+export function getUpdateClassMerkleRootInstructionDataSerializer(): Serializer<
+  UpdateClassMerkleRootInstructionDataArgs,
+  UpdateClassMerkleRootInstructionData
+> {
+  return mapSerializer<
+    UpdateClassMerkleRootInstructionDataArgs,
+    any,
+    UpdateClassMerkleRootInstructionData
+  >(
+    struct<UpdateClassMerkleRootInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['merkleRoot', bytes({ size: 32 })],
+      ],
+      { description: 'UpdateClassMerkleRootInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 33 })
+  ) as Serializer<
+    UpdateClassMerkleRootInstructionDataArgs,
+    UpdateClassMerkleRootInstructionData
+  >;
+}
+
+// Args.
+export type UpdateClassMerkleRootInstructionArgs =
+  UpdateClassMerkleRootInstructionDataArgs;
+
+// Instruction.
+export function updateClassMerkleRoot(
+  context: Pick<Context, 'programs'>,
+  input: UpdateClassMerkleRootInstructionAccounts &
+    UpdateClassMerkleRootInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    class: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateClassMerkleRootInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateClassMerkleRootInstructionDataSerializer().serialize(
+    resolvedArgs as UpdateClassMerkleRootInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
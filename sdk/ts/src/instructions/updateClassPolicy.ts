@@ -0,0 +1,128 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  ClassPolicy,
+  ClassPolicyArgs,
+  getClassPolicySerializer,
+} from '../types';
+
+// Accounts.
+export type UpdateClassPolicyInstructionAccounts = {
+  /** Authority of the class */
+  authority: Signer;
+  /** Class account to be updated */
+  class: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateClassPolicyInstructionData = {
+  discriminator: number;
+  policy: ClassPolicy;
+};
+
+export type UpdateClassPolicyInstructionDataArgs = { policy: ClassPolicyArgs };
+
+export function getUpdateClassPolicyInstructionDataSerializer(): Serializer<
+  UpdateClassPolicyInstructionDataArgs,
+  UpdateClassPolicyInstructionData
+> {
+  return mapSerializer<
+    UpdateClassPolicyInstructionDataArgs,
+    any,
+    UpdateClassPolicyInstructionData
+  >(
+    struct<UpdateClassPolicyInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['policy', getClassPolicySerializer()],
+      ],
+      { description: 'UpdateClassPolicyInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 23 })
+  ) as Serializer<
+    UpdateClassPolicyInstructionDataArgs,
+    UpdateClassPolicyInstructionData
+  >;
+}
+
+// Args.
+export type UpdateClassPolicyInstructionArgs =
+  UpdateClassPolicyInstructionDataArgs;
+
+// Instruction.
+export function updateClassPolicy(
+  context: Pick<Context, 'programs'>,
+  input: UpdateClassPolicyInstructionAccounts & UpdateClassPolicyInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trezoaRecordService',
+    'srsUi2TVUUCyGcZdopxJauk8ZBzgAaHHZCVUhm5ifPa'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    class: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.class ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateClassPolicyInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateClassPolicyInstructionDataSerializer().serialize(
+    resolvedArgs as UpdateClassPolicyInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
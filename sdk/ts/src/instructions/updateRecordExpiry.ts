@@ -39,6 +39,10 @@ export type UpdateRecordExpiryInstructionAccounts = {
   class: PublicKey | Pda;
   /** System Program used to extend our record account */
   systemProgram?: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Optional record delegate account of the authority */
+  recordDelegate?: PublicKey | Pda;
 };
 
 // Data.
@@ -115,6 +119,16 @@ export function updateRecordExpiry(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    classDelegate: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    recordDelegate: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.recordDelegate ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
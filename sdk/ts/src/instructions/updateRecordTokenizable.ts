@@ -39,6 +39,12 @@ export type UpdateRecordTokenizableInstructionAccounts = {
   class: PublicKey | Pda;
   /** System Program used to extend our record account */
   systemProgram?: PublicKey | Pda;
+  /** Optional class delegate account of the authority */
+  classDelegate?: PublicKey | Pda;
+  /** Optional record delegate account of the authority */
+  recordDelegate?: PublicKey | Pda;
+  /** Schema account of the class, required if the class has a schema */
+  schema?: PublicKey | Pda;
 };
 
 // Data.
@@ -117,6 +123,21 @@ export function updateRecordTokenizable(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    classDelegate: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.classDelegate ?? null,
+    },
+    recordDelegate: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.recordDelegate ?? null,
+    },
+    schema: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.schema ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
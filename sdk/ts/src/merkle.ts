@@ -0,0 +1,80 @@
+import { createHash } from 'crypto';
+import { PublicKey, publicKeyBytes } from '@trezoaplex-foundation/umi';
+
+/**
+ * Merkle tree of the owners allowed to create records in a class.
+ *
+ * The root is stored in the class with `updateClassMerkleRoot` and the proof
+ * of an owner is passed to `createRecordWithProof`.
+ */
+export class AllowlistTree {
+  private readonly layers: Uint8Array[][];
+
+  constructor(owners: PublicKey[]) {
+    this.layers = [owners.map(hashLeaf)];
+
+    while (this.layers[this.layers.length - 1].length > 1) {
+      const previous = this.layers[this.layers.length - 1];
+      const layer: Uint8Array[] = [];
+      for (let i = 0; i < previous.length; i += 2) {
+        // The last node of an odd layer is moved up unchanged
+        layer.push(
+          i + 1 < previous.length
+            ? hashNode(previous[i], previous[i + 1])
+            : previous[i]
+        );
+      }
+      this.layers.push(layer);
+    }
+  }
+
+  /** Root of the tree, zeroed if the allowlist is empty. */
+  root(): Uint8Array {
+    return this.layers[this.layers.length - 1][0] ?? new Uint8Array(32);
+  }
+
+  /** Sibling hashes from the leaf of `owner` to the root, if it is in the allowlist. */
+  proof(owner: PublicKey): Uint8Array[] | null {
+    const leaf = hashLeaf(owner);
+    let index = this.layers[0].findIndex((node) => compare(node, leaf) === 0);
+    if (index < 0) {
+      return null;
+    }
+
+    const proof: Uint8Array[] = [];
+    for (const layer of this.layers.slice(0, -1)) {
+      const sibling = layer[index ^ 1];
+      if (sibling !== undefined) {
+        proof.push(sibling);
+      }
+      index = Math.floor(index / 2);
+    }
+
+    return proof;
+  }
+}
+
+function hashLeaf(owner: PublicKey): Uint8Array {
+  return sha256([Uint8Array.of(0x00), publicKeyBytes(owner)]);
+}
+
+function hashNode(left: Uint8Array, right: Uint8Array): Uint8Array {
+  return compare(left, right) <= 0
+    ? sha256([Uint8Array.of(0x01), left, right])
+    : sha256([Uint8Array.of(0x01), right, left]);
+}
+
+function sha256(values: Uint8Array[]): Uint8Array {
+  const hash = createHash('sha256');
+  values.forEach((value) => hash.update(value));
+  return new Uint8Array(hash.digest());
+}
+
+function compare(a: Uint8Array, b: Uint8Array): number {
+  for (let i = 0; i < a.length; i++) {
+    if (a[i] !== b[i]) {
+      return a[i] - b[i];
+    }
+  }
+  return 0;
+}
@@ -0,0 +1,49 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Serializer,
+  bytes,
+  i64,
+  struct,
+  u32,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+/** Record created by BatchCreateRecords, with its owner and record accounts as remaining accounts */
+export type BatchRecord = {
+  expiration: bigint;
+  contentType: number;
+  bump: number;
+  seed: Uint8Array;
+  data: Uint8Array;
+};
+
+export type BatchRecordArgs = {
+  expiration: number | bigint;
+  contentType: number;
+  bump: number;
+  seed: Uint8Array;
+  data: Uint8Array;
+};
+
+export function getBatchRecordSerializer(): Serializer<
+  BatchRecordArgs,
+  BatchRecord
+> {
+  return struct<BatchRecord>(
+    [
+      ['expiration', i64()],
+      ['contentType', u8()],
+      ['bump', u8()],
+      ['seed', bytes({ size: u8() })],
+      ['data', bytes({ size: u32() })],
+    ],
+    { description: 'BatchRecord' }
+  ) as Serializer<BatchRecordArgs, BatchRecord>;
+}
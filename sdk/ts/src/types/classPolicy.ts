@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import { Serializer, struct } from '@trezoaplex-foundation/umi/serializers';
+import { Policy, PolicyArgs, getPolicySerializer } from '.';
+
+/** Policy of each action on the records of a class */
+export type ClassPolicy = {
+  updateData: Policy;
+  updateExpiry: Policy;
+  transfer: Policy;
+  delete: Policy;
+  tokenize: Policy;
+};
+
+export type ClassPolicyArgs = {
+  updateData: PolicyArgs;
+  updateExpiry: PolicyArgs;
+  transfer: PolicyArgs;
+  delete: PolicyArgs;
+  tokenize: PolicyArgs;
+};
+
+export function getClassPolicySerializer(): Serializer<
+  ClassPolicyArgs,
+  ClassPolicy
+> {
+  return struct<ClassPolicy>(
+    [
+      ['updateData', getPolicySerializer()],
+      ['updateExpiry', getPolicySerializer()],
+      ['transfer', getPolicySerializer()],
+      ['delete', getPolicySerializer()],
+      ['tokenize', getPolicySerializer()],
+    ],
+    { description: 'ClassPolicy' }
+  ) as Serializer<ClassPolicyArgs, ClassPolicy>;
+}
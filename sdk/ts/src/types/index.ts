@@ -7,4 +7,10 @@
  */
 
 export * from './additionalMetadata';
+export * from './batchRecord';
+export * from './classPolicy';
 export * from './metadata';
+export * from './policy';
+export * from './recordServiceEvent';
+export * from './schemaField';
+export * from './schemaFieldType';
@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import { Serializer, scalarEnum } from '@trezoaplex-foundation/umi/serializers';
+
+/** Who may perform an action on the records of a class */
+export enum Policy {
+  Owner,
+  Authority,
+  Either,
+}
+
+export type PolicyArgs = Policy;
+
+export function getPolicySerializer(): Serializer<PolicyArgs, Policy> {
+  return scalarEnum<Policy>(Policy, { description: 'Policy' }) as Serializer<
+    PolicyArgs,
+    Policy
+  >;
+}
@@ -0,0 +1,749 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  bool,
+  bytes,
+  dataEnum,
+  i64,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+  u32,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+/** Events emitted by the program through sol_log_data */
+export type RecordServiceEvent =
+  | {
+      __kind: 'ClassCreated';
+      class: PublicKey;
+      authority: PublicKey;
+      isPermissioned: boolean;
+      isFrozen: boolean;
+      isNonTransferable: boolean;
+      maxRecords: bigint;
+    }
+  | { __kind: 'ClassMetadataUpdated'; class: PublicKey }
+  | { __kind: 'ClassAuthorityUpdated'; class: PublicKey; authority: PublicKey }
+  | { __kind: 'ClassFrozen'; class: PublicKey; isFrozen: boolean }
+  | {
+      __kind: 'RecordCreated';
+      record: PublicKey;
+      class: PublicKey;
+      owner: PublicKey;
+      expiry: bigint;
+    }
+  | {
+      __kind: 'RecordDataUpdated';
+      record: PublicKey;
+      version: bigint;
+      hash: Uint8Array;
+    }
+  | {
+      __kind: 'RecordExpiryUpdated';
+      record: PublicKey;
+      expiry: bigint;
+      version: bigint;
+      hash: Uint8Array;
+    }
+  | { __kind: 'RecordTransferred'; record: PublicKey; newOwner: PublicKey }
+  | { __kind: 'RecordDeleted'; record: PublicKey }
+  | { __kind: 'RecordFrozen'; record: PublicKey; isFrozen: boolean }
+  | {
+      __kind: 'RecordTokenized';
+      record: PublicKey;
+      mint: PublicKey;
+      owner: PublicKey;
+    }
+  | {
+      __kind: 'TokenizedRecordFrozen';
+      record: PublicKey;
+      mint: PublicKey;
+      isFrozen: boolean;
+    }
+  | {
+      __kind: 'TokenizedRecordTransferred';
+      record: PublicKey;
+      mint: PublicKey;
+      newTokenAccount: PublicKey;
+    }
+  | { __kind: 'RecordBurned'; record: PublicKey; mint: PublicKey }
+  | { __kind: 'ExpiredRecordClosed'; record: PublicKey; owner: PublicKey }
+  | {
+      __kind: 'ClassAuthorityProposed';
+      class: PublicKey;
+      pendingAuthority: PublicKey;
+    }
+  | { __kind: 'ClassAuthorityTransferCancelled'; class: PublicKey }
+  | {
+      __kind: 'ClassDelegateUpdated';
+      class: PublicKey;
+      delegate: PublicKey;
+      permissions: number;
+    }
+  | { __kind: 'ClassDelegateRevoked'; class: PublicKey; delegate: PublicKey }
+  | {
+      __kind: 'RecordDelegateApproved';
+      record: PublicKey;
+      delegate: PublicKey;
+      permissions: number;
+      expiry: bigint;
+    }
+  | { __kind: 'RecordDelegateRevoked'; record: PublicKey; delegate: PublicKey }
+  | { __kind: 'ClassSchemaUpdated'; class: PublicKey }
+  | {
+      __kind: 'ClassPolicyUpdated';
+      class: PublicKey;
+      updateData: number;
+      updateExpiry: number;
+      transfer: number;
+      delete: number;
+      tokenize: number;
+    }
+  | {
+      __kind: 'RecordRevoked';
+      record: PublicKey;
+      reason: number;
+      revokedAt: bigint;
+    }
+  | {
+      __kind: 'ClassFeeUpdated';
+      class: PublicKey;
+      creationFee: bigint;
+      treasury: PublicKey;
+    }
+  | {
+      __kind: 'ClassMerkleRootUpdated';
+      class: PublicKey;
+      merkleRoot: Uint8Array;
+    }
+  | { __kind: 'ClassClosed'; class: PublicKey }
+  | {
+      __kind: 'RecordMigrated';
+      record: PublicKey;
+      newRecord: PublicKey;
+      newClass: PublicKey;
+    }
+  | {
+      __kind: 'RecordRecreated';
+      record: PublicKey;
+      class: PublicKey;
+      owner: PublicKey;
+      expiry: bigint;
+      generation: number;
+    }
+  | { __kind: 'RecordWriteStarted'; record: PublicKey }
+  | {
+      __kind: 'RecordChunkWritten';
+      record: PublicKey;
+      offset: number;
+      len: number;
+    }
+  | { __kind: 'RecordWriteCancelled'; record: PublicKey; isDeleted: boolean }
+  | { __kind: 'ClassLayoutMigrated'; class: PublicKey }
+  | { __kind: 'RecordLayoutMigrated'; record: PublicKey; class: PublicKey };
+
+export type RecordServiceEventArgs =
+  | {
+      __kind: 'ClassCreated';
+      class: PublicKey;
+      authority: PublicKey;
+      isPermissioned: boolean;
+      isFrozen: boolean;
+      isNonTransferable: boolean;
+      maxRecords: number | bigint;
+    }
+  | { __kind: 'ClassMetadataUpdated'; class: PublicKey }
+  | { __kind: 'ClassAuthorityUpdated'; class: PublicKey; authority: PublicKey }
+  | { __kind: 'ClassFrozen'; class: PublicKey; isFrozen: boolean }
+  | {
+      __kind: 'RecordCreated';
+      record: PublicKey;
+      class: PublicKey;
+      owner: PublicKey;
+      expiry: number | bigint;
+    }
+  | {
+      __kind: 'RecordDataUpdated';
+      record: PublicKey;
+      version: number | bigint;
+      hash: Uint8Array;
+    }
+  | {
+      __kind: 'RecordExpiryUpdated';
+      record: PublicKey;
+      expiry: number | bigint;
+      version: number | bigint;
+      hash: Uint8Array;
+    }
+  | { __kind: 'RecordTransferred'; record: PublicKey; newOwner: PublicKey }
+  | { __kind: 'RecordDeleted'; record: PublicKey }
+  | { __kind: 'RecordFrozen'; record: PublicKey; isFrozen: boolean }
+  | {
+      __kind: 'RecordTokenized';
+      record: PublicKey;
+      mint: PublicKey;
+      owner: PublicKey;
+    }
+  | {
+      __kind: 'TokenizedRecordFrozen';
+      record: PublicKey;
+      mint: PublicKey;
+      isFrozen: boolean;
+    }
+  | {
+      __kind: 'TokenizedRecordTransferred';
+      record: PublicKey;
+      mint: PublicKey;
+      newTokenAccount: PublicKey;
+    }
+  | { __kind: 'RecordBurned'; record: PublicKey; mint: PublicKey }
+  | { __kind: 'ExpiredRecordClosed'; record: PublicKey; owner: PublicKey }
+  | {
+      __kind: 'ClassAuthorityProposed';
+      class: PublicKey;
+      pendingAuthority: PublicKey;
+    }
+  | { __kind: 'ClassAuthorityTransferCancelled'; class: PublicKey }
+  | {
+      __kind: 'ClassDelegateUpdated';
+      class: PublicKey;
+      delegate: PublicKey;
+      permissions: number;
+    }
+  | { __kind: 'ClassDelegateRevoked'; class: PublicKey; delegate: PublicKey }
+  | {
+      __kind: 'RecordDelegateApproved';
+      record: PublicKey;
+      delegate: PublicKey;
+      permissions: number;
+      expiry: number | bigint;
+    }
+  | { __kind: 'RecordDelegateRevoked'; record: PublicKey; delegate: PublicKey }
+  | { __kind: 'ClassSchemaUpdated'; class: PublicKey }
+  | {
+      __kind: 'ClassPolicyUpdated';
+      class: PublicKey;
+      updateData: number;
+      updateExpiry: number;
+      transfer: number;
+      delete: number;
+      tokenize: number;
+    }
+  | {
+      __kind: 'RecordRevoked';
+      record: PublicKey;
+      reason: number;
+      revokedAt: number | bigint;
+    }
+  | {
+      __kind: 'ClassFeeUpdated';
+      class: PublicKey;
+      creationFee: number | bigint;
+      treasury: PublicKey;
+    }
+  | {
+      __kind: 'ClassMerkleRootUpdated';
+      class: PublicKey;
+      merkleRoot: Uint8Array;
+    }
+  | { __kind: 'ClassClosed'; class: PublicKey }
+  | {
+      __kind: 'RecordMigrated';
+      record: PublicKey;
+      newRecord: PublicKey;
+      newClass: PublicKey;
+    }
+  | {
+      __kind: 'RecordRecreated';
+      record: PublicKey;
+      class: PublicKey;
+      owner: PublicKey;
+      expiry: number | bigint;
+      generation: number;
+    }
+  | { __kind: 'RecordWriteStarted'; record: PublicKey }
+  | {
+      __kind: 'RecordChunkWritten';
+      record: PublicKey;
+      offset: number;
+      len: number;
+    }
+  | { __kind: 'RecordWriteCancelled'; record: PublicKey; isDeleted: boolean }
+  | { __kind: 'ClassLayoutMigrated'; class: PublicKey }
+  | { __kind: 'RecordLayoutMigrated'; record: PublicKey; class: PublicKey };
+
+export function getRecordServiceEventSerializer(): Serializer<
+  RecordServiceEventArgs,
+  RecordServiceEvent
+> {
+  return dataEnum<RecordServiceEvent>(
+    [
+      [
+        'ClassCreated',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'ClassCreated'>>([
+          ['class', publicKeySerializer()],
+          ['authority', publicKeySerializer()],
+          ['isPermissioned', bool()],
+          ['isFrozen', bool()],
+          ['isNonTransferable', bool()],
+          ['maxRecords', u64()],
+        ]),
+      ],
+      [
+        'ClassMetadataUpdated',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ClassMetadataUpdated'>
+        >([
+          ['class', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ClassAuthorityUpdated',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ClassAuthorityUpdated'>
+        >([
+          ['class', publicKeySerializer()],
+          ['authority', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ClassFrozen',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'ClassFrozen'>>([
+          ['class', publicKeySerializer()],
+          ['isFrozen', bool()],
+        ]),
+      ],
+      [
+        'RecordCreated',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'RecordCreated'>>([
+          ['record', publicKeySerializer()],
+          ['class', publicKeySerializer()],
+          ['owner', publicKeySerializer()],
+          ['expiry', i64()],
+        ]),
+      ],
+      [
+        'RecordDataUpdated',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'RecordDataUpdated'>
+        >([
+          ['record', publicKeySerializer()],
+          ['version', u64()],
+          ['hash', bytes({ size: 32 })],
+        ]),
+      ],
+      [
+        'RecordExpiryUpdated',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'RecordExpiryUpdated'>
+        >([
+          ['record', publicKeySerializer()],
+          ['expiry', i64()],
+          ['version', u64()],
+          ['hash', bytes({ size: 32 })],
+        ]),
+      ],
+      [
+        'RecordTransferred',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'RecordTransferred'>
+        >([
+          ['record', publicKeySerializer()],
+          ['newOwner', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'RecordDeleted',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'RecordDeleted'>>([
+          ['record', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'RecordFrozen',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'RecordFrozen'>>([
+          ['record', publicKeySerializer()],
+          ['isFrozen', bool()],
+        ]),
+      ],
+      [
+        'RecordTokenized',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'RecordTokenized'>>([
+          ['record', publicKeySerializer()],
+          ['mint', publicKeySerializer()],
+          ['owner', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'TokenizedRecordFrozen',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'TokenizedRecordFrozen'>
+        >([
+          ['record', publicKeySerializer()],
+          ['mint', publicKeySerializer()],
+          ['isFrozen', bool()],
+        ]),
+      ],
+      [
+        'TokenizedRecordTransferred',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'TokenizedRecordTransferred'>
+        >([
+          ['record', publicKeySerializer()],
+          ['mint', publicKeySerializer()],
+          ['newTokenAccount', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'RecordBurned',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'RecordBurned'>>([
+          ['record', publicKeySerializer()],
+          ['mint', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ExpiredRecordClosed',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ExpiredRecordClosed'>
+        >([
+          ['record', publicKeySerializer()],
+          ['owner', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ClassAuthorityProposed',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ClassAuthorityProposed'>
+        >([
+          ['class', publicKeySerializer()],
+          ['pendingAuthority', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ClassAuthorityTransferCancelled',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ClassAuthorityTransferCancelled'>
+        >([
+          ['class', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ClassDelegateUpdated',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ClassDelegateUpdated'>
+        >([
+          ['class', publicKeySerializer()],
+          ['delegate', publicKeySerializer()],
+          ['permissions', u8()],
+        ]),
+      ],
+      [
+        'ClassDelegateRevoked',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ClassDelegateRevoked'>
+        >([
+          ['class', publicKeySerializer()],
+          ['delegate', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'RecordDelegateApproved',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'RecordDelegateApproved'>
+        >([
+          ['record', publicKeySerializer()],
+          ['delegate', publicKeySerializer()],
+          ['permissions', u8()],
+          ['expiry', i64()],
+        ]),
+      ],
+      [
+        'RecordDelegateRevoked',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'RecordDelegateRevoked'>
+        >([
+          ['record', publicKeySerializer()],
+          ['delegate', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ClassSchemaUpdated',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ClassSchemaUpdated'>
+        >([
+          ['class', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ClassPolicyUpdated',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ClassPolicyUpdated'>
+        >([
+          ['class', publicKeySerializer()],
+          ['updateData', u8()],
+          ['updateExpiry', u8()],
+          ['transfer', u8()],
+          ['delete', u8()],
+          ['tokenize', u8()],
+        ]),
+      ],
+      [
+        'RecordRevoked',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'RecordRevoked'>>([
+          ['record', publicKeySerializer()],
+          ['reason', u16()],
+          ['revokedAt', i64()],
+        ]),
+      ],
+      [
+        'ClassFeeUpdated',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'ClassFeeUpdated'>>([
+          ['class', publicKeySerializer()],
+          ['creationFee', u64()],
+          ['treasury', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ClassMerkleRootUpdated',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ClassMerkleRootUpdated'>
+        >([
+          ['class', publicKeySerializer()],
+          ['merkleRoot', bytes({ size: 32 })],
+        ]),
+      ],
+      [
+        'ClassClosed',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'ClassClosed'>>([
+          ['class', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'RecordMigrated',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'RecordMigrated'>>([
+          ['record', publicKeySerializer()],
+          ['newRecord', publicKeySerializer()],
+          ['newClass', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'RecordRecreated',
+        struct<GetDataEnumKindContent<RecordServiceEvent, 'RecordRecreated'>>([
+          ['record', publicKeySerializer()],
+          ['class', publicKeySerializer()],
+          ['owner', publicKeySerializer()],
+          ['expiry', i64()],
+          ['generation', u32()],
+        ]),
+      ],
+      [
+        'RecordWriteStarted',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'RecordWriteStarted'>
+        >([
+          ['record', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'RecordChunkWritten',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'RecordChunkWritten'>
+        >([
+          ['record', publicKeySerializer()],
+          ['offset', u32()],
+          ['len', u32()],
+        ]),
+      ],
+      [
+        'RecordWriteCancelled',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'RecordWriteCancelled'>
+        >([
+          ['record', publicKeySerializer()],
+          ['isDeleted', bool()],
+        ]),
+      ],
+      [
+        'ClassLayoutMigrated',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'ClassLayoutMigrated'>
+        >([
+          ['class', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'RecordLayoutMigrated',
+        struct<
+          GetDataEnumKindContent<RecordServiceEvent, 'RecordLayoutMigrated'>
+        >([
+          ['record', publicKeySerializer()],
+          ['class', publicKeySerializer()],
+        ]),
+      ],
+    ],
+    { description: 'RecordServiceEvent' }
+  ) as Serializer<RecordServiceEventArgs, RecordServiceEvent>;
+}
+
+// Data Enum Helpers.
+export function recordServiceEvent(
+  kind: 'ClassCreated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassCreated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassCreated'>;
+export function recordServiceEvent(
+  kind: 'ClassMetadataUpdated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassMetadataUpdated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassMetadataUpdated'>;
+export function recordServiceEvent(
+  kind: 'ClassAuthorityUpdated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassAuthorityUpdated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassAuthorityUpdated'>;
+export function recordServiceEvent(
+  kind: 'ClassFrozen',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassFrozen'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassFrozen'>;
+export function recordServiceEvent(
+  kind: 'RecordCreated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordCreated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordCreated'>;
+export function recordServiceEvent(
+  kind: 'RecordDataUpdated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordDataUpdated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordDataUpdated'>;
+export function recordServiceEvent(
+  kind: 'RecordExpiryUpdated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordExpiryUpdated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordExpiryUpdated'>;
+export function recordServiceEvent(
+  kind: 'RecordTransferred',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordTransferred'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordTransferred'>;
+export function recordServiceEvent(
+  kind: 'RecordDeleted',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordDeleted'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordDeleted'>;
+export function recordServiceEvent(
+  kind: 'RecordFrozen',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordFrozen'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordFrozen'>;
+export function recordServiceEvent(
+  kind: 'RecordTokenized',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordTokenized'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordTokenized'>;
+export function recordServiceEvent(
+  kind: 'TokenizedRecordFrozen',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'TokenizedRecordFrozen'>
+): GetDataEnumKind<RecordServiceEventArgs, 'TokenizedRecordFrozen'>;
+export function recordServiceEvent(
+  kind: 'TokenizedRecordTransferred',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'TokenizedRecordTransferred'>
+): GetDataEnumKind<RecordServiceEventArgs, 'TokenizedRecordTransferred'>;
+export function recordServiceEvent(
+  kind: 'RecordBurned',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordBurned'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordBurned'>;
+export function recordServiceEvent(
+  kind: 'ExpiredRecordClosed',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ExpiredRecordClosed'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ExpiredRecordClosed'>;
+export function recordServiceEvent(
+  kind: 'ClassAuthorityProposed',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassAuthorityProposed'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassAuthorityProposed'>;
+export function recordServiceEvent(
+  kind: 'ClassAuthorityTransferCancelled',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassAuthorityTransferCancelled'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassAuthorityTransferCancelled'>;
+export function recordServiceEvent(
+  kind: 'ClassDelegateUpdated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassDelegateUpdated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassDelegateUpdated'>;
+export function recordServiceEvent(
+  kind: 'ClassDelegateRevoked',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassDelegateRevoked'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassDelegateRevoked'>;
+export function recordServiceEvent(
+  kind: 'RecordDelegateApproved',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordDelegateApproved'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordDelegateApproved'>;
+export function recordServiceEvent(
+  kind: 'RecordDelegateRevoked',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordDelegateRevoked'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordDelegateRevoked'>;
+export function recordServiceEvent(
+  kind: 'ClassSchemaUpdated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassSchemaUpdated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassSchemaUpdated'>;
+export function recordServiceEvent(
+  kind: 'ClassPolicyUpdated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassPolicyUpdated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassPolicyUpdated'>;
+export function recordServiceEvent(
+  kind: 'RecordRevoked',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordRevoked'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordRevoked'>;
+export function recordServiceEvent(
+  kind: 'ClassFeeUpdated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassFeeUpdated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassFeeUpdated'>;
+export function recordServiceEvent(
+  kind: 'ClassMerkleRootUpdated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassMerkleRootUpdated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassMerkleRootUpdated'>;
+export function recordServiceEvent(
+  kind: 'ClassClosed',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassClosed'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassClosed'>;
+export function recordServiceEvent(
+  kind: 'RecordMigrated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordMigrated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordMigrated'>;
+export function recordServiceEvent(
+  kind: 'RecordRecreated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordRecreated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordRecreated'>;
+export function recordServiceEvent(
+  kind: 'RecordWriteStarted',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordWriteStarted'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordWriteStarted'>;
+export function recordServiceEvent(
+  kind: 'RecordChunkWritten',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordChunkWritten'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordChunkWritten'>;
+export function recordServiceEvent(
+  kind: 'RecordWriteCancelled',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordWriteCancelled'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordWriteCancelled'>;
+export function recordServiceEvent(
+  kind: 'ClassLayoutMigrated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'ClassLayoutMigrated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'ClassLayoutMigrated'>;
+export function recordServiceEvent(
+  kind: 'RecordLayoutMigrated',
+  data: GetDataEnumKindContent<RecordServiceEventArgs, 'RecordLayoutMigrated'>
+): GetDataEnumKind<RecordServiceEventArgs, 'RecordLayoutMigrated'>;
+export function recordServiceEvent<
+  K extends RecordServiceEventArgs['__kind'],
+  Data,
+>(kind: K, data?: Data) {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isRecordServiceEvent<K extends RecordServiceEvent['__kind']>(
+  kind: K,
+  value: RecordServiceEvent
+): value is RecordServiceEvent & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,51 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import {
+  Serializer,
+  bool,
+  string,
+  struct,
+  u16,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  SchemaFieldType,
+  SchemaFieldTypeArgs,
+  getSchemaFieldTypeSerializer,
+} from '.';
+
+/** Field of a class schema, string and bytes fields are limited to maxLen bytes */
+export type SchemaField = {
+  fieldType: SchemaFieldType;
+  isRequired: boolean;
+  maxLen: number;
+  name: string;
+};
+
+export type SchemaFieldArgs = {
+  fieldType: SchemaFieldTypeArgs;
+  isRequired: boolean;
+  maxLen: number;
+  name: string;
+};
+
+export function getSchemaFieldSerializer(): Serializer<
+  SchemaFieldArgs,
+  SchemaField
+> {
+  return struct<SchemaField>(
+    [
+      ['fieldType', getSchemaFieldTypeSerializer()],
+      ['isRequired', bool()],
+      ['maxLen', u16()],
+      ['name', string({ size: u8() })],
+    ],
+    { description: 'SchemaField' }
+  ) as Serializer<SchemaFieldArgs, SchemaField>;
+}
@@ -0,0 +1,30 @@
+/**
+ * This code was AUTOGENERATED using the codoma library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun codoma to update it.
+ *
+ * @see https://github.com/trzledgerfoundation-idl/codoma
+ */
+
+import { Serializer, scalarEnum } from '@trezoaplex-foundation/umi/serializers';
+
+/** Type of a class schema field */
+export enum SchemaFieldType {
+  U64,
+  I64,
+  Bool,
+  Pubkey,
+  String,
+  Bytes,
+}
+
+export type SchemaFieldTypeArgs = SchemaFieldType;
+
+export function getSchemaFieldTypeSerializer(): Serializer<
+  SchemaFieldTypeArgs,
+  SchemaFieldType
+> {
+  return scalarEnum<SchemaFieldType>(SchemaFieldType, {
+    description: 'SchemaFieldType',
+  }) as Serializer<SchemaFieldTypeArgs, SchemaFieldType>;
+}
@@ -35,6 +35,8 @@ describe('test', () => {
         const ix = program.getCreateClassInstruction({
             isPermissioned: false,
             isFrozen: false,
+            isNonTransferable: false,
+            maxRecords: 0,
             name: "twitter",
             metadata: "test",
             authority,